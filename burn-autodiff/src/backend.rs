@@ -1,5 +1,5 @@
 use crate::{grads::Gradients, graph::backward::backward, tensor::AutodiffTensor};
-use burn_tensor::backend::{AutodiffBackend, Backend};
+use burn_tensor::backend::{AutodiffBackend, Backend, MemoryUsage};
 use core::marker::PhantomData;
 
 /// Enable auto-differentiation on a backend.
@@ -40,6 +40,10 @@ impl<B: Backend> Backend for Autodiff<B> {
     fn sync(device: &B::Device) {
         B::sync(device);
     }
+
+    fn memory_usage(device: &B::Device) -> Option<MemoryUsage> {
+        B::memory_usage(device)
+    }
 }
 
 impl<B: Backend> AutodiffBackend for Autodiff<B> {
@@ -15,6 +15,9 @@ pub mod id;
 /// std environments.
 pub mod rand;
 
+/// Memory module contains types describing the memory used on a device.
+pub mod memory;
+
 /// Stub module contains types for stubs for non-std environments and for std environments.
 pub mod stub;
 
@@ -0,0 +1,8 @@
+/// The memory used by a backend or a memory management strategy on a device, in bytes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MemoryUsage {
+    /// The memory used by the tensors or resources handed out.
+    pub allocated: usize,
+    /// The memory reserved in the storage, including the memory kept for reuse.
+    pub reserved: usize,
+}
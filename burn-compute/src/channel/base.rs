@@ -1,3 +1,4 @@
+use crate::memory_management::MemoryUsage;
 use crate::server::{ComputeServer, Handle};
 use alloc::vec::Vec;
 use burn_common::reader::Reader;
@@ -19,4 +20,11 @@ pub trait ComputeChannel<Server: ComputeServer>: Clone + core::fmt::Debug {
 
     /// Wait for the completion of every task in the server.
     fn sync(&self);
+
+    /// The memory allocated and reserved by the server.
+    ///
+    /// Returns no memory used by default, for channels not forwarding the request to the server.
+    fn memory_usage(&self) -> MemoryUsage {
+        MemoryUsage::default()
+    }
 }
@@ -1,5 +1,6 @@
 use crate::{
     channel::ComputeChannel,
+    memory_management::MemoryUsage,
     server::{ComputeServer, Handle},
     tune::{AutotuneOperationSet, Tuner},
 };
@@ -71,6 +72,11 @@ where
         self.channel.sync()
     }
 
+    /// The memory allocated and reserved by the server.
+    pub fn memory_usage(&self) -> MemoryUsage {
+        self.channel.memory_usage()
+    }
+
     /// Executes the fastest kernel in the autotune operation, using (cached) runtime benchmarks
     pub fn execute_autotune(
         &self,
@@ -1,5 +1,7 @@
 use crate::storage::ComputeStorage;
 
+pub use burn_common::memory::MemoryUsage;
+
 /// The MemoryHandle trait is an abstract way to refer to some memory segment.
 /// It should not contain actual references to data.
 ///
@@ -50,4 +52,11 @@ pub trait MemoryManagement<Storage: ComputeStorage>: Send + core::fmt::Debug {
     /// This is useful if you need to time the deallocations based on async computation, or to
     /// change the mode of storage for different reasons.
     fn storage(&mut self) -> &mut Storage;
+
+    /// The memory currently allocated and reserved in the storage.
+    ///
+    /// Returns no memory used by default, for strategies not tracking their memory.
+    fn memory_usage(&self) -> MemoryUsage {
+        MemoryUsage::default()
+    }
 }
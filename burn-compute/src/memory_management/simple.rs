@@ -1,4 +1,4 @@
-use super::{MemoryHandle, MemoryManagement};
+use super::{MemoryHandle, MemoryManagement, MemoryUsage};
 use crate::{
     memory_id_type,
     storage::{ComputeStorage, StorageHandle, StorageUtilization},
@@ -202,6 +202,26 @@ impl<Storage: ComputeStorage> MemoryManagement<Storage> for SimpleMemoryManageme
     fn storage(&mut self) -> &mut Storage {
         &mut self.storage
     }
+
+    fn memory_usage(&self) -> MemoryUsage {
+        let mut usage = MemoryUsage::default();
+
+        for (chunk_id, (resource, slices)) in self.chunks.iter() {
+            usage.reserved += resource.size();
+
+            // A chunk with slices is only used through its slices.
+            if slices.is_empty() && !chunk_id.is_free() {
+                usage.allocated += resource.size();
+            }
+        }
+        for (slice_id, (resource, _chunk_id)) in self.slices.iter() {
+            if !slice_id.is_free() {
+                usage.allocated += resource.size();
+            }
+        }
+
+        usage
+    }
 }
 
 impl<Storage: ComputeStorage> SimpleMemoryManagement<Storage> {
@@ -351,7 +371,7 @@ impl<Storage: ComputeStorage> SimpleMemoryManagement<Storage> {
 #[cfg(test)]
 mod tests {
     use crate::{
-        memory_management::{MemoryHandle, MemoryManagement, SliceStrategy},
+        memory_management::{MemoryHandle, MemoryManagement, MemoryUsage, SliceStrategy},
         storage::BytesStorage,
     };
 
@@ -374,6 +394,42 @@ mod tests {
         assert!(x.can_mut());
     }
 
+    #[test]
+    fn memory_usage_tracks_used_chunks_and_slices() {
+        let mut memory_management = SimpleMemoryManagement::new(
+            BytesStorage::default(),
+            DeallocStrategy::Never,
+            SliceStrategy::Ratio(0.5),
+        );
+
+        let chunk = memory_management.reserve(8);
+        assert_eq!(
+            memory_management.memory_usage(),
+            MemoryUsage {
+                allocated: 8,
+                reserved: 8
+            }
+        );
+
+        core::mem::drop(chunk);
+        assert_eq!(
+            memory_management.memory_usage(),
+            MemoryUsage {
+                allocated: 0,
+                reserved: 8
+            }
+        );
+
+        let _slice = memory_management.reserve(6);
+        assert_eq!(
+            memory_management.memory_usage(),
+            MemoryUsage {
+                allocated: 6,
+                reserved: 8
+            }
+        );
+    }
+
     #[test]
     fn two_tensor_references_remove_mutability() {
         let mut memory_management = SimpleMemoryManagement::new(
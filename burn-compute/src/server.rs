@@ -1,7 +1,7 @@
 use core::fmt::Debug;
 
 use crate::{
-    memory_management::{MemoryHandle, MemoryManagement},
+    memory_management::{MemoryHandle, MemoryManagement, MemoryUsage},
     storage::ComputeStorage,
     tune::AutotuneKey,
 };
@@ -42,6 +42,13 @@ where
 
     /// Wait for the completion of every task in the server.
     fn sync(&mut self);
+
+    /// The memory allocated and reserved by the server.
+    ///
+    /// Returns no memory used by default, for servers not tracking their memory.
+    fn memory_usage(&self) -> MemoryUsage {
+        MemoryUsage::default()
+    }
 }
 
 /// Server handle containing the [memory handle](MemoryManagement::Handle).
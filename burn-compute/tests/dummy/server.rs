@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use burn_common::reader::Reader;
 use burn_compute::{
-    memory_management::{MemoryManagement, SimpleMemoryManagement},
+    memory_management::{MemoryManagement, MemoryUsage, SimpleMemoryManagement},
     server::{ComputeServer, Handle},
     storage::BytesStorage,
 };
@@ -61,4 +61,8 @@ where
     fn sync(&mut self) {
         // Nothing to do with dummy backend.
     }
+
+    fn memory_usage(&self) -> MemoryUsage {
+        self.memory_management.memory_usage()
+    }
 }
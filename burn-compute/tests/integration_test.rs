@@ -28,6 +28,19 @@ fn empty_allocates_memory() {
     assert_eq!(empty_resource.read().len(), 4);
 }
 
+#[test]
+fn memory_usage_counts_reserved_memory() {
+    let client = client(&DummyDevice);
+    let before = client.memory_usage();
+    let resource_description = client.empty(16);
+
+    let usage = client.memory_usage();
+
+    assert_eq!(usage.allocated, before.allocated + 16);
+    assert!(usage.reserved >= usage.allocated);
+    core::mem::drop(resource_description);
+}
+
 #[test]
 fn execute_elementwise_addition() {
     let client = client(&DummyDevice);
@@ -13,6 +13,12 @@ pub use burn_autodiff::Autodiff;
 #[cfg(feature = "fusion")]
 pub use burn_fusion::Fusion;
 
+#[cfg(feature = "profiler")]
+pub use burn_profiler as profiler;
+
+#[cfg(feature = "profiler")]
+pub use burn_profiler::Profiler;
+
 #[cfg(feature = "wgpu")]
 pub use burn_wgpu as wgpu;
 
@@ -11,10 +11,35 @@ pub struct Progress {
     pub items_total: usize,
 }
 
+/// The position of a data loader, used to resume iterating where a previous iterator stopped.
+#[derive(new, Clone, Debug, Default, PartialEq, Eq)]
+pub struct DataLoaderState {
+    /// The number of iterators created before the current one, which determines the shuffling
+    /// of the current iterator.
+    pub num_iterators: usize,
+
+    /// The number of batches already returned by the current iterator.
+    pub num_batches: usize,
+}
+
 /// A data loader iterator that can be used to iterate over a data loader.
 pub trait DataLoaderIterator<O>: Iterator<Item = O> {
     /// Returns the progress of the data loader.
     fn progress(&self) -> Progress;
+
+    /// Skip the given number of batches, returning the number of batches skipped, which is
+    /// smaller when the iterator ends.
+    ///
+    /// The default implementation loads the skipped batches, iterators that know which items
+    /// are part of each batch should skip them without loading them.
+    fn skip_batches(&mut self, num_batches: usize) -> usize {
+        let mut num_skipped = 0;
+        while num_skipped < num_batches && self.next().is_some() {
+            num_skipped += 1;
+        }
+
+        num_skipped
+    }
 }
 
 /// A data loader that can be used to iterate over a dataset.
@@ -24,4 +49,16 @@ pub trait DataLoader<O> {
     /// The number of items (not the number of batches nor the number of iterations),
     /// corresponding to the items_total of the progress returned by the iterator.
     fn num_items(&self) -> usize;
+    /// Returns a boxed [iterator](DataLoaderIterator) resuming from the given
+    /// [state](DataLoaderState).
+    ///
+    /// The default implementation only [skips](DataLoaderIterator::skip_batches) the batches
+    /// already returned, data loaders that shuffle their dataset should also restore the
+    /// shuffling of the iterator.
+    fn iter_from<'a>(&'a self, state: &DataLoaderState) -> Box<dyn DataLoaderIterator<O> + 'a> {
+        let mut iterator = self.iter();
+        iterator.skip_batches(state.num_batches);
+
+        iterator
+    }
 }
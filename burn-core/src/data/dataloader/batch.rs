@@ -1,6 +1,6 @@
 use super::{
-    batcher::Batcher, BatchStrategy, DataLoader, DataLoaderIterator, MultiThreadDataLoader,
-    Progress,
+    batcher::Batcher, BatchStrategy, DataLoader, DataLoaderIterator, DataLoaderState,
+    MultiThreadDataLoader, Progress,
 };
 use burn_dataset::{
     transform::{PartialDataset, ShuffledDataset},
@@ -15,6 +15,7 @@ pub struct BatchDataLoader<I, O> {
     dataset: Arc<dyn Dataset<I>>,
     batcher: Arc<dyn Batcher<I, O>>,
     rng: Option<spin::Mutex<rand::rngs::StdRng>>,
+    rng_initial: Option<rand::rngs::StdRng>,
 }
 
 impl<I, O> BatchDataLoader<I, O> {
@@ -41,6 +42,7 @@ impl<I, O> BatchDataLoader<I, O> {
             strategy,
             dataset,
             batcher,
+            rng_initial: rng.clone(),
             rng: rng.map(spin::Mutex::new),
         }
     }
@@ -126,6 +128,24 @@ impl<I: Send + Sync + Clone + 'static, O: Send + Sync> DataLoader<O> for BatchDa
     fn num_items(&self) -> usize {
         self.dataset.len()
     }
+
+    fn iter_from<'a>(&'a self, state: &DataLoaderState) -> Box<dyn DataLoaderIterator<O> + 'a> {
+        // Restore the rng as if the previous iterators were created, so that the dataset is
+        // shuffled the same way.
+        if let (Some(rng), Some(rng_initial)) = (&self.rng, &self.rng_initial) {
+            let mut rng = rng.lock();
+            *rng = rng_initial.clone();
+
+            for _ in 0..state.num_iterators {
+                let _seed: u64 = rng.sample(Standard);
+            }
+        }
+
+        let mut iterator = self.iter();
+        iterator.skip_batches(state.num_batches);
+
+        iterator
+    }
 }
 
 impl<I, O> BatchDataloaderIterator<I, O> {
@@ -179,11 +199,36 @@ impl<I, O> DataLoaderIterator<O> for BatchDataloaderIterator<I, O> {
     fn progress(&self) -> Progress {
         Progress::new(self.current_index, self.dataset.len())
     }
+
+    fn skip_batches(&mut self, num_batches: usize) -> usize {
+        let batch_size = match self.strategy.batch_size() {
+            Some(batch_size) if batch_size > 0 => batch_size,
+            _ => return default_skip_batches(self, num_batches),
+        };
+
+        // Only advance the index, so that the items of the skipped batches aren't loaded.
+        let num_items = self.dataset.len().saturating_sub(self.current_index);
+        let num_batches_left = num_items / batch_size + usize::from(num_items % batch_size != 0);
+        let num_skipped = num_batches.min(num_batches_left);
+        self.current_index = self
+            .dataset
+            .len()
+            .min(self.current_index + num_skipped * batch_size);
+
+        num_skipped
+    }
+}
+
+/// Skip the batches by loading them, like the default implementation of
+/// [skip_batches](DataLoaderIterator::skip_batches).
+fn default_skip_batches<O>(iterator: &mut impl Iterator<Item = O>, num_batches: usize) -> usize {
+    iterator.take(num_batches).count()
 }
 
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
+    use std::sync::atomic::{AtomicUsize, Ordering};
 
     use super::*;
     use crate::data::dataloader::batcher::TestBatcher;
@@ -252,4 +297,96 @@ mod tests {
 
         assert_eq!(items_single_thread, items_multi_thread);
     }
+
+    #[test]
+    fn test_batch_dataloader_resume_from_state() {
+        let batcher = Arc::new(TestBatcher::new());
+        let dataset = Arc::new(FakeDataset::<String>::new(27));
+        let dataloader = || {
+            BatchDataLoader::new(
+                Box::new(FixBatchStrategy::new(5)),
+                dataset.clone(),
+                batcher.clone(),
+                Some(StdRng::seed_from_u64(42)),
+            )
+        };
+
+        let dataloader_trained = dataloader();
+        let _first_epoch = dataloader_trained.iter().collect::<Vec<_>>();
+        let expected = dataloader_trained.iter().skip(2).collect::<Vec<_>>();
+
+        let dataloader_resumed = dataloader();
+        let state = DataLoaderState::new(1, 2);
+        let items = dataloader_resumed.iter_from(&state).collect::<Vec<_>>();
+
+        assert_eq!(items, expected);
+    }
+
+    #[test]
+    fn test_multi_thread_batch_dataloader_resume_from_state() {
+        let batcher = Arc::new(TestBatcher::new());
+        let dataset = Arc::new(FakeDataset::<String>::new(27));
+        let dataloader = || {
+            BatchDataLoader::multi_thread(
+                Box::new(FixBatchStrategy::new(2)),
+                dataset.clone(),
+                batcher.clone(),
+                4,
+                Some(StdRng::seed_from_u64(42)),
+            )
+        };
+
+        let dataloader_trained = dataloader();
+        let _first_epoch = dataloader_trained.iter().collect::<Vec<_>>();
+        let second_epoch = dataloader_trained.iter().collect::<Vec<_>>();
+
+        for num_batches in 0..=second_epoch.len() {
+            let state = DataLoaderState::new(1, num_batches);
+            let items = dataloader().iter_from(&state).collect::<Vec<_>>();
+
+            assert_eq!(items, second_epoch[num_batches..]);
+        }
+    }
+
+    #[test]
+    fn test_batch_dataloader_skip_batches_without_loading_items() {
+        struct CountingDataset {
+            dataset: FakeDataset<String>,
+            num_loaded: AtomicUsize,
+        }
+
+        impl Dataset<String> for CountingDataset {
+            fn get(&self, index: usize) -> Option<String> {
+                let item = self.dataset.get(index);
+                if item.is_some() {
+                    self.num_loaded.fetch_add(1, Ordering::Relaxed);
+                }
+
+                item
+            }
+
+            fn len(&self) -> usize {
+                self.dataset.len()
+            }
+        }
+
+        let dataset = Arc::new(CountingDataset {
+            dataset: FakeDataset::new(27),
+            num_loaded: AtomicUsize::new(0),
+        });
+        let dataloader = BatchDataLoader::new(
+            Box::new(FixBatchStrategy::new(5)),
+            dataset.clone(),
+            Arc::new(TestBatcher::new()),
+            None,
+        );
+
+        let items = dataloader
+            .iter_from(&DataLoaderState::new(0, 4))
+            .collect::<Vec<_>>();
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[1].len(), 2);
+        assert_eq!(dataset.num_loaded.load(Ordering::Relaxed), 7);
+    }
 }
@@ -1,10 +1,13 @@
-use super::{DataLoader, DataLoaderIterator, Progress};
+use super::{DataLoader, DataLoaderIterator, DataLoaderState, Progress};
 use std::sync::{mpsc, Arc};
 use std::thread;
 
 const MAX_QUEUED_ITEMS: usize = 100;
 
 /// A multi-threaded data loader that can be used to iterate over a dataset.
+///
+/// Each data loader is iterated in its own thread, and their batches are returned in turn so that
+/// the order of the batches is deterministic.
 pub struct MultiThreadDataLoader<O> {
     dataloaders: Vec<Arc<dyn DataLoader<O> + Send + Sync>>,
 }
@@ -20,10 +23,19 @@ pub enum Message<O> {
 }
 
 struct MultiThreadsDataloaderIterator<O> {
-    num_done: usize,
     workers: Vec<thread::JoinHandle<()>>,
-    receiver: mpsc::Receiver<Message<O>>,
+    receivers: Vec<mpsc::Receiver<Message<O>>>,
     progresses: Vec<Progress>,
+    /// The workers that may still return batches, in turn.
+    active: Vec<usize>,
+    /// The position in `active` of the worker returning the next batch.
+    current: usize,
+}
+
+/// The channels used to make a worker skip batches before it starts sending them.
+struct Skipper {
+    sender: mpsc::Sender<usize>,
+    receiver: mpsc::Receiver<(usize, Progress)>,
 }
 
 impl<O> MultiThreadDataLoader<O> {
@@ -46,9 +58,35 @@ where
     O: Send + 'static + std::fmt::Debug,
 {
     fn iter<'a>(&'a self) -> Box<dyn DataLoaderIterator<O> + 'a> {
-        let (sender, receiver) = mpsc::sync_channel::<Message<O>>(MAX_QUEUED_ITEMS);
+        self.spawn(None, 0)
+    }
+
+    fn num_items(&self) -> usize {
+        self.dataloaders.iter().map(|dl| dl.num_items()).sum()
+    }
+
+    fn iter_from<'a>(&'a self, state: &DataLoaderState) -> Box<dyn DataLoaderIterator<O> + 'a> {
+        self.spawn(
+            Some(DataLoaderState::new(state.num_iterators, 0)),
+            state.num_batches,
+        )
+    }
+}
+
+impl<O> MultiThreadDataLoader<O>
+where
+    O: Send + 'static + std::fmt::Debug,
+{
+    fn spawn(
+        &self,
+        state: Option<DataLoaderState>,
+        num_batches_skipped: usize,
+    ) -> Box<dyn DataLoaderIterator<O>> {
+        let num_queued_items = (MAX_QUEUED_ITEMS / self.dataloaders.len().max(1)).max(1);
 
         let mut progresses = Vec::with_capacity(self.dataloaders.len());
+        let mut receivers = Vec::with_capacity(self.dataloaders.len());
+        let mut skippers = Vec::with_capacity(self.dataloaders.len());
 
         let handlers: Vec<_> = self
             .dataloaders
@@ -57,11 +95,33 @@ where
             .enumerate()
             .map(|(index, dataloader)| {
                 let dataloader_cloned = dataloader;
-                let sender_cloned = sender.clone();
+                let (sender_cloned, receiver) = mpsc::sync_channel::<Message<O>>(num_queued_items);
+                let (skip_sender, skip_receiver) = mpsc::channel::<usize>();
+                let (skipped_sender, skipped_receiver) = mpsc::channel();
+                let state = state.clone();
                 progresses.push(Progress::new(0, dataloader_cloned.num_items()));
+                receivers.push(receiver);
+                skippers.push(Skipper {
+                    sender: skip_sender,
+                    receiver: skipped_receiver,
+                });
 
                 thread::spawn(move || {
-                    let mut iterator = dataloader_cloned.iter();
+                    let mut iterator = match state {
+                        Some(state) => dataloader_cloned.iter_from(&state),
+                        None => dataloader_cloned.iter(),
+                    };
+                    // Skip the batches requested until the skippers are dropped.
+                    for num_batches in skip_receiver.iter() {
+                        let num_skipped = iterator.skip_batches(num_batches);
+                        if skipped_sender
+                            .send((num_skipped, iterator.progress()))
+                            .is_err()
+                        {
+                            return;
+                        }
+                    }
+
                     while let Some(item) = iterator.next() {
                         let progress = iterator.progress();
 
@@ -78,29 +138,73 @@ where
             })
             .collect();
 
-        Box::new(MultiThreadsDataloaderIterator::new(
-            receiver, handlers, progresses,
-        ))
-    }
+        let mut iterator = MultiThreadsDataloaderIterator::new(receivers, handlers, progresses);
+        iterator.skip_batches_in_workers(&skippers, num_batches_skipped);
 
-    fn num_items(&self) -> usize {
-        self.dataloaders.iter().map(|dl| dl.num_items()).sum()
+        Box::new(iterator)
     }
 }
 
 impl<O> MultiThreadsDataloaderIterator<O> {
     pub fn new(
-        receiver: mpsc::Receiver<Message<O>>,
+        receivers: Vec<mpsc::Receiver<Message<O>>>,
         workers: Vec<thread::JoinHandle<()>>,
         progresses: Vec<Progress>,
     ) -> Self {
         MultiThreadsDataloaderIterator {
-            num_done: 0,
+            active: (0..workers.len()).collect(),
+            current: 0,
             workers,
-            receiver,
+            receivers,
             progresses,
         }
     }
+
+    /// Make the workers skip the given number of batches, in the same order as the batches are
+    /// returned, so that the items of the skipped batches are skipped by the data loader of each
+    /// worker.
+    fn skip_batches_in_workers(&mut self, skippers: &[Skipper], mut num_batches: usize) {
+        let skip = |worker: usize, num_batches: usize, progresses: &mut Vec<Progress>| {
+            let skipper = &skippers[worker];
+            skipper.sender.send(num_batches).ok();
+            let (num_skipped, progress) = skipper.receiver.recv().unwrap();
+            progresses[worker] = progress;
+
+            num_skipped
+        };
+
+        // Skip whole turns of the active workers, removing the workers that end.
+        while !self.active.is_empty() && num_batches >= self.active.len() {
+            let num_turns = num_batches / self.active.len();
+            let mut active = Vec::with_capacity(self.active.len());
+
+            for worker in self.active.iter().copied() {
+                let num_skipped = skip(worker, num_turns, &mut self.progresses);
+                num_batches -= num_skipped;
+                if num_skipped == num_turns {
+                    active.push(worker);
+                }
+            }
+            self.active = active;
+        }
+
+        // Then one batch for each worker in turn.
+        while num_batches > 0 && !self.active.is_empty() {
+            let worker = self.active[self.current];
+            match skip(worker, 1, &mut self.progresses) {
+                0 => {
+                    self.active.remove(self.current);
+                }
+                _ => {
+                    num_batches -= 1;
+                    self.current += 1;
+                }
+            }
+            if self.current >= self.active.len() {
+                self.current = 0;
+            }
+        }
+    }
 }
 impl<O: std::fmt::Debug> DataLoaderIterator<O> for MultiThreadsDataloaderIterator<O> {
     fn progress(&self) -> Progress {
@@ -124,8 +228,9 @@ impl<O: std::fmt::Debug> Iterator for MultiThreadsDataloaderIterator<O> {
             return None;
         }
 
-        loop {
-            let item = self.receiver.recv();
+        while !self.active.is_empty() {
+            let worker = self.active[self.current];
+            let item = self.receivers[worker].recv();
             let item = item.unwrap();
 
             match item {
@@ -133,19 +238,21 @@ impl<O: std::fmt::Debug> Iterator for MultiThreadsDataloaderIterator<O> {
                     if let Some(current) = self.progresses.get_mut(index) {
                         *current = progress;
                     }
+                    self.current = (self.current + 1) % self.active.len();
                     return Some(item);
                 }
                 Message::Done => {
-                    self.num_done += 1;
+                    self.active.remove(self.current);
+                    if self.current >= self.active.len() {
+                        self.current = 0;
+                    }
                 }
             };
+        }
 
-            if self.num_done == self.workers.len() {
-                while let Some(worker) = self.workers.pop() {
-                    worker.join().unwrap();
-                }
-                return None;
-            }
+        while let Some(worker) = self.workers.pop() {
+            worker.join().unwrap();
         }
+        None
     }
 }
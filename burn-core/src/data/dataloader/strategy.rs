@@ -24,6 +24,15 @@ pub trait BatchStrategy<I>: Send + Sync {
     ///
     /// The new strategy.
     fn new_like(&self) -> Box<dyn BatchStrategy<I>>;
+
+    /// The number of items of each batch, except the last one, if it is fixed.
+    ///
+    /// # Returns
+    ///
+    /// The batch size, used to skip batches without loading their items.
+    fn batch_size(&self) -> Option<usize> {
+        None
+    }
 }
 
 /// A strategy to batch items with a fixed batch size.
@@ -73,4 +82,8 @@ impl<I: Send + Sync + 'static> BatchStrategy<I> for FixBatchStrategy<I> {
     fn new_like(&self) -> Box<dyn BatchStrategy<I>> {
         Box::new(Self::new(self.batch_size))
     }
+
+    fn batch_size(&self) -> Option<usize> {
+        Some(self.batch_size)
+    }
 }
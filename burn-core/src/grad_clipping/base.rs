@@ -11,6 +11,13 @@ pub enum GradientClippingConfig {
 
     /// Clip the gradient by norm.
     Norm(f32),
+
+    /// Clip the gradients by the norm computed over all the gradients of the module.
+    GlobalNorm(f32),
+
+    /// Adaptive gradient clipping, clip the gradient of each parameter relative to the norm of
+    /// the parameter.
+    Adaptive(f32),
 }
 
 impl GradientClippingConfig {
@@ -23,6 +30,8 @@ impl GradientClippingConfig {
         match self {
             GradientClippingConfig::Value(val) => GradientClipping::Value(*val),
             GradientClippingConfig::Norm(val) => GradientClipping::Norm(*val),
+            GradientClippingConfig::GlobalNorm(val) => GradientClipping::GlobalNorm(*val),
+            GradientClippingConfig::Adaptive(val) => GradientClipping::Adaptive(*val),
         }
     }
 }
@@ -36,6 +45,17 @@ pub enum GradientClipping {
 
     /// Clip the gradient by norm.
     Norm(f32),
+
+    /// Clip the gradients by the norm computed over all the gradients of the module, so that
+    /// the global norm doesn't exceed the threshold.
+    GlobalNorm(f32),
+
+    /// Adaptive gradient clipping as described in
+    /// [High-Performance Large-Scale Image Recognition Without Normalization](https://arxiv.org/abs/2102.06171).
+    ///
+    /// The norm of the gradient of each parameter is clipped to the given ratio of the norm of
+    /// the parameter.
+    Adaptive(f32),
 }
 
 impl GradientClipping {
@@ -48,10 +68,19 @@ impl GradientClipping {
     /// # Returns
     ///
     /// The clipped gradient.
+    ///
+    /// # Notes
+    ///
+    /// Global norm and adaptive clipping need all the gradients of a module, or the parameters,
+    /// and should be applied with
+    /// [clip_gradients_params](GradientClipping::clip_gradients_params). On a single tensor,
+    /// global norm clipping clips the norm of the tensor, while adaptive clipping leaves it as is.
     pub fn clip_gradient<B: Backend, const D: usize>(&self, grad: Tensor<B, D>) -> Tensor<B, D> {
         match self {
             GradientClipping::Value(threshold) => self.clip_by_value(grad, *threshold),
             GradientClipping::Norm(max_norm) => self.clip_by_norm(grad, *max_norm),
+            GradientClipping::GlobalNorm(max_norm) => self.clip_by_norm(grad, *max_norm),
+            GradientClipping::Adaptive(_) => grad,
         }
     }
 
@@ -96,8 +125,7 @@ impl GradientClipping {
         }
     }
 
-    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
-    fn l2_norm<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, 1> {
+    pub(crate) fn l2_norm<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, 1> {
         let squared = tensor.powf_scalar(2.0);
         let sum = squared.sum();
 
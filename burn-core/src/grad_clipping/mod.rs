@@ -1,2 +1,5 @@
 mod base;
+#[cfg(feature = "std")]
+mod params;
+
 pub use base::*;
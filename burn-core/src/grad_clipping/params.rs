@@ -0,0 +1,220 @@
+use super::GradientClipping;
+use crate::module::{AutodiffModule, ModuleVisitor, ParamId};
+use crate::optim::GradientsParams;
+use burn_tensor::{
+    backend::{AutodiffBackend, Backend},
+    Tensor,
+};
+
+/// Minimum norm of a parameter used by adaptive clipping, so that parameters initialized to
+/// zero can still be updated.
+const ADAPTIVE_PARAM_NORM_MIN: f64 = 1e-3;
+const NORM_EPSILON: f64 = 1e-6;
+
+impl GradientClipping {
+    /// Clip all the gradients of the given [module](AutodiffModule).
+    ///
+    /// # Arguments
+    ///
+    /// * `module` - The module the gradients were computed for.
+    /// * `grads` - The gradients to clip.
+    ///
+    /// # Returns
+    ///
+    /// The clipped gradients along with the global norm of the gradients before clipping, which is
+    /// `None` when no gradients are registered for the module.
+    pub fn clip_gradients_params<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+        module: &M,
+        mut grads: GradientsParams,
+    ) -> (GradientsParams, Option<Tensor<B::InnerBackend, 1>>) {
+        let mut norm_visitor = GlobalNormVisitor {
+            grads: &grads,
+            sum_squares: None,
+        };
+        module.visit(&mut norm_visitor);
+
+        let norm = match norm_visitor.sum_squares {
+            Some(sum_squares) => sum_squares.sqrt(),
+            None => return (grads, None),
+        };
+
+        let mut clipper = GradientsClipper {
+            clipping: self,
+            grads: &mut grads,
+            scale: match self {
+                GradientClipping::GlobalNorm(max_norm) => Some(
+                    norm.clone()
+                        .add_scalar(NORM_EPSILON)
+                        .recip()
+                        .mul_scalar(*max_norm)
+                        .clamp_max(1.0),
+                ),
+                _ => None,
+            },
+        };
+        module.visit(&mut clipper);
+
+        (grads, Some(norm))
+    }
+}
+
+struct GlobalNormVisitor<'a, B: Backend> {
+    grads: &'a GradientsParams,
+    sum_squares: Option<Tensor<B, 1>>,
+}
+
+impl<'a, B: AutodiffBackend> ModuleVisitor<B> for GlobalNormVisitor<'a, B::InnerBackend> {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        let grad = match self.grads.get::<B::InnerBackend, D>(id) {
+            Some(grad) => grad,
+            None => return,
+        };
+        let sum_squares = grad.powf_scalar(2.0).sum();
+
+        self.sum_squares = Some(match self.sum_squares.take() {
+            Some(total) => total.add(sum_squares),
+            None => sum_squares,
+        });
+    }
+}
+
+struct GradientsClipper<'a, B: Backend> {
+    clipping: &'a GradientClipping,
+    grads: &'a mut GradientsParams,
+    /// The scale applied to all the gradients with global norm clipping.
+    scale: Option<Tensor<B, 1>>,
+}
+
+impl<'a, B: AutodiffBackend> ModuleVisitor<B> for GradientsClipper<'a, B::InnerBackend> {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        let grad = match self.grads.remove::<B::InnerBackend, D>(id) {
+            Some(grad) => grad,
+            None => return,
+        };
+
+        let grad = match (self.clipping, &self.scale) {
+            (GradientClipping::GlobalNorm(_), Some(scale)) => grad.mul(scale.clone().unsqueeze()),
+            (GradientClipping::Adaptive(ratio), _) => {
+                let param_norm = GradientClipping::l2_norm(tensor.clone().inner())
+                    .clamp_min(ADAPTIVE_PARAM_NORM_MIN);
+                let grad_norm = GradientClipping::l2_norm(grad.clone()).clamp_min(NORM_EPSILON);
+                let scale = param_norm.mul_scalar(*ratio).div(grad_norm).clamp_max(1.0);
+
+                grad.mul(scale.unsqueeze())
+            }
+            _ => self.clipping.clip_gradient(grad),
+        };
+
+        self.grads.register::<B::InnerBackend, D>(id.clone(), grad);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{Linear, LinearConfig, LinearRecord};
+    use crate::{module::Module, tensor::Data, TestAutodiffBackend};
+
+    #[test]
+    fn should_clip_by_global_norm() {
+        let device = Default::default();
+        let layer = given_layer(&device);
+        let grads = given_grads(&layer);
+
+        let (grads, norm) = GradientClipping::GlobalNorm(1.0).clip_gradients_params(&layer, grads);
+
+        // sqrt(4 * 3^2 + 2 * 4^2) = sqrt(68)
+        norm.unwrap()
+            .into_data()
+            .assert_approx_eq(&Data::from([68f32.sqrt()]), 3);
+        let (weight, bias) = grads_of(&layer, &grads);
+        let scale = 1.0 / 68f32.sqrt();
+        weight.assert_approx_eq(
+            &Data::from([[3.0 * scale, 3.0 * scale], [3.0 * scale, 3.0 * scale]]),
+            3,
+        );
+        bias.assert_approx_eq(&Data::from([4.0 * scale, 4.0 * scale]), 3);
+    }
+
+    #[test]
+    fn should_not_clip_when_global_norm_is_lower_than_threshold() {
+        let device = Default::default();
+        let layer = given_layer(&device);
+        let grads = given_grads(&layer);
+
+        let (grads, _) = GradientClipping::GlobalNorm(10.0).clip_gradients_params(&layer, grads);
+
+        let (weight, bias) = grads_of(&layer, &grads);
+        weight.assert_approx_eq(&Data::from([[3.0, 3.0], [3.0, 3.0]]), 3);
+        bias.assert_approx_eq(&Data::from([4.0, 4.0]), 3);
+    }
+
+    #[test]
+    fn should_clip_relative_to_param_norm() {
+        let device = Default::default();
+        let layer = given_layer(&device);
+        let grads = given_grads(&layer);
+
+        let (grads, _) = GradientClipping::Adaptive(0.1).clip_gradients_params(&layer, grads);
+
+        // Weight norm of 4, grad norm of 6: clipped to a norm of 0.4.
+        // Bias norm of 0 (clamped to 1e-3), grad norm of sqrt(32): clipped to a norm of 1e-4.
+        let (weight, bias) = grads_of(&layer, &grads);
+        weight.assert_approx_eq(&Data::from([[0.2, 0.2], [0.2, 0.2]]), 3);
+        let bias_value = 1e-4 / 2f32.sqrt();
+        bias.assert_approx_eq(&Data::from([bias_value, bias_value]), 5);
+    }
+
+    fn given_layer(
+        device: &<TestAutodiffBackend as Backend>::Device,
+    ) -> Linear<TestAutodiffBackend> {
+        let layer = LinearConfig::new(2, 2).init::<TestAutodiffBackend>(device);
+        let record = LinearRecord {
+            weight: layer
+                .weight
+                .clone()
+                .map(|tensor| tensor.ones_like().mul_scalar(2.0)),
+            bias: layer
+                .bias
+                .clone()
+                .map(|bias| bias.map(|tensor| tensor.zeros_like())),
+        };
+
+        layer.load_record(record)
+    }
+
+    /// Gradients of 3 for the weight and 4 for the bias.
+    fn given_grads(layer: &Linear<TestAutodiffBackend>) -> GradientsParams {
+        let mut grads = GradientsParams::new();
+        grads.register(
+            layer.weight.id.clone(),
+            layer.weight.val().inner().ones_like().mul_scalar(3.0),
+        );
+        let bias = layer.bias.as_ref().unwrap();
+        grads.register(
+            bias.id.clone(),
+            bias.val().inner().ones_like().mul_scalar(4.0),
+        );
+
+        grads
+    }
+
+    fn grads_of(
+        layer: &Linear<TestAutodiffBackend>,
+        grads: &GradientsParams,
+    ) -> (Data<f32, 2>, Data<f32, 1>) {
+        type InnerBackend = <TestAutodiffBackend as AutodiffBackend>::InnerBackend;
+
+        let weight = grads
+            .get::<InnerBackend, 2>(&layer.weight.id)
+            .unwrap()
+            .into_data();
+        let bias = grads
+            .get::<InnerBackend, 1>(&layer.bias.as_ref().unwrap().id)
+            .unwrap()
+            .into_data();
+
+        (weight, bias)
+    }
+}
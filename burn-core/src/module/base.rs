@@ -201,10 +201,42 @@ pub trait Module<B: Backend>: Clone + Send + Sync + core::fmt::Debug {
 
         Ok(self.load_record(record))
     }
+
+    #[cfg(feature = "std")]
+    /// Load the tensors of the module from a file, using the provided
+    /// [streaming recorder](crate::record::StreamingRecorder).
+    ///
+    /// Only the tensors selected by the [filter](crate::record::ModuleFilter) are loaded, which
+    /// can be used to load some submodules of the record. Each tensor is loaded on the device of
+    /// the tensor it replaces.
+    ///
+    /// Only the safetensors recorders read the tensors one at a time, the other recorders load
+    /// the full record first.
+    ///
+    /// ## Notes
+    ///
+    /// The file extension is automatically added depending on the file recorder provided, you
+    /// don't have to specify it.
+    fn load_file_streaming<SR, PB>(
+        self,
+        file_path: PB,
+        recorder: &SR,
+        filter: &crate::record::ModuleFilter,
+    ) -> Result<Self, crate::record::RecorderError>
+    where
+        SR: crate::record::StreamingRecorder<B> + crate::record::FileRecorder<B>,
+        PB: Into<std::path::PathBuf>,
+    {
+        recorder.load_module(self, file_path.into(), filter)
+    }
 }
 
 /// Module visitor trait.
 pub trait ModuleVisitor<B: Backend> {
+    /// Enter a submodule, or an item of a list of modules, with the given name.
+    fn enter_module(&mut self, _name: &str) {}
+    /// Exit the submodule with the given name.
+    fn exit_module(&mut self, _name: &str) {}
     /// Visit a float tensor in the module.
     fn visit_float<const D: usize>(&mut self, _id: &ParamId, _tensor: &Tensor<B, D>) {}
     /// Visit an int tensor in the module.
@@ -215,6 +247,10 @@ pub trait ModuleVisitor<B: Backend> {
 
 /// Module mapper trait.
 pub trait ModuleMapper<B: Backend> {
+    /// Enter a submodule, or an item of a list of modules, with the given name.
+    fn enter_module(&mut self, _name: &str) {}
+    /// Exit the submodule with the given name.
+    fn exit_module(&mut self, _name: &str) {}
     /// Map a float tensor in the module.
     fn map_float<const D: usize>(&mut self, _id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
         tensor
@@ -1,4 +1,5 @@
 use crate::module::{AutodiffModule, Module, ModuleMapper, ModuleVisitor};
+use alloc::string::ToString;
 use alloc::vec::Vec;
 use burn_tensor::backend::{AutodiffBackend, Backend};
 use core::fmt::Debug;
@@ -75,13 +76,20 @@ where
     }
 
     fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
-        self.iter().for_each(|module| {
+        self.iter().enumerate().for_each(|(index, module)| {
+            let name = index.to_string();
+
+            visitor.enter_module(&name);
             module.visit(visitor);
+            visitor.exit_module(&name);
         });
     }
 
     fn map<M: ModuleMapper<B>>(self, mapper: &mut M) -> Self {
-        self.into_iter().map(|module| module.map(mapper)).collect()
+        self.into_iter()
+            .enumerate()
+            .map(|(index, module)| map_item(index, module, mapper))
+            .collect()
     }
 
     fn into_record(self) -> Self::Record {
@@ -152,13 +160,23 @@ where
     }
 
     fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
-        self.iter().for_each(|module| {
+        self.iter().enumerate().for_each(|(index, module)| {
+            let name = index.to_string();
+
+            visitor.enter_module(&name);
             module.visit(visitor);
+            visitor.exit_module(&name);
         });
     }
 
     fn map<M: ModuleMapper<B>>(self, mapper: &mut M) -> Self {
-        self.map(|module| module.map(mapper))
+        let mut index = 0;
+
+        self.map(|module| {
+            let module = map_item(index, module, mapper);
+            index += 1;
+            module
+        })
     }
 
     fn load_record(self, record: Self::Record) -> Self {
@@ -197,3 +215,18 @@ where
         self.map(|module| module.valid())
     }
 }
+
+/// Map an item of a list of modules, named after its index.
+fn map_item<T: Module<B>, B: Backend, M: ModuleMapper<B>>(
+    index: usize,
+    module: T,
+    mapper: &mut M,
+) -> T {
+    let name = index.to_string();
+
+    mapper.enter_module(&name);
+    let module = module.map(mapper);
+    mapper.exit_module(&name);
+
+    module
+}
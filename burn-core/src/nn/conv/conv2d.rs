@@ -49,13 +49,13 @@ pub struct Conv2dConfig {
 /// - bias:   Tensor of shape `[channels_out]`
 #[derive(Module, Debug)]
 pub struct Conv2d<B: Backend> {
-    weight: Param<Tensor<B, 4>>,
-    bias: Option<Param<Tensor<B, 1>>>,
-    stride: [usize; 2],
-    kernel_size: [usize; 2],
-    dilation: [usize; 2],
-    groups: usize,
-    padding: PaddingConfig2d,
+    pub(crate) weight: Param<Tensor<B, 4>>,
+    pub(crate) bias: Option<Param<Tensor<B, 1>>>,
+    pub(crate) stride: [usize; 2],
+    pub(crate) kernel_size: [usize; 2],
+    pub(crate) dilation: [usize; 2],
+    pub(crate) groups: usize,
+    pub(crate) padding: PaddingConfig2d,
 }
 
 impl Conv2dConfig {
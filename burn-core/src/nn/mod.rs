@@ -13,6 +13,9 @@ pub mod loss;
 /// Pooling module
 pub mod pool;
 
+/// Quantization module
+pub mod quantization;
+
 /// Transformer module
 pub mod transformer;
 
@@ -0,0 +1,23 @@
+/// Run the forward pass of a model on some batches, such as the batches of a
+/// [dataloader](crate::data::dataloader::DataLoader) given with `dataloader.iter()`, to
+/// calibrate the ranges of its [observers](super::Observer) before quantizing it, returning the
+/// number of batches.
+///
+/// # Notes
+///
+/// The observers are only updated when autodiff is enabled, so the model must be on an autodiff
+/// backend. The outputs of the forward pass are dropped without computing any gradient.
+pub fn calibrate<M, I, O, F>(model: &M, batches: I, mut forward: F) -> usize
+where
+    I: IntoIterator,
+    F: FnMut(&M, I::Item) -> O,
+{
+    let mut num_batches = 0;
+
+    for batch in batches {
+        forward(model, batch);
+        num_batches += 1;
+    }
+
+    num_batches
+}
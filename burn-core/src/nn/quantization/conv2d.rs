@@ -0,0 +1,177 @@
+use crate as burn;
+
+use super::{fake_quantize, Observer, QuantizationParams, QuantizedTensor};
+use crate::module::{Module, Param};
+use crate::nn::conv::Conv2d;
+use crate::nn::PaddingConfig2d;
+use crate::tensor::{backend::Backend, Tensor};
+use burn_tensor::module::conv2d;
+use burn_tensor::ops::ConvOptions;
+
+/// A [conv2d](Conv2d) layer fake quantizing its weights and its inputs, created with a
+/// [quantization config](super::QuantizationConfig).
+///
+/// The layer is trained like the wrapped layer, while the observers track the ranges of the
+/// weights and of the inputs, then [converted](QatConv2d::quantize) to a
+/// [quantized conv2d](QuantizedConv2d) layer.
+#[derive(Module, Debug)]
+pub struct QatConv2d<B: Backend> {
+    /// The wrapped conv2d layer.
+    pub conv: Conv2d<B>,
+    pub(crate) weight_observer: Observer<B>,
+    pub(crate) activation_observer: Observer<B>,
+}
+
+impl<B: Backend> QatConv2d<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: [batch_size, channels_in, height_in, width_in],
+    /// - output: [batch_size, channels_out, height_out, width_out],
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let input = self.activation_observer.forward(input);
+        let weight = self.weight_observer.forward(self.conv.weight.val());
+        let bias = self.conv.bias.as_ref().map(|bias| bias.val());
+
+        forward_conv2d(
+            input,
+            weight,
+            bias,
+            &self.conv.padding,
+            self.conv.kernel_size,
+            self.conv.stride,
+            self.conv.dilation,
+            self.conv.groups,
+        )
+    }
+
+    /// Quantize the weights, with the range of the inputs observed so far if any.
+    pub fn quantize(self) -> QuantizedConv2d<B> {
+        let weight = self.conv.weight.val();
+        let params = self.weight_observer.tensor_params(weight.clone());
+        let activation = match self.activation_observer.count() {
+            0 => None,
+            _ => Some(self.activation_observer.params()),
+        };
+
+        QuantizedConv2d {
+            weight: QuantizedTensor::quantize(weight, params),
+            bias: self.conv.bias,
+            activation,
+            stride: self.conv.stride,
+            kernel_size: self.conv.kernel_size,
+            dilation: self.conv.dilation,
+            groups: self.conv.groups,
+            padding: self.conv.padding,
+        }
+    }
+}
+
+/// A [conv2d](Conv2d) layer with 8-bit weights, saved as 8-bit integers with their scales.
+///
+/// # Notes
+///
+/// The weights are dequantized for the convolution, and the inputs are fake quantized with their
+/// observed range, which gives the results of an integer implementation.
+#[derive(Module, Debug)]
+pub struct QuantizedConv2d<B: Backend> {
+    /// The quantized weights of shape
+    /// `[channels_out, channels_in / groups, kernel_size_1, kernel_size_2]`.
+    pub weight: QuantizedTensor<B, 4>,
+    /// The bias of size `channels_out`, kept in floats.
+    pub bias: Option<Param<Tensor<B, 1>>>,
+    /// The quantization parameters of the inputs, if their range was observed.
+    pub activation: Option<QuantizationParams<B>>,
+    stride: [usize; 2],
+    kernel_size: [usize; 2],
+    dilation: [usize; 2],
+    groups: usize,
+    padding: PaddingConfig2d,
+}
+
+impl<B: Backend> QuantizedConv2d<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: [batch_size, channels_in, height_in, width_in],
+    /// - output: [batch_size, channels_out, height_out, width_out],
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let input = match &self.activation {
+            Some(params) => fake_quantize(input, params),
+            None => input,
+        };
+
+        forward_conv2d(
+            input,
+            self.weight.dequantize(),
+            self.bias.as_ref().map(|bias| bias.val()),
+            &self.padding,
+            self.kernel_size,
+            self.stride,
+            self.dilation,
+            self.groups,
+        )
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn forward_conv2d<B: Backend>(
+    input: Tensor<B, 4>,
+    weight: Tensor<B, 4>,
+    bias: Option<Tensor<B, 1>>,
+    padding: &PaddingConfig2d,
+    kernel_size: [usize; 2],
+    stride: [usize; 2],
+    dilation: [usize; 2],
+    groups: usize,
+) -> Tensor<B, 4> {
+    let [_batch_size, _channels_in, height_in, width_in] = input.dims();
+    let padding = padding.calculate_padding_2d(height_in, width_in, &kernel_size, &stride);
+
+    conv2d(
+        input,
+        weight,
+        bias,
+        ConvOptions::new(stride, padding, dilation, groups),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::AutodiffModule;
+    use crate::nn::conv::Conv2dConfig;
+    use crate::nn::quantization::{calibrate, QuantizationConfig, QuantizationScheme};
+    use crate::TestAutodiffBackend;
+    use burn_tensor::Distribution;
+
+    #[test]
+    fn should_quantize_each_output_channel_after_calibration() {
+        let device = Default::default();
+        let conv = Conv2dConfig::new([2, 4], [3, 3])
+            .with_padding(PaddingConfig2d::Same)
+            .init::<TestAutodiffBackend>(&device);
+        let expected_conv = conv.valid();
+        let model = QuantizationConfig::new()
+            .with_activation(QuantizationScheme::PerTensorSymmetric)
+            .with_momentum(Some(0.1))
+            .init_conv2d(conv);
+        let batches = (0..3).map(|_| {
+            Tensor::<TestAutodiffBackend, 4>::random([2, 2, 5, 5], Distribution::Default, &device)
+        });
+
+        calibrate(&model, batches, |model, batch| model.forward(batch));
+        let quantized = model.quantize().valid();
+
+        assert_eq!(quantized.weight.params().scale.dims(), [4]);
+        assert_eq!(quantized.weight.params().axis, Some(0));
+        let input = Tensor::random([2, 2, 5, 5], Distribution::Default, &device);
+        let output = quantized.forward(input.clone());
+        assert_eq!(output.dims(), [2, 4, 5, 5]);
+        output
+            .into_data()
+            .assert_approx_eq_diff(&expected_conv.forward(input).into_data(), 0.05);
+    }
+}
@@ -0,0 +1,176 @@
+use crate as burn;
+
+use super::{fake_quantize, Observer, QuantizationParams, QuantizedTensor};
+use crate::module::{Module, Param};
+use crate::nn::Linear;
+use crate::tensor::{backend::Backend, Tensor};
+
+/// A [linear](Linear) layer fake quantizing its weights and its inputs, created with a
+/// [quantization config](super::QuantizationConfig).
+///
+/// The layer is trained like the wrapped layer, while the observers track the ranges of the
+/// weights and of the inputs, then [converted](QatLinear::quantize) to a
+/// [quantized linear](QuantizedLinear) layer.
+#[derive(Module, Debug)]
+pub struct QatLinear<B: Backend> {
+    /// The wrapped linear layer.
+    pub linear: Linear<B>,
+    pub(crate) weight_observer: Observer<B>,
+    pub(crate) activation_observer: Observer<B>,
+}
+
+impl<B: Backend> QatLinear<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any, d_input]`
+    /// - output: `[..., any, d_output]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let input = self.activation_observer.forward(input);
+        let weight = self.weight_observer.forward(self.linear.weight.val());
+        let output = input.matmul(weight.unsqueeze());
+
+        match &self.linear.bias {
+            Some(bias) => output + bias.val().unsqueeze(),
+            None => output,
+        }
+    }
+
+    /// Quantize the weights, with the range of the inputs observed so far if any.
+    pub fn quantize(self) -> QuantizedLinear<B> {
+        let weight = self.linear.weight.val();
+        let params = self.weight_observer.tensor_params(weight.clone());
+        let activation = match self.activation_observer.count() {
+            0 => None,
+            _ => Some(self.activation_observer.params()),
+        };
+
+        QuantizedLinear {
+            weight: QuantizedTensor::quantize(weight, params),
+            bias: self.linear.bias,
+            activation,
+        }
+    }
+}
+
+/// A [linear](Linear) layer with 8-bit weights, saved as 8-bit integers with their scales.
+///
+/// # Notes
+///
+/// The weights are dequantized for the matrix multiplication, and the inputs are fake quantized
+/// with their observed range, which gives the results of an integer implementation.
+#[derive(Module, Debug)]
+pub struct QuantizedLinear<B: Backend> {
+    /// The quantized weights of shape `[d_input, d_output]`.
+    pub weight: QuantizedTensor<B, 2>,
+    /// The bias of size `d_output`, kept in floats.
+    pub bias: Option<Param<Tensor<B, 1>>>,
+    /// The quantization parameters of the inputs, if their range was observed.
+    pub activation: Option<QuantizationParams<B>>,
+}
+
+impl<B: Backend> QuantizedLinear<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any, d_input]`
+    /// - output: `[..., any, d_output]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let input = match &self.activation {
+            Some(params) => fake_quantize(input, params),
+            None => input,
+        };
+        let output = input.matmul(self.weight.dequantize().unsqueeze());
+
+        match &self.bias {
+            Some(bias) => output + bias.val().unsqueeze(),
+            None => output,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::AutodiffModule;
+    use crate::nn::quantization::{calibrate, QuantizationConfig};
+    use crate::nn::LinearConfig;
+    use crate::optim::{GradientsParams, Optimizer, SgdConfig};
+    use crate::record::{BinBytesRecorder, FullPrecisionSettings, Recorder};
+    use crate::{TestAutodiffBackend, TestBackend};
+    use burn_tensor::Distribution;
+
+    #[test]
+    fn should_train_with_fake_quantization() {
+        let device = Default::default();
+        let linear = LinearConfig::new(4, 3).init::<TestAutodiffBackend>(&device);
+        let mut model = QuantizationConfig::new().init_linear(linear);
+        let mut optim = SgdConfig::new().init();
+        let input = Tensor::random([8, 4], Distribution::Default, &device);
+
+        let loss = model.forward(input.clone()).powf_scalar(2.0).mean();
+        let initial = loss.clone().into_scalar();
+        for _ in 0..10 {
+            let loss = model.forward(input.clone()).powf_scalar(2.0).mean();
+            let grads = GradientsParams::from_grads(loss.backward(), &model);
+            model = optim.step(0.1, model, grads);
+        }
+        let loss = model.forward(input).powf_scalar(2.0).mean();
+
+        assert!(loss.into_scalar() < initial);
+        assert_eq!(model.activation_observer.count(), 12);
+    }
+
+    #[test]
+    fn should_quantize_after_calibration() {
+        let device = Default::default();
+        let linear = LinearConfig::new(16, 8).init::<TestAutodiffBackend>(&device);
+        let expected_linear = linear.valid();
+        let model = QuantizationConfig::new().init_linear(linear);
+        let batches = (0..4)
+            .map(|_| {
+                Tensor::<TestAutodiffBackend, 2>::random([4, 16], Distribution::Default, &device)
+            })
+            .collect::<Vec<_>>();
+
+        let num_batches = calibrate(&model, batches, |model, batch| model.forward(batch));
+        let quantized = model.quantize().valid();
+
+        assert_eq!(num_batches, 4);
+        assert!(quantized.activation.is_some());
+        let input = Tensor::<TestBackend, 2>::random([4, 16], Distribution::Default, &device);
+        quantized
+            .forward(input.clone())
+            .into_data()
+            .assert_approx_eq_diff(&expected_linear.forward(input).into_data(), 0.05);
+    }
+
+    #[test]
+    fn should_save_the_weights_as_8_bit_integers() {
+        let device = Default::default();
+        let linear = LinearConfig::new(32, 32).init::<TestBackend>(&device);
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let float_bytes = recorder.record(linear.clone().into_record(), ()).unwrap();
+        let quantized = QuantizationConfig::new().init_linear(linear).quantize();
+
+        let bytes = recorder
+            .record(quantized.clone().into_record(), ())
+            .unwrap();
+        let loaded = quantized
+            .clone()
+            .load_record(recorder.load(bytes.clone(), &device).unwrap());
+
+        assert!(bytes.len() * 2 < float_bytes.len());
+        assert_eq!(
+            loaded.weight.values().into_data(),
+            quantized.weight.values().into_data()
+        );
+        loaded
+            .weight
+            .dequantize()
+            .into_data()
+            .assert_approx_eq(&quantized.weight.dequantize().into_data(), 5);
+    }
+}
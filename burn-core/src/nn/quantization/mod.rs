@@ -0,0 +1,19 @@
+mod calibration;
+mod conv2d;
+mod linear;
+mod observer;
+mod ops;
+mod params;
+// The variants of the scheme are named after the granularity of their parameters.
+#[allow(clippy::enum_variant_names)]
+mod scheme;
+mod tensor;
+
+pub use calibration::*;
+pub use conv2d::*;
+pub use linear::*;
+pub use observer::*;
+pub use ops::*;
+pub use params::*;
+pub use scheme::*;
+pub use tensor::*;
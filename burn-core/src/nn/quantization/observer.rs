@@ -0,0 +1,212 @@
+use crate as burn;
+
+use super::params::range;
+use super::{fake_quantize, QuantizationParams, QuantizationScheme};
+use crate::config::Config;
+use crate::module::{Module, RunningState};
+use crate::tensor::backend::Backend;
+use crate::tensor::{ElementConversion, Tensor};
+
+/// Configuration to create an [observer](Observer).
+#[derive(Config, Debug)]
+pub struct ObserverConfig {
+    /// The quantization scheme.
+    pub scheme: QuantizationScheme,
+    /// The number of channels, for per channel schemes.
+    #[config(default = 1)]
+    pub num_channels: usize,
+    /// The dimension of the channels, for per channel schemes.
+    #[config(default = 0)]
+    pub axis: usize,
+    /// The momentum of the moving averages of the ranges, or `None` to keep the minimum and the
+    /// maximum of all the observed tensors.
+    #[config(default = "None")]
+    pub momentum: Option<f64>,
+}
+
+impl ObserverConfig {
+    /// Initialize a new [observer](Observer).
+    pub fn init<B: Backend>(&self, device: &B::Device) -> Observer<B> {
+        let num_channels = match self.scheme.is_per_channel() {
+            true => self.num_channels,
+            false => 1,
+        };
+
+        Observer {
+            min: RunningState::new(Tensor::zeros([num_channels], device)),
+            max: RunningState::new(Tensor::zeros([num_channels], device)),
+            count: RunningState::new(Tensor::zeros([1], device)),
+            scheme: self.scheme.clone(),
+            axis: self.scheme.is_per_channel().then_some(self.axis),
+            momentum: self.momentum,
+        }
+    }
+}
+
+/// Track the range of the tensors given during training, to compute the
+/// [quantization parameters](QuantizationParams) of the tensors of a layer.
+///
+/// The range is only updated on backends with autodiff enabled, like the statistics of the
+/// [batch norm](crate::nn::BatchNorm), so it stays fixed during inference.
+#[derive(Module, Debug)]
+pub struct Observer<B: Backend> {
+    min: RunningState<Tensor<B, 1>>,
+    max: RunningState<Tensor<B, 1>>,
+    count: RunningState<Tensor<B, 1>>,
+    scheme: QuantizationScheme,
+    axis: Option<usize>,
+    momentum: Option<f64>,
+}
+
+impl<B: Backend> Observer<B> {
+    /// Update the range with a tensor.
+    pub fn observe<const D: usize>(&self, tensor: Tensor<B, D>) {
+        let (min, max) = range(tensor, self.axis);
+        let count = self.count.value_sync();
+
+        // The first observation replaces the initial range, then the range is either extended or
+        // averaged with the momentum.
+        let weight = count.clone().add_scalar(1.0).recip();
+        let (min, max) = match self.momentum {
+            Some(momentum) => {
+                let weight = weight.clamp_min(momentum);
+                let average = |current: Tensor<B, 1>, value: Tensor<B, 1>| {
+                    current.clone() + (value - current).mul(weight.clone())
+                };
+
+                (
+                    average(self.min.value_sync(), min),
+                    average(self.max.value_sync(), max),
+                )
+            }
+            None => {
+                let current_min = self.min.value_sync();
+                let current_max = self.max.value_sync();
+                let first = weight.equal_elem(1.0).float();
+                let keep = first.clone().neg().add_scalar(1.0);
+
+                (
+                    minimum(current_min, min.clone()).mul(keep.clone()) + min.mul(first.clone()),
+                    maximum(current_max, max.clone()).mul(keep) + max.mul(first),
+                )
+            }
+        };
+
+        self.min.update(min);
+        self.max.update(max);
+        self.count.update(count.add_scalar(1.0));
+    }
+
+    /// The number of observed tensors.
+    pub fn count(&self) -> usize {
+        self.count.value_sync().into_scalar().elem::<f64>() as usize
+    }
+
+    /// The quantization parameters of the observed range.
+    pub fn params(&self) -> QuantizationParams<B> {
+        QuantizationParams::from_range(
+            self.min.value_sync(),
+            self.max.value_sync(),
+            self.scheme.clone(),
+            self.axis,
+        )
+    }
+
+    /// The quantization parameters of the range of a tensor, with the scheme of the observer.
+    pub fn tensor_params<const D: usize>(&self, tensor: Tensor<B, D>) -> QuantizationParams<B> {
+        let (min, max) = range(tensor, self.axis);
+
+        QuantizationParams::from_range(min, max, self.scheme.clone(), self.axis)
+    }
+
+    /// Observe the tensor when autodiff is enabled, then [fake quantize](fake_quantize) it with
+    /// the quantization parameters of the observed range.
+    pub fn forward<const D: usize>(&self, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        if B::ad_enabled() {
+            self.observe(tensor.clone());
+        }
+
+        fake_quantize(tensor, &self.params())
+    }
+}
+
+fn minimum<B: Backend>(lhs: Tensor<B, 1>, rhs: Tensor<B, 1>) -> Tensor<B, 1> {
+    lhs.clone().mask_where(rhs.clone().lower(lhs), rhs)
+}
+
+fn maximum<B: Backend>(lhs: Tensor<B, 1>, rhs: Tensor<B, 1>) -> Tensor<B, 1> {
+    lhs.clone().mask_where(rhs.clone().greater(lhs), rhs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::AutodiffModule;
+    use crate::{TestAutodiffBackend, TestBackend};
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_keep_the_range_of_all_the_observations() {
+        let device = Default::default();
+        let observer = ObserverConfig::new(QuantizationScheme::PerChannelAffine)
+            .with_num_channels(2)
+            .with_axis(1)
+            .init::<TestAutodiffBackend>(&device);
+
+        observer.observe(Tensor::<TestAutodiffBackend, 2>::from_floats(
+            [[1.0, -2.0], [3.0, -1.0]],
+            &device,
+        ));
+        observer.observe(Tensor::<TestAutodiffBackend, 2>::from_floats(
+            [[2.0, -4.0], [2.0, -3.0]],
+            &device,
+        ));
+
+        assert_eq!(observer.count(), 2);
+        assert_eq!(
+            observer.min.value_sync().into_data(),
+            Data::from([1.0, -4.0])
+        );
+        assert_eq!(
+            observer.max.value_sync().into_data(),
+            Data::from([3.0, -1.0])
+        );
+    }
+
+    #[test]
+    fn should_average_the_ranges_with_momentum() {
+        let device = Default::default();
+        let observer = ObserverConfig::new(QuantizationScheme::PerTensorAffine)
+            .with_momentum(Some(0.5))
+            .init::<TestAutodiffBackend>(&device);
+
+        observer.observe(Tensor::<TestAutodiffBackend, 1>::from_floats(
+            [-2.0, 4.0],
+            &device,
+        ));
+        observer.observe(Tensor::<TestAutodiffBackend, 1>::from_floats(
+            [-4.0, 2.0],
+            &device,
+        ));
+
+        assert_eq!(observer.min.value_sync().into_data(), Data::from([-3.0]));
+        assert_eq!(observer.max.value_sync().into_data(), Data::from([3.0]));
+    }
+
+    #[test]
+    fn should_not_observe_during_inference() {
+        let device = Default::default();
+        let observer = ObserverConfig::new(QuantizationScheme::PerTensorSymmetric)
+            .init::<TestAutodiffBackend>(&device);
+        let tensor = Tensor::<TestAutodiffBackend, 1>::from_floats([-1.0, 1.0], &device);
+        observer.forward(tensor);
+
+        let observer = observer.valid();
+        let output = observer.forward(Tensor::<TestBackend, 1>::from_floats([-3.0, 0.4], &device));
+
+        assert_eq!(observer.count(), 1);
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([-1.0, 51.0 / 127.0]), 5);
+    }
+}
@@ -0,0 +1,123 @@
+use super::params::round_clamped;
+use super::QuantizationParams;
+use crate::tensor::activation::relu;
+use crate::tensor::backend::Backend;
+use crate::tensor::{Int, Tensor};
+
+/// Quantize a tensor to 8-bit integers with the given [parameters](QuantizationParams).
+pub fn quantize<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    params: &QuantizationParams<B>,
+) -> Tensor<B, D, Int> {
+    let (quant_min, quant_max) = params.scheme.range();
+    let (scale, zero_point) = params.broadcast::<D>();
+
+    round_clamped(tensor.div(scale).add(zero_point), quant_min, quant_max).int()
+}
+
+/// Map quantized values back to floats with the given [parameters](QuantizationParams).
+pub fn dequantize<B: Backend, const D: usize>(
+    tensor: Tensor<B, D, Int>,
+    params: &QuantizationParams<B>,
+) -> Tensor<B, D> {
+    let (scale, zero_point) = params.broadcast::<D>();
+
+    tensor.float().sub(zero_point).mul(scale)
+}
+
+/// Quantize and dequantize a tensor, to simulate the quantization error while keeping float
+/// values.
+///
+/// The gradient is computed with the straight-through estimator: it flows unchanged through the
+/// values inside the quantization range, and is zero for the clipped values.
+pub fn fake_quantize<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    params: &QuantizationParams<B>,
+) -> Tensor<B, D> {
+    let (quant_min, quant_max) = params.scheme.range();
+    let (scale, zero_point) = params.broadcast::<D>();
+    let lower = zero_point
+        .clone()
+        .neg()
+        .add_scalar(quant_min)
+        .mul(scale.clone());
+    let upper = zero_point.neg().add_scalar(quant_max).mul(scale);
+
+    // Clamp with differentiable operations, since the bounds can differ for each channel.
+    let clipped = relu(tensor.clone().sub(lower.clone())) - relu(tensor.clone().sub(upper)) + lower;
+    let quantized = dequantize(quantize(tensor.detach(), params), params);
+
+    // The rounding error is added without tracking, so the gradient is the one of the clipping.
+    let error = quantized - clipped.clone().detach();
+
+    clipped + error
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::quantization::QuantizationScheme;
+    use crate::{TestAutodiffBackend, TestBackend};
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_quantize_and_dequantize_per_tensor() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1>::from_floats([-1.0, 0.0, 0.6, 2.0], &device);
+        let params =
+            QuantizationParams::from_tensor(tensor.clone(), QuantizationScheme::PerTensorAffine, 0);
+
+        let quantized = quantize(tensor.clone(), &params);
+        let dequantized = dequantize(quantized.clone(), &params);
+
+        assert_eq!(
+            quantized.into_data().convert::<i32>(),
+            Data::from([-128, -43, 8, 127])
+        );
+        dequantized
+            .into_data()
+            .assert_approx_eq_diff(&tensor.into_data(), 3.0 / 255.0 / 2.0);
+    }
+
+    #[test]
+    fn should_quantize_each_channel_with_its_scale() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2>::from_floats([[1.0, 10.0], [-0.4, -4.0]], &device);
+        let params = QuantizationParams::from_tensor(
+            tensor.clone(),
+            QuantizationScheme::PerChannelSymmetric,
+            1,
+        );
+
+        let quantized = quantize(tensor, &params);
+
+        assert_eq!(
+            quantized.into_data().convert::<i32>(),
+            Data::from([[127, 127], [-51, -51]])
+        );
+    }
+
+    #[test]
+    fn should_pass_the_gradient_through_the_values_in_range() {
+        let device = Default::default();
+        let params = QuantizationParams::<TestAutodiffBackend>::from_range(
+            Tensor::from_floats([-1.0], &device),
+            Tensor::from_floats([1.0], &device),
+            QuantizationScheme::PerTensorSymmetric,
+            None,
+        );
+        let tensor = Tensor::<TestAutodiffBackend, 1>::from_floats([-2.0, -0.3, 0.7, 3.0], &device)
+            .require_grad();
+
+        let output = fake_quantize(tensor.clone(), &params);
+        let grads = output.clone().sum().backward();
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([-1.0, -38.0 / 127.0, 89.0 / 127.0, 1.0]), 5);
+        assert_eq!(
+            tensor.grad(&grads).unwrap().into_data(),
+            Data::from([0.0, 1.0, 1.0, 0.0])
+        );
+    }
+}
@@ -0,0 +1,290 @@
+use super::QuantizationScheme;
+use crate::module::{AutodiffModule, Devices, Module, ModuleMapper, ModuleVisitor};
+use crate::record::{PrecisionSettings, Record};
+use crate::tensor::backend::{AutodiffBackend, Backend};
+use crate::tensor::{Data, ElementConversion, Tensor};
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+
+/// The smallest scale, so that constant tensors don't divide by zero.
+const MIN_SCALE: f64 = 1e-8;
+
+/// The scales and the zero points mapping a tensor to 8-bit integers with a
+/// [scheme](QuantizationScheme).
+///
+/// # Notes
+///
+/// As a module, the parameters are constant, but they are saved in its record as 32-bit floats
+/// for the scales and 8-bit integers for the zero points.
+#[derive(Clone, Debug)]
+pub struct QuantizationParams<B: Backend> {
+    /// The scale of the tensor or of each channel.
+    pub scale: Tensor<B, 1>,
+    /// The zero point of the tensor or of each channel, holding integer values.
+    pub zero_point: Tensor<B, 1>,
+    /// The scheme of the parameters.
+    pub scheme: QuantizationScheme,
+    /// The dimension of the channels for per channel schemes.
+    pub axis: Option<usize>,
+}
+
+impl<B: Backend> QuantizationParams<B> {
+    /// Compute the parameters mapping the range between `min` and `max` to the quantized
+    /// values, with one value per channel along `axis` for per channel schemes.
+    ///
+    /// The range is extended to include zero, so that zero is exactly represented.
+    pub fn from_range(
+        min: Tensor<B, 1>,
+        max: Tensor<B, 1>,
+        scheme: QuantizationScheme,
+        axis: Option<usize>,
+    ) -> Self {
+        let (quant_min, quant_max) = scheme.range();
+        let min = min.clamp_max(0.0);
+        let max = max.clamp_min(0.0);
+
+        let (scale, zero_point) = match scheme.is_symmetric() {
+            true => {
+                let neg_min = min.neg();
+                let abs_max = max.clone().mask_where(max.lower(neg_min.clone()), neg_min);
+                let scale = abs_max
+                    .div_scalar(((quant_max - quant_min) / 2) as f64)
+                    .clamp_min(MIN_SCALE);
+                let zero_point = scale.zeros_like();
+
+                (scale, zero_point)
+            }
+            false => {
+                let scale = (max - min.clone())
+                    .div_scalar((quant_max - quant_min) as f64)
+                    .clamp_min(MIN_SCALE);
+                let zero_point = round_clamped(
+                    min.div(scale.clone()).neg().add_scalar(quant_min),
+                    quant_min,
+                    quant_max,
+                );
+
+                (scale, zero_point)
+            }
+        };
+
+        let axis = axis.filter(|_| scheme.is_per_channel());
+
+        Self {
+            scale,
+            zero_point,
+            scheme,
+            axis,
+        }
+    }
+
+    /// Compute the parameters of a tensor from its range, per channel along `axis` for per
+    /// channel schemes.
+    pub fn from_tensor<const D: usize>(
+        tensor: Tensor<B, D>,
+        scheme: QuantizationScheme,
+        axis: usize,
+    ) -> Self {
+        let (min, max) = range(tensor, scheme.is_per_channel().then_some(axis));
+
+        Self::from_range(min, max, scheme, Some(axis))
+    }
+
+    /// Reshape the parameters to broadcast them with a tensor of `D` dimensions.
+    pub(crate) fn broadcast<const D: usize>(&self) -> (Tensor<B, D>, Tensor<B, D>) {
+        let mut shape = [1; D];
+        if let Some(axis) = self.axis {
+            shape[axis] = self.scale.dims()[0];
+        }
+
+        (
+            self.scale.clone().reshape(shape),
+            self.zero_point.clone().reshape(shape),
+        )
+    }
+}
+
+/// The minimum and the maximum of a tensor, or of each channel along `axis`.
+pub(crate) fn range<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    axis: Option<usize>,
+) -> (Tensor<B, 1>, Tensor<B, 1>) {
+    let tensor = tensor.detach();
+
+    match axis {
+        Some(axis) => {
+            let num_channels = tensor.dims()[axis];
+            let channels = tensor.swap_dims(0, axis).reshape([num_channels as i32, -1]);
+            let min = channels.clone().min_dim(1).reshape([num_channels]);
+            let max = channels.max_dim(1).reshape([num_channels]);
+
+            (min, max)
+        }
+        None => {
+            let tensor = tensor.flatten::<1>(0, D - 1);
+
+            (tensor.clone().min(), tensor.max())
+        }
+    }
+}
+
+/// Round the values after clamping them between integer bounds.
+pub(crate) fn round_clamped<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    min: i32,
+    max: i32,
+) -> Tensor<B, D> {
+    // The conversion to integers truncates the values, which rounds them once shifted to be
+    // positive.
+    tensor
+        .clamp(min, max)
+        .sub_scalar(min)
+        .add_scalar(0.5)
+        .int()
+        .float()
+        .add_scalar(min)
+}
+
+/// The [item](Record::Item) of the [quantization parameters](QuantizationParams).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct QuantizationParamsItem {
+    scale: Vec<f32>,
+    zero_point: Vec<i8>,
+    scheme: QuantizationScheme,
+    axis: Option<usize>,
+}
+
+impl<B: Backend> Record<B> for QuantizationParams<B> {
+    type Item<S: PrecisionSettings> = QuantizationParamsItem;
+
+    fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
+        let scale = self.scale.into_data().convert::<f32>().value;
+        let zero_point = self
+            .zero_point
+            .into_data()
+            .value
+            .into_iter()
+            .map(|value| value.elem::<f32>() as i8)
+            .collect();
+
+        QuantizationParamsItem {
+            scale,
+            zero_point,
+            scheme: self.scheme,
+            axis: self.axis,
+        }
+    }
+
+    fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
+        let num_channels = item.scale.len();
+        let zero_point = item
+            .zero_point
+            .into_iter()
+            .map(|value| value as f32)
+            .collect();
+
+        Self {
+            scale: Tensor::from_data(
+                Data::new(item.scale, [num_channels].into()).convert(),
+                device,
+            ),
+            zero_point: Tensor::from_data(
+                Data::new(zero_point, [num_channels].into()).convert(),
+                device,
+            ),
+            scheme: item.scheme,
+            axis: item.axis,
+        }
+    }
+}
+
+impl<B: Backend> Module<B> for QuantizationParams<B> {
+    type Record = Self;
+
+    fn visit<V: ModuleVisitor<B>>(&self, _visitor: &mut V) {}
+
+    fn map<M: ModuleMapper<B>>(self, _mapper: &mut M) -> Self {
+        self
+    }
+
+    fn into_record(self) -> Self::Record {
+        self
+    }
+
+    fn load_record(self, record: Self::Record) -> Self {
+        record
+    }
+
+    fn to_device(self, device: &B::Device) -> Self {
+        Self {
+            scale: self.scale.to_device(device),
+            zero_point: self.zero_point.to_device(device),
+            ..self
+        }
+    }
+
+    fn fork(self, device: &B::Device) -> Self {
+        self.to_device(device)
+    }
+
+    fn collect_devices(&self, devices: Devices<B>) -> Devices<B> {
+        self.scale.collect_devices(devices)
+    }
+}
+
+impl<B: AutodiffBackend> AutodiffModule<B> for QuantizationParams<B> {
+    type InnerModule = QuantizationParams<B::InnerBackend>;
+
+    fn valid(&self) -> Self::InnerModule {
+        QuantizationParams {
+            scale: self.scale.clone().inner(),
+            zero_point: self.zero_point.clone().inner(),
+            scheme: self.scheme.clone(),
+            axis: self.axis,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn should_compute_affine_params_including_zero() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2>::from_floats([[0.5, 1.0], [2.0, 3.0]], &device);
+
+        let params =
+            QuantizationParams::from_tensor(tensor, QuantizationScheme::PerTensorAffine, 0);
+
+        params
+            .scale
+            .into_data()
+            .assert_approx_eq(&Data::from([3.0 / 255.0]), 5);
+        params
+            .zero_point
+            .into_data()
+            .assert_approx_eq(&Data::from([-128.0]), 5);
+        assert_eq!(params.axis, None);
+    }
+
+    #[test]
+    fn should_compute_symmetric_params_per_channel() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2>::from_floats([[-2.54, 0.5], [1.0, 0.127]], &device);
+
+        let params =
+            QuantizationParams::from_tensor(tensor, QuantizationScheme::PerChannelSymmetric, 1);
+
+        params
+            .scale
+            .into_data()
+            .assert_approx_eq(&Data::from([0.02, 0.5 / 127.0]), 5);
+        params
+            .zero_point
+            .into_data()
+            .assert_approx_eq(&Data::from([0.0, 0.0]), 5);
+        assert_eq!(params.axis, Some(1));
+    }
+}
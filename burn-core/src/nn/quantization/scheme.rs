@@ -0,0 +1,105 @@
+use crate as burn;
+
+use super::{ObserverConfig, QatConv2d, QatLinear};
+use crate::config::Config;
+use crate::module::Module;
+use crate::nn::conv::Conv2d;
+use crate::nn::Linear;
+use crate::tensor::backend::Backend;
+
+/// The scheme mapping float values to 8-bit integers, where each value `x` is quantized to
+/// `q = clamp(round(x / scale) + zero_point)`.
+#[derive(Module, Config, Debug, PartialEq)]
+pub enum QuantizationScheme {
+    /// A scale and a zero point for the whole tensor, with the values in `[-128, 127]`.
+    PerTensorAffine,
+    /// A scale for the whole tensor and a zero point of `0`, with the values in `[-127, 127]`.
+    PerTensorSymmetric,
+    /// A scale and a zero point for each channel, with the values in `[-128, 127]`.
+    PerChannelAffine,
+    /// A scale for each channel and a zero point of `0`, with the values in `[-127, 127]`.
+    PerChannelSymmetric,
+}
+
+impl QuantizationScheme {
+    /// If the zero point is always `0`.
+    pub fn is_symmetric(&self) -> bool {
+        matches!(self, Self::PerTensorSymmetric | Self::PerChannelSymmetric)
+    }
+
+    /// If each channel has its own scale and zero point.
+    pub fn is_per_channel(&self) -> bool {
+        matches!(self, Self::PerChannelAffine | Self::PerChannelSymmetric)
+    }
+
+    /// The minimum and the maximum of the quantized values.
+    pub fn range(&self) -> (i32, i32) {
+        match self.is_symmetric() {
+            true => (-127, 127),
+            false => (-128, 127),
+        }
+    }
+}
+
+/// Configuration to prepare the layers of a model for quantization-aware training or for
+/// post-training quantization.
+#[derive(Config, Debug)]
+pub struct QuantizationConfig {
+    /// The scheme of the weights, per output channel when the scheme is per channel.
+    #[config(default = "QuantizationScheme::PerChannelSymmetric")]
+    pub weight: QuantizationScheme,
+    /// The scheme of the inputs of the layers, which must be per tensor.
+    #[config(default = "QuantizationScheme::PerTensorAffine")]
+    pub activation: QuantizationScheme,
+    /// The momentum of the moving averages of the ranges of the inputs, or `None` to keep the
+    /// minimum and the maximum of all the observed inputs.
+    #[config(default = "None")]
+    pub momentum: Option<f64>,
+}
+
+impl QuantizationConfig {
+    /// Wrap a [linear](Linear) layer to fake quantize its weights and its inputs.
+    pub fn init_linear<B: Backend>(&self, linear: Linear<B>) -> QatLinear<B> {
+        let [_, d_output] = linear.weight.dims();
+        let device = linear.weight.device();
+
+        QatLinear {
+            weight_observer: self.weight_observer(d_output, 1).init(&device),
+            activation_observer: self.activation_observer().init(&device),
+            linear,
+        }
+    }
+
+    /// Wrap a [conv2d](Conv2d) layer to fake quantize its weights and its inputs.
+    pub fn init_conv2d<B: Backend>(&self, conv: Conv2d<B>) -> QatConv2d<B> {
+        let [channels_out, _, _, _] = conv.weight.dims();
+        let device = conv.weight.device();
+
+        QatConv2d {
+            weight_observer: self.weight_observer(channels_out, 0).init(&device),
+            activation_observer: self.activation_observer().init(&device),
+            conv,
+        }
+    }
+
+    fn weight_observer(&self, num_channels: usize, axis: usize) -> ObserverConfig {
+        // The weights are observed once per forward pass, so their range follows the last weights.
+        let config = ObserverConfig::new(self.weight.clone()).with_momentum(Some(1.0));
+
+        match self.weight.is_per_channel() {
+            true => config.with_num_channels(num_channels).with_axis(axis),
+            false => config,
+        }
+    }
+
+    fn activation_observer(&self) -> ObserverConfig {
+        if self.activation.is_per_channel() {
+            panic!(
+                "The inputs of the layers can only be quantized per tensor, got {:?}",
+                self.activation
+            );
+        }
+
+        ObserverConfig::new(self.activation.clone()).with_momentum(self.momentum)
+    }
+}
@@ -0,0 +1,115 @@
+use super::{dequantize, quantize, QuantizationParams, QuantizationParamsItem};
+use crate::module::{AutodiffModule, Devices, Module, ModuleMapper, ModuleVisitor};
+use crate::record::{PrecisionSettings, Record};
+use crate::tensor::backend::{AutodiffBackend, Backend};
+use crate::tensor::{Data, DataSerialize, Int, Tensor};
+use serde::{Deserialize, Serialize};
+
+/// A tensor of 8-bit integers with its [quantization parameters](QuantizationParams).
+///
+/// # Notes
+///
+/// The values are stored in integer tensors of the backend, since backends don't have 8-bit
+/// tensors, but they are saved in the record of the module as 8-bit integers.
+#[derive(Clone, Debug)]
+pub struct QuantizedTensor<B: Backend, const D: usize> {
+    values: Tensor<B, D, Int>,
+    params: QuantizationParams<B>,
+}
+
+impl<B: Backend, const D: usize> QuantizedTensor<B, D> {
+    /// Quantize a float tensor with the given parameters.
+    pub fn quantize(tensor: Tensor<B, D>, params: QuantizationParams<B>) -> Self {
+        Self {
+            values: quantize(tensor, &params),
+            params,
+        }
+    }
+
+    /// The quantized values.
+    pub fn values(&self) -> Tensor<B, D, Int> {
+        self.values.clone()
+    }
+
+    /// The quantization parameters.
+    pub fn params(&self) -> &QuantizationParams<B> {
+        &self.params
+    }
+
+    /// Map the quantized values back to floats.
+    pub fn dequantize(&self) -> Tensor<B, D> {
+        dequantize(self.values.clone(), &self.params)
+    }
+}
+
+/// The [item](Record::Item) of a [quantized tensor](QuantizedTensor), with the values as 8-bit
+/// integers.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct QuantizedTensorItem {
+    values: DataSerialize<i8>,
+    params: QuantizationParamsItem,
+}
+
+impl<B: Backend, const D: usize> Record<B> for QuantizedTensor<B, D> {
+    type Item<S: PrecisionSettings> = QuantizedTensorItem;
+
+    fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
+        QuantizedTensorItem {
+            values: self.values.into_data().convert::<i8>().serialize(),
+            params: <QuantizationParams<B> as Record<B>>::into_item::<S>(self.params),
+        }
+    }
+
+    fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
+        let values = Data::<i8, D>::from(item.values).convert();
+
+        Self {
+            values: Tensor::from_data(values, device),
+            params: <QuantizationParams<B> as Record<B>>::from_item::<S>(item.params, device),
+        }
+    }
+}
+
+impl<B: Backend, const D: usize> Module<B> for QuantizedTensor<B, D> {
+    type Record = Self;
+
+    fn visit<V: ModuleVisitor<B>>(&self, _visitor: &mut V) {}
+
+    fn map<M: ModuleMapper<B>>(self, _mapper: &mut M) -> Self {
+        self
+    }
+
+    fn into_record(self) -> Self::Record {
+        self
+    }
+
+    fn load_record(self, record: Self::Record) -> Self {
+        record
+    }
+
+    fn to_device(self, device: &B::Device) -> Self {
+        Self {
+            values: self.values.to_device(device),
+            params: self.params.to_device(device),
+        }
+    }
+
+    fn fork(self, device: &B::Device) -> Self {
+        self.to_device(device)
+    }
+
+    fn collect_devices(&self, devices: Devices<B>) -> Devices<B> {
+        self.values.collect_devices(devices)
+    }
+}
+
+impl<B: AutodiffBackend, const D: usize> AutodiffModule<B> for QuantizedTensor<B, D> {
+    type InnerModule = QuantizedTensor<B::InnerBackend, D>;
+
+    fn valid(&self) -> Self::InnerModule {
+        QuantizedTensor {
+            values: self.values.clone().inner(),
+            params: self.params.valid(),
+        }
+    }
+}
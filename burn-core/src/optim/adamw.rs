@@ -4,7 +4,7 @@ use crate::{
 };
 use std::marker::PhantomData;
 
-use super::{Optimizer, SimpleOptimizer};
+use super::{MixedPrecision, Optimizer, SimpleOptimizer};
 use crate::config::Config;
 use crate::optim::adaptor::OptimizerAdaptor;
 use crate::tensor::{backend::AutodiffBackend, Tensor};
@@ -84,7 +84,31 @@ impl AdamWConfig {
     ///
     /// Returns an optimizer that can be used to optimize a module.
     pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(&self) -> impl Optimizer<M, B> {
-        let optim = AdamW {
+        let mut optim = OptimizerAdaptor::from(self.optimizer::<B::InnerBackend>());
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+
+    /// Initialize AdamW optimizer for [mixed precision](MixedPrecision) training.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that keeps full precision master weights and state.
+    pub fn init_mixed_precision<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+    ) -> impl Optimizer<M, B> {
+        let optim = self.optimizer::<<B::InnerBackend as Backend>::FullPrecisionBackend>();
+        let mut optim = OptimizerAdaptor::from(MixedPrecision::new(optim));
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+
+    fn optimizer<B: Backend>(&self) -> AdamW<B> {
+        AdamW {
             momentum: AdaptiveMomentumW {
                 beta_1: self.beta_1,
                 beta_2: self.beta_2,
@@ -92,13 +116,7 @@ impl AdamWConfig {
             },
             weight_decay: self.weight_decay,
             _phantom: Default::default(),
-        };
-
-        let mut optim = OptimizerAdaptor::from(optim);
-        if let Some(config) = &self.grad_clipping {
-            optim = optim.with_grad_clipping(config.init());
         }
-        optim
     }
 }
 
@@ -0,0 +1,153 @@
+use crate::module::{AutodiffModule, Module, ModuleMapper, ModuleVisitor, ParamId};
+use crate::record::{PrecisionSettings, Record};
+use burn_tensor::{
+    backend::{AutodiffBackend, Backend},
+    container::TensorContainer,
+    Tensor,
+};
+use hashbrown::HashSet;
+use serde::{Deserialize, Serialize};
+
+/// Type alias to the record of the averaged module, which lives on the inner backend.
+pub type AveragedModuleRecordOf<B, M> = AveragedModuleRecord<
+    <<M as AutodiffModule<B>>::InnerModule as Module<<B as AutodiffBackend>::InnerBackend>>::Record,
+>;
+
+/// Maintain an averaged copy of the weights of a [module](AutodiffModule) during training.
+///
+/// The averaged module lives on the inner backend, so it never tracks gradients and can be used
+/// directly for validation or inference.
+pub trait ModuleAveraging<B, M>: Send + Sync
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B>,
+{
+    /// Update the averaged weights with the weights of the given module.
+    ///
+    /// This should be called after each optimizer step.
+    fn update(&mut self, module: &M);
+
+    /// Get the averaged module, if at least one update was registered.
+    fn module(&self) -> Option<M::InnerModule>;
+
+    /// Get the current state of the averaging as a [record](Record).
+    fn to_record(&self) -> AveragedModuleRecordOf<B, M>;
+
+    /// Load the state of the averaging from a [record](Record).
+    ///
+    /// The given module is used as a template to create the averaged module.
+    fn load_record(&mut self, module: &M, record: AveragedModuleRecordOf<B, M>);
+}
+
+/// Record of a [module averaging](ModuleAveraging).
+#[derive(new, Debug, Clone)]
+pub struct AveragedModuleRecord<R> {
+    /// The record of the averaged module.
+    pub module: Option<R>,
+    /// The number of updates taken into account in the average.
+    pub num_updates: usize,
+}
+
+/// Serializable item of an [averaged module record](AveragedModuleRecord).
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct AveragedModuleRecordItem<B: Backend, R: Record<B>, S: PrecisionSettings> {
+    module: Option<R::Item<S>>,
+    num_updates: usize,
+}
+
+impl<B, R> Record<B> for AveragedModuleRecord<R>
+where
+    B: Backend,
+    R: Record<B>,
+{
+    type Item<S: PrecisionSettings> = AveragedModuleRecordItem<B, R, S>;
+
+    fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
+        AveragedModuleRecordItem {
+            module: self.module.map(Record::into_item),
+            num_updates: self.num_updates,
+        }
+    }
+
+    fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
+        Self {
+            module: item.module.map(|item| R::from_item(item, device)),
+            num_updates: item.num_updates,
+        }
+    }
+}
+
+/// Snapshot of the float tensors of a module, detached from the autodiff graph.
+pub(crate) struct ModuleParams {
+    tensors: TensorContainer<ParamId>,
+    trainable: HashSet<ParamId>,
+}
+
+impl ModuleParams {
+    /// Collect the float tensors of the given module.
+    ///
+    /// Tensors that require gradients are considered trainable parameters, others are buffers
+    /// such as [running states](crate::module::RunningState).
+    pub(crate) fn from_module<B: AutodiffBackend, M: AutodiffModule<B>>(module: &M) -> Self {
+        let mut params = Self {
+            tensors: TensorContainer::new(),
+            trainable: HashSet::new(),
+        };
+        module.visit(&mut ParamsCollector {
+            params: &mut params,
+        });
+
+        params
+    }
+
+    /// Move the averaged module toward the collected parameters.
+    ///
+    /// Each trainable parameter is updated with `averaged * (1 - weight) + current * weight`,
+    /// while buffers are copied as is.
+    pub(crate) fn average_into<B: Backend, M: Module<B>>(mut self, averaged: M, weight: f64) -> M {
+        let mut mapper = ParamsAverager {
+            params: &mut self,
+            weight,
+        };
+        averaged.map(&mut mapper)
+    }
+}
+
+struct ParamsCollector<'a> {
+    params: &'a mut ModuleParams,
+}
+
+impl<'a, B: AutodiffBackend> ModuleVisitor<B> for ParamsCollector<'a> {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        if tensor.is_require_grad() {
+            self.params.trainable.insert(id.clone());
+        }
+
+        self.params
+            .tensors
+            .register::<B::InnerBackend, D>(id.clone(), tensor.clone().inner());
+    }
+}
+
+struct ParamsAverager<'a> {
+    params: &'a mut ModuleParams,
+    weight: f64,
+}
+
+impl<'a, B: Backend> ModuleMapper<B> for ParamsAverager<'a> {
+    fn map_float<const D: usize>(&mut self, id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let current = match self.params.tensors.remove::<B, D>(id) {
+            Some(current) => current,
+            None => return tensor,
+        };
+
+        if !self.params.trainable.contains(id) {
+            return current;
+        }
+
+        tensor
+            .mul_scalar(1.0 - self.weight)
+            .add(current.mul_scalar(self.weight))
+    }
+}
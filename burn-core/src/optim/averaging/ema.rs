@@ -0,0 +1,149 @@
+use super::{AveragedModuleRecord, AveragedModuleRecordOf, ModuleAveraging, ModuleParams};
+use crate as burn;
+use crate::config::Config;
+use crate::module::{AutodiffModule, Module};
+use burn_tensor::backend::AutodiffBackend;
+
+/// Configuration to create an [exponential moving average](EmaModule) of a module's weights.
+#[derive(Config)]
+pub struct EmaConfig {
+    /// The decay applied to the averaged weights at each update.
+    #[config(default = 0.999)]
+    decay: f64,
+    /// Ramp up the decay during the first updates, using `min(decay, (1 + n) / (10 + n))`
+    /// where `n` is the number of updates.
+    #[config(default = false)]
+    warmup: bool,
+}
+
+/// Keep an exponential moving average (EMA) of the weights of a module.
+///
+/// After each update, every trainable parameter becomes
+/// `averaged * decay + current * (1 - decay)`, while buffers such as the
+/// [running states](crate::module::RunningState) of batch norm are copied from the current
+/// module.
+///
+/// The module can be created with [EmaConfig](EmaConfig).
+pub struct EmaModule<B: AutodiffBackend, M: AutodiffModule<B>> {
+    module: Option<M::InnerModule>,
+    decay: f64,
+    warmup: bool,
+    num_updates: usize,
+}
+
+impl EmaConfig {
+    /// Initialize a new [exponential moving average](EmaModule).
+    pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(&self) -> EmaModule<B, M> {
+        EmaModule {
+            module: None,
+            decay: self.decay,
+            warmup: self.warmup,
+            num_updates: 0,
+        }
+    }
+}
+
+impl<B: AutodiffBackend, M: AutodiffModule<B>> EmaModule<B, M> {
+    /// The decay used for the next update.
+    pub fn decay(&self) -> f64 {
+        if !self.warmup {
+            return self.decay;
+        }
+
+        let num_updates = self.num_updates as f64;
+        f64::min(self.decay, (1.0 + num_updates) / (10.0 + num_updates))
+    }
+}
+
+impl<B: AutodiffBackend, M: AutodiffModule<B>> ModuleAveraging<B, M> for EmaModule<B, M> {
+    fn update(&mut self, module: &M) {
+        let decay = self.decay();
+
+        self.module = Some(match self.module.take() {
+            Some(averaged) => ModuleParams::from_module(module).average_into(averaged, 1.0 - decay),
+            None => module.valid(),
+        });
+        self.num_updates += 1;
+    }
+
+    fn module(&self) -> Option<M::InnerModule> {
+        self.module.clone()
+    }
+
+    fn to_record(&self) -> AveragedModuleRecordOf<B, M> {
+        AveragedModuleRecord::new(
+            self.module.clone().map(Module::into_record),
+            self.num_updates,
+        )
+    }
+
+    fn load_record(&mut self, module: &M, record: AveragedModuleRecordOf<B, M>) {
+        self.module = record
+            .module
+            .map(|record| module.valid().load_record(record));
+        self.num_updates = record.num_updates;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        nn::{Linear, LinearConfig},
+        optim::averaging::tests::AddMapper,
+        TestAutodiffBackend,
+    };
+
+    #[test]
+    fn should_average_weights_exponentially() {
+        let device = Default::default();
+        let layer = LinearConfig::new(4, 4).init::<TestAutodiffBackend>(&device);
+        let mut ema = EmaConfig::new().with_decay(0.5).init();
+
+        ema.update(&layer);
+        let layer_updated = layer.clone().map(&mut AddMapper(2.0));
+        ema.update(&layer_updated);
+
+        let expected = layer.weight.val().inner().add_scalar(1.0).into_data();
+        ema.module()
+            .unwrap()
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_ramp_up_decay_with_warmup() {
+        let device = Default::default();
+        let layer = LinearConfig::new(4, 4).init::<TestAutodiffBackend>(&device);
+        let mut ema: EmaModule<TestAutodiffBackend, Linear<TestAutodiffBackend>> =
+            EmaConfig::new().with_warmup(true).init();
+
+        assert_eq!(ema.decay(), 0.1);
+        ema.update(&layer);
+        assert_eq!(ema.decay(), 2.0 / 11.0);
+    }
+
+    #[test]
+    fn should_load_state() {
+        let device = Default::default();
+        let layer = LinearConfig::new(4, 4).init::<TestAutodiffBackend>(&device);
+        let mut ema = EmaConfig::new().with_decay(0.5).init();
+
+        ema.update(&layer);
+        ema.update(&layer.clone().map(&mut AddMapper(2.0)));
+
+        let mut ema_new = EmaConfig::new().init();
+        ema_new.load_record(&layer, ema.to_record());
+
+        assert_eq!(ema_new.num_updates, 2);
+        ema_new
+            .module()
+            .unwrap()
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&ema.module().unwrap().weight.val().into_data(), 3);
+    }
+}
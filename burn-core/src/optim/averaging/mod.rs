@@ -0,0 +1,30 @@
+mod base;
+mod ema;
+mod swa;
+
+pub use base::*;
+pub use ema::*;
+pub use swa::*;
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use crate::module::{ModuleMapper, ParamId};
+    use crate::tensor::{backend::Backend, Tensor};
+
+    /// Add a value to all the parameters, keeping them as they are otherwise.
+    pub(crate) struct AddMapper(pub(crate) f32);
+
+    impl<B: Backend> ModuleMapper<B> for AddMapper {
+        fn map_float<const D: usize>(
+            &mut self,
+            _id: &ParamId,
+            tensor: Tensor<B, D>,
+        ) -> Tensor<B, D> {
+            let is_require_grad = tensor.is_require_grad();
+            tensor
+                .add_scalar(self.0)
+                .detach()
+                .set_require_grad(is_require_grad)
+        }
+    }
+}
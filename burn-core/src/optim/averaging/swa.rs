@@ -0,0 +1,125 @@
+use super::{AveragedModuleRecord, AveragedModuleRecordOf, ModuleAveraging, ModuleParams};
+use crate as burn;
+use crate::config::Config;
+use crate::module::{AutodiffModule, Module};
+use burn_tensor::backend::AutodiffBackend;
+
+/// Configuration to create a [stochastic weight averaging](SwaModule) of a module's weights.
+#[derive(Config)]
+pub struct SwaConfig {
+    /// The number of updates to skip before starting to average the weights.
+    #[config(default = 0)]
+    start: usize,
+    /// The number of updates between two averaged snapshots.
+    #[config(default = 1)]
+    frequency: usize,
+}
+
+/// Stochastic weight averaging (SWA) as described in
+/// [Averaging Weights Leads to Wider Optima and Better Generalization](https://arxiv.org/abs/1803.05407).
+///
+/// Once the `start` update is reached, a snapshot of the weights is added to an equally weighted
+/// running average every `frequency` updates. Buffers such as the
+/// [running states](crate::module::RunningState) of batch norm are copied from the latest
+/// snapshot.
+///
+/// The module can be created with [SwaConfig](SwaConfig).
+pub struct SwaModule<B: AutodiffBackend, M: AutodiffModule<B>> {
+    module: Option<M::InnerModule>,
+    start: usize,
+    frequency: usize,
+    num_steps: usize,
+    num_averaged: usize,
+}
+
+impl SwaConfig {
+    /// Initialize a new [stochastic weight averaging](SwaModule).
+    pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(&self) -> SwaModule<B, M> {
+        SwaModule {
+            module: None,
+            start: self.start,
+            frequency: usize::max(self.frequency, 1),
+            num_steps: 0,
+            num_averaged: 0,
+        }
+    }
+}
+
+impl<B: AutodiffBackend, M: AutodiffModule<B>> SwaModule<B, M> {
+    /// The number of snapshots taken into account in the average.
+    pub fn num_averaged(&self) -> usize {
+        self.num_averaged
+    }
+}
+
+impl<B: AutodiffBackend, M: AutodiffModule<B>> ModuleAveraging<B, M> for SwaModule<B, M> {
+    fn update(&mut self, module: &M) {
+        self.num_steps += 1;
+
+        if self.num_steps <= self.start {
+            return;
+        }
+
+        let num_snapshots = (self.num_steps - self.start) / self.frequency;
+        if num_snapshots == self.num_averaged {
+            return;
+        }
+
+        self.num_averaged = num_snapshots;
+        let weight = 1.0 / self.num_averaged as f64;
+
+        self.module = Some(match self.module.take() {
+            Some(averaged) => ModuleParams::from_module(module).average_into(averaged, weight),
+            None => module.valid(),
+        });
+    }
+
+    fn module(&self) -> Option<M::InnerModule> {
+        self.module.clone()
+    }
+
+    fn to_record(&self) -> AveragedModuleRecordOf<B, M> {
+        AveragedModuleRecord::new(self.module.clone().map(Module::into_record), self.num_steps)
+    }
+
+    fn load_record(&mut self, module: &M, record: AveragedModuleRecordOf<B, M>) {
+        self.module = record
+            .module
+            .map(|record| module.valid().load_record(record));
+        self.num_steps = record.num_updates;
+        self.num_averaged = match self.num_steps > self.start {
+            true => (self.num_steps - self.start) / self.frequency,
+            false => 0,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{nn::LinearConfig, optim::averaging::tests::AddMapper, TestAutodiffBackend};
+
+    #[test]
+    fn should_compute_the_mean_of_snapshots_after_start() {
+        let device = Default::default();
+        let layer = LinearConfig::new(4, 4).init::<TestAutodiffBackend>(&device);
+        let mut swa = SwaConfig::new().with_start(1).init();
+
+        // Skipped, before start.
+        swa.update(&layer.clone().map(&mut AddMapper(10.0)));
+        assert!(swa.module().is_none());
+
+        swa.update(&layer);
+        swa.update(&layer.clone().map(&mut AddMapper(1.0)));
+        swa.update(&layer.clone().map(&mut AddMapper(2.0)));
+
+        let expected = layer.weight.val().inner().add_scalar(1.0).into_data();
+        assert_eq!(swa.num_averaged(), 3);
+        swa.module()
+            .unwrap()
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&expected, 3);
+    }
+}
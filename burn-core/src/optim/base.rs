@@ -2,6 +2,7 @@ use super::GradientsParams;
 use crate::module::AutodiffModule;
 use crate::record::Record;
 use crate::tensor::backend::AutodiffBackend;
+use crate::tensor::Tensor;
 use crate::LearningRate;
 
 /// General trait to optimize [module](AutodiffModule).
@@ -17,9 +18,33 @@ where
     /// The updated module is returned.
     fn step(&mut self, lr: LearningRate, module: M, grads: GradientsParams) -> M;
 
+    /// The global norm of the gradients used in the last step, before clipping.
+    ///
+    /// Only available for optimizers with [gradient clipping](crate::grad_clipping::GradientClipping).
+    fn grad_norm(&self) -> Option<Tensor<B::InnerBackend, 1>> {
+        None
+    }
+
     /// Get the current state of the optimizer as a [record](Record).
     fn to_record(&self) -> Self::Record;
 
     /// Load the state of the optimizer as a [record](Record).
     fn load_record(self, record: Self::Record) -> Self;
 }
+
+/// Trait for [optimizers](Optimizer) that may evaluate the loss several times per step, such as
+/// optimizers relying on a line search.
+pub trait ClosureOptimizer<M, B>: Optimizer<M, B>
+where
+    M: AutodiffModule<B>,
+    B: AutodiffBackend,
+{
+    /// Perform the optimizer step using the given learning rate.
+    ///
+    /// The closure computes the loss of the given module and can be called multiple times during
+    /// a single step, the gradients being computed from the returned loss. The updated module is
+    /// returned along with the loss of the module before the step.
+    fn step_with_closure<F>(&mut self, lr: LearningRate, module: M, closure: F) -> (M, f64)
+    where
+        F: FnMut(&M) -> Tensor<B, 1>;
+}
@@ -3,9 +3,16 @@ use core::marker::PhantomData;
 use crate::module::{AutodiffModule, ModuleVisitor, ParamId};
 
 use burn_tensor::{backend::AutodiffBackend, Tensor};
+use hashbrown::HashMap;
 
 use super::GradientsParams;
 
+/// Record of the gradients accumulated by a [gradients accumulator](GradientsAccumulator).
+///
+/// Gradients are flattened, they are reshaped like their parameter when loaded.
+pub type GradientsAccumulatorRecord<B> =
+    HashMap<ParamId, Tensor<<B as AutodiffBackend>::InnerBackend, 1>>;
+
 /// Accumulate gradients into a single [Gradients](AutodiffBackend::Gradients) object.
 pub struct GradientsAccumulator<M> {
     grads: GradientsParams,
@@ -45,6 +52,66 @@ impl<M> GradientsAccumulator<M> {
 
         grads
     }
+
+    /// Get the gradients accumulated so far as a [record](GradientsAccumulatorRecord), so that
+    /// the accumulation can be resumed later.
+    pub fn to_record<B: AutodiffBackend>(&self, module: &M) -> GradientsAccumulatorRecord<B>
+    where
+        M: AutodiffModule<B>,
+    {
+        let mut visitor = GradientsRecorder {
+            grads: &self.grads,
+            record: HashMap::new(),
+        };
+        module.visit(&mut visitor);
+
+        visitor.record
+    }
+
+    /// Load the accumulated gradients from a [record](GradientsAccumulatorRecord), replacing
+    /// the current ones.
+    pub fn load_record<B: AutodiffBackend>(
+        &mut self,
+        module: &M,
+        record: GradientsAccumulatorRecord<B>,
+    ) where
+        M: AutodiffModule<B>,
+    {
+        let mut visitor = GradientsLoader {
+            grads: GradientsParams::new(),
+            record,
+        };
+        module.visit(&mut visitor);
+
+        self.grads = visitor.grads;
+    }
+}
+
+struct GradientsRecorder<'a, B: AutodiffBackend> {
+    grads: &'a GradientsParams,
+    record: GradientsAccumulatorRecord<B>,
+}
+
+impl<'a, B: AutodiffBackend> ModuleVisitor<B> for GradientsRecorder<'a, B> {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        if let Some(grad) = self.grads.get::<B::InnerBackend, D>(id) {
+            self.record.insert(id.clone(), grad.flatten(0, D - 1));
+        }
+    }
+}
+
+struct GradientsLoader<B: AutodiffBackend> {
+    grads: GradientsParams,
+    record: GradientsAccumulatorRecord<B>,
+}
+
+impl<B: AutodiffBackend> ModuleVisitor<B> for GradientsLoader<B> {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        if let Some(grad) = self.record.remove(id) {
+            self.grads
+                .register::<B::InnerBackend, D>(id.clone(), grad.reshape(tensor.shape()));
+        }
+    }
 }
 
 #[derive(new)]
@@ -114,6 +181,29 @@ mod tests {
         assert_eq!(grads.len(), 2)
     }
 
+    #[test]
+    fn test_resume_accumulation_from_record() {
+        let device = Default::default();
+        let mut accumulator = GradientsAccumulator::new();
+        let layer = layer::<TestAutodiffBackend>(&device);
+        let loss = layer.forward(random_tensor(&device));
+        accumulator.accumulate(&layer, GradientsParams::from_grads(loss.backward(), &layer));
+
+        let mut accumulator_resumed = GradientsAccumulator::new();
+        accumulator_resumed.load_record(&layer, accumulator.to_record(&layer));
+
+        let id = &layer.weight.id;
+        type InnerBackend = <TestAutodiffBackend as AutodiffBackend>::InnerBackend;
+        let expected = accumulator.grads().get::<InnerBackend, 2>(id).unwrap();
+        let grads = accumulator_resumed.grads();
+        assert_eq!(grads.len(), 2);
+        grads
+            .get::<InnerBackend, 2>(id)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 5);
+    }
+
     fn layer<B: Backend>(device: &B::Device) -> Linear<B> {
         LinearConfig::new(20, 20).with_bias(true).init(device)
     }
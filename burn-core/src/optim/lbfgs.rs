@@ -0,0 +1,665 @@
+use crate::module::{AutodiffModule, ModuleMapper, ModuleVisitor, ParamId};
+use crate::{self as burn, LearningRate};
+
+use super::{ClosureOptimizer, GradientsParams, Optimizer};
+use crate::config::Config;
+use crate::record::Record;
+use crate::tensor::Tensor;
+use alloc::vec::Vec;
+use burn_tensor::backend::{AutodiffBackend, Backend};
+use burn_tensor::ElementConversion;
+use core::marker::PhantomData;
+
+/// Line search used by the [L-BFGS](Lbfgs) optimizer.
+#[derive(Config, Debug, PartialEq, Eq)]
+pub enum LineSearch {
+    /// Line search satisfying the strong Wolfe conditions, using cubic interpolation.
+    StrongWolfe,
+}
+
+/// Configuration to create the [L-BFGS](Lbfgs) optimizer.
+#[derive(Config)]
+pub struct LbfgsConfig {
+    /// Maximum number of iterations per optimization step.
+    #[config(default = 20)]
+    max_iter: usize,
+    /// Maximum number of loss evaluations per optimization step, defaults to `max_iter * 5 / 4`.
+    max_eval: Option<usize>,
+    /// Termination tolerance on the first order optimality.
+    #[config(default = 1e-7)]
+    tolerance_grad: f64,
+    /// Termination tolerance on the loss and parameter changes.
+    #[config(default = 1e-9)]
+    tolerance_change: f64,
+    /// Number of curvature pairs kept to approximate the inverse Hessian.
+    #[config(default = 100)]
+    history_size: usize,
+    /// [Line search](LineSearch) used when stepping with a closure, a fixed step size equal to
+    /// the learning rate is used otherwise.
+    line_search: Option<LineSearch>,
+}
+
+/// Limited-memory BFGS optimizer.
+///
+/// All trainable parameters of the module are flattened into a single vector, so the optimizer
+/// works with any [module](AutodiffModule). Since each iteration may need to evaluate the loss
+/// more than once, it is meant to be used with
+/// [step_with_closure](ClosureOptimizer::step_with_closure), which runs up to `max_iter`
+/// iterations. When used as a regular [optimizer](Optimizer), a single iteration is performed
+/// with the given gradients and no line search.
+///
+/// The optimizer can be configured with [LbfgsConfig](LbfgsConfig).
+pub struct Lbfgs<M, B: AutodiffBackend> {
+    max_iter: usize,
+    max_eval: usize,
+    tolerance_grad: f64,
+    tolerance_change: f64,
+    history_size: usize,
+    line_search: Option<LineSearch>,
+    state: Option<LbfgsState<B>>,
+    module: PhantomData<M>,
+}
+
+/// State of [Lbfgs](Lbfgs).
+#[derive(Record, Clone)]
+pub struct LbfgsState<B: Backend> {
+    /// The flattened gradient at the start of the last iteration.
+    pub grad: Tensor<B, 1>,
+    /// The last search direction.
+    pub direction: Tensor<B, 1>,
+    /// The step size taken along the last search direction.
+    pub step_size: f64,
+    /// The parameter differences of the curvature pairs.
+    pub steps: Vec<Tensor<B, 1>>,
+    /// The gradient differences of the curvature pairs.
+    pub grad_diffs: Vec<Tensor<B, 1>>,
+    /// The inverse of the dot product of each curvature pair.
+    pub rho: Vec<f64>,
+    /// The scaling of the initial inverse Hessian approximation.
+    pub hessian_diag: f64,
+    /// The total number of iterations performed.
+    pub num_iter: usize,
+}
+
+impl LbfgsConfig {
+    /// Initialize the [L-BFGS](Lbfgs) optimizer.
+    pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(&self) -> Lbfgs<M, B> {
+        Lbfgs {
+            max_iter: self.max_iter,
+            max_eval: self.max_eval.unwrap_or(self.max_iter * 5 / 4),
+            tolerance_grad: self.tolerance_grad,
+            tolerance_change: self.tolerance_change,
+            history_size: self.history_size,
+            line_search: self.line_search.clone(),
+            state: None,
+            module: PhantomData,
+        }
+    }
+}
+
+impl<M, B> Lbfgs<M, B>
+where
+    M: AutodiffModule<B>,
+    B: AutodiffBackend,
+{
+    /// Compute the search direction for the given gradient with the two-loop recursion,
+    /// updating the curvature pairs with the last iteration.
+    fn direction(&mut self, grad: Tensor<B, 1>) -> Tensor<B, 1> {
+        let state = match &mut self.state {
+            Some(state) => state,
+            None => return grad.neg(),
+        };
+
+        let grad_diff = grad.clone().sub(state.grad.clone());
+        let step = state.direction.clone().mul_scalar(state.step_size);
+        let curvature = dot(&grad_diff, &step);
+
+        if curvature > 1e-10 {
+            if state.steps.len() == self.history_size {
+                state.steps.remove(0);
+                state.grad_diffs.remove(0);
+                state.rho.remove(0);
+            }
+
+            state.hessian_diag = curvature / dot(&grad_diff, &grad_diff);
+            state.steps.push(step);
+            state.grad_diffs.push(grad_diff);
+            state.rho.push(1.0 / curvature);
+        }
+
+        let num_pairs = state.steps.len();
+        let mut alpha = Vec::with_capacity(num_pairs);
+        let mut direction = grad.neg();
+
+        for i in (0..num_pairs).rev() {
+            let alpha_i = dot(&state.steps[i], &direction) * state.rho[i];
+            direction = direction.sub(state.grad_diffs[i].clone().mul_scalar(alpha_i));
+            alpha.push(alpha_i);
+        }
+        alpha.reverse();
+
+        let mut direction = direction.mul_scalar(state.hessian_diag);
+
+        for (i, alpha_i) in alpha.into_iter().enumerate() {
+            let beta = dot(&state.grad_diffs[i], &direction) * state.rho[i];
+            direction = direction.add(state.steps[i].clone().mul_scalar(alpha_i - beta));
+        }
+
+        direction
+    }
+
+    /// The step size to try along the search direction.
+    ///
+    /// The first iteration is scaled by the gradient norm, since no curvature information is
+    /// available yet.
+    fn initial_step_size(&self, lr: LearningRate, grad: &Tensor<B, 1>) -> f64 {
+        match self.state {
+            Some(_) => lr,
+            None => {
+                let norm = grad.clone().abs().sum().into_scalar().elem::<f64>();
+                f64::min(1.0, 1.0 / norm) * lr
+            }
+        }
+    }
+
+    /// Save the gradient, direction and step size of the current iteration.
+    fn register(&mut self, grad: Tensor<B, 1>, direction: Tensor<B, 1>, step_size: f64) {
+        self.state = Some(match self.state.take() {
+            Some(state) => LbfgsState {
+                grad,
+                direction,
+                step_size,
+                num_iter: state.num_iter + 1,
+                ..state
+            },
+            None => LbfgsState {
+                grad,
+                direction,
+                step_size,
+                steps: Vec::new(),
+                grad_diffs: Vec::new(),
+                rho: Vec::new(),
+                hessian_diag: 1.0,
+                num_iter: 1,
+            },
+        });
+    }
+}
+
+impl<M, B> Optimizer<M, B> for Lbfgs<M, B>
+where
+    M: AutodiffModule<B>,
+    B: AutodiffBackend,
+{
+    type Record = Option<LbfgsState<B>>;
+
+    fn step(&mut self, lr: LearningRate, module: M, grads: GradientsParams) -> M {
+        let grad = flatten_grads(&module, grads);
+        let direction = self.direction(grad.clone());
+        let step_size = self.initial_step_size(lr, &grad);
+
+        self.register(grad, direction.clone(), step_size);
+        add_flat(module, direction.mul_scalar(step_size))
+    }
+
+    fn to_record(&self) -> Self::Record {
+        self.state.clone()
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.state = record;
+        self
+    }
+}
+
+impl<M, B> ClosureOptimizer<M, B> for Lbfgs<M, B>
+where
+    M: AutodiffModule<B>,
+    B: AutodiffBackend,
+{
+    fn step_with_closure<F>(&mut self, lr: LearningRate, mut module: M, mut closure: F) -> (M, f64)
+    where
+        F: FnMut(&M) -> Tensor<B, 1>,
+    {
+        let (mut loss, mut grad) = evaluate(&module, &mut closure);
+        let loss_init = loss;
+        let mut num_evals = 1;
+
+        if max_abs(&grad) <= self.tolerance_grad {
+            return (module, loss_init);
+        }
+
+        for iter in 1..=self.max_iter {
+            let direction = self.direction(grad.clone());
+            let mut step_size = self.initial_step_size(lr, &grad);
+            let grad_dot_dir = dot(&grad, &direction);
+
+            self.register(grad.clone(), direction.clone(), step_size);
+
+            // The direction is not a descent direction.
+            if grad_dot_dir > -self.tolerance_change {
+                break;
+            }
+
+            let loss_prev = loss;
+
+            match self.line_search {
+                Some(LineSearch::StrongWolfe) => {
+                    let base = module.clone();
+                    let mut objective = |step_size: f64| {
+                        let module =
+                            add_flat(base.clone(), direction.clone().mul_scalar(step_size));
+                        evaluate(&module, &mut closure)
+                    };
+                    let (point, evals) = strong_wolfe(
+                        &mut objective,
+                        &direction,
+                        step_size,
+                        LinePoint::new(0.0, loss, grad, grad_dot_dir),
+                        self.tolerance_change,
+                    );
+
+                    step_size = point.step_size;
+                    loss = point.loss;
+                    grad = point.grad;
+                    num_evals += evals;
+
+                    if let Some(state) = &mut self.state {
+                        state.step_size = step_size;
+                    }
+                    module = add_flat(module, direction.clone().mul_scalar(step_size));
+                }
+                None => {
+                    module = add_flat(module, direction.clone().mul_scalar(step_size));
+
+                    if iter != self.max_iter {
+                        (loss, grad) = evaluate(&module, &mut closure);
+                        num_evals += 1;
+                    }
+                }
+            }
+
+            if iter == self.max_iter || num_evals >= self.max_eval {
+                break;
+            }
+
+            if max_abs(&grad) <= self.tolerance_grad {
+                break;
+            }
+
+            if max_abs(&direction) * step_size <= self.tolerance_change {
+                break;
+            }
+
+            if f64::abs(loss - loss_prev) < self.tolerance_change {
+                break;
+            }
+        }
+
+        (module, loss_init)
+    }
+}
+
+/// Point evaluated during the line search.
+#[derive(new)]
+struct LinePoint<B: Backend> {
+    step_size: f64,
+    loss: f64,
+    grad: Tensor<B, 1>,
+    /// The directional derivative at this point.
+    grad_dot_dir: f64,
+}
+
+impl<B: Backend> Clone for LinePoint<B> {
+    fn clone(&self) -> Self {
+        Self::new(
+            self.step_size,
+            self.loss,
+            self.grad.clone(),
+            self.grad_dot_dir,
+        )
+    }
+}
+
+const WOLFE_C1: f64 = 1e-4;
+const WOLFE_C2: f64 = 0.9;
+const LINE_SEARCH_MAX_ITER: usize = 25;
+
+/// Line search finding a step size that satisfies the strong Wolfe conditions.
+///
+/// Returns the selected point along with the number of loss evaluations.
+fn strong_wolfe<B: Backend, F>(
+    objective: &mut F,
+    direction: &Tensor<B, 1>,
+    step_size: f64,
+    init: LinePoint<B>,
+    tolerance_change: f64,
+) -> (LinePoint<B>, usize)
+where
+    F: FnMut(f64) -> (f64, Tensor<B, 1>),
+{
+    let direction_max = max_abs(direction);
+    let mut evaluate = |step_size: f64| {
+        let (loss, grad) = objective(step_size);
+        let grad_dot_dir = dot(&grad, direction);
+        LinePoint::new(step_size, loss, grad, grad_dot_dir)
+    };
+    let sufficient_decrease = |point: &LinePoint<B>| {
+        point.loss <= init.loss + WOLFE_C1 * point.step_size * init.grad_dot_dir
+    };
+    let curvature =
+        |point: &LinePoint<B>| point.grad_dot_dir.abs() <= -WOLFE_C2 * init.grad_dot_dir;
+
+    let mut current = evaluate(step_size);
+    let mut prev = init.clone();
+    let mut num_evals = 1;
+    let mut iter = 0;
+
+    // Find an interval containing a point satisfying the conditions.
+    let mut bracket = loop {
+        if iter == LINE_SEARCH_MAX_ITER {
+            break [init.clone(), current];
+        }
+
+        if !sufficient_decrease(&current) || (iter > 1 && current.loss >= prev.loss) {
+            break [prev, current];
+        }
+
+        if curvature(&current) {
+            return (current, num_evals);
+        }
+
+        if current.grad_dot_dir >= 0.0 {
+            break [prev, current];
+        }
+
+        let min_step = current.step_size + 0.01 * (current.step_size - prev.step_size);
+        let max_step = current.step_size * 10.0;
+        let step_size = cubic_interpolate(&prev, &current, (min_step, max_step));
+
+        prev = current;
+        current = evaluate(step_size);
+        num_evals += 1;
+        iter += 1;
+    };
+
+    // Zoom into the interval until a point satisfies the conditions.
+    let mut insufficient_progress = false;
+    let (mut low, mut high) = match bracket[0].loss <= bracket[1].loss {
+        true => (0, 1),
+        false => (1, 0),
+    };
+
+    while iter < LINE_SEARCH_MAX_ITER {
+        let step_min = f64::min(bracket[0].step_size, bracket[1].step_size);
+        let step_max = f64::max(bracket[0].step_size, bracket[1].step_size);
+
+        if (step_max - step_min) * direction_max < tolerance_change {
+            break;
+        }
+
+        let mut step_size = cubic_interpolate(&bracket[0], &bracket[1], (step_min, step_max));
+
+        // Force the step away from the boundaries when the interval barely shrinks.
+        let eps = 0.1 * (step_max - step_min);
+        if f64::min(step_max - step_size, step_size - step_min) < eps {
+            if insufficient_progress || step_size >= step_max || step_size <= step_min {
+                step_size = match (step_size - step_max).abs() < (step_size - step_min).abs() {
+                    true => step_max - eps,
+                    false => step_min + eps,
+                };
+                insufficient_progress = false;
+            } else {
+                insufficient_progress = true;
+            }
+        } else {
+            insufficient_progress = false;
+        }
+
+        let point = evaluate(step_size);
+        num_evals += 1;
+        iter += 1;
+
+        if !sufficient_decrease(&point) || point.loss >= bracket[low].loss {
+            bracket[high] = point;
+            (low, high) = match bracket[0].loss <= bracket[1].loss {
+                true => (0, 1),
+                false => (1, 0),
+            };
+        } else {
+            if curvature(&point) {
+                return (point, num_evals);
+            }
+
+            if point.grad_dot_dir * (bracket[high].step_size - bracket[low].step_size) >= 0.0 {
+                bracket[high] = bracket[low].clone();
+            }
+            bracket[low] = point;
+        }
+    }
+
+    let [first, second] = bracket;
+    let point = match low {
+        0 => first,
+        _ => second,
+    };
+
+    (point, num_evals)
+}
+
+/// Minimizer of the cubic interpolating the two points, clamped to the given bounds.
+fn cubic_interpolate<B: Backend>(
+    point_1: &LinePoint<B>,
+    point_2: &LinePoint<B>,
+    (bound_min, bound_max): (f64, f64),
+) -> f64 {
+    let (x1, f1, g1) = (point_1.step_size, point_1.loss, point_1.grad_dot_dir);
+    let (x2, f2, g2) = (point_2.step_size, point_2.loss, point_2.grad_dot_dir);
+
+    let d1 = g1 + g2 - 3.0 * (f1 - f2) / (x1 - x2);
+    let d2_square = d1 * d1 - g1 * g2;
+
+    if d2_square < 0.0 {
+        return (bound_min + bound_max) / 2.0;
+    }
+
+    let d2 = d2_square.sqrt();
+    let min_pos = match x1 <= x2 {
+        true => x2 - (x2 - x1) * ((g2 + d2 - d1) / (g2 - g1 + 2.0 * d2)),
+        false => x1 - (x1 - x2) * ((g1 + d2 - d1) / (g1 - g2 + 2.0 * d2)),
+    };
+
+    // Fall back to bisection when the interpolation is degenerate.
+    if min_pos.is_nan() {
+        return (bound_min + bound_max) / 2.0;
+    }
+
+    min_pos.clamp(bound_min, bound_max)
+}
+
+/// Compute the loss of the module and its flattened gradient.
+fn evaluate<B, M, F>(module: &M, closure: &mut F) -> (f64, Tensor<B, 1>)
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B>,
+    F: FnMut(&M) -> Tensor<B, 1>,
+{
+    let loss = closure(module);
+    let grads = GradientsParams::from_grads(loss.backward(), module);
+    let grad = flatten_grads(module, grads);
+
+    (loss.into_scalar().elem::<f64>(), grad)
+}
+
+fn dot<B: Backend>(lhs: &Tensor<B, 1>, rhs: &Tensor<B, 1>) -> f64 {
+    lhs.clone()
+        .mul(rhs.clone())
+        .sum()
+        .into_scalar()
+        .elem::<f64>()
+}
+
+fn max_abs<B: Backend>(tensor: &Tensor<B, 1>) -> f64 {
+    tensor.clone().abs().max().into_scalar().elem::<f64>()
+}
+
+/// Concatenate the gradients of all trainable parameters into a single vector.
+///
+/// Parameters without gradients are considered to have a zero gradient.
+fn flatten_grads<B: AutodiffBackend, M: AutodiffModule<B>>(
+    module: &M,
+    grads: GradientsParams,
+) -> Tensor<B, 1> {
+    let mut visitor = GradsFlattener {
+        grads,
+        flattened: Vec::new(),
+    };
+    module.visit(&mut visitor);
+
+    assert!(
+        !visitor.flattened.is_empty(),
+        "L-BFGS requires the module to have at least one trainable parameter"
+    );
+
+    Tensor::cat(visitor.flattened, 0)
+}
+
+/// Add the flattened delta to the trainable parameters of the module.
+///
+/// The parameters are visited in the same order as in [flatten_grads].
+fn add_flat<B: AutodiffBackend, M: AutodiffModule<B>>(module: M, delta: Tensor<B, 1>) -> M {
+    module.map(&mut FlatAdder { delta, offset: 0 })
+}
+
+struct GradsFlattener<B: AutodiffBackend> {
+    grads: GradientsParams,
+    flattened: Vec<Tensor<B, 1>>,
+}
+
+impl<B: AutodiffBackend> ModuleVisitor<B> for GradsFlattener<B> {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        if !tensor.is_require_grad() {
+            return;
+        }
+
+        let grad = match self.grads.remove::<B::InnerBackend, D>(id) {
+            Some(grad) => grad,
+            None => tensor.clone().inner().zeros_like(),
+        };
+        let num_elements = grad.shape().num_elements();
+
+        self.flattened
+            .push(Tensor::from_inner(grad.reshape([num_elements])));
+    }
+}
+
+struct FlatAdder<B: AutodiffBackend> {
+    delta: Tensor<B, 1>,
+    offset: usize,
+}
+
+impl<B: AutodiffBackend> ModuleMapper<B> for FlatAdder<B> {
+    fn map_float<const D: usize>(&mut self, _id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        if !tensor.is_require_grad() {
+            return tensor;
+        }
+
+        let shape = tensor.shape();
+        let range = self.offset..self.offset + shape.num_elements();
+        self.offset = range.end;
+        let delta = self.delta.clone().slice([range]).reshape(shape);
+
+        Tensor::from_inner(tensor.inner().add(delta.inner())).require_grad()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        nn::{Linear, LinearConfig},
+        tensor::Data,
+        TestAutodiffBackend,
+    };
+
+    type TestModule = Linear<TestAutodiffBackend>;
+
+    #[test]
+    fn should_fit_linear_regression_with_strong_wolfe() {
+        let layer = given_linear_layer();
+        let mut optim = LbfgsConfig::new()
+            .with_line_search(Some(LineSearch::StrongWolfe))
+            .init::<TestAutodiffBackend, TestModule>();
+
+        let (layer, loss_init) = optim.step_with_closure(1.0, layer, mse_loss);
+        let loss = mse_loss(&layer).into_scalar();
+
+        assert!(loss_init > loss as f64);
+        assert!(loss < 1e-6, "Loss should converge, got {loss}");
+        layer
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&Data::from([[2.0], [-1.0]]), 3);
+        layer
+            .bias
+            .unwrap()
+            .val()
+            .into_data()
+            .assert_approx_eq(&Data::from([0.5]), 3);
+    }
+
+    #[test]
+    fn should_decrease_loss_without_line_search() {
+        let mut layer = given_linear_layer();
+        let mut optim = LbfgsConfig::new()
+            .with_max_iter(1)
+            .init::<TestAutodiffBackend, TestModule>();
+        let loss_init = mse_loss(&layer).into_scalar();
+
+        for _ in 0..10 {
+            let grads = GradientsParams::from_grads(mse_loss(&layer).backward(), &layer);
+            layer = optim.step(0.1, layer, grads);
+        }
+
+        let loss = mse_loss(&layer).into_scalar();
+        assert!(loss < loss_init, "{loss} should be lower than {loss_init}");
+        assert_eq!(optim.to_record().unwrap().num_iter, 10);
+    }
+
+    #[test]
+    fn should_keep_a_bounded_history() {
+        let layer = given_linear_layer();
+        let mut optim = LbfgsConfig::new()
+            .with_history_size(2)
+            .with_tolerance_grad(0.0)
+            .with_tolerance_change(0.0)
+            .init::<TestAutodiffBackend, TestModule>();
+
+        optim.step_with_closure(0.1, layer, mse_loss);
+
+        let state = optim.to_record().unwrap();
+        assert_eq!(state.steps.len(), 2);
+        assert_eq!(state.grad_diffs.len(), 2);
+        assert_eq!(state.rho.len(), 2);
+    }
+
+    fn given_linear_layer() -> TestModule {
+        LinearConfig::new(2, 1).init::<TestAutodiffBackend>(&Default::default())
+    }
+
+    /// Mean squared error against `y = 2 x1 - x2 + 0.5`.
+    fn mse_loss(layer: &TestModule) -> Tensor<TestAutodiffBackend, 1> {
+        let device = Default::default();
+        let inputs = Tensor::<TestAutodiffBackend, 2>::from_floats(
+            [[1.0, 2.0], [-1.0, 0.5], [3.0, -2.0], [0.0, 1.0], [2.0, 2.0]],
+            &device,
+        );
+        let targets = Tensor::<TestAutodiffBackend, 2>::from_floats(
+            [[0.5], [-2.0], [8.5], [-0.5], [2.5]],
+            &device,
+        );
+
+        layer.forward(inputs).sub(targets).powf_scalar(2.0).mean()
+    }
+}
@@ -0,0 +1,248 @@
+use crate as burn;
+
+use super::GradientsParams;
+use crate::config::Config;
+use crate::module::{AutodiffModule, ModuleVisitor, ParamId};
+use crate::record::Record;
+use crate::tensor::{
+    backend::{AutodiffBackend, Backend},
+    ElementConversion, Tensor,
+};
+
+type InnerBackend<B> = <B as AutodiffBackend>::InnerBackend;
+type FullPrecisionBackend<B> = <InnerBackend<B> as Backend>::FullPrecisionBackend;
+
+/// Configuration to create a [dynamic loss scaler](DynamicLossScaler).
+#[derive(Config)]
+pub struct DynamicLossScalerConfig {
+    /// The initial scale of the loss.
+    #[config(default = 65536.0)]
+    pub init_scale: f64,
+    /// The factor by which the scale is multiplied after `growth_interval` finite gradients.
+    #[config(default = 2.0)]
+    pub growth_factor: f64,
+    /// The factor by which the scale is multiplied when the gradients aren't finite.
+    #[config(default = 0.5)]
+    pub backoff_factor: f64,
+    /// The number of consecutive finite gradients before the scale is increased.
+    #[config(default = 2000)]
+    pub growth_interval: usize,
+}
+
+/// Scale the loss before the backward pass so that small gradients don't underflow when
+/// training in half precision, then unscale the gradients before the optimizer step.
+///
+/// The scale is reduced each time the gradients contain infinite or NaN values, in which case
+/// the optimizer step should be skipped, and increased after a number of finite gradients.
+#[derive(Clone)]
+pub struct DynamicLossScaler {
+    config: DynamicLossScalerConfig,
+    scale: f64,
+    num_finite: usize,
+}
+
+/// Record of the state of a [dynamic loss scaler](DynamicLossScaler), so that the training can be
+/// resumed with the same scale.
+#[derive(Record, Clone, Debug)]
+pub struct DynamicLossScalerRecord {
+    /// The current scale of the loss.
+    pub scale: f64,
+    /// The number of consecutive finite gradients since the scale was last changed.
+    pub num_finite: usize,
+}
+
+impl DynamicLossScalerConfig {
+    /// Initialize a new [dynamic loss scaler](DynamicLossScaler).
+    pub fn init(&self) -> DynamicLossScaler {
+        DynamicLossScaler {
+            config: self.clone(),
+            scale: self.init_scale,
+            num_finite: 0,
+        }
+    }
+}
+
+impl DynamicLossScaler {
+    /// The current scale of the loss.
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// The record of the current state of the scaler.
+    pub fn to_record(&self) -> DynamicLossScalerRecord {
+        DynamicLossScalerRecord {
+            scale: self.scale,
+            num_finite: self.num_finite,
+        }
+    }
+
+    /// Restore the state of the scaler from a record.
+    pub fn load_record(mut self, record: DynamicLossScalerRecord) -> Self {
+        self.scale = record.scale;
+        self.num_finite = record.num_finite;
+        self
+    }
+
+    /// Scale the loss before the backward pass.
+    pub fn scale_loss<B: Backend, const D: usize>(&self, loss: Tensor<B, D>) -> Tensor<B, D> {
+        loss.mul_scalar(self.scale)
+    }
+
+    /// Update the scale depending on whether the last gradients were finite.
+    pub fn update(&mut self, finite: bool) {
+        if !finite {
+            self.scale *= self.config.backoff_factor;
+            self.num_finite = 0;
+            return;
+        }
+
+        self.num_finite += 1;
+
+        if self.num_finite >= self.config.growth_interval {
+            self.scale *= self.config.growth_factor;
+            self.num_finite = 0;
+        }
+    }
+
+    /// Unscale the gradients computed from a [scaled loss](DynamicLossScaler::scale_loss) and
+    /// update the scale.
+    ///
+    /// # Returns
+    ///
+    /// The unscaled gradients, or an error with the unscaled gradients if they contain infinite
+    /// or NaN values, in which case the optimizer step should be skipped.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn unscale<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &mut self,
+        module: &M,
+        grads: GradientsParams,
+    ) -> Result<GradientsParams, GradientsParams> {
+        let mut unscaler = GradientsUnscaler::<B> {
+            grads,
+            scale: self.scale,
+            sum: None,
+        };
+        module.visit(&mut unscaler);
+
+        // The sum of the gradients isn't finite as soon as one of the gradients isn't.
+        let finite = unscaler
+            .sum
+            .map(|sum| sum.into_scalar().elem::<f64>().is_finite())
+            .unwrap_or(true);
+        self.update(finite);
+
+        match finite {
+            true => Ok(unscaler.grads),
+            false => Err(unscaler.grads),
+        }
+    }
+}
+
+struct GradientsUnscaler<B: AutodiffBackend> {
+    grads: GradientsParams,
+    scale: f64,
+    sum: Option<Tensor<FullPrecisionBackend<B>, 1>>,
+}
+
+impl<B: AutodiffBackend> ModuleVisitor<B> for GradientsUnscaler<B> {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        let Some(grad) = self.grads.remove::<InnerBackend<B>, D>(id) else {
+            return;
+        };
+
+        // Unscale in full precision, since the scaled gradients may not be representable.
+        let grad = grad.to_full_precision().div_scalar(self.scale);
+        let sum = grad.clone().sum();
+        self.sum = Some(match self.sum.take() {
+            Some(total) => total + sum,
+            None => sum,
+        });
+
+        self.grads
+            .register::<InnerBackend<B>, D>(id.clone(), Tensor::from_full_precision(grad));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        nn::{Linear, LinearConfig},
+        tensor::Distribution,
+        TestAutodiffBackend,
+    };
+
+    #[test]
+    fn should_unscale_gradients() {
+        let device = Default::default();
+        let layer: Linear<TestAutodiffBackend> = LinearConfig::new(4, 2).init(&device);
+        let mut scaler = DynamicLossScalerConfig::new().with_init_scale(8.0).init();
+        let x = Tensor::random([3, 4], Distribution::Default, &device);
+
+        let expected = GradientsParams::from_grads(layer.forward(x.clone()).backward(), &layer);
+        let grads = scaler.scale_loss(layer.forward(x)).backward();
+        let grads = scaler
+            .unscale(&layer, GradientsParams::from_grads(grads, &layer))
+            .ok()
+            .unwrap();
+
+        let id = &layer.weight.id;
+        grads
+            .get::<InnerBackend<TestAutodiffBackend>, 2>(id)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(
+                &expected
+                    .get::<InnerBackend<TestAutodiffBackend>, 2>(id)
+                    .unwrap()
+                    .into_data(),
+                5,
+            );
+        assert_eq!(scaler.scale(), 8.0);
+    }
+
+    #[test]
+    fn should_skip_non_finite_gradients_and_backoff() {
+        let device = Default::default();
+        let layer: Linear<TestAutodiffBackend> = LinearConfig::new(4, 2).init(&device);
+        let mut scaler = DynamicLossScalerConfig::new().with_init_scale(1e300).init();
+        let x = Tensor::random([3, 4], Distribution::Default, &device);
+
+        let grads = scaler.scale_loss(layer.forward(x)).backward();
+        let grads = scaler.unscale(&layer, GradientsParams::from_grads(grads, &layer));
+
+        assert!(grads.is_err());
+        assert_eq!(scaler.scale(), 5e299);
+    }
+
+    #[test]
+    fn should_grow_scale_after_interval() {
+        let mut scaler = DynamicLossScalerConfig::new()
+            .with_init_scale(4.0)
+            .with_growth_interval(2)
+            .init();
+
+        scaler.update(true);
+        assert_eq!(scaler.scale(), 4.0);
+        scaler.update(true);
+        assert_eq!(scaler.scale(), 8.0);
+        scaler.update(false);
+        assert_eq!(scaler.scale(), 4.0);
+    }
+
+    #[test]
+    fn should_restore_the_state_from_record() {
+        let config = DynamicLossScalerConfig::new()
+            .with_init_scale(4.0)
+            .with_growth_interval(2);
+        let mut scaler = config.init();
+        scaler.update(false);
+        scaler.update(true);
+
+        let mut restored = config.init().load_record(scaler.to_record());
+
+        assert_eq!(restored.scale(), 2.0);
+        restored.update(true);
+        assert_eq!(restored.scale(), 4.0);
+    }
+}
@@ -7,9 +7,13 @@ pub mod momentum;
 mod adagrad;
 mod adam;
 mod adamw;
+mod averaging;
 mod base;
 mod grad_accum;
 mod grads;
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+mod lbfgs;
+mod loss_scaler;
 mod rmsprop;
 mod sgd;
 mod simple;
@@ -18,9 +22,13 @@ mod visitor;
 pub use adagrad::*;
 pub use adam::*;
 pub use adamw::*;
+pub use averaging::*;
 pub use base::*;
 pub use grad_accum::*;
 pub use grads::*;
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub use lbfgs::*;
+pub use loss_scaler::*;
 pub use rmsprop::*;
 pub use sgd::*;
 pub use simple::*;
@@ -5,7 +5,7 @@ use crate::{
 
 use super::{
     decay::{WeightDecay, WeightDecayConfig},
-    SimpleOptimizer,
+    MixedPrecision, SimpleOptimizer,
 };
 use crate::config::Config;
 use crate::optim::adaptor::OptimizerAdaptor;
@@ -42,9 +42,40 @@ impl RMSPropConfig {
     pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(
         &self,
     ) -> OptimizerAdaptor<RMSProp<B::InnerBackend>, M, B> {
+        let mut optim = OptimizerAdaptor::from(self.optimizer());
+
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+
+        optim
+    }
+
+    /// Initialize RMSProp optimizer for [mixed precision](MixedPrecision) training.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that keeps full precision master weights and state.
+    pub fn init_mixed_precision<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+    ) -> OptimizerAdaptor<
+        MixedPrecision<RMSProp<<B::InnerBackend as Backend>::FullPrecisionBackend>>,
+        M,
+        B,
+    > {
+        let mut optim = OptimizerAdaptor::from(MixedPrecision::new(self.optimizer()));
+
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+
+        optim
+    }
+
+    fn optimizer<B: Backend>(&self) -> RMSProp<B> {
         let weight_decay = self.weight_decay.as_ref().map(WeightDecay::new);
 
-        let mut optim = OptimizerAdaptor::from(RMSProp {
+        RMSProp {
             alpha: self.alpha,
             centered: self.centered,
             weight_decay,
@@ -52,13 +83,7 @@ impl RMSPropConfig {
                 momentum: self.momentum,
                 epsilon: self.epsilon,
             },
-        });
-
-        if let Some(config) = &self.grad_clipping {
-            optim = optim.with_grad_clipping(config.init());
         }
-
-        optim
     }
 }
 
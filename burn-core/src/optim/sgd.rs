@@ -4,7 +4,7 @@ use crate::{self as burn, LearningRate};
 
 use super::decay::{WeightDecay, WeightDecayConfig};
 use super::momentum::{Momentum, MomentumConfig, MomentumState};
-use super::SimpleOptimizer;
+use super::{MixedPrecision, SimpleOptimizer};
 use crate::config::Config;
 use crate::optim::adaptor::OptimizerAdaptor;
 use crate::record::Record;
@@ -41,17 +41,37 @@ impl SgdConfig {
     pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(
         &self,
     ) -> OptimizerAdaptor<Sgd<B::InnerBackend>, M, B> {
+        let mut optim = OptimizerAdaptor::from(self.optimizer());
+        if let Some(config) = &self.gradient_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+
+    /// Creates a [Sgd](Sgd) optimizer for [mixed precision](MixedPrecision) training, keeping
+    /// full precision master weights and state.
+    pub fn init_mixed_precision<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+    ) -> OptimizerAdaptor<
+        MixedPrecision<Sgd<<B::InnerBackend as Backend>::FullPrecisionBackend>>,
+        M,
+        B,
+    > {
+        let mut optim = OptimizerAdaptor::from(MixedPrecision::new(self.optimizer()));
+        if let Some(config) = &self.gradient_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+
+    fn optimizer<B: Backend>(&self) -> Sgd<B> {
         let momentum = self.momentum.as_ref().map(Momentum::new);
         let weight_decay = self.weight_decay.as_ref().map(WeightDecay::new);
 
-        let mut optim = OptimizerAdaptor::from(Sgd {
+        Sgd {
             momentum,
             weight_decay,
-        });
-        if let Some(config) = &self.gradient_clipping {
-            optim = optim.with_grad_clipping(config.init());
         }
-        optim
     }
 }
 
@@ -134,6 +154,20 @@ mod tests {
         assert!(optim.has_gradient_clipping());
     }
 
+    #[test]
+    fn with_gradient_clipping_should_report_grad_norm() {
+        let device = Default::default();
+        let layer = layer::<TestAutodiffBackend>(&device);
+        let mut optim = sgd_with_all().with_grad_clipping(GradientClipping::GlobalNorm(1.0));
+        assert!(optim.grad_norm().is_none());
+
+        let loss = layer.forward(random_tensor(&device));
+        let grads = GradientsParams::from_grads(loss.backward(), &layer);
+        let _layer = optim.step(LEARNING_RATE, layer, grads);
+
+        assert!(optim.grad_norm().is_some());
+    }
+
     #[test]
     fn should_load_state() {
         let device = Default::default();
@@ -21,6 +21,7 @@ where
     records: HashMap<ParamId, AdaptorRecord<O, B>>,
     module: PhantomData<M>,
     grad_clipping: Option<GradientClipping>,
+    grad_norm: Option<Tensor<B::InnerBackend, 1>>,
 }
 
 impl<O, B, M> From<O> for OptimizerAdaptor<O, M, B>
@@ -35,6 +36,7 @@ where
             records: HashMap::new(),
             module: PhantomData,
             grad_clipping: None,
+            grad_norm: None,
         }
     }
 }
@@ -74,16 +76,21 @@ where
     type Record = HashMap<ParamId, AdaptorRecord<O, B>>;
 
     fn step(&mut self, lr: LearningRate, module: M, mut grads: GradientsParams) -> M {
-        let mut mapper = SimpleOptimizerMapper::<M, B, O>::new(
-            &self.optim,
-            &mut self.records,
-            &mut grads,
-            lr,
-            self.grad_clipping.as_ref(),
-        );
+        if let Some(grad_clipping) = &self.grad_clipping {
+            let (grads_clipped, grad_norm) = grad_clipping.clip_gradients_params(&module, grads);
+            grads = grads_clipped;
+            self.grad_norm = grad_norm;
+        }
+
+        let mut mapper =
+            SimpleOptimizerMapper::<M, B, O>::new(&self.optim, &mut self.records, &mut grads, lr);
         module.map(&mut mapper)
     }
 
+    fn grad_norm(&self) -> Option<Tensor<B::InnerBackend, 1>> {
+        self.grad_norm.clone()
+    }
+
     fn to_record(&self) -> Self::Record {
         self.records.clone()
     }
@@ -94,6 +101,27 @@ where
     }
 }
 
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+impl<O, B, M> crate::optim::ClosureOptimizer<M, B> for OptimizerAdaptor<O, M, B>
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B>,
+    O: SimpleOptimizer<B::InnerBackend>,
+{
+    fn step_with_closure<F>(&mut self, lr: LearningRate, module: M, mut closure: F) -> (M, f64)
+    where
+        F: FnMut(&M) -> Tensor<B, 1>,
+    {
+        use burn_tensor::ElementConversion;
+
+        let loss = closure(&module);
+        let grads = GradientsParams::from_grads(loss.backward(), &module);
+        let loss = loss.into_scalar().elem::<f64>();
+
+        (self.step(lr, module, grads), loss)
+    }
+}
+
 #[derive(new)]
 struct SimpleOptimizerMapper<'a, M, B, O>
 where
@@ -106,7 +134,6 @@ where
     grads: &'a mut GradientsParams,
     lr: LearningRate,
     phantom: PhantomData<M>,
-    grad_clipping: Option<&'a GradientClipping>,
 }
 
 impl<'a, M, B, O> ModuleMapper<B> for SimpleOptimizerMapper<'a, M, B, O>
@@ -123,16 +150,10 @@ where
             let is_require_grad = tensor.is_require_grad();
             let (key, record) = self.records.remove_entry(id).unzip();
 
-            let clipped_grad = if let Some(g_clipping) = self.grad_clipping {
-                g_clipping.clip_gradient(grad)
-            } else {
-                grad
-            };
-
             let (tensor, state) = self.optimizer.step(
                 self.lr,
                 tensor.inner(),
-                clipped_grad,
+                grad,
                 record.map(|record| O::to_device(record.into_state(), &device)),
             );
 
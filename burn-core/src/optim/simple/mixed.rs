@@ -0,0 +1,147 @@
+use super::SimpleOptimizer;
+use crate::{
+    record::{PrecisionSettings, Record},
+    LearningRate,
+};
+use burn_tensor::{backend::Backend, Tensor};
+use serde::{Deserialize, Serialize};
+
+type FullPrecisionBackend<B> = <B as Backend>::FullPrecisionBackend;
+
+/// [Simple optimizer](SimpleOptimizer) wrapper used for mixed precision training.
+///
+/// The parameters of the module are kept in the precision of the backend, which can be half
+/// precision, while the wrapped optimizer updates a full precision copy of each parameter, called
+/// the master weights, with a state also in full precision. The parameters are then updated with
+/// the master weights converted back to the precision of the backend.
+#[derive(new)]
+pub struct MixedPrecision<O> {
+    optim: O,
+}
+
+/// State of the [mixed precision](MixedPrecision) optimizer.
+pub struct MixedPrecisionState<O, B, const D: usize>
+where
+    O: SimpleOptimizer<FullPrecisionBackend<B>>,
+    B: Backend,
+{
+    /// The full precision master weights.
+    pub master: Tensor<FullPrecisionBackend<B>, D>,
+    /// The state of the wrapped optimizer.
+    pub state: Option<O::State<D>>,
+}
+
+/// [Mixed precision state](MixedPrecisionState) record item.
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct MixedPrecisionStateItem<O, B, S, const D: usize>
+where
+    O: SimpleOptimizer<FullPrecisionBackend<B>>,
+    B: Backend,
+    S: PrecisionSettings,
+{
+    master: <Tensor<FullPrecisionBackend<B>, D> as Record<FullPrecisionBackend<B>>>::Item<S>,
+    state: <Option<O::State<D>> as Record<FullPrecisionBackend<B>>>::Item<S>,
+}
+
+impl<O, B, const D: usize> Clone for MixedPrecisionState<O, B, D>
+where
+    O: SimpleOptimizer<FullPrecisionBackend<B>>,
+    B: Backend,
+{
+    fn clone(&self) -> Self {
+        Self {
+            master: self.master.clone(),
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<O, B, const D: usize> Record<B> for MixedPrecisionState<O, B, D>
+where
+    O: SimpleOptimizer<FullPrecisionBackend<B>>,
+    B: Backend,
+{
+    type Item<S: PrecisionSettings> = MixedPrecisionStateItem<O, B, S, D>;
+
+    fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
+        MixedPrecisionStateItem {
+            master: Record::<FullPrecisionBackend<B>>::into_item(self.master),
+            state: Record::<FullPrecisionBackend<B>>::into_item(self.state),
+        }
+    }
+
+    fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
+        Self {
+            master: Record::<FullPrecisionBackend<B>>::from_item::<S>(item.master, device),
+            state: Record::<FullPrecisionBackend<B>>::from_item::<S>(item.state, device),
+        }
+    }
+}
+
+impl<O, B> SimpleOptimizer<B> for MixedPrecision<O>
+where
+    O: SimpleOptimizer<FullPrecisionBackend<B>> + 'static,
+    B: Backend,
+{
+    type State<const D: usize> = MixedPrecisionState<O, B, D>;
+
+    fn step<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        let (master, state) = match state {
+            Some(state) => (state.master, state.state),
+            None => (tensor.to_full_precision(), None),
+        };
+
+        let (master, state) = self.optim.step(lr, master, grad.to_full_precision(), state);
+        let tensor = Tensor::from_full_precision(master.clone());
+
+        (tensor, Some(MixedPrecisionState { master, state }))
+    }
+
+    fn to_device<const D: usize>(state: Self::State<D>, device: &B::Device) -> Self::State<D> {
+        MixedPrecisionState {
+            master: state.master.to_device(device),
+            state: state.state.map(|state| O::to_device(state, device)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        nn::{Linear, LinearConfig},
+        optim::{AdamConfig, GradientsParams, Optimizer},
+        tensor::Distribution,
+        TestAutodiffBackend,
+    };
+
+    #[test]
+    fn should_match_full_precision_optimizer() {
+        let device = Default::default();
+        let mut layer: Linear<TestAutodiffBackend> = LinearConfig::new(4, 2).init(&device);
+        let mut layer_mixed = layer.clone();
+        let mut optim = AdamConfig::new().init();
+        let mut optim_mixed = AdamConfig::new().init_mixed_precision();
+
+        for _ in 0..3 {
+            let x = Tensor::random([3, 4], Distribution::Default, &device);
+            let grads = GradientsParams::from_grads(layer.forward(x.clone()).backward(), &layer);
+            layer = optim.step(0.1, layer, grads);
+            let grads =
+                GradientsParams::from_grads(layer_mixed.forward(x).backward(), &layer_mixed);
+            layer_mixed = optim_mixed.step(0.1, layer_mixed, grads);
+        }
+
+        layer_mixed
+            .weight
+            .to_data()
+            .assert_approx_eq(&layer.weight.to_data(), 5);
+    }
+}
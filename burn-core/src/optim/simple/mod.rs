@@ -1,5 +1,7 @@
 mod base;
+mod mixed;
 pub use base::*;
+pub use mixed::*;
 
 /// Adaptor module for optimizers.
 pub mod adaptor;
@@ -16,7 +16,17 @@ mod file;
 #[cfg(feature = "std")]
 pub use file::*;
 
+#[cfg(feature = "std")]
+mod stream;
+#[cfg(feature = "std")]
+pub use stream::*;
+
 pub use primitive::ParamSerde;
 
 #[cfg(feature = "record-item-custom-serde")]
 pub mod serde;
+
+#[cfg(feature = "record-safetensors")]
+mod safetensors;
+#[cfg(feature = "record-safetensors")]
+pub use self::safetensors::*;
@@ -0,0 +1,956 @@
+use super::serde::{
+    adapter::{BurnModuleAdapter, DefaultAdapter},
+    data::{insert_nested_value, remap, NestedValue},
+    de::Deserializer,
+    ser::Serializer,
+};
+use super::{
+    load_module_from_source, BytesRecorder, FileRecorder, FullPrecisionSettings, ModuleFilter,
+    PrecisionSettings, Record, Recorder, RecorderError, StreamingRecorder, TensorSource,
+};
+use crate::module::{Module, ModuleMapper, ParamId};
+use burn_tensor::{backend::Backend, DataSerialize, Element, ElementConversion, Tensor};
+use core::any::TypeId;
+use core::marker::PhantomData;
+use half::{bf16, f16};
+use regex::Regex;
+use safetensors::{tensor::TensorView, Dtype, SafeTensors};
+use serde::{de::DeserializeOwned, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::{fs::File, path::PathBuf};
+
+/// Prefix of the metadata keys holding the [burn metadata](super::BurnMetadata) of the record.
+const BURN_METADATA_PREFIX: &str = "__burn__";
+
+/// File recorder using the [safetensors](https://github.com/huggingface/safetensors) format.
+///
+/// Tensors are saved with their flattened path in the record, such as `layers.0.linear.weight`,
+/// following the naming used by Hugging Face models. Other values of the record, including the
+/// parameter ids, are saved in the metadata of the file.
+///
+/// When loading, the file is memory-mapped and each tensor is converted to the
+/// [precision settings](PrecisionSettings) of the recorder. Files that were not created by Burn
+/// can be loaded as well, in which case new parameter ids are generated. Keys can be remapped
+/// with [with_key_remap](SafetensorsFileRecorder::with_key_remap).
+///
+/// # Notes
+///
+/// The weights of [linear](crate::nn::Linear) layers are transposed to the layout of PyTorch
+/// and Hugging Face models, `[d_output, d_input]`, when saving and back to the layout of Burn,
+/// `[d_input, d_output]`, when loading. This can be disabled with
+/// [with_pytorch_layout](SafetensorsFileRecorder::with_pytorch_layout).
+#[derive(Debug, Clone)]
+pub struct SafetensorsFileRecorder<S: PrecisionSettings> {
+    key_remap: Vec<(Regex, String)>,
+    pytorch_layout: bool,
+    _settings: PhantomData<S>,
+}
+
+/// In memory recorder using the [safetensors](https://github.com/huggingface/safetensors) format.
+///
+/// See [SafetensorsFileRecorder](SafetensorsFileRecorder) for the details of the format.
+#[derive(Debug, Clone)]
+pub struct SafetensorsBytesRecorder<S: PrecisionSettings> {
+    key_remap: Vec<(Regex, String)>,
+    pytorch_layout: bool,
+    _settings: PhantomData<S>,
+}
+
+macro_rules! recorder_builder {
+    ($recorder:ident) => {
+        impl<S: PrecisionSettings> Default for $recorder<S> {
+            fn default() -> Self {
+                Self {
+                    key_remap: Vec::new(),
+                    pytorch_layout: true,
+                    _settings: PhantomData,
+                }
+            }
+        }
+
+        impl<S: PrecisionSettings> $recorder<S> {
+            /// Create a new recorder.
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            /// If the weights of linear layers are transposed to the layout of PyTorch,
+            /// `[d_output, d_input]`, when saving and loading, which is the default.
+            ///
+            /// Disable it to save the weights with the layout of Burn, `[d_input, d_output]`.
+            pub fn with_pytorch_layout(mut self, pytorch_layout: bool) -> Self {
+                self.pytorch_layout = pytorch_layout;
+                self
+            }
+
+            /// Remap the keys of the tensors when loading.
+            ///
+            /// # Arguments
+            ///
+            /// * `pattern` - The Regex pattern to be replaced, matched against the whole key.
+            /// * `replacement` - The pattern to replace with.
+            ///
+            /// See [Regex](https://docs.rs/regex/1.5.4/regex/#syntax) for the pattern syntax and
+            /// [Replacement](https://docs.rs/regex/latest/regex/struct.Regex.html#method.replace)
+            /// for the replacement syntax.
+            pub fn with_key_remap(mut self, pattern: &str, replacement: &str) -> Self {
+                let regex = Regex::new(&format!("^{}$", pattern)).unwrap();
+
+                self.key_remap.push((regex, replacement.into()));
+                self
+            }
+        }
+    };
+}
+
+recorder_builder!(SafetensorsFileRecorder);
+recorder_builder!(SafetensorsBytesRecorder);
+
+impl<S: PrecisionSettings, B: Backend> FileRecorder<B> for SafetensorsFileRecorder<S> {
+    fn file_extension() -> &'static str {
+        "safetensors"
+    }
+}
+
+impl<S: PrecisionSettings, B: Backend> BytesRecorder<B> for SafetensorsBytesRecorder<S> {}
+
+impl<S: PrecisionSettings, B: Backend> Recorder<B> for SafetensorsFileRecorder<S> {
+    type Settings = S;
+    type RecordArgs = PathBuf;
+    type RecordOutput = ();
+    type LoadArgs = PathBuf;
+
+    fn save_item<I: Serialize>(
+        &self,
+        item: I,
+        mut file: Self::RecordArgs,
+    ) -> Result<(), RecorderError> {
+        file.set_extension(<Self as FileRecorder<B>>::file_extension());
+        let path = file.as_path();
+
+        // Add parent directories if they don't exist
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).ok();
+        }
+
+        let flattened = FlattenedRecord::from_item::<S, _>(item, self.pytorch_layout)?;
+        safetensors::serialize_to_file(flattened.views()?, &Some(flattened.metadata.clone()), path)
+            .map_err(|err| RecorderError::Unknown(format!("{err:?}")))
+    }
+
+    fn load_item<I: DeserializeOwned>(&self, args: Self::LoadArgs) -> Result<I, RecorderError> {
+        let mmap = self.mmap(args)?;
+        let root = nested_from_bytes::<S>(&mmap, &self.key_remap)?;
+
+        deserialize(root, self.pytorch_layout)
+    }
+
+    fn load<R: Record<B>>(
+        &self,
+        args: Self::LoadArgs,
+        device: &B::Device,
+    ) -> Result<R, RecorderError> {
+        let mmap = self.mmap(args)?;
+        let item = nested_item_from_bytes::<S>(&mmap, &self.key_remap)?;
+
+        Ok(R::from_item::<S>(
+            deserialize(item, self.pytorch_layout)?,
+            device,
+        ))
+    }
+}
+
+impl<S: PrecisionSettings> SafetensorsFileRecorder<S> {
+    fn mmap(&self, mut file: PathBuf) -> Result<memmap2::Mmap, RecorderError> {
+        file.set_extension("safetensors");
+
+        let file = File::open(file.as_path()).map_err(|err| match err.kind() {
+            std::io::ErrorKind::NotFound => RecorderError::FileNotFound(err.to_string()),
+            _ => RecorderError::Unknown(err.to_string()),
+        })?;
+
+        // SAFETY: The file is only read and must not be modified while the record is loaded,
+        // which is the same assumption made by other safetensors implementations.
+        unsafe { memmap2::Mmap::map(&file) }.map_err(|err| RecorderError::Unknown(err.to_string()))
+    }
+}
+
+impl<S: PrecisionSettings, B: Backend> Recorder<B> for SafetensorsBytesRecorder<S> {
+    type Settings = S;
+    type RecordArgs = ();
+    type RecordOutput = Vec<u8>;
+    type LoadArgs = Vec<u8>;
+
+    fn save_item<I: Serialize>(
+        &self,
+        item: I,
+        _args: Self::RecordArgs,
+    ) -> Result<Self::RecordOutput, RecorderError> {
+        let flattened = FlattenedRecord::from_item::<S, _>(item, self.pytorch_layout)?;
+        safetensors::serialize(flattened.views()?, &Some(flattened.metadata.clone()))
+            .map_err(|err| RecorderError::Unknown(format!("{err:?}")))
+    }
+
+    fn load_item<I: DeserializeOwned>(&self, args: Self::LoadArgs) -> Result<I, RecorderError> {
+        deserialize(
+            nested_from_bytes::<S>(&args, &self.key_remap)?,
+            self.pytorch_layout,
+        )
+    }
+
+    fn load<R: Record<B>>(
+        &self,
+        args: Self::LoadArgs,
+        device: &B::Device,
+    ) -> Result<R, RecorderError> {
+        let item = nested_item_from_bytes::<S>(&args, &self.key_remap)?;
+
+        Ok(R::from_item::<S>(
+            deserialize(item, self.pytorch_layout)?,
+            device,
+        ))
+    }
+}
+
+impl<S: PrecisionSettings, B: Backend> StreamingRecorder<B> for SafetensorsFileRecorder<S> {
+    fn load_module<M: Module<B>>(
+        &self,
+        module: M,
+        args: Self::LoadArgs,
+        filter: &ModuleFilter,
+    ) -> Result<M, RecorderError> {
+        let transposed = transposed_weights(&module, self.pytorch_layout)?;
+        let mmap = self.mmap(args)?;
+        let source = SafetensorsSource::new(&mmap, &self.key_remap, transposed)?;
+
+        load_module_from_source(module, &source, filter)
+    }
+}
+
+impl<S: PrecisionSettings, B: Backend> StreamingRecorder<B> for SafetensorsBytesRecorder<S> {
+    fn load_module<M: Module<B>>(
+        &self,
+        module: M,
+        args: Self::LoadArgs,
+        filter: &ModuleFilter,
+    ) -> Result<M, RecorderError> {
+        let transposed = transposed_weights(&module, self.pytorch_layout)?;
+        let source = SafetensorsSource::new(&args, &self.key_remap, transposed)?;
+
+        load_module_from_source(module, &source, filter)
+    }
+}
+
+/// [Tensor source](TensorSource) reading the tensors of safetensors bytes only when requested.
+struct SafetensorsSource<'a> {
+    views: HashMap<String, TensorView<'a>>,
+    /// The tensors saved with the layout of PyTorch.
+    transposed: HashSet<String>,
+}
+
+impl<'a> SafetensorsSource<'a> {
+    fn new(
+        bytes: &'a [u8],
+        key_remap: &[(Regex, String)],
+        transposed: HashSet<String>,
+    ) -> Result<Self, RecorderError> {
+        let tensors = SafeTensors::deserialize(bytes)
+            .map_err(|err| RecorderError::DeserializeError(format!("{err:?}")))?;
+        let views = remap(tensors.tensors().into_iter().collect(), key_remap.to_vec());
+
+        Ok(Self { views, transposed })
+    }
+}
+
+impl TensorSource for SafetensorsSource<'_> {
+    fn read<E: Element>(&self, name: &str) -> Result<Option<DataSerialize<E>>, RecorderError> {
+        let data = self.views.get(name).map(read_view).transpose()?;
+
+        match self.transposed.contains(name) {
+            true => Ok(data.map(transpose_data)),
+            false => Ok(data),
+        }
+    }
+
+    fn read_bool(&self, name: &str) -> Result<Option<DataSerialize<bool>>, RecorderError> {
+        Ok(self.views.get(name).map(read_view_bool))
+    }
+}
+
+/// A record flattened into named tensors and string metadata.
+struct FlattenedRecord {
+    tensors: Vec<(String, Dtype, Vec<usize>, Vec<u8>)>,
+    metadata: HashMap<String, String>,
+}
+
+impl FlattenedRecord {
+    /// Flatten a [burn record](super::BurnRecord) item.
+    fn from_item<S: PrecisionSettings, I: Serialize>(
+        item: I,
+        pytorch_layout: bool,
+    ) -> Result<Self, RecorderError> {
+        let serializer = match pytorch_layout {
+            true => Serializer::with_adapter::<PyTorchLayoutAdapter>(),
+            false => Serializer::new(),
+        };
+        let mut root = match item.serialize(serializer)? {
+            NestedValue::Map(root) => root,
+            _ => return Err(RecorderError::Unknown("Expected a record".into())),
+        };
+        let item = root
+            .remove("item")
+            .ok_or_else(|| RecorderError::Unknown("Expected a record with an item".into()))?;
+
+        let mut flattened = Self {
+            tensors: Vec::new(),
+            metadata: HashMap::new(),
+        };
+        for (key, value) in root {
+            flattened.flatten::<S>(format!("{BURN_METADATA_PREFIX}.{key}"), value)?;
+        }
+        flattened.flatten::<S>(String::new(), item)?;
+
+        Ok(flattened)
+    }
+
+    fn flatten<S: PrecisionSettings>(
+        &mut self,
+        path: String,
+        value: NestedValue,
+    ) -> Result<(), RecorderError> {
+        let child_path = |key: &str| match path.is_empty() {
+            true => key.to_string(),
+            false => format!("{path}.{key}"),
+        };
+
+        match value {
+            NestedValue::Map(mut map) => {
+                if is_tensor(&map) {
+                    return self.push_tensor::<S>(path, map);
+                }
+
+                // Parameters are saved with the path of the module field, their id is kept in
+                // the metadata.
+                if map.len() == 2 && map.contains_key("id") {
+                    if let Some(NestedValue::Map(param)) = map.remove("param") {
+                        if is_tensor(&param) {
+                            self.flatten::<S>(child_path("id"), map.remove("id").unwrap())?;
+                            return self.push_tensor::<S>(path, param);
+                        }
+
+                        map.insert("param".into(), NestedValue::Map(param));
+                    }
+                }
+
+                for (key, value) in map {
+                    self.flatten::<S>(child_path(&key), value)?;
+                }
+            }
+            NestedValue::Vec(vec) => {
+                for (index, value) in vec.into_iter().enumerate() {
+                    self.flatten::<S>(child_path(&index.to_string()), value)?;
+                }
+            }
+            // Missing values are restored with their default value.
+            NestedValue::Default => {}
+            leaf => {
+                self.metadata.insert(path, encode_leaf(&leaf));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn push_tensor<S: PrecisionSettings>(
+        &mut self,
+        path: String,
+        mut map: HashMap<String, NestedValue>,
+    ) -> Result<(), RecorderError> {
+        let shape = match map.remove("shape") {
+            Some(NestedValue::Vec(shape)) => shape
+                .into_iter()
+                .map(|dim| dim.as_u64().map(|dim| dim as usize))
+                .collect::<Option<Vec<_>>>(),
+            _ => None,
+        }
+        .ok_or_else(|| RecorderError::Unknown(format!("Invalid shape for tensor {path}")))?;
+
+        let values = match map.remove("value") {
+            Some(NestedValue::Vec(values)) => values,
+            _ => return Err(RecorderError::Unknown(format!("Invalid tensor {path}"))),
+        };
+
+        let dtype = match values.first() {
+            Some(NestedValue::Bool(_)) => Dtype::BOOL,
+            Some(NestedValue::I16(_)) => Dtype::I16,
+            Some(NestedValue::I32(_)) => Dtype::I32,
+            Some(NestedValue::I64(_)) => Dtype::I64,
+            Some(NestedValue::U64(_)) => Dtype::U64,
+            Some(NestedValue::F64(_)) => Dtype::F64,
+            Some(NestedValue::U16(_)) if TypeId::of::<S::FloatElem>() == TypeId::of::<bf16>() => {
+                Dtype::BF16
+            }
+            Some(NestedValue::U16(_)) => Dtype::F16,
+            _ => Dtype::F32,
+        };
+
+        let mut data = Vec::with_capacity(values.len() * dtype.size());
+        for value in values {
+            match value {
+                NestedValue::Bool(value) => data.push(value as u8),
+                NestedValue::I16(value) => data.extend(value.to_le_bytes()),
+                NestedValue::I32(value) => data.extend(value.to_le_bytes()),
+                NestedValue::I64(value) => data.extend(value.to_le_bytes()),
+                NestedValue::U64(value) => data.extend(value.to_le_bytes()),
+                NestedValue::U16(value) => data.extend(value.to_le_bytes()),
+                NestedValue::F32(value) => data.extend(value.to_le_bytes()),
+                NestedValue::F64(value) => data.extend(value.to_le_bytes()),
+                value => {
+                    return Err(RecorderError::Unknown(format!(
+                        "Unsupported tensor element {value:?} for tensor {path}"
+                    )))
+                }
+            }
+        }
+
+        self.tensors.push((path, dtype, shape, data));
+        Ok(())
+    }
+
+    fn views(&self) -> Result<Vec<(&str, TensorView<'_>)>, RecorderError> {
+        self.tensors
+            .iter()
+            .map(|(name, dtype, shape, data)| {
+                TensorView::new(*dtype, shape.clone(), data)
+                    .map(|view| (name.as_str(), view))
+                    .map_err(|err| RecorderError::Unknown(format!("{err:?}")))
+            })
+            .collect()
+    }
+}
+
+/// Adapter transposing the weights of linear layers between the layout of Burn and the layout of
+/// PyTorch, which is the same in both directions.
+struct PyTorchLayoutAdapter;
+
+impl BurnModuleAdapter for PyTorchLayoutAdapter {
+    fn adapt_linear(data: NestedValue) -> NestedValue {
+        let NestedValue::Map(mut map) = data else {
+            return data;
+        };
+
+        if let Some(NestedValue::Map(weight)) = map.get_mut("weight") {
+            // The tensor is either the parameter itself or wrapped with its id.
+            let tensor = match weight.get_mut("param") {
+                Some(NestedValue::Map(param)) => param,
+                _ => weight,
+            };
+            transpose_nested(tensor);
+        }
+
+        NestedValue::Map(map)
+    }
+}
+
+/// Transpose the values of a 2D tensor in place, other tensors are left untouched.
+fn transpose_nested(tensor: &mut HashMap<String, NestedValue>) {
+    let dims = match tensor.get("shape") {
+        Some(NestedValue::Vec(shape)) => shape
+            .iter()
+            .map(|dim| dim.clone().as_u64().map(|dim| dim as usize))
+            .collect::<Option<Vec<_>>>(),
+        _ => None,
+    };
+    let (rows, cols) = match dims.as_deref() {
+        Some([rows, cols]) => (*rows, *cols),
+        _ => return,
+    };
+
+    if let Some(NestedValue::Vec(values)) = tensor.get_mut("value") {
+        if values.len() != rows * cols {
+            return;
+        }
+        *values = transpose_values(core::mem::take(values), rows, cols);
+        tensor.insert(
+            "shape".to_string(),
+            NestedValue::Vec(vec![
+                NestedValue::U64(cols as u64),
+                NestedValue::U64(rows as u64),
+            ]),
+        );
+    }
+}
+
+/// Transpose the data of a 2D tensor.
+fn transpose_data<E: Element>(data: DataSerialize<E>) -> DataSerialize<E> {
+    match data.shape[..] {
+        [rows, cols] => {
+            DataSerialize::new(transpose_values(data.value, rows, cols), vec![cols, rows])
+        }
+        _ => data,
+    }
+}
+
+/// Transpose the row-major values of a `[rows, cols]` matrix.
+fn transpose_values<T>(values: Vec<T>, rows: usize, cols: usize) -> Vec<T> {
+    let mut values = values.into_iter().map(Some).collect::<Vec<_>>();
+
+    (0..cols)
+        .flat_map(|col| (0..rows).map(move |row| row * cols + col))
+        .map(|index| values[index].take().unwrap())
+        .collect()
+}
+
+/// The names of the tensors of the module that are saved with the layout of PyTorch, i.e. the
+/// weights of its linear layers.
+///
+/// The module is serialized with a single element in each tensor, so that the weights can be
+/// found without reading its tensors.
+fn transposed_weights<B: Backend, M: Module<B>>(
+    module: &M,
+    pytorch_layout: bool,
+) -> Result<HashSet<String>, RecorderError> {
+    /// Replace the float tensors with tensors of shape `[1, 2]` for 2D tensors, which only
+    /// have the shape `[2, 1]` in the record when they're transposed.
+    struct Skeleton;
+
+    impl<B: Backend> ModuleMapper<B> for Skeleton {
+        fn map_float<const D: usize>(
+            &mut self,
+            _id: &ParamId,
+            tensor: Tensor<B, D>,
+        ) -> Tensor<B, D> {
+            let mut dims = [1; D];
+            if D == 2 {
+                dims[1] = 2;
+            }
+
+            Tensor::zeros(dims, &tensor.device())
+        }
+    }
+
+    if !pytorch_layout {
+        return Ok(HashSet::new());
+    }
+
+    let item = module
+        .clone()
+        .map(&mut Skeleton)
+        .into_record()
+        .into_item::<FullPrecisionSettings>()
+        .serialize(Serializer::with_adapter::<PyTorchLayoutAdapter>())?;
+    let mut flattened = FlattenedRecord {
+        tensors: Vec::new(),
+        metadata: HashMap::new(),
+    };
+    flattened.flatten::<FullPrecisionSettings>(String::new(), item)?;
+
+    Ok(flattened
+        .tensors
+        .into_iter()
+        .filter(|(_, _, shape, _)| shape[..] == [2, 1])
+        .map(|(name, ..)| name)
+        .collect())
+}
+
+/// If the map holds the data of a tensor, i.e. its values and shape.
+fn is_tensor(map: &HashMap<String, NestedValue>) -> bool {
+    map.len() == 2 && map.contains_key("value") && map.contains_key("shape")
+}
+
+/// Encode a leaf value with its type so that it can be restored exactly.
+fn encode_leaf(value: &NestedValue) -> String {
+    match value {
+        NestedValue::Bool(value) => format!("bool:{value}"),
+        NestedValue::String(value) => format!("str:{value}"),
+        NestedValue::F32(value) => format!("f32:{value}"),
+        NestedValue::F64(value) => format!("f64:{value}"),
+        NestedValue::I16(value) => format!("i16:{value}"),
+        NestedValue::I32(value) => format!("i32:{value}"),
+        NestedValue::I64(value) => format!("i64:{value}"),
+        NestedValue::U16(value) => format!("u16:{value}"),
+        NestedValue::U64(value) => format!("u64:{value}"),
+        NestedValue::Default | NestedValue::Map(_) | NestedValue::Vec(_) => {
+            unreachable!("Only leaves are encoded")
+        }
+    }
+}
+
+/// Decode a leaf value, values without a known type are considered strings.
+fn decode_leaf(value: &str) -> NestedValue {
+    let (kind, content) = value.split_once(':').unwrap_or(("str", value));
+    let leaf = match kind {
+        "bool" => content.parse().ok().map(NestedValue::Bool),
+        "str" => Some(NestedValue::String(content.to_string())),
+        "f32" => content.parse().ok().map(NestedValue::F32),
+        "f64" => content.parse().ok().map(NestedValue::F64),
+        "i16" => content.parse().ok().map(NestedValue::I16),
+        "i32" => content.parse().ok().map(NestedValue::I32),
+        "i64" => content.parse().ok().map(NestedValue::I64),
+        "u16" => content.parse().ok().map(NestedValue::U16),
+        "u64" => content.parse().ok().map(NestedValue::U64),
+        _ => None,
+    };
+
+    leaf.unwrap_or_else(|| NestedValue::String(value.to_string()))
+}
+
+/// Create the nested value of a [burn record](super::BurnRecord) from safetensors bytes.
+fn nested_from_bytes<S: PrecisionSettings>(
+    bytes: &[u8],
+    key_remap: &[(Regex, String)],
+) -> Result<NestedValue, RecorderError> {
+    let (item, burn_metadata) = load_nested::<S>(bytes, key_remap)?;
+
+    let mut root = NestedValue::Map(HashMap::new());
+    for (key, value) in burn_metadata {
+        insert_nested_value(&mut root, &key.split('.').collect::<Vec<_>>(), value);
+    }
+    insert_nested_value(&mut root, &["item"], item);
+
+    Ok(root)
+}
+
+/// Create the nested value of the item of a record from safetensors bytes.
+fn nested_item_from_bytes<S: PrecisionSettings>(
+    bytes: &[u8],
+    key_remap: &[(Regex, String)],
+) -> Result<NestedValue, RecorderError> {
+    load_nested::<S>(bytes, key_remap).map(|(item, _)| item)
+}
+
+/// Load the item of the record along with the burn metadata.
+fn load_nested<S: PrecisionSettings>(
+    bytes: &[u8],
+    key_remap: &[(Regex, String)],
+) -> Result<(NestedValue, Vec<(String, NestedValue)>), RecorderError> {
+    let tensors = SafeTensors::deserialize(bytes)
+        .map_err(|err| RecorderError::DeserializeError(format!("{err:?}")))?;
+    let (_, header) = SafeTensors::read_metadata(bytes)
+        .map_err(|err| RecorderError::DeserializeError(format!("{err:?}")))?;
+
+    let mut burn_metadata = Vec::new();
+    let mut leaves = HashMap::new();
+    for (key, value) in header.metadata().clone().unwrap_or_default() {
+        match key.strip_prefix(BURN_METADATA_PREFIX) {
+            Some(key) => {
+                burn_metadata.push((key.trim_start_matches('.').to_string(), decode_leaf(&value)))
+            }
+            None => {
+                leaves.insert(key, decode_leaf(&value));
+            }
+        }
+    }
+
+    // Files not created by Burn only contain parameters.
+    let is_burn_file = !burn_metadata.is_empty();
+    let leaves = remap(leaves, key_remap.to_vec());
+    let views = remap(tensors.tensors().into_iter().collect(), key_remap.to_vec());
+
+    let mut item = NestedValue::Map(HashMap::new());
+    for (name, view) in views.iter() {
+        let data = tensor_to_nested::<S>(view)?;
+        let id_key = format!("{name}.id");
+
+        let (path, value) = match (is_burn_file, leaves.contains_key(&id_key)) {
+            (true, true) => (format!("{name}.param"), data),
+            (true, false) => (name.clone(), data),
+            (false, _) => (name.clone(), param_nested(data)?),
+        };
+        insert_nested_value(&mut item, &path.split('.').collect::<Vec<_>>(), value);
+    }
+
+    for (key, value) in leaves {
+        insert_nested_value(&mut item, &key.split('.').collect::<Vec<_>>(), value);
+    }
+
+    Ok((item, burn_metadata))
+}
+
+/// Wrap the data of a tensor into a parameter with a new id.
+fn param_nested(data: NestedValue) -> Result<NestedValue, RecorderError> {
+    let mut map = HashMap::new();
+    map.insert(
+        "id".to_string(),
+        NestedValue::String(ParamId::new().into_string()),
+    );
+    map.insert("param".to_string(), data);
+
+    Ok(NestedValue::Map(map))
+}
+
+/// Read a tensor view into the nested value of its data, converting the elements to the given
+/// precision settings.
+fn tensor_to_nested<S: PrecisionSettings>(
+    view: &TensorView<'_>,
+) -> Result<NestedValue, RecorderError> {
+    match view.dtype() {
+        Dtype::BOOL => serialize_data(read_view_bool(view)),
+        Dtype::F16 | Dtype::BF16 | Dtype::F32 | Dtype::F64 => {
+            serialize_data(read_view::<S::FloatElem>(view)?)
+        }
+        _ => serialize_data(read_view::<S::IntElem>(view)?),
+    }
+}
+
+/// Read the data of a tensor view, converting its elements to the given type.
+fn read_view<E: Element>(view: &TensorView<'_>) -> Result<DataSerialize<E>, RecorderError> {
+    macro_rules! read {
+        ($ty:ty $(as $cast:ty)?) => {
+            view.data()
+                .chunks_exact(core::mem::size_of::<$ty>())
+                .map(|bytes| <$ty>::from_le_bytes(bytes.try_into().unwrap()) $(as $cast)?)
+                .map(|value| value.elem::<E>())
+                .collect::<Vec<_>>()
+        };
+    }
+
+    let values = match view.dtype() {
+        Dtype::BOOL => read!(u8),
+        Dtype::U8 => read!(u8),
+        Dtype::I8 => read!(i8),
+        Dtype::I16 => read!(i16),
+        Dtype::I32 => read!(i32),
+        Dtype::I64 => read!(i64),
+        Dtype::U16 => read!(u16 as i32),
+        Dtype::U32 => read!(u32 as i64),
+        Dtype::U64 => read!(u64 as i64),
+        Dtype::F16 => read!(f16),
+        Dtype::BF16 => read!(bf16),
+        Dtype::F32 => read!(f32),
+        Dtype::F64 => read!(f64),
+        dtype => {
+            return Err(RecorderError::DeserializeError(format!(
+                "Unsupported dtype {dtype:?}"
+            )))
+        }
+    };
+
+    Ok(DataSerialize::new(values, view.shape().to_vec()))
+}
+
+/// Read the data of a tensor view as booleans, where non-zero values are `true`.
+fn read_view_bool(view: &TensorView<'_>) -> DataSerialize<bool> {
+    let size = view.dtype().size();
+    let values = view
+        .data()
+        .chunks_exact(size)
+        .map(|bytes| bytes.iter().any(|byte| *byte != 0))
+        .collect::<Vec<_>>();
+
+    DataSerialize::new(values, view.shape().to_vec())
+}
+
+fn serialize_data<E: Serialize>(data: DataSerialize<E>) -> Result<NestedValue, RecorderError> {
+    data.serialize(Serializer::new())
+        .map_err(RecorderError::from)
+}
+
+fn deserialize<I: DeserializeOwned>(
+    value: NestedValue,
+    pytorch_layout: bool,
+) -> Result<I, RecorderError> {
+    match pytorch_layout {
+        true => I::deserialize(Deserializer::<PyTorchLayoutAdapter>::new(value, true)),
+        false => I::deserialize(Deserializer::<DefaultAdapter>::new(value, true)),
+    }
+    .map_err(RecorderError::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as burn;
+    use crate::{
+        module::Module,
+        nn::{Linear, LinearConfig},
+        record::{BinBytesRecorder, FullPrecisionSettings, HalfPrecisionSettings},
+        tensor::Data,
+        TestBackend,
+    };
+
+    #[derive(Module, Debug)]
+    pub struct Model<B: Backend> {
+        layers: Vec<Linear<B>>,
+        num_steps: usize,
+    }
+
+    #[test]
+    fn should_save_and_load_file() {
+        let device = Default::default();
+        let file = std::env::temp_dir().join("burn_test_safetensors_recorder");
+        let recorder = SafetensorsFileRecorder::<FullPrecisionSettings>::new();
+        let model = create_model(&device);
+
+        recorder
+            .record(model.clone().into_record(), file.clone())
+            .unwrap();
+        let record = Recorder::<TestBackend>::load(&recorder, file, &device).unwrap();
+
+        assert_same_records(model, create_model(&device).load_record(record));
+    }
+
+    #[test]
+    fn should_save_flattened_tensor_names() {
+        let device = Default::default();
+        let recorder = SafetensorsBytesRecorder::<FullPrecisionSettings>::new();
+        let bytes = recorder
+            .record(create_model(&device).into_record(), ())
+            .unwrap();
+
+        let tensors = SafeTensors::deserialize(&bytes).unwrap();
+        let mut names = tensors.names();
+        names.sort();
+
+        assert_eq!(
+            names,
+            vec![
+                "layers.0.bias",
+                "layers.0.weight",
+                "layers.1.bias",
+                "layers.1.weight"
+            ]
+        );
+        assert_eq!(tensors.tensor("layers.1.weight").unwrap().shape(), &[2, 4]);
+    }
+
+    #[test]
+    fn should_save_linear_weights_with_the_pytorch_layout_unless_disabled() {
+        let device = Default::default();
+        let model = create_model(&device);
+        let weight = model.layers[0].weight.val();
+        let read_weight = |recorder: SafetensorsBytesRecorder<FullPrecisionSettings>| {
+            let bytes = recorder.record(model.clone().into_record(), ()).unwrap();
+            let tensors = SafeTensors::deserialize(&bytes).unwrap();
+            let view = tensors.tensor("layers.0.weight").unwrap();
+            let data = read_view::<f32>(&view).unwrap();
+            let record = Recorder::<TestBackend>::load(&recorder, bytes, &device).unwrap();
+            assert_same_records(model.clone(), create_model(&device).load_record(record));
+
+            data
+        };
+
+        let data = read_weight(SafetensorsBytesRecorder::new());
+        assert_eq!(data.shape, vec![4, 2]);
+        Data::from(data).assert_approx_eq(&weight.clone().transpose().into_data(), 5);
+
+        let data = read_weight(SafetensorsBytesRecorder::new().with_pytorch_layout(false));
+        assert_eq!(data.shape, vec![2, 4]);
+        Data::from(data).assert_approx_eq(&weight.into_data(), 5);
+    }
+
+    #[test]
+    fn should_respect_precision_settings() {
+        let device = Default::default();
+        let model = create_model(&device);
+        let recorder = SafetensorsBytesRecorder::<HalfPrecisionSettings>::new();
+        let bytes = recorder.record(model.clone().into_record(), ()).unwrap();
+
+        let tensors = SafeTensors::deserialize(&bytes).unwrap();
+        assert_eq!(
+            tensors.tensor("layers.0.weight").unwrap().dtype(),
+            Dtype::F16
+        );
+
+        let record = Recorder::<TestBackend>::load(&recorder, bytes, &device).unwrap();
+        let model_loaded = create_model(&device).load_record(record);
+        model_loaded.layers[0]
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&model.layers[0].weight.val().into_data(), 2);
+    }
+
+    #[test]
+    fn should_load_foreign_file_with_key_remap() {
+        let device = Default::default();
+        // The weight of a linear layer with the layout of PyTorch, `[d_output, d_input]`.
+        let weight = [1.0f32, 3.0, 5.0, 2.0, 4.0, 6.0];
+        let bias = [0.5f32, -0.5];
+        let weight_bytes: Vec<u8> = weight.iter().flat_map(|v| v.to_le_bytes()).collect();
+        let bias_bytes: Vec<u8> = bias.iter().flat_map(|v| v.to_le_bytes()).collect();
+        let bytes = safetensors::serialize(
+            [
+                (
+                    "model.fc.weight",
+                    TensorView::new(Dtype::F32, vec![2, 3], &weight_bytes).unwrap(),
+                ),
+                (
+                    "model.fc.bias",
+                    TensorView::new(Dtype::F32, vec![2], &bias_bytes).unwrap(),
+                ),
+            ],
+            &None,
+        )
+        .unwrap();
+
+        let recorder = SafetensorsBytesRecorder::<FullPrecisionSettings>::new()
+            .with_key_remap("model\\.fc\\.(.*)", "$1");
+        let record = Recorder::<TestBackend>::load(&recorder, bytes.clone(), &device).unwrap();
+        let linear: Linear<TestBackend> = LinearConfig::new(3, 2).init(&device).load_record(record);
+        let linear_streamed = recorder
+            .load_module(
+                LinearConfig::new(3, 2).init::<TestBackend>(&device),
+                bytes,
+                &ModuleFilter::all(),
+            )
+            .unwrap();
+
+        for linear in [linear, linear_streamed] {
+            linear
+                .weight
+                .val()
+                .into_data()
+                .assert_approx_eq(&Data::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]), 3);
+            linear
+                .bias
+                .unwrap()
+                .val()
+                .into_data()
+                .assert_approx_eq(&Data::from([0.5, -0.5]), 3);
+        }
+    }
+
+    #[test]
+    fn should_stream_tensors_into_module() {
+        let device = Default::default();
+        let file = std::env::temp_dir().join("burn_test_safetensors_streaming");
+        let recorder = SafetensorsFileRecorder::<FullPrecisionSettings>::new();
+        let model = create_model(&device);
+        Recorder::<TestBackend>::record(&recorder, model.clone().into_record(), file.clone())
+            .unwrap();
+
+        let model_new = create_model(&device);
+        let model_loaded = recorder
+            .load_module(model_new.clone(), file, &ModuleFilter::all())
+            .unwrap();
+
+        model_loaded.layers[1]
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&model.layers[1].weight.val().into_data(), 5);
+        assert_eq!(
+            model_loaded.layers[1].weight.id,
+            model_new.layers[1].weight.id
+        );
+    }
+
+    fn create_model(device: &<TestBackend as Backend>::Device) -> Model<TestBackend> {
+        Model {
+            layers: vec![
+                LinearConfig::new(2, 4).init(device),
+                LinearConfig::new(4, 2).init(device),
+            ],
+            num_steps: 42,
+        }
+    }
+
+    fn assert_same_records(expected: Model<TestBackend>, actual: Model<TestBackend>) {
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let expected = recorder.record(expected.into_record(), ()).unwrap();
+        let actual = recorder.record(actual.into_record(), ()).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+}
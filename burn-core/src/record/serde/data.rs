@@ -187,7 +187,7 @@ pub fn remap<T>(
 }
 
 /// Helper function to insert a value into a nested map/vector of tensors.
-fn insert_nested_value(current: &mut NestedValue, keys: &[&str], value: NestedValue) {
+pub(crate) fn insert_nested_value(current: &mut NestedValue, keys: &[&str], value: NestedValue) {
     if keys.is_empty() {
         *current = value;
         return;
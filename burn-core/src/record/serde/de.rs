@@ -9,7 +9,7 @@ use serde::{
     forward_to_deserialize_any,
 };
 
-const RECORD_ITEM_SUFFIX: &str = "RecordItem";
+pub(crate) const RECORD_ITEM_SUFFIX: &str = "RecordItem";
 
 /// A deserializer for the nested value data structure.
 pub struct Deserializer<A: BurnModuleAdapter> {
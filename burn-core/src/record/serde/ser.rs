@@ -1,12 +1,14 @@
 use std::collections::HashMap;
 
 use super::{
+    adapter::{BurnModuleAdapter, DefaultAdapter},
     data::NestedValue,
+    de::RECORD_ITEM_SUFFIX,
     error::{self, Error},
 };
 
 use serde::{
-    ser::{self, SerializeSeq, SerializeStruct, Serializer as SerializerTrait},
+    ser::{self, SerializeMap, SerializeSeq, SerializeStruct, Serializer as SerializerTrait},
     Serialize,
 };
 
@@ -18,12 +20,34 @@ use serde::{
 pub struct Serializer {
     // The state of the serialization process
     state: Option<NestedValue>,
+    // The name of the struct being serialized
+    name: Option<&'static str>,
+    // Adapts the serialized modules
+    adapt: fn(&str, NestedValue) -> NestedValue,
 }
 
 impl Serializer {
     /// Creates a new serializer.
     pub fn new() -> Self {
-        Serializer { state: None }
+        Self::with_adapter::<DefaultAdapter>()
+    }
+
+    /// Creates a new serializer adapting the serialized modules with the given adapter, the same
+    /// way the [deserializer](super::de::Deserializer) adapts the modules it reads.
+    pub fn with_adapter<A: BurnModuleAdapter>() -> Self {
+        Serializer {
+            state: None,
+            name: None,
+            adapt: A::adapt,
+        }
+    }
+
+    fn child(&self) -> Self {
+        Serializer {
+            state: None,
+            name: None,
+            adapt: self.adapt,
+        }
     }
 }
 
@@ -40,15 +64,16 @@ impl SerializerTrait for Serializer {
     type SerializeTuple = ser::Impossible<NestedValue, Self::Error>;
     type SerializeTupleStruct = ser::Impossible<NestedValue, Self::Error>;
     type SerializeTupleVariant = ser::Impossible<NestedValue, Self::Error>;
-    type SerializeMap = ser::Impossible<NestedValue, Self::Error>;
+    type SerializeMap = MapSerializer;
     type SerializeStruct = Self;
     type SerializeStructVariant = ser::Impossible<NestedValue, Self::Error>;
 
     fn serialize_struct(
-        self,
-        _name: &'static str,
+        mut self,
+        name: &'static str,
         _len: usize,
     ) -> Result<Self::SerializeStruct, Self::Error> {
+        self.name = Some(name);
         Ok(self)
     }
 
@@ -116,8 +141,8 @@ impl SerializerTrait for Serializer {
     fn serialize_u32(self, _v: u32) -> Result<Self::Ok, Self::Error> {
         unimplemented!()
     }
-    fn serialize_bool(self, _v: bool) -> Result<Self::Ok, Self::Error> {
-        unimplemented!()
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
+        Ok(NestedValue::Bool(v))
     }
 
     fn serialize_i8(self, _v: i8) -> Result<Self::Ok, Self::Error> {
@@ -140,7 +165,7 @@ impl SerializerTrait for Serializer {
     }
 
     fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
-        unimplemented!()
+        Ok(NestedValue::Default)
     }
 
     fn serialize_unit_variant(
@@ -188,7 +213,11 @@ impl SerializerTrait for Serializer {
     }
 
     fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
-        unimplemented!()
+        Ok(MapSerializer {
+            map: HashMap::new(),
+            key: None,
+            serializer: self,
+        })
     }
 
     fn serialize_struct_variant(
@@ -215,7 +244,7 @@ impl SerializeStruct for Serializer {
     where
         T: Serialize,
     {
-        let serialized_value = value.serialize(Serializer::new())?;
+        let serialized_value = value.serialize(self.child())?;
 
         match self.state {
             Some(NestedValue::Map(ref mut map)) => {
@@ -235,11 +264,20 @@ impl SerializeStruct for Serializer {
     }
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
-        if self.state.is_none() {
+        let value = if self.state.is_none() {
             // If the state is empty, return an empty map
-            Ok(NestedValue::Map(HashMap::new()))
+            NestedValue::Map(HashMap::new())
         } else {
-            self.state.ok_or(error::Error::InvalidState)
+            self.state.ok_or(error::Error::InvalidState)?
+        };
+
+        // Adapt modules
+        match self
+            .name
+            .and_then(|name| name.strip_suffix(RECORD_ITEM_SUFFIX))
+        {
+            Some(name) => Ok((self.adapt)(name, value)),
+            None => Ok(value),
         }
     }
 }
@@ -252,7 +290,7 @@ impl SerializeSeq for Serializer {
     where
         T: Serialize,
     {
-        let serialized_value = value.serialize(Serializer::new())?;
+        let serialized_value = value.serialize(self.child())?;
 
         match self.state {
             Some(NestedValue::Vec(ref mut vec)) => {
@@ -279,6 +317,43 @@ impl SerializeSeq for Serializer {
     }
 }
 
+/// Serializer for maps with string keys, such as the records of optimizers.
+pub struct MapSerializer {
+    map: HashMap<String, NestedValue>,
+    key: Option<String>,
+    serializer: Serializer,
+}
+
+impl SerializeMap for MapSerializer {
+    type Ok = NestedValue;
+    type Error = Error;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Self::Error> {
+        match key.serialize(self.serializer.child())? {
+            NestedValue::String(key) => {
+                self.key = Some(key);
+                Ok(())
+            }
+            key => Err(ser::Error::custom(format!(
+                "Map keys must be strings, got {:?}",
+                key
+            ))),
+        }
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        let key = self.key.take().ok_or(Error::InvalidState)?;
+        self.map
+            .insert(key, value.serialize(self.serializer.child())?);
+
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(NestedValue::Map(self.map))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -0,0 +1,468 @@
+use super::{
+    BinBytesRecorder, BinFileRecorder, BinGzFileRecorder, JsonGzFileRecorder,
+    NamedMpkBytesRecorder, NamedMpkFileRecorder, NamedMpkGzFileRecorder, PrecisionSettings,
+    PrettyJsonFileRecorder, Recorder, RecorderError,
+};
+use crate::module::{Module, ModuleMapper, ModuleVisitor, ParamId};
+use burn_tensor::{backend::Backend, BasicOps, Bool, Data, DataSerialize, Element, Int, Tensor};
+use std::collections::HashMap;
+
+/// A source of named tensors that can be read one at a time, such as a memory-mapped file.
+///
+/// Tensors are named after their path in the module, e.g. `layers.0.weight`.
+pub trait TensorSource {
+    /// Read the tensor with the given name, converting its elements to the given type.
+    ///
+    /// Returns `None` when the source doesn't contain the tensor.
+    fn read<E: Element>(&self, name: &str) -> Result<Option<DataSerialize<E>>, RecorderError>;
+
+    /// Read the bool tensor with the given name.
+    ///
+    /// Returns `None` when the source doesn't contain the tensor.
+    fn read_bool(&self, name: &str) -> Result<Option<DataSerialize<bool>>, RecorderError>;
+}
+
+/// Select the tensors of a module loaded by a [streaming recorder](StreamingRecorder).
+#[derive(Debug, Clone, Default)]
+pub struct ModuleFilter {
+    submodules: Vec<String>,
+    allow_missing: bool,
+}
+
+impl ModuleFilter {
+    /// Load all the tensors of the module.
+    pub fn all() -> Self {
+        Self::default()
+    }
+
+    /// Only load the tensors of the given submodule, such as `encoder.layers.0`.
+    ///
+    /// Can be called multiple times to load many submodules, the other tensors of the module
+    /// are left untouched.
+    pub fn with_submodule(mut self, path: &str) -> Self {
+        self.submodules.push(path.to_string());
+        self
+    }
+
+    /// Keep the current value of the tensors that are missing from the record instead of
+    /// returning an error.
+    pub fn with_allow_missing(mut self, allow_missing: bool) -> Self {
+        self.allow_missing = allow_missing;
+        self
+    }
+
+    /// If the tensor with the given path is selected.
+    pub fn is_selected(&self, path: &str) -> bool {
+        self.submodules.is_empty()
+            || self.submodules.iter().any(|submodule| {
+                path == submodule
+                    || path
+                        .strip_prefix(submodule.as_str())
+                        .map(|rest| rest.starts_with('.'))
+                        .unwrap_or(false)
+            })
+    }
+}
+
+/// Recorder that can load the tensors of a record directly into a module, only loading some
+/// submodules with a [filter](ModuleFilter).
+///
+/// # Notes
+///
+/// Only the safetensors recorders stream the tensors, reading them one at a time, which avoids
+/// keeping the whole record in memory when loading large models.
+///
+/// The other recorders don't stream: they load the full record first and only support selecting
+/// the tensors to load. The record is loaded into a copy of the module and its tensors are read
+/// back, all while the given module is kept, so their peak memory usage is about three times the
+/// size of the module.
+pub trait StreamingRecorder<B: Backend>: Recorder<B> {
+    /// Load the tensors selected by the filter into the given module.
+    ///
+    /// Each tensor is loaded on the device of the tensor it replaces, and the parameter ids of
+    /// the module are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `module` - The module to load the tensors into.
+    /// * `args` - Arguments used to load the record.
+    /// * `filter` - The tensors to load.
+    ///
+    /// # Returns
+    ///
+    /// The module with the loaded tensors.
+    fn load_module<M: Module<B>>(
+        &self,
+        module: M,
+        args: Self::LoadArgs,
+        filter: &ModuleFilter,
+    ) -> Result<M, RecorderError>;
+}
+
+/// Load the tensors of a module from the given [source](TensorSource), one at a time.
+///
+/// The memory of each tensor of the module is released before the new value is read, so the
+/// peak memory usage stays close to the size of the module.
+pub fn load_module_from_source<B, M, S>(
+    module: M,
+    source: &S,
+    filter: &ModuleFilter,
+) -> Result<M, RecorderError>
+where
+    B: Backend,
+    M: Module<B>,
+    S: TensorSource,
+{
+    let mut loader = SourceLoader {
+        source,
+        filter,
+        path: Vec::new(),
+        error: None,
+    };
+    let module = module.map(&mut loader);
+
+    match loader.error {
+        Some(err) => Err(err),
+        None => Ok(module),
+    }
+}
+
+/// A [tensor source](TensorSource) holding the tensors of a module.
+///
+/// It is used to load records with a format that can't be read one tensor at a time.
+pub struct ModuleSource<B: Backend> {
+    tensors: HashMap<String, ModuleTensor<B>>,
+}
+
+enum ModuleTensor<B: Backend> {
+    Float(DataSerialize<B::FloatElem>),
+    Int(DataSerialize<B::IntElem>),
+    Bool(DataSerialize<bool>),
+}
+
+impl<B: Backend> ModuleSource<B> {
+    /// Collect the tensors of the given module.
+    ///
+    /// Returns an error on wasm without the `wasm-sync` feature, since the tensors can't be read
+    /// synchronously.
+    pub fn from_module<M: Module<B>>(module: &M) -> Result<Self, RecorderError> {
+        let mut collector = ModuleTensorCollector {
+            tensors: HashMap::new(),
+            path: Vec::new(),
+            error: None,
+        };
+        module.visit(&mut collector);
+
+        match collector.error {
+            Some(err) => Err(err),
+            None => Ok(Self {
+                tensors: collector.tensors,
+            }),
+        }
+    }
+}
+
+impl<B: Backend> TensorSource for ModuleSource<B> {
+    fn read<E: Element>(&self, name: &str) -> Result<Option<DataSerialize<E>>, RecorderError> {
+        Ok(match self.tensors.get(name) {
+            Some(ModuleTensor::Float(data)) => Some(data.clone().convert()),
+            Some(ModuleTensor::Int(data)) => Some(data.clone().convert()),
+            Some(ModuleTensor::Bool(_)) => {
+                return Err(RecorderError::Unknown(format!(
+                    "Expected a numeric tensor for {name}, got a bool tensor"
+                )))
+            }
+            None => None,
+        })
+    }
+
+    fn read_bool(&self, name: &str) -> Result<Option<DataSerialize<bool>>, RecorderError> {
+        Ok(match self.tensors.get(name) {
+            Some(ModuleTensor::Bool(data)) => Some(data.clone()),
+            Some(_) => {
+                return Err(RecorderError::Unknown(format!(
+                    "Expected a bool tensor for {name}, got a numeric tensor"
+                )))
+            }
+            None => None,
+        })
+    }
+}
+
+struct ModuleTensorCollector<B: Backend> {
+    tensors: HashMap<String, ModuleTensor<B>>,
+    path: Vec<String>,
+    error: Option<RecorderError>,
+}
+
+impl<B: Backend> ModuleTensorCollector<B> {
+    #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+    fn unsupported(&mut self) {
+        self.error = Some(RecorderError::Unknown(
+            "Reading the tensors of a module isn't supported on wasm without the `wasm-sync` \
+             feature"
+                .to_string(),
+        ));
+    }
+}
+
+impl<B: Backend> ModuleVisitor<B> for ModuleTensorCollector<B> {
+    fn enter_module(&mut self, name: &str) {
+        self.path.push(name.to_string());
+    }
+
+    fn exit_module(&mut self, _name: &str) {
+        self.path.pop();
+    }
+
+    fn visit_float<const D: usize>(&mut self, _id: &ParamId, tensor: &Tensor<B, D>) {
+        #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+        self.unsupported();
+
+        #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+        self.tensors.insert(
+            self.path.join("."),
+            ModuleTensor::Float(tensor.to_data().serialize()),
+        );
+    }
+
+    fn visit_int<const D: usize>(&mut self, _id: &ParamId, tensor: &Tensor<B, D, Int>) {
+        #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+        self.unsupported();
+
+        #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+        self.tensors.insert(
+            self.path.join("."),
+            ModuleTensor::Int(tensor.to_data().serialize()),
+        );
+    }
+
+    fn visit_bool<const D: usize>(&mut self, _id: &ParamId, tensor: &Tensor<B, D, Bool>) {
+        #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+        self.unsupported();
+
+        #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+        self.tensors.insert(
+            self.path.join("."),
+            ModuleTensor::Bool(tensor.to_data().serialize()),
+        );
+    }
+}
+
+struct SourceLoader<'a, S> {
+    source: &'a S,
+    filter: &'a ModuleFilter,
+    path: Vec<String>,
+    error: Option<RecorderError>,
+}
+
+impl<'a, S: TensorSource> SourceLoader<'a, S> {
+    fn load<B, K, F, const D: usize>(&mut self, tensor: Tensor<B, D, K>, read: F) -> Tensor<B, D, K>
+    where
+        B: Backend,
+        K: BasicOps<B>,
+        F: FnOnce(&S, &str) -> Result<Option<DataSerialize<K::Elem>>, RecorderError>,
+    {
+        let path = self.path.join(".");
+
+        if self.error.is_some() || !self.filter.is_selected(&path) {
+            return tensor;
+        }
+
+        let data = match read(self.source, &path) {
+            Ok(Some(data)) => data,
+            Ok(None) if self.filter.allow_missing => return tensor,
+            Ok(None) => {
+                self.error = Some(RecorderError::Unknown(format!(
+                    "Tensor {path} is missing from the record"
+                )));
+                return tensor;
+            }
+            Err(err) => {
+                self.error = Some(err);
+                return tensor;
+            }
+        };
+
+        let dims = tensor.dims();
+        if data.shape != dims {
+            self.error = Some(RecorderError::Unknown(format!(
+                "Tensor {path} has the shape {:?} in the record, expected {:?}",
+                data.shape, dims
+            )));
+            return tensor;
+        }
+
+        let device = tensor.device();
+        // Release the current value before allocating the loaded one.
+        core::mem::drop(tensor);
+
+        Tensor::from_data(Data::from(data), &device)
+    }
+}
+
+impl<'a, B: Backend, S: TensorSource> ModuleMapper<B> for SourceLoader<'a, S> {
+    fn enter_module(&mut self, name: &str) {
+        self.path.push(name.to_string());
+    }
+
+    fn exit_module(&mut self, _name: &str) {
+        self.path.pop();
+    }
+
+    fn map_float<const D: usize>(&mut self, _id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let is_require_grad = tensor.is_require_grad();
+        let tensor = self.load(tensor, |source, name| source.read::<B::FloatElem>(name));
+
+        tensor.set_require_grad(is_require_grad)
+    }
+
+    fn map_int<const D: usize>(
+        &mut self,
+        _id: &ParamId,
+        tensor: Tensor<B, D, Int>,
+    ) -> Tensor<B, D, Int> {
+        self.load(tensor, |source, name| source.read::<B::IntElem>(name))
+    }
+
+    fn map_bool<const D: usize>(
+        &mut self,
+        _id: &ParamId,
+        tensor: Tensor<B, D, Bool>,
+    ) -> Tensor<B, D, Bool> {
+        self.load(tensor, |source, name| source.read_bool(name))
+    }
+}
+
+/// Formats that can't be read one tensor at a time load the full record first, so only the
+/// selection of the tensors is supported. The module, a copy of the module with the record loaded
+/// and the data of its tensors are all kept in memory while loading.
+macro_rules! impl_streaming_recorder_with_full_record {
+    ($($recorder:ident),*) => {
+        $(
+            impl<S: PrecisionSettings, B: Backend> StreamingRecorder<B> for $recorder<S> {
+                fn load_module<M: Module<B>>(
+                    &self,
+                    module: M,
+                    args: Self::LoadArgs,
+                    filter: &ModuleFilter,
+                ) -> Result<M, RecorderError> {
+                    let device = module.devices().into_iter().next().unwrap_or_default();
+                    let record = self.load(args, &device)?;
+                    let source = ModuleSource::from_module(&module.clone().load_record(record))?;
+
+                    load_module_from_source(module, &source, filter)
+                }
+            }
+        )*
+    };
+}
+
+impl_streaming_recorder_with_full_record!(
+    BinBytesRecorder,
+    NamedMpkBytesRecorder,
+    BinFileRecorder,
+    BinGzFileRecorder,
+    JsonGzFileRecorder,
+    PrettyJsonFileRecorder,
+    NamedMpkFileRecorder,
+    NamedMpkGzFileRecorder
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as burn;
+    use crate::{
+        nn::{Linear, LinearConfig},
+        record::FullPrecisionSettings,
+        TestBackend,
+    };
+
+    #[derive(Module, Debug)]
+    pub struct Model<B: Backend> {
+        layers: Vec<Linear<B>>,
+        head: Option<Linear<B>>,
+    }
+
+    #[test]
+    fn should_name_tensors_after_their_path() {
+        let source = ModuleSource::from_module(&create_model()).unwrap();
+        let mut names = source.tensors.keys().cloned().collect::<Vec<_>>();
+        names.sort();
+
+        assert_eq!(
+            names,
+            vec![
+                "head.bias",
+                "head.weight",
+                "layers.0.bias",
+                "layers.0.weight",
+                "layers.1.bias",
+                "layers.1.weight"
+            ]
+        );
+    }
+
+    #[test]
+    fn should_load_only_selected_submodules() {
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let model_saved = create_model();
+        let bytes = recorder
+            .record(model_saved.clone().into_record(), ())
+            .unwrap();
+
+        let model = create_model();
+        let filter = ModuleFilter::all().with_submodule("layers.1");
+        let model_loaded = recorder.load_module(model.clone(), bytes, &filter).unwrap();
+
+        model_loaded.layers[0]
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&model.layers[0].weight.val().into_data(), 5);
+        model_loaded.layers[1]
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&model_saved.layers[1].weight.val().into_data(), 5);
+        assert_eq!(model_loaded.layers[1].weight.id, model.layers[1].weight.id);
+    }
+
+    #[test]
+    fn should_fail_on_missing_tensors_unless_allowed() {
+        let model = create_model();
+        let source = ModuleSource::<TestBackend>::from_module(&Model {
+            layers: model.layers.clone(),
+            head: None,
+        })
+        .unwrap();
+
+        let result = load_module_from_source(model.clone(), &source, &ModuleFilter::all());
+        assert!(result.is_err());
+
+        let filter = ModuleFilter::all().with_allow_missing(true);
+        assert!(load_module_from_source(model, &source, &filter).is_ok());
+    }
+
+    #[test]
+    fn should_match_submodule_prefixes_on_path_segments() {
+        let filter = ModuleFilter::all().with_submodule("layers.1");
+
+        assert!(filter.is_selected("layers.1.weight"));
+        assert!(!filter.is_selected("layers.10.weight"));
+        assert!(!filter.is_selected("layers.0.weight"));
+    }
+
+    fn create_model() -> Model<TestBackend> {
+        let device = Default::default();
+
+        Model {
+            layers: vec![
+                LinearConfig::new(2, 4).init(&device),
+                LinearConfig::new(4, 4).init(&device),
+            ],
+            head: Some(LinearConfig::new(4, 1).init(&device)),
+        }
+    }
+}
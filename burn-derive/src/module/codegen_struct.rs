@@ -29,7 +29,9 @@ impl ModuleCodegen for StructModuleCodegen {
     fn gen_visit(&self) -> TokenStream {
         let body = self.gen_fields_fn(|name| {
             quote! {
+                visitor.enter_module(stringify!(#name));
                 burn::module::Module::visit(&self.#name, visitor);
+                visitor.exit_module(stringify!(#name));
             }
         });
 
@@ -98,7 +100,9 @@ impl ModuleCodegen for StructModuleCodegen {
     fn gen_map(&self) -> TokenStream {
         let (names, body) = self.gen_fields_fn_names(|name| {
             quote! {
+                mapper.enter_module(stringify!(#name));
                 let #name = burn::module::Module::<B>::map(self.#name, mapper);
+                mapper.exit_module(stringify!(#name));
             }
         });
 
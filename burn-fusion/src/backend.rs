@@ -3,7 +3,10 @@ use crate::{
     stream::{Context, OperationDescription},
     FusionClientLocator, FusionTensor,
 };
-use burn_tensor::{backend::Backend, Device, Shape};
+use burn_tensor::{
+    backend::{Backend, MemoryUsage},
+    Device, Shape,
+};
 use serde::{de::DeserializeOwned, Serialize};
 use std::marker::PhantomData;
 
@@ -49,6 +52,10 @@ impl<B: FusionBackend> Backend for Fusion<B> {
         client.drain();
         B::sync(device)
     }
+
+    fn memory_usage(device: &Self::Device) -> Option<MemoryUsage> {
+        B::memory_usage(device)
+    }
 }
 
 /// The status of a [builder](OptimizationBuilder).
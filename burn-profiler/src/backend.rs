@@ -0,0 +1,90 @@
+use burn_tensor::backend::{Backend, MemoryUsage};
+use core::marker::PhantomData;
+
+/// Record the timings of the tensor operations of a backend.
+///
+/// This works as a backend decorator, forwarding every operation to the inner backend. While
+/// [recording](crate::start_recording), each operation is recorded with the shapes of its
+/// tensors, its wall time and the time spent waiting for the device to finish it.
+///
+/// # Notes
+///
+/// The device is synchronized after each recorded operation, so that the time of asynchronous
+/// backends is attributed to the right operation, which also serializes their execution. Wrap
+/// the backend before the autodiff decorator, such as `Autodiff<Profiler<Wgpu>>`, to record the
+/// operations of the backward pass.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Profiler<B> {
+    _b: PhantomData<B>,
+}
+
+impl<B: Backend> Backend for Profiler<B> {
+    type Device = B::Device;
+
+    type FullPrecisionElem = B::FullPrecisionElem;
+    type FullPrecisionBackend = Profiler<B::FullPrecisionBackend>;
+
+    type TensorPrimitive<const D: usize> = B::TensorPrimitive<D>;
+    type FloatElem = B::FloatElem;
+
+    type IntTensorPrimitive<const D: usize> = B::IntTensorPrimitive<D>;
+    type IntElem = B::IntElem;
+
+    type BoolTensorPrimitive<const D: usize> = B::BoolTensorPrimitive<D>;
+
+    fn name() -> String {
+        format!("profiler<{}>", B::name())
+    }
+
+    fn seed(seed: u64) {
+        B::seed(seed)
+    }
+
+    fn sync(device: &B::Device) {
+        B::sync(device);
+    }
+
+    fn memory_usage(device: &B::Device) -> Option<MemoryUsage> {
+        B::memory_usage(device)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{start_recording, stop_recording};
+    use burn_autodiff::Autodiff;
+    use burn_ndarray::NdArray;
+    use burn_tensor::{Distribution, Tensor};
+
+    type TestBackend = Autodiff<Profiler<NdArray<f32>>>;
+
+    #[test]
+    fn should_record_the_operations_of_the_forward_and_backward_passes() {
+        let device = Default::default();
+        let lhs =
+            Tensor::<TestBackend, 2>::random([2, 3], Distribution::Default, &device).require_grad();
+        let rhs = Tensor::<TestBackend, 2>::random([3, 4], Distribution::Default, &device);
+
+        start_recording();
+        let grads = lhs.clone().matmul(rhs).sum().backward();
+        let profile = stop_recording();
+
+        assert!(lhs.grad(&grads).is_some());
+        let matmuls = profile
+            .records
+            .iter()
+            .filter(|record| record.name == "float_matmul")
+            .map(|record| record.shapes.clone())
+            .collect::<Vec<_>>();
+        assert!(matmuls.contains(&vec![vec![2, 3], vec![3, 4]]));
+        // The gradient of the left hand side is `grad.matmul(rhs.transpose())`.
+        assert!(matmuls.contains(&vec![vec![2, 4], vec![4, 3]]));
+        assert!(profile
+            .records
+            .iter()
+            .all(|record| record.sync <= record.wall));
+        assert!(profile.table(usize::MAX).contains("float_matmul"));
+        assert!(profile.to_chrome_trace().contains("\"float_matmul\""));
+    }
+}
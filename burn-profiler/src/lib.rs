@@ -0,0 +1,29 @@
+#![warn(missing_docs)]
+
+//! # Burn Profiler
+//!
+//! This library is a part of the Burn project. It is a standalone crate that can be used to
+//! record the timings of the tensor operations of any backend, to find the operations dominating
+//! the runtime of a model.
+
+mod backend;
+mod ops;
+mod profile;
+mod session;
+
+pub use backend::*;
+pub use profile::*;
+pub use session::*;
+
+extern crate alloc;
+
+#[cfg(test)]
+mod tests {
+    type TestBackend = crate::Profiler<burn_ndarray::NdArray<f32>>;
+    type TestTensor<const D: usize> = burn_tensor::Tensor<TestBackend, D>;
+    type TestTensorInt<const D: usize> = burn_tensor::Tensor<TestBackend, D, burn_tensor::Int>;
+    type TestTensorBool<const D: usize> = burn_tensor::Tensor<TestBackend, D, burn_tensor::Bool>;
+
+    burn_tensor::testgen_all!();
+    burn_autodiff::testgen_all!();
+}
@@ -0,0 +1,70 @@
+use super::float_dims;
+use crate::session::profile;
+use crate::Profiler;
+use burn_tensor::backend::Backend;
+use burn_tensor::ops::*;
+
+impl<B: Backend> ActivationOps<Self> for Profiler<B> {
+    fn relu<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        profile!(
+            "relu",
+            vec![float_dims::<B, D>(&tensor)],
+            Some(B::device(&tensor)),
+            B::relu(tensor)
+        )
+    }
+
+    fn relu_backward<const D: usize>(
+        output: FloatTensor<Self, D>,
+        grad: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        profile!(
+            "relu_backward",
+            vec![float_dims::<B, D>(&output), float_dims::<B, D>(&grad)],
+            Some(B::device(&output)),
+            B::relu_backward(output, grad)
+        )
+    }
+
+    fn gelu<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        profile!(
+            "gelu",
+            vec![float_dims::<B, D>(&tensor)],
+            Some(B::device(&tensor)),
+            B::gelu(tensor)
+        )
+    }
+
+    fn gelu_backward<const D: usize>(
+        x: FloatTensor<Self, D>,
+        grad: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        profile!(
+            "gelu_backward",
+            vec![float_dims::<B, D>(&x), float_dims::<B, D>(&grad)],
+            Some(B::device(&x)),
+            B::gelu_backward(x, grad)
+        )
+    }
+
+    fn sigmoid<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        profile!(
+            "sigmoid",
+            vec![float_dims::<B, D>(&tensor)],
+            Some(B::device(&tensor)),
+            B::sigmoid(tensor)
+        )
+    }
+
+    fn sigmoid_backward<const D: usize>(
+        output: FloatTensor<Self, D>,
+        grad: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        profile!(
+            "sigmoid_backward",
+            vec![float_dims::<B, D>(&output), float_dims::<B, D>(&grad)],
+            Some(B::device(&output)),
+            B::sigmoid_backward(output, grad)
+        )
+    }
+}
@@ -0,0 +1,218 @@
+use super::bool_dims;
+use crate::session::profile;
+use crate::Profiler;
+use burn_tensor::backend::Backend;
+use burn_tensor::ops::*;
+use burn_tensor::{Data, Device, Reader, Shape};
+use core::ops::Range;
+
+impl<B: Backend> BoolTensorOps<Self> for Profiler<B> {
+    fn bool_empty<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> BoolTensor<Self, D> {
+        profile!(
+            "bool_empty",
+            vec![shape.dims.to_vec()],
+            Some(device.clone()),
+            B::bool_empty(shape, device)
+        )
+    }
+
+    fn bool_shape<const D: usize>(tensor: &BoolTensor<Self, D>) -> Shape<D> {
+        B::bool_shape(tensor)
+    }
+
+    fn bool_into_data<const D: usize>(tensor: BoolTensor<Self, D>) -> Reader<Data<bool, D>> {
+        profile!(
+            "bool_into_data",
+            vec![bool_dims::<B, D>(&tensor)],
+            Some(B::bool_device(&tensor)),
+            B::bool_into_data(tensor)
+        )
+    }
+
+    fn bool_to_data<const D: usize>(tensor: &BoolTensor<Self, D>) -> Reader<Data<bool, D>> {
+        profile!(
+            "bool_to_data",
+            vec![bool_dims::<B, D>(tensor)],
+            Some(B::bool_device(tensor)),
+            B::bool_to_data(tensor)
+        )
+    }
+
+    fn bool_from_data<const D: usize>(
+        data: Data<bool, D>,
+        device: &Device<Self>,
+    ) -> BoolTensor<Self, D> {
+        profile!(
+            "bool_from_data",
+            vec![data.shape.dims.to_vec()],
+            Some(device.clone()),
+            B::bool_from_data(data, device)
+        )
+    }
+
+    fn bool_into_int<const D: usize>(tensor: BoolTensor<Self, D>) -> IntTensor<Self, D> {
+        profile!(
+            "bool_into_int",
+            vec![bool_dims::<B, D>(&tensor)],
+            Some(B::bool_device(&tensor)),
+            B::bool_into_int(tensor)
+        )
+    }
+
+    fn bool_into_float<const D: usize>(tensor: BoolTensor<Self, D>) -> FloatTensor<Self, D> {
+        profile!(
+            "bool_into_float",
+            vec![bool_dims::<B, D>(&tensor)],
+            Some(B::bool_device(&tensor)),
+            B::bool_into_float(tensor)
+        )
+    }
+
+    fn bool_device<const D: usize>(tensor: &BoolTensor<Self, D>) -> Device<Self> {
+        B::bool_device(tensor)
+    }
+
+    fn bool_to_device<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        device: &Device<Self>,
+    ) -> BoolTensor<Self, D> {
+        profile!(
+            "bool_to_device",
+            vec![bool_dims::<B, D>(&tensor)],
+            Some(B::bool_device(&tensor)),
+            B::bool_to_device(tensor, device)
+        )
+    }
+
+    fn bool_reshape<const D1: usize, const D2: usize>(
+        tensor: BoolTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> BoolTensor<Self, D2> {
+        profile!(
+            "bool_reshape",
+            vec![bool_dims::<B, D1>(&tensor), shape.dims.to_vec()],
+            Some(B::bool_device(&tensor)),
+            B::bool_reshape(tensor, shape)
+        )
+    }
+
+    fn bool_slice<const D1: usize, const D2: usize>(
+        tensor: BoolTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+    ) -> BoolTensor<Self, D1> {
+        profile!(
+            "bool_slice",
+            vec![bool_dims::<B, D1>(&tensor)],
+            Some(B::bool_device(&tensor)),
+            B::bool_slice(tensor, ranges)
+        )
+    }
+
+    fn bool_slice_assign<const D1: usize, const D2: usize>(
+        tensor: BoolTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+        value: BoolTensor<Self, D1>,
+    ) -> BoolTensor<Self, D1> {
+        profile!(
+            "bool_slice_assign",
+            vec![bool_dims::<B, D1>(&tensor), bool_dims::<B, D1>(&value)],
+            Some(B::bool_device(&tensor)),
+            B::bool_slice_assign(tensor, ranges, value)
+        )
+    }
+
+    fn bool_repeat<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        dim: usize,
+        times: usize,
+    ) -> BoolTensor<Self, D> {
+        profile!(
+            "bool_repeat",
+            vec![bool_dims::<B, D>(&tensor)],
+            Some(B::bool_device(&tensor)),
+            B::bool_repeat(tensor, dim, times)
+        )
+    }
+
+    fn bool_cat<const D: usize>(
+        tensors: Vec<BoolTensor<Self, D>>,
+        dim: usize,
+    ) -> BoolTensor<Self, D> {
+        profile!(
+            "bool_cat",
+            tensors.iter().map(bool_dims::<B, D>).collect(),
+            tensors.first().map(B::bool_device),
+            B::bool_cat(tensors, dim)
+        )
+    }
+
+    fn bool_equal<const D: usize>(
+        lhs: BoolTensor<Self, D>,
+        rhs: BoolTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        profile!(
+            "bool_equal",
+            vec![bool_dims::<B, D>(&lhs), bool_dims::<B, D>(&rhs)],
+            Some(B::bool_device(&lhs)),
+            B::bool_equal(lhs, rhs)
+        )
+    }
+
+    fn bool_not<const D: usize>(tensor: BoolTensor<Self, D>) -> BoolTensor<Self, D> {
+        profile!(
+            "bool_not",
+            vec![bool_dims::<B, D>(&tensor)],
+            Some(B::bool_device(&tensor)),
+            B::bool_not(tensor)
+        )
+    }
+
+    fn bool_transpose<const D: usize>(tensor: BoolTensor<Self, D>) -> BoolTensor<Self, D> {
+        profile!(
+            "bool_transpose",
+            vec![bool_dims::<B, D>(&tensor)],
+            Some(B::bool_device(&tensor)),
+            B::bool_transpose(tensor)
+        )
+    }
+
+    fn bool_swap_dims<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        dim1: usize,
+        dim2: usize,
+    ) -> BoolTensor<Self, D> {
+        profile!(
+            "bool_swap_dims",
+            vec![bool_dims::<B, D>(&tensor)],
+            Some(B::bool_device(&tensor)),
+            B::bool_swap_dims(tensor, dim1, dim2)
+        )
+    }
+
+    fn bool_narrow<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        dim: usize,
+        start: usize,
+        length: usize,
+    ) -> BoolTensor<Self, D> {
+        profile!(
+            "bool_narrow",
+            vec![bool_dims::<B, D>(&tensor)],
+            Some(B::bool_device(&tensor)),
+            B::bool_narrow(tensor, dim, start, length)
+        )
+    }
+
+    fn bool_chunk<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        chunks: usize,
+        dim: usize,
+    ) -> Vec<BoolTensor<Self, D>> {
+        profile!(
+            "bool_chunk",
+            vec![bool_dims::<B, D>(&tensor)],
+            Some(B::bool_device(&tensor)),
+            B::bool_chunk(tensor, chunks, dim)
+        )
+    }
+}
@@ -0,0 +1,902 @@
+use super::{bool_dims, float_dims, int_dims};
+use crate::session::profile;
+use crate::Profiler;
+use burn_tensor::backend::Backend;
+use burn_tensor::ops::*;
+use burn_tensor::{Data, Device, Distribution, Reader, Shape};
+use core::ops::Range;
+
+impl<B: Backend> TensorOps<Self> for Profiler<B> {
+    fn from_data<const D: usize>(
+        data: Data<FloatElem<Self>, D>,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        profile!(
+            "float_from_data",
+            vec![data.shape.dims.to_vec()],
+            Some(device.clone()),
+            B::from_data(data, device)
+        )
+    }
+
+    fn random<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        profile!(
+            "float_random",
+            vec![shape.dims.to_vec()],
+            Some(device.clone()),
+            B::random(shape, distribution, device)
+        )
+    }
+
+    fn zeros<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> FloatTensor<Self, D> {
+        profile!(
+            "float_zeros",
+            vec![shape.dims.to_vec()],
+            Some(device.clone()),
+            B::zeros(shape, device)
+        )
+    }
+
+    fn ones<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> FloatTensor<Self, D> {
+        profile!(
+            "float_ones",
+            vec![shape.dims.to_vec()],
+            Some(device.clone()),
+            B::ones(shape, device)
+        )
+    }
+
+    fn full<const D: usize>(
+        shape: Shape<D>,
+        fill_value: FloatElem<Self>,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        profile!(
+            "float_full",
+            vec![shape.dims.to_vec()],
+            Some(device.clone()),
+            B::full(shape, fill_value, device)
+        )
+    }
+
+    fn shape<const D: usize>(tensor: &FloatTensor<Self, D>) -> Shape<D> {
+        B::shape(tensor)
+    }
+
+    fn to_data<const D: usize>(tensor: &FloatTensor<Self, D>) -> Reader<Data<FloatElem<Self>, D>> {
+        profile!(
+            "float_to_data",
+            vec![float_dims::<B, D>(tensor)],
+            Some(B::device(tensor)),
+            B::to_data(tensor)
+        )
+    }
+
+    fn into_data<const D: usize>(tensor: FloatTensor<Self, D>) -> Reader<Data<FloatElem<Self>, D>> {
+        profile!(
+            "float_into_data",
+            vec![float_dims::<B, D>(&tensor)],
+            Some(B::device(&tensor)),
+            B::into_data(tensor)
+        )
+    }
+
+    fn device<const D: usize>(tensor: &FloatTensor<Self, D>) -> Device<Self> {
+        B::device(tensor)
+    }
+
+    fn to_device<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        profile!(
+            "float_to_device",
+            vec![float_dims::<B, D>(&tensor)],
+            Some(B::device(&tensor)),
+            B::to_device(tensor, device)
+        )
+    }
+
+    fn arange(range: Range<usize>, device: &Device<Self>) -> IntTensor<Self, 1> {
+        profile!(
+            "arange",
+            vec![],
+            Some(device.clone()),
+            B::arange(range, device)
+        )
+    }
+
+    fn into_int<const D: usize>(tensor: FloatTensor<Self, D>) -> IntTensor<Self, D> {
+        profile!(
+            "float_into_int",
+            vec![float_dims::<B, D>(&tensor)],
+            Some(B::device(&tensor)),
+            B::into_int(tensor)
+        )
+    }
+
+    fn arange_step(range: Range<usize>, step: usize, device: &Device<Self>) -> IntTensor<Self, 1> {
+        profile!(
+            "arange_step",
+            vec![],
+            Some(device.clone()),
+            B::arange_step(range, step, device)
+        )
+    }
+
+    fn empty<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> FloatTensor<Self, D> {
+        profile!(
+            "float_empty",
+            vec![shape.dims.to_vec()],
+            Some(device.clone()),
+            B::empty(shape, device)
+        )
+    }
+
+    fn repeat<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        times: usize,
+    ) -> FloatTensor<Self, D> {
+        profile!(
+            "float_repeat",
+            vec![float_dims::<B, D>(&tensor)],
+            Some(B::device(&tensor)),
+            B::repeat(tensor, dim, times)
+        )
+    }
+
+    fn add<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        profile!(
+            "float_add",
+            vec![float_dims::<B, D>(&lhs), float_dims::<B, D>(&rhs)],
+            Some(B::device(&lhs)),
+            B::add(lhs, rhs)
+        )
+    }
+
+    fn add_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        profile!(
+            "float_add_scalar",
+            vec![float_dims::<B, D>(&lhs)],
+            Some(B::device(&lhs)),
+            B::add_scalar(lhs, rhs)
+        )
+    }
+
+    fn clamp_min<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        min: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        profile!(
+            "float_clamp_min",
+            vec![float_dims::<B, D>(&tensor)],
+            Some(B::device(&tensor)),
+            B::clamp_min(tensor, min)
+        )
+    }
+
+    fn clamp_max<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        max: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        profile!(
+            "float_clamp_max",
+            vec![float_dims::<B, D>(&tensor)],
+            Some(B::device(&tensor)),
+            B::clamp_max(tensor, max)
+        )
+    }
+
+    fn clamp<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        min: FloatElem<Self>,
+        max: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        profile!(
+            "float_clamp",
+            vec![float_dims::<B, D>(&tensor)],
+            Some(B::device(&tensor)),
+            B::clamp(tensor, min, max)
+        )
+    }
+
+    fn sub<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        profile!(
+            "float_sub",
+            vec![float_dims::<B, D>(&lhs), float_dims::<B, D>(&rhs)],
+            Some(B::device(&lhs)),
+            B::sub(lhs, rhs)
+        )
+    }
+
+    fn sub_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        profile!(
+            "float_sub_scalar",
+            vec![float_dims::<B, D>(&lhs)],
+            Some(B::device(&lhs)),
+            B::sub_scalar(lhs, rhs)
+        )
+    }
+
+    fn mul<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        profile!(
+            "float_mul",
+            vec![float_dims::<B, D>(&lhs), float_dims::<B, D>(&rhs)],
+            Some(B::device(&lhs)),
+            B::mul(lhs, rhs)
+        )
+    }
+
+    fn mul_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        profile!(
+            "float_mul_scalar",
+            vec![float_dims::<B, D>(&lhs)],
+            Some(B::device(&lhs)),
+            B::mul_scalar(lhs, rhs)
+        )
+    }
+
+    fn div<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        profile!(
+            "float_div",
+            vec![float_dims::<B, D>(&lhs), float_dims::<B, D>(&rhs)],
+            Some(B::device(&lhs)),
+            B::div(lhs, rhs)
+        )
+    }
+
+    fn div_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        profile!(
+            "float_div_scalar",
+            vec![float_dims::<B, D>(&lhs)],
+            Some(B::device(&lhs)),
+            B::div_scalar(lhs, rhs)
+        )
+    }
+
+    fn matmul<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        profile!(
+            "float_matmul",
+            vec![float_dims::<B, D>(&lhs), float_dims::<B, D>(&rhs)],
+            Some(B::device(&lhs)),
+            B::matmul(lhs, rhs)
+        )
+    }
+
+    fn neg<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        profile!(
+            "float_neg",
+            vec![float_dims::<B, D>(&tensor)],
+            Some(B::device(&tensor)),
+            B::neg(tensor)
+        )
+    }
+
+    fn recip<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        profile!(
+            "float_recip",
+            vec![float_dims::<B, D>(&tensor)],
+            Some(B::device(&tensor)),
+            B::recip(tensor)
+        )
+    }
+
+    fn transpose<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        profile!(
+            "float_transpose",
+            vec![float_dims::<B, D>(&tensor)],
+            Some(B::device(&tensor)),
+            B::transpose(tensor)
+        )
+    }
+
+    fn swap_dims<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim1: usize,
+        dim2: usize,
+    ) -> FloatTensor<Self, D> {
+        profile!(
+            "float_swap_dims",
+            vec![float_dims::<B, D>(&tensor)],
+            Some(B::device(&tensor)),
+            B::swap_dims(tensor, dim1, dim2)
+        )
+    }
+
+    fn reshape<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> FloatTensor<Self, D2> {
+        profile!(
+            "float_reshape",
+            vec![float_dims::<B, D1>(&tensor), shape.dims.to_vec()],
+            Some(B::device(&tensor)),
+            B::reshape(tensor, shape)
+        )
+    }
+
+    fn gather<const D: usize>(
+        dim: usize,
+        tensor: FloatTensor<Self, D>,
+        indices: IntTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        profile!(
+            "float_gather",
+            vec![float_dims::<B, D>(&tensor), int_dims::<B, D>(&indices)],
+            Some(B::device(&tensor)),
+            B::gather(dim, tensor, indices)
+        )
+    }
+
+    fn scatter<const D: usize>(
+        dim: usize,
+        tensor: FloatTensor<Self, D>,
+        indices: IntTensor<Self, D>,
+        value: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        profile!(
+            "float_scatter",
+            vec![
+                float_dims::<B, D>(&tensor),
+                int_dims::<B, D>(&indices),
+                float_dims::<B, D>(&value)
+            ],
+            Some(B::device(&tensor)),
+            B::scatter(dim, tensor, indices, value)
+        )
+    }
+
+    fn select<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<Self, 1>,
+    ) -> FloatTensor<Self, D> {
+        profile!(
+            "float_select",
+            vec![float_dims::<B, D>(&tensor), int_dims::<B, 1>(&indices)],
+            Some(B::device(&tensor)),
+            B::select(tensor, dim, indices)
+        )
+    }
+
+    fn select_assign<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<Self, 1>,
+        value: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        profile!(
+            "float_select_assign",
+            vec![
+                float_dims::<B, D>(&tensor),
+                int_dims::<B, 1>(&indices),
+                float_dims::<B, D>(&value)
+            ],
+            Some(B::device(&tensor)),
+            B::select_assign(tensor, dim, indices, value)
+        )
+    }
+
+    fn slice<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+    ) -> FloatTensor<Self, D1> {
+        profile!(
+            "float_slice",
+            vec![float_dims::<B, D1>(&tensor)],
+            Some(B::device(&tensor)),
+            B::slice(tensor, ranges)
+        )
+    }
+
+    fn slice_assign<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+        value: FloatTensor<Self, D1>,
+    ) -> FloatTensor<Self, D1> {
+        profile!(
+            "float_slice_assign",
+            vec![float_dims::<B, D1>(&tensor), float_dims::<B, D1>(&value)],
+            Some(B::device(&tensor)),
+            B::slice_assign(tensor, ranges, value)
+        )
+    }
+
+    fn mask_where<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        mask: BoolTensor<Self, D>,
+        value: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        profile!(
+            "float_mask_where",
+            vec![
+                float_dims::<B, D>(&tensor),
+                bool_dims::<B, D>(&mask),
+                float_dims::<B, D>(&value)
+            ],
+            Some(B::device(&tensor)),
+            B::mask_where(tensor, mask, value)
+        )
+    }
+
+    fn mask_fill<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        mask: BoolTensor<Self, D>,
+        value: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        profile!(
+            "float_mask_fill",
+            vec![float_dims::<B, D>(&tensor), bool_dims::<B, D>(&mask)],
+            Some(B::device(&tensor)),
+            B::mask_fill(tensor, mask, value)
+        )
+    }
+
+    fn equal<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        profile!(
+            "float_equal",
+            vec![float_dims::<B, D>(&lhs), float_dims::<B, D>(&rhs)],
+            Some(B::device(&lhs)),
+            B::equal(lhs, rhs)
+        )
+    }
+
+    fn equal_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        profile!(
+            "float_equal_elem",
+            vec![float_dims::<B, D>(&lhs)],
+            Some(B::device(&lhs)),
+            B::equal_elem(lhs, rhs)
+        )
+    }
+
+    fn greater<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        profile!(
+            "float_greater",
+            vec![float_dims::<B, D>(&lhs), float_dims::<B, D>(&rhs)],
+            Some(B::device(&lhs)),
+            B::greater(lhs, rhs)
+        )
+    }
+
+    fn greater_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        profile!(
+            "float_greater_elem",
+            vec![float_dims::<B, D>(&lhs)],
+            Some(B::device(&lhs)),
+            B::greater_elem(lhs, rhs)
+        )
+    }
+
+    fn greater_equal<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        profile!(
+            "float_greater_equal",
+            vec![float_dims::<B, D>(&lhs), float_dims::<B, D>(&rhs)],
+            Some(B::device(&lhs)),
+            B::greater_equal(lhs, rhs)
+        )
+    }
+
+    fn greater_equal_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        profile!(
+            "float_greater_equal_elem",
+            vec![float_dims::<B, D>(&lhs)],
+            Some(B::device(&lhs)),
+            B::greater_equal_elem(lhs, rhs)
+        )
+    }
+
+    fn lower<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        profile!(
+            "float_lower",
+            vec![float_dims::<B, D>(&lhs), float_dims::<B, D>(&rhs)],
+            Some(B::device(&lhs)),
+            B::lower(lhs, rhs)
+        )
+    }
+
+    fn lower_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        profile!(
+            "float_lower_elem",
+            vec![float_dims::<B, D>(&lhs)],
+            Some(B::device(&lhs)),
+            B::lower_elem(lhs, rhs)
+        )
+    }
+
+    fn lower_equal<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        profile!(
+            "float_lower_equal",
+            vec![float_dims::<B, D>(&lhs), float_dims::<B, D>(&rhs)],
+            Some(B::device(&lhs)),
+            B::lower_equal(lhs, rhs)
+        )
+    }
+
+    fn lower_equal_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        profile!(
+            "float_lower_equal_elem",
+            vec![float_dims::<B, D>(&lhs)],
+            Some(B::device(&lhs)),
+            B::lower_equal_elem(lhs, rhs)
+        )
+    }
+
+    fn detach<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        B::detach(tensor)
+    }
+
+    fn set_require_grad<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        _require_grad: bool,
+    ) -> FloatTensor<Self, D> {
+        B::set_require_grad(tensor, _require_grad)
+    }
+
+    fn is_require_grad<const D: usize>(_tensor: &FloatTensor<Self, D>) -> bool {
+        B::is_require_grad(_tensor)
+    }
+
+    fn sum<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
+        profile!(
+            "float_sum",
+            vec![float_dims::<B, D>(&tensor)],
+            Some(B::device(&tensor)),
+            B::sum(tensor)
+        )
+    }
+
+    fn sum_dim<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> FloatTensor<Self, D> {
+        profile!(
+            "float_sum_dim",
+            vec![float_dims::<B, D>(&tensor)],
+            Some(B::device(&tensor)),
+            B::sum_dim(tensor, dim)
+        )
+    }
+
+    fn mean<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
+        profile!(
+            "float_mean",
+            vec![float_dims::<B, D>(&tensor)],
+            Some(B::device(&tensor)),
+            B::mean(tensor)
+        )
+    }
+
+    fn mean_dim<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> FloatTensor<Self, D> {
+        profile!(
+            "float_mean_dim",
+            vec![float_dims::<B, D>(&tensor)],
+            Some(B::device(&tensor)),
+            B::mean_dim(tensor, dim)
+        )
+    }
+
+    fn to_full_precision<const D: usize>(
+        tensor: &FloatTensor<Self, D>,
+    ) -> FloatTensor<FullPrecisionBackend<Self>, D> {
+        profile!(
+            "float_to_full_precision",
+            vec![float_dims::<B, D>(tensor)],
+            Some(B::device(tensor)),
+            B::to_full_precision(tensor)
+        )
+    }
+
+    fn from_full_precision<const D: usize>(
+        tensor: FloatTensor<FullPrecisionBackend<Self>, D>,
+    ) -> FloatTensor<Self, D> {
+        profile!(
+            "float_from_full_precision",
+            vec![float_dims::<B::FullPrecisionBackend, D>(&tensor)],
+            Some(B::FullPrecisionBackend::device(&tensor)),
+            B::from_full_precision(tensor)
+        )
+    }
+
+    fn exp<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        profile!(
+            "float_exp",
+            vec![float_dims::<B, D>(&tensor)],
+            Some(B::device(&tensor)),
+            B::exp(tensor)
+        )
+    }
+
+    fn log<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        profile!(
+            "float_log",
+            vec![float_dims::<B, D>(&tensor)],
+            Some(B::device(&tensor)),
+            B::log(tensor)
+        )
+    }
+
+    fn log1p<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        profile!(
+            "float_log1p",
+            vec![float_dims::<B, D>(&tensor)],
+            Some(B::device(&tensor)),
+            B::log1p(tensor)
+        )
+    }
+
+    fn powf<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        profile!(
+            "float_powf",
+            vec![float_dims::<B, D>(&lhs), float_dims::<B, D>(&rhs)],
+            Some(B::device(&lhs)),
+            B::powf(lhs, rhs)
+        )
+    }
+
+    fn powi<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        profile!(
+            "float_powi",
+            vec![float_dims::<B, D>(&lhs), int_dims::<B, D>(&rhs)],
+            Some(B::device(&lhs)),
+            B::powi(lhs, rhs)
+        )
+    }
+
+    fn powi_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        profile!(
+            "float_powi_scalar",
+            vec![float_dims::<B, D>(&lhs)],
+            Some(B::device(&lhs)),
+            B::powi_scalar(lhs, rhs)
+        )
+    }
+
+    fn powf_scalar<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        value: f32,
+    ) -> FloatTensor<Self, D> {
+        profile!(
+            "float_powf_scalar",
+            vec![float_dims::<B, D>(&tensor)],
+            Some(B::device(&tensor)),
+            B::powf_scalar(tensor, value)
+        )
+    }
+
+    fn sqrt<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        profile!(
+            "float_sqrt",
+            vec![float_dims::<B, D>(&tensor)],
+            Some(B::device(&tensor)),
+            B::sqrt(tensor)
+        )
+    }
+
+    fn abs<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        profile!(
+            "float_abs",
+            vec![float_dims::<B, D>(&tensor)],
+            Some(B::device(&tensor)),
+            B::abs(tensor)
+        )
+    }
+
+    fn cos<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        profile!(
+            "float_cos",
+            vec![float_dims::<B, D>(&tensor)],
+            Some(B::device(&tensor)),
+            B::cos(tensor)
+        )
+    }
+
+    fn sin<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        profile!(
+            "float_sin",
+            vec![float_dims::<B, D>(&tensor)],
+            Some(B::device(&tensor)),
+            B::sin(tensor)
+        )
+    }
+
+    fn tanh<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        profile!(
+            "float_tanh",
+            vec![float_dims::<B, D>(&tensor)],
+            Some(B::device(&tensor)),
+            B::tanh(tensor)
+        )
+    }
+
+    fn erf<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        profile!(
+            "float_erf",
+            vec![float_dims::<B, D>(&tensor)],
+            Some(B::device(&tensor)),
+            B::erf(tensor)
+        )
+    }
+
+    fn cat<const D: usize>(tensors: Vec<FloatTensor<Self, D>>, dim: usize) -> FloatTensor<Self, D> {
+        profile!(
+            "float_cat",
+            tensors.iter().map(float_dims::<B, D>).collect(),
+            tensors.first().map(B::device),
+            B::cat(tensors, dim)
+        )
+    }
+
+    fn argmax<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        profile!(
+            "float_argmax",
+            vec![float_dims::<B, D>(&tensor)],
+            Some(B::device(&tensor)),
+            B::argmax(tensor, dim)
+        )
+    }
+
+    fn argmin<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        profile!(
+            "float_argmin",
+            vec![float_dims::<B, D>(&tensor)],
+            Some(B::device(&tensor)),
+            B::argmin(tensor, dim)
+        )
+    }
+
+    fn max<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
+        profile!(
+            "float_max",
+            vec![float_dims::<B, D>(&tensor)],
+            Some(B::device(&tensor)),
+            B::max(tensor)
+        )
+    }
+
+    fn max_dim<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> FloatTensor<Self, D> {
+        profile!(
+            "float_max_dim",
+            vec![float_dims::<B, D>(&tensor)],
+            Some(B::device(&tensor)),
+            B::max_dim(tensor, dim)
+        )
+    }
+
+    fn max_dim_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, IntTensor<Self, D>) {
+        profile!(
+            "float_max_dim_with_indices",
+            vec![float_dims::<B, D>(&tensor)],
+            Some(B::device(&tensor)),
+            B::max_dim_with_indices(tensor, dim)
+        )
+    }
+
+    fn min<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
+        profile!(
+            "float_min",
+            vec![float_dims::<B, D>(&tensor)],
+            Some(B::device(&tensor)),
+            B::min(tensor)
+        )
+    }
+
+    fn min_dim<const D: usize>(tensor: FloatTensor<Self, D>, dim: usize) -> FloatTensor<Self, D> {
+        profile!(
+            "float_min_dim",
+            vec![float_dims::<B, D>(&tensor)],
+            Some(B::device(&tensor)),
+            B::min_dim(tensor, dim)
+        )
+    }
+
+    fn min_dim_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, IntTensor<Self, D>) {
+        profile!(
+            "float_min_dim_with_indices",
+            vec![float_dims::<B, D>(&tensor)],
+            Some(B::device(&tensor)),
+            B::min_dim_with_indices(tensor, dim)
+        )
+    }
+
+    fn narrow<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        start: usize,
+        length: usize,
+    ) -> FloatTensor<Self, D> {
+        profile!(
+            "float_narrow",
+            vec![float_dims::<B, D>(&tensor)],
+            Some(B::device(&tensor)),
+            B::narrow(tensor, dim, start, length)
+        )
+    }
+
+    fn chunk<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        chunks: usize,
+        dim: usize,
+    ) -> Vec<FloatTensor<Self, D>> {
+        profile!(
+            "float_chunk",
+            vec![float_dims::<B, D>(&tensor)],
+            Some(B::device(&tensor)),
+            B::chunk(tensor, chunks, dim)
+        )
+    }
+}
@@ -0,0 +1,738 @@
+use super::{bool_dims, float_dims, int_dims};
+use crate::session::profile;
+use crate::Profiler;
+use burn_tensor::backend::Backend;
+use burn_tensor::ops::*;
+use burn_tensor::{Data, Device, Reader, Shape};
+use core::ops::Range;
+
+impl<B: Backend> IntTensorOps<Self> for Profiler<B> {
+    fn int_empty<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> IntTensor<Self, D> {
+        profile!(
+            "int_empty",
+            vec![shape.dims.to_vec()],
+            Some(device.clone()),
+            B::int_empty(shape, device)
+        )
+    }
+
+    fn int_shape<const D: usize>(tensor: &IntTensor<Self, D>) -> Shape<D> {
+        B::int_shape(tensor)
+    }
+
+    fn int_into_data<const D: usize>(tensor: IntTensor<Self, D>) -> Reader<Data<IntElem<Self>, D>> {
+        profile!(
+            "int_into_data",
+            vec![int_dims::<B, D>(&tensor)],
+            Some(B::int_device(&tensor)),
+            B::int_into_data(tensor)
+        )
+    }
+
+    fn int_to_data<const D: usize>(tensor: &IntTensor<Self, D>) -> Reader<Data<IntElem<Self>, D>> {
+        profile!(
+            "int_to_data",
+            vec![int_dims::<B, D>(tensor)],
+            Some(B::int_device(tensor)),
+            B::int_to_data(tensor)
+        )
+    }
+
+    fn int_from_data<const D: usize>(
+        data: Data<IntElem<Self>, D>,
+        device: &Device<Self>,
+    ) -> IntTensor<Self, D> {
+        profile!(
+            "int_from_data",
+            vec![data.shape.dims.to_vec()],
+            Some(device.clone()),
+            B::int_from_data(data, device)
+        )
+    }
+
+    fn int_device<const D: usize>(tensor: &IntTensor<Self, D>) -> Device<Self> {
+        B::int_device(tensor)
+    }
+
+    fn int_to_device<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        device: &Device<Self>,
+    ) -> IntTensor<Self, D> {
+        profile!(
+            "int_to_device",
+            vec![int_dims::<B, D>(&tensor)],
+            Some(B::int_device(&tensor)),
+            B::int_to_device(tensor, device)
+        )
+    }
+
+    fn int_reshape<const D1: usize, const D2: usize>(
+        tensor: IntTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> IntTensor<Self, D2> {
+        profile!(
+            "int_reshape",
+            vec![int_dims::<B, D1>(&tensor), shape.dims.to_vec()],
+            Some(B::int_device(&tensor)),
+            B::int_reshape(tensor, shape)
+        )
+    }
+
+    fn int_slice<const D1: usize, const D2: usize>(
+        tensor: IntTensor<Self, D1>,
+        indices: [Range<usize>; D2],
+    ) -> IntTensor<Self, D1> {
+        profile!(
+            "int_slice",
+            vec![int_dims::<B, D1>(&tensor)],
+            Some(B::int_device(&tensor)),
+            B::int_slice(tensor, indices)
+        )
+    }
+
+    fn int_slice_assign<const D1: usize, const D2: usize>(
+        tensor: IntTensor<Self, D1>,
+        indices: [Range<usize>; D2],
+        value: IntTensor<Self, D1>,
+    ) -> IntTensor<Self, D1> {
+        profile!(
+            "int_slice_assign",
+            vec![int_dims::<B, D1>(&tensor), int_dims::<B, D1>(&value)],
+            Some(B::int_device(&tensor)),
+            B::int_slice_assign(tensor, indices, value)
+        )
+    }
+
+    fn int_into_float<const D: usize>(tensor: IntTensor<Self, D>) -> FloatTensor<Self, D> {
+        profile!(
+            "int_into_float",
+            vec![int_dims::<B, D>(&tensor)],
+            Some(B::int_device(&tensor)),
+            B::int_into_float(tensor)
+        )
+    }
+
+    fn int_mask_where<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        mask: BoolTensor<Self, D>,
+        source: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        profile!(
+            "int_mask_where",
+            vec![
+                int_dims::<B, D>(&tensor),
+                bool_dims::<B, D>(&mask),
+                int_dims::<B, D>(&source)
+            ],
+            Some(B::int_device(&tensor)),
+            B::int_mask_where(tensor, mask, source)
+        )
+    }
+
+    fn int_mask_fill<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        mask: BoolTensor<Self, D>,
+        value: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        profile!(
+            "int_mask_fill",
+            vec![int_dims::<B, D>(&tensor), bool_dims::<B, D>(&mask)],
+            Some(B::int_device(&tensor)),
+            B::int_mask_fill(tensor, mask, value)
+        )
+    }
+
+    fn int_gather<const D: usize>(
+        dim: usize,
+        tensor: IntTensor<Self, D>,
+        indices: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        profile!(
+            "int_gather",
+            vec![int_dims::<B, D>(&tensor), int_dims::<B, D>(&indices)],
+            Some(B::int_device(&tensor)),
+            B::int_gather(dim, tensor, indices)
+        )
+    }
+
+    fn int_scatter<const D: usize>(
+        dim: usize,
+        tensor: IntTensor<Self, D>,
+        indices: IntTensor<Self, D>,
+        value: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        profile!(
+            "int_scatter",
+            vec![
+                int_dims::<B, D>(&tensor),
+                int_dims::<B, D>(&indices),
+                int_dims::<B, D>(&value)
+            ],
+            Some(B::int_device(&tensor)),
+            B::int_scatter(dim, tensor, indices, value)
+        )
+    }
+
+    fn int_select<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<Self, 1>,
+    ) -> IntTensor<Self, D> {
+        profile!(
+            "int_select",
+            vec![int_dims::<B, D>(&tensor), int_dims::<B, 1>(&indices)],
+            Some(B::int_device(&tensor)),
+            B::int_select(tensor, dim, indices)
+        )
+    }
+
+    fn int_select_assign<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<Self, 1>,
+        value: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        profile!(
+            "int_select_assign",
+            vec![
+                int_dims::<B, D>(&tensor),
+                int_dims::<B, 1>(&indices),
+                int_dims::<B, D>(&value)
+            ],
+            Some(B::int_device(&tensor)),
+            B::int_select_assign(tensor, dim, indices, value)
+        )
+    }
+
+    fn int_repeat<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        times: usize,
+    ) -> IntTensor<Self, D> {
+        profile!(
+            "int_repeat",
+            vec![int_dims::<B, D>(&tensor)],
+            Some(B::int_device(&tensor)),
+            B::int_repeat(tensor, dim, times)
+        )
+    }
+
+    fn int_cat<const D: usize>(tensors: Vec<IntTensor<Self, D>>, dim: usize) -> IntTensor<Self, D> {
+        profile!(
+            "int_cat",
+            tensors.iter().map(int_dims::<B, D>).collect(),
+            tensors.first().map(B::int_device),
+            B::int_cat(tensors, dim)
+        )
+    }
+
+    fn int_equal<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        profile!(
+            "int_equal",
+            vec![int_dims::<B, D>(&lhs), int_dims::<B, D>(&rhs)],
+            Some(B::int_device(&lhs)),
+            B::int_equal(lhs, rhs)
+        )
+    }
+
+    fn int_equal_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        profile!(
+            "int_equal_elem",
+            vec![int_dims::<B, D>(&lhs)],
+            Some(B::int_device(&lhs)),
+            B::int_equal_elem(lhs, rhs)
+        )
+    }
+
+    fn int_greater<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        profile!(
+            "int_greater",
+            vec![int_dims::<B, D>(&lhs), int_dims::<B, D>(&rhs)],
+            Some(B::int_device(&lhs)),
+            B::int_greater(lhs, rhs)
+        )
+    }
+
+    fn int_greater_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        profile!(
+            "int_greater_elem",
+            vec![int_dims::<B, D>(&lhs)],
+            Some(B::int_device(&lhs)),
+            B::int_greater_elem(lhs, rhs)
+        )
+    }
+
+    fn int_greater_equal<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        profile!(
+            "int_greater_equal",
+            vec![int_dims::<B, D>(&lhs), int_dims::<B, D>(&rhs)],
+            Some(B::int_device(&lhs)),
+            B::int_greater_equal(lhs, rhs)
+        )
+    }
+
+    fn int_greater_equal_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        profile!(
+            "int_greater_equal_elem",
+            vec![int_dims::<B, D>(&lhs)],
+            Some(B::int_device(&lhs)),
+            B::int_greater_equal_elem(lhs, rhs)
+        )
+    }
+
+    fn int_lower<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        profile!(
+            "int_lower",
+            vec![int_dims::<B, D>(&lhs), int_dims::<B, D>(&rhs)],
+            Some(B::int_device(&lhs)),
+            B::int_lower(lhs, rhs)
+        )
+    }
+
+    fn int_lower_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        profile!(
+            "int_lower_elem",
+            vec![int_dims::<B, D>(&lhs)],
+            Some(B::int_device(&lhs)),
+            B::int_lower_elem(lhs, rhs)
+        )
+    }
+
+    fn int_lower_equal<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        profile!(
+            "int_lower_equal",
+            vec![int_dims::<B, D>(&lhs), int_dims::<B, D>(&rhs)],
+            Some(B::int_device(&lhs)),
+            B::int_lower_equal(lhs, rhs)
+        )
+    }
+
+    fn int_lower_equal_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        profile!(
+            "int_lower_equal_elem",
+            vec![int_dims::<B, D>(&lhs)],
+            Some(B::int_device(&lhs)),
+            B::int_lower_equal_elem(lhs, rhs)
+        )
+    }
+
+    fn int_add<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        profile!(
+            "int_add",
+            vec![int_dims::<B, D>(&lhs), int_dims::<B, D>(&rhs)],
+            Some(B::int_device(&lhs)),
+            B::int_add(lhs, rhs)
+        )
+    }
+
+    fn int_add_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        profile!(
+            "int_add_scalar",
+            vec![int_dims::<B, D>(&lhs)],
+            Some(B::int_device(&lhs)),
+            B::int_add_scalar(lhs, rhs)
+        )
+    }
+
+    fn int_powi<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        profile!(
+            "int_powi",
+            vec![int_dims::<B, D>(&lhs), int_dims::<B, D>(&rhs)],
+            Some(B::int_device(&lhs)),
+            B::int_powi(lhs, rhs)
+        )
+    }
+
+    fn int_powf<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        profile!(
+            "int_powf",
+            vec![int_dims::<B, D>(&lhs), float_dims::<B, D>(&rhs)],
+            Some(B::int_device(&lhs)),
+            B::int_powf(lhs, rhs)
+        )
+    }
+
+    fn int_powi_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        profile!(
+            "int_powi_scalar",
+            vec![int_dims::<B, D>(&lhs)],
+            Some(B::int_device(&lhs)),
+            B::int_powi_scalar(lhs, rhs)
+        )
+    }
+
+    fn int_powf_scalar<const D: usize>(lhs: IntTensor<Self, D>, rhs: f32) -> IntTensor<Self, D> {
+        profile!(
+            "int_powf_scalar",
+            vec![int_dims::<B, D>(&lhs)],
+            Some(B::int_device(&lhs)),
+            B::int_powf_scalar(lhs, rhs)
+        )
+    }
+
+    fn int_clamp_min<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        min: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        profile!(
+            "int_clamp_min",
+            vec![int_dims::<B, D>(&tensor)],
+            Some(B::int_device(&tensor)),
+            B::int_clamp_min(tensor, min)
+        )
+    }
+
+    fn int_clamp_max<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        max: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        profile!(
+            "int_clamp_max",
+            vec![int_dims::<B, D>(&tensor)],
+            Some(B::int_device(&tensor)),
+            B::int_clamp_max(tensor, max)
+        )
+    }
+
+    fn int_clamp<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        min: IntElem<Self>,
+        max: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        profile!(
+            "int_clamp",
+            vec![int_dims::<B, D>(&tensor)],
+            Some(B::int_device(&tensor)),
+            B::int_clamp(tensor, min, max)
+        )
+    }
+
+    fn int_sub<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        profile!(
+            "int_sub",
+            vec![int_dims::<B, D>(&lhs), int_dims::<B, D>(&rhs)],
+            Some(B::int_device(&lhs)),
+            B::int_sub(lhs, rhs)
+        )
+    }
+
+    fn int_sub_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        profile!(
+            "int_sub_scalar",
+            vec![int_dims::<B, D>(&lhs)],
+            Some(B::int_device(&lhs)),
+            B::int_sub_scalar(lhs, rhs)
+        )
+    }
+
+    fn int_mul<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        profile!(
+            "int_mul",
+            vec![int_dims::<B, D>(&lhs), int_dims::<B, D>(&rhs)],
+            Some(B::int_device(&lhs)),
+            B::int_mul(lhs, rhs)
+        )
+    }
+
+    fn int_mul_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        profile!(
+            "int_mul_scalar",
+            vec![int_dims::<B, D>(&lhs)],
+            Some(B::int_device(&lhs)),
+            B::int_mul_scalar(lhs, rhs)
+        )
+    }
+
+    fn int_div<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        profile!(
+            "int_div",
+            vec![int_dims::<B, D>(&lhs), int_dims::<B, D>(&rhs)],
+            Some(B::int_device(&lhs)),
+            B::int_div(lhs, rhs)
+        )
+    }
+
+    fn int_div_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        profile!(
+            "int_div_scalar",
+            vec![int_dims::<B, D>(&lhs)],
+            Some(B::int_device(&lhs)),
+            B::int_div_scalar(lhs, rhs)
+        )
+    }
+
+    fn int_neg<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, D> {
+        profile!(
+            "int_neg",
+            vec![int_dims::<B, D>(&tensor)],
+            Some(B::int_device(&tensor)),
+            B::int_neg(tensor)
+        )
+    }
+
+    fn int_zeros<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> IntTensor<Self, D> {
+        profile!(
+            "int_zeros",
+            vec![shape.dims.to_vec()],
+            Some(device.clone()),
+            B::int_zeros(shape, device)
+        )
+    }
+
+    fn int_ones<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> IntTensor<Self, D> {
+        profile!(
+            "int_ones",
+            vec![shape.dims.to_vec()],
+            Some(device.clone()),
+            B::int_ones(shape, device)
+        )
+    }
+
+    fn int_full<const D: usize>(
+        shape: Shape<D>,
+        fill_value: IntElem<Self>,
+        device: &Device<Self>,
+    ) -> IntTensor<Self, D> {
+        profile!(
+            "int_full",
+            vec![shape.dims.to_vec()],
+            Some(device.clone()),
+            B::int_full(shape, fill_value, device)
+        )
+    }
+
+    fn int_sum<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, 1> {
+        profile!(
+            "int_sum",
+            vec![int_dims::<B, D>(&tensor)],
+            Some(B::int_device(&tensor)),
+            B::int_sum(tensor)
+        )
+    }
+
+    fn int_sum_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        profile!(
+            "int_sum_dim",
+            vec![int_dims::<B, D>(&tensor)],
+            Some(B::int_device(&tensor)),
+            B::int_sum_dim(tensor, dim)
+        )
+    }
+
+    fn int_mean<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, 1> {
+        profile!(
+            "int_mean",
+            vec![int_dims::<B, D>(&tensor)],
+            Some(B::int_device(&tensor)),
+            B::int_mean(tensor)
+        )
+    }
+
+    fn int_mean_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        profile!(
+            "int_mean_dim",
+            vec![int_dims::<B, D>(&tensor)],
+            Some(B::int_device(&tensor)),
+            B::int_mean_dim(tensor, dim)
+        )
+    }
+
+    fn int_argmax<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        profile!(
+            "int_argmax",
+            vec![int_dims::<B, D>(&tensor)],
+            Some(B::int_device(&tensor)),
+            B::int_argmax(tensor, dim)
+        )
+    }
+
+    fn int_argmin<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        profile!(
+            "int_argmin",
+            vec![int_dims::<B, D>(&tensor)],
+            Some(B::int_device(&tensor)),
+            B::int_argmin(tensor, dim)
+        )
+    }
+
+    fn int_max<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, 1> {
+        profile!(
+            "int_max",
+            vec![int_dims::<B, D>(&tensor)],
+            Some(B::int_device(&tensor)),
+            B::int_max(tensor)
+        )
+    }
+
+    fn int_max_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        profile!(
+            "int_max_dim",
+            vec![int_dims::<B, D>(&tensor)],
+            Some(B::int_device(&tensor)),
+            B::int_max_dim(tensor, dim)
+        )
+    }
+
+    fn int_max_dim_with_indices<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+    ) -> (IntTensor<Self, D>, IntTensor<Self, D>) {
+        profile!(
+            "int_max_dim_with_indices",
+            vec![int_dims::<B, D>(&tensor)],
+            Some(B::int_device(&tensor)),
+            B::int_max_dim_with_indices(tensor, dim)
+        )
+    }
+
+    fn int_min<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, 1> {
+        profile!(
+            "int_min",
+            vec![int_dims::<B, D>(&tensor)],
+            Some(B::int_device(&tensor)),
+            B::int_min(tensor)
+        )
+    }
+
+    fn int_min_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        profile!(
+            "int_min_dim",
+            vec![int_dims::<B, D>(&tensor)],
+            Some(B::int_device(&tensor)),
+            B::int_min_dim(tensor, dim)
+        )
+    }
+
+    fn int_min_dim_with_indices<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+    ) -> (IntTensor<Self, D>, IntTensor<Self, D>) {
+        profile!(
+            "int_min_dim_with_indices",
+            vec![int_dims::<B, D>(&tensor)],
+            Some(B::int_device(&tensor)),
+            B::int_min_dim_with_indices(tensor, dim)
+        )
+    }
+
+    fn int_abs<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, D> {
+        profile!(
+            "int_abs",
+            vec![int_dims::<B, D>(&tensor)],
+            Some(B::int_device(&tensor)),
+            B::int_abs(tensor)
+        )
+    }
+
+    fn int_transpose<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, D> {
+        profile!(
+            "int_transpose",
+            vec![int_dims::<B, D>(&tensor)],
+            Some(B::int_device(&tensor)),
+            B::int_transpose(tensor)
+        )
+    }
+
+    fn int_swap_dims<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim1: usize,
+        dim2: usize,
+    ) -> IntTensor<Self, D> {
+        profile!(
+            "int_swap_dims",
+            vec![int_dims::<B, D>(&tensor)],
+            Some(B::int_device(&tensor)),
+            B::int_swap_dims(tensor, dim1, dim2)
+        )
+    }
+
+    fn int_narrow<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        start: usize,
+        length: usize,
+    ) -> IntTensor<Self, D> {
+        profile!(
+            "int_narrow",
+            vec![int_dims::<B, D>(&tensor)],
+            Some(B::int_device(&tensor)),
+            B::int_narrow(tensor, dim, start, length)
+        )
+    }
+
+    fn int_chunk<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        chunks: usize,
+        dim: usize,
+    ) -> Vec<IntTensor<Self, D>> {
+        profile!(
+            "int_chunk",
+            vec![int_dims::<B, D>(&tensor)],
+            Some(B::int_device(&tensor)),
+            B::int_chunk(tensor, chunks, dim)
+        )
+    }
+}
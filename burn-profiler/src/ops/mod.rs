@@ -0,0 +1,23 @@
+mod activation;
+mod boolean;
+mod float;
+mod int;
+mod module;
+
+use burn_tensor::backend::Backend;
+use burn_tensor::ops::{BoolTensor, FloatTensor, IntTensor};
+
+/// The dimensions of a float tensor, to record them.
+pub(crate) fn float_dims<B: Backend, const D: usize>(tensor: &FloatTensor<B, D>) -> Vec<usize> {
+    B::shape(tensor).dims.to_vec()
+}
+
+/// The dimensions of an int tensor, to record them.
+pub(crate) fn int_dims<B: Backend, const D: usize>(tensor: &IntTensor<B, D>) -> Vec<usize> {
+    B::int_shape(tensor).dims.to_vec()
+}
+
+/// The dimensions of a bool tensor, to record them.
+pub(crate) fn bool_dims<B: Backend, const D: usize>(tensor: &BoolTensor<B, D>) -> Vec<usize> {
+    B::bool_shape(tensor).dims.to_vec()
+}
@@ -0,0 +1,454 @@
+use super::{float_dims, int_dims};
+use crate::session::profile;
+use crate::Profiler;
+use burn_tensor::backend::Backend;
+use burn_tensor::ops::*;
+
+impl<B: Backend> ModuleOps<Self> for Profiler<B> {
+    fn embedding(
+        weights: FloatTensor<Self, 2>,
+        indices: IntTensor<Self, 2>,
+    ) -> FloatTensor<Self, 3> {
+        profile!(
+            "embedding",
+            vec![float_dims::<B, 2>(&weights), int_dims::<B, 2>(&indices)],
+            Some(B::device(&weights)),
+            B::embedding(weights, indices)
+        )
+    }
+
+    fn embedding_backward(
+        weights: FloatTensor<Self, 2>,
+        output_grad: FloatTensor<Self, 3>,
+        indices: IntTensor<Self, 2>,
+    ) -> FloatTensor<Self, 2> {
+        profile!(
+            "embedding_backward",
+            vec![
+                float_dims::<B, 2>(&weights),
+                float_dims::<B, 3>(&output_grad),
+                int_dims::<B, 2>(&indices)
+            ],
+            Some(B::device(&weights)),
+            B::embedding_backward(weights, output_grad, indices)
+        )
+    }
+
+    fn conv1d(
+        x: FloatTensor<Self, 3>,
+        weight: FloatTensor<Self, 3>,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvOptions<1>,
+    ) -> FloatTensor<Self, 3> {
+        profile!(
+            "conv1d",
+            vec![float_dims::<B, 3>(&x), float_dims::<B, 3>(&weight)]
+                .into_iter()
+                .chain(bias.iter().map(float_dims::<B, 1>))
+                .collect(),
+            Some(B::device(&x)),
+            B::conv1d(x, weight, bias, options)
+        )
+    }
+
+    fn conv1d_backward(
+        x: FloatTensor<Self, 3>,
+        weight: FloatTensor<Self, 3>,
+        bias: Option<FloatTensor<Self, 1>>,
+        output_grad: FloatTensor<Self, 3>,
+        options: ConvOptions<1>,
+    ) -> Conv1dBackward<Self> {
+        profile!(
+            "conv1d_backward",
+            vec![
+                float_dims::<B, 3>(&x),
+                float_dims::<B, 3>(&weight),
+                float_dims::<B, 3>(&output_grad)
+            ]
+            .into_iter()
+            .chain(bias.iter().map(float_dims::<B, 1>))
+            .collect(),
+            Some(B::device(&x)),
+            {
+                let c = B::conv1d_backward(x, weight, bias, output_grad, options);
+                Conv1dBackward::new(c.x_grad, c.weights_grad, c.bias_grad)
+            }
+        )
+    }
+
+    fn conv2d(
+        x: FloatTensor<Self, 4>,
+        weight: FloatTensor<Self, 4>,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvOptions<2>,
+    ) -> FloatTensor<Self, 4> {
+        profile!(
+            "conv2d",
+            vec![float_dims::<B, 4>(&x), float_dims::<B, 4>(&weight)]
+                .into_iter()
+                .chain(bias.iter().map(float_dims::<B, 1>))
+                .collect(),
+            Some(B::device(&x)),
+            B::conv2d(x, weight, bias, options)
+        )
+    }
+
+    fn conv2d_backward(
+        x: FloatTensor<Self, 4>,
+        weight: FloatTensor<Self, 4>,
+        bias: Option<FloatTensor<Self, 1>>,
+        output_grad: FloatTensor<Self, 4>,
+        options: ConvOptions<2>,
+    ) -> Conv2dBackward<Self> {
+        profile!(
+            "conv2d_backward",
+            vec![
+                float_dims::<B, 4>(&x),
+                float_dims::<B, 4>(&weight),
+                float_dims::<B, 4>(&output_grad)
+            ]
+            .into_iter()
+            .chain(bias.iter().map(float_dims::<B, 1>))
+            .collect(),
+            Some(B::device(&x)),
+            {
+                let c = B::conv2d_backward(x, weight, bias, output_grad, options);
+                Conv2dBackward::new(c.x_grad, c.weights_grad, c.bias_grad)
+            }
+        )
+    }
+
+    fn conv_transpose1d(
+        x: FloatTensor<Self, 3>,
+        weight: FloatTensor<Self, 3>,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvTransposeOptions<1>,
+    ) -> FloatTensor<Self, 3> {
+        profile!(
+            "conv_transpose1d",
+            vec![float_dims::<B, 3>(&x), float_dims::<B, 3>(&weight)]
+                .into_iter()
+                .chain(bias.iter().map(float_dims::<B, 1>))
+                .collect(),
+            Some(B::device(&x)),
+            B::conv_transpose1d(x, weight, bias, options)
+        )
+    }
+
+    fn conv_transpose1d_backward(
+        x: FloatTensor<Self, 3>,
+        weight: FloatTensor<Self, 3>,
+        bias: Option<FloatTensor<Self, 1>>,
+        output_grad: FloatTensor<Self, 3>,
+        options: ConvTransposeOptions<1>,
+    ) -> Conv1dBackward<Self> {
+        profile!(
+            "conv_transpose1d_backward",
+            vec![
+                float_dims::<B, 3>(&x),
+                float_dims::<B, 3>(&weight),
+                float_dims::<B, 3>(&output_grad)
+            ]
+            .into_iter()
+            .chain(bias.iter().map(float_dims::<B, 1>))
+            .collect(),
+            Some(B::device(&x)),
+            {
+                let c = B::conv_transpose1d_backward(x, weight, bias, output_grad, options);
+                Conv1dBackward::new(c.x_grad, c.weights_grad, c.bias_grad)
+            }
+        )
+    }
+
+    fn conv_transpose2d(
+        x: FloatTensor<Self, 4>,
+        weight: FloatTensor<Self, 4>,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvTransposeOptions<2>,
+    ) -> FloatTensor<Self, 4> {
+        profile!(
+            "conv_transpose2d",
+            vec![float_dims::<B, 4>(&x), float_dims::<B, 4>(&weight)]
+                .into_iter()
+                .chain(bias.iter().map(float_dims::<B, 1>))
+                .collect(),
+            Some(B::device(&x)),
+            B::conv_transpose2d(x, weight, bias, options)
+        )
+    }
+
+    fn conv_transpose2d_backward(
+        x: FloatTensor<Self, 4>,
+        weight: FloatTensor<Self, 4>,
+        bias: Option<FloatTensor<Self, 1>>,
+        output_grad: FloatTensor<Self, 4>,
+        options: ConvTransposeOptions<2>,
+    ) -> Conv2dBackward<Self> {
+        profile!(
+            "conv_transpose2d_backward",
+            vec![
+                float_dims::<B, 4>(&x),
+                float_dims::<B, 4>(&weight),
+                float_dims::<B, 4>(&output_grad)
+            ]
+            .into_iter()
+            .chain(bias.iter().map(float_dims::<B, 1>))
+            .collect(),
+            Some(B::device(&x)),
+            {
+                let c = B::conv_transpose2d_backward(x, weight, bias, output_grad, options);
+                Conv2dBackward::new(c.x_grad, c.weights_grad, c.bias_grad)
+            }
+        )
+    }
+
+    fn unfold4d(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        options: UnfoldOptions,
+    ) -> FloatTensor<Self, 3> {
+        profile!(
+            "unfold4d",
+            vec![float_dims::<B, 4>(&x)],
+            Some(B::device(&x)),
+            B::unfold4d(x, kernel_size, options)
+        )
+    }
+
+    fn avg_pool1d(
+        x: FloatTensor<Self, 3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+        count_include_pad: bool,
+    ) -> FloatTensor<Self, 3> {
+        profile!(
+            "avg_pool1d",
+            vec![float_dims::<B, 3>(&x)],
+            Some(B::device(&x)),
+            B::avg_pool1d(x, kernel_size, stride, padding, count_include_pad)
+        )
+    }
+
+    fn avg_pool1d_backward(
+        x: FloatTensor<Self, 3>,
+        grad: FloatTensor<Self, 3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+        count_include_pad: bool,
+    ) -> FloatTensor<Self, 3> {
+        profile!(
+            "avg_pool1d_backward",
+            vec![float_dims::<B, 3>(&x), float_dims::<B, 3>(&grad)],
+            Some(B::device(&x)),
+            B::avg_pool1d_backward(x, grad, kernel_size, stride, padding, count_include_pad)
+        )
+    }
+
+    fn avg_pool2d(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        count_include_pad: bool,
+    ) -> FloatTensor<Self, 4> {
+        profile!(
+            "avg_pool2d",
+            vec![float_dims::<B, 4>(&x)],
+            Some(B::device(&x)),
+            B::avg_pool2d(x, kernel_size, stride, padding, count_include_pad)
+        )
+    }
+
+    fn avg_pool2d_backward(
+        x: FloatTensor<Self, 4>,
+        grad: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        count_include_pad: bool,
+    ) -> FloatTensor<Self, 4> {
+        profile!(
+            "avg_pool2d_backward",
+            vec![float_dims::<B, 4>(&x), float_dims::<B, 4>(&grad)],
+            Some(B::device(&x)),
+            B::avg_pool2d_backward(x, grad, kernel_size, stride, padding, count_include_pad)
+        )
+    }
+
+    fn adaptive_avg_pool2d(
+        x: FloatTensor<Self, 4>,
+        output_size: [usize; 2],
+    ) -> FloatTensor<Self, 4> {
+        profile!(
+            "adaptive_avg_pool2d",
+            vec![float_dims::<B, 4>(&x)],
+            Some(B::device(&x)),
+            B::adaptive_avg_pool2d(x, output_size)
+        )
+    }
+
+    fn adaptive_avg_pool2d_backward(
+        x: FloatTensor<Self, 4>,
+        grad: FloatTensor<Self, 4>,
+    ) -> FloatTensor<Self, 4> {
+        profile!(
+            "adaptive_avg_pool2d_backward",
+            vec![float_dims::<B, 4>(&x), float_dims::<B, 4>(&grad)],
+            Some(B::device(&x)),
+            B::adaptive_avg_pool2d_backward(x, grad)
+        )
+    }
+
+    fn adaptive_avg_pool1d(x: FloatTensor<Self, 3>, output_size: usize) -> FloatTensor<Self, 3> {
+        profile!(
+            "adaptive_avg_pool1d",
+            vec![float_dims::<B, 3>(&x)],
+            Some(B::device(&x)),
+            B::adaptive_avg_pool1d(x, output_size)
+        )
+    }
+
+    fn adaptive_avg_pool1d_backward(
+        x: FloatTensor<Self, 3>,
+        grad: FloatTensor<Self, 3>,
+    ) -> FloatTensor<Self, 3> {
+        profile!(
+            "adaptive_avg_pool1d_backward",
+            vec![float_dims::<B, 3>(&x), float_dims::<B, 3>(&grad)],
+            Some(B::device(&x)),
+            B::adaptive_avg_pool1d_backward(x, grad)
+        )
+    }
+
+    fn max_pool1d(
+        x: FloatTensor<Self, 3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+        dilation: usize,
+    ) -> FloatTensor<Self, 3> {
+        profile!(
+            "max_pool1d",
+            vec![float_dims::<B, 3>(&x)],
+            Some(B::device(&x)),
+            B::max_pool1d(x, kernel_size, stride, padding, dilation)
+        )
+    }
+
+    fn max_pool1d_with_indices(
+        x: FloatTensor<Self, 3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+        dilation: usize,
+    ) -> MaxPool1dWithIndices<Self> {
+        profile!(
+            "max_pool1d_with_indices",
+            vec![float_dims::<B, 3>(&x)],
+            Some(B::device(&x)),
+            {
+                let m = B::max_pool1d_with_indices(x, kernel_size, stride, padding, dilation);
+                MaxPool1dWithIndices::new(m.output, m.indices)
+            }
+        )
+    }
+
+    fn max_pool1d_with_indices_backward(
+        x: FloatTensor<Self, 3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+        dilation: usize,
+        output_grad: FloatTensor<Self, 3>,
+        indices: IntTensor<Self, 3>,
+    ) -> MaxPool1dBackward<Self> {
+        profile!(
+            "max_pool1d_with_indices_backward",
+            vec![
+                float_dims::<B, 3>(&x),
+                float_dims::<B, 3>(&output_grad),
+                int_dims::<B, 3>(&indices)
+            ],
+            Some(B::device(&x)),
+            {
+                let m = B::max_pool1d_with_indices_backward(
+                    x,
+                    kernel_size,
+                    stride,
+                    padding,
+                    dilation,
+                    output_grad,
+                    indices,
+                );
+                MaxPool1dBackward::new(m.x_grad)
+            }
+        )
+    }
+
+    fn max_pool2d(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+    ) -> FloatTensor<Self, 4> {
+        profile!(
+            "max_pool2d",
+            vec![float_dims::<B, 4>(&x)],
+            Some(B::device(&x)),
+            B::max_pool2d(x, kernel_size, stride, padding, dilation)
+        )
+    }
+
+    fn max_pool2d_with_indices(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+    ) -> MaxPool2dWithIndices<Self> {
+        profile!(
+            "max_pool2d_with_indices",
+            vec![float_dims::<B, 4>(&x)],
+            Some(B::device(&x)),
+            {
+                let m = B::max_pool2d_with_indices(x, kernel_size, stride, padding, dilation);
+                MaxPool2dWithIndices::new(m.output, m.indices)
+            }
+        )
+    }
+
+    fn max_pool2d_with_indices_backward(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+        output_grad: FloatTensor<Self, 4>,
+        indices: IntTensor<Self, 4>,
+    ) -> MaxPool2dBackward<Self> {
+        profile!(
+            "max_pool2d_with_indices_backward",
+            vec![
+                float_dims::<B, 4>(&x),
+                float_dims::<B, 4>(&output_grad),
+                int_dims::<B, 4>(&indices)
+            ],
+            Some(B::device(&x)),
+            {
+                let m = B::max_pool2d_with_indices_backward(
+                    x,
+                    kernel_size,
+                    stride,
+                    padding,
+                    dilation,
+                    output_grad,
+                    indices,
+                );
+                MaxPool2dBackward::new(m.x_grad)
+            }
+        )
+    }
+}
@@ -0,0 +1,208 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::Duration;
+
+/// A recorded call of a tensor operation.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct OpRecord {
+    /// The name of the operation, such as `float_matmul` or `conv2d`.
+    pub name: &'static str,
+    /// The shapes of the tensors given to the operation, or of the created tensor.
+    pub shapes: Vec<Vec<usize>>,
+    /// When the operation started, since the start of the recording.
+    pub start: Duration,
+    /// The time from the start of the operation until the device finished it.
+    pub wall: Duration,
+    /// The part of the wall time spent waiting for the device after the operation returned,
+    /// which is close to zero for synchronous backends.
+    pub sync: Duration,
+    /// The thread of the operation, numbered in the order the threads first recorded an
+    /// operation.
+    pub thread: u64,
+}
+
+/// The statistics of an operation over a [profile](Profile).
+#[derive(Clone, Debug, PartialEq)]
+pub struct OpSummary {
+    /// The name of the operation.
+    pub name: &'static str,
+    /// The number of calls.
+    pub calls: usize,
+    /// The total wall time of the calls.
+    pub total: Duration,
+    /// The total time spent waiting for the device.
+    pub sync: Duration,
+    /// The share of the total wall time of all the operations, between `0` and `1`.
+    pub share: f64,
+}
+
+impl OpSummary {
+    /// The mean wall time of a call.
+    pub fn mean(&self) -> Duration {
+        self.total / self.calls.max(1) as u32
+    }
+}
+
+/// The operations recorded between the [start](crate::start_recording) and the
+/// [end](crate::stop_recording) of a recording.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Profile {
+    /// The operations, in the order they were recorded.
+    pub records: Vec<OpRecord>,
+    /// The duration of the recording.
+    pub duration: Duration,
+}
+
+impl Profile {
+    /// Creates a profile from recorded operations.
+    pub fn new(records: Vec<OpRecord>, duration: Duration) -> Self {
+        Self { records, duration }
+    }
+
+    /// The statistics of each operation, from the largest total wall time to the smallest.
+    pub fn summary(&self) -> Vec<OpSummary> {
+        let mut operations = HashMap::<&'static str, OpSummary>::new();
+        let mut total = Duration::ZERO;
+
+        for record in self.records.iter() {
+            let summary = operations.entry(record.name).or_insert(OpSummary {
+                name: record.name,
+                calls: 0,
+                total: Duration::ZERO,
+                sync: Duration::ZERO,
+                share: 0.0,
+            });
+            summary.calls += 1;
+            summary.total += record.wall;
+            summary.sync += record.sync;
+            total += record.wall;
+        }
+
+        let mut summary = operations
+            .into_values()
+            .map(|mut summary| {
+                if !total.is_zero() {
+                    summary.share = summary.total.as_secs_f64() / total.as_secs_f64();
+                }
+                summary
+            })
+            .collect::<Vec<_>>();
+        summary.sort_by(|a, b| b.total.cmp(&a.total).then(a.name.cmp(b.name)));
+
+        summary
+    }
+
+    /// The [summary](Self::summary) as a table, with the `limit` operations taking the most
+    /// time.
+    pub fn table(&self, limit: usize) -> String {
+        let mut table = format!(
+            "{:<32} {:>8} {:>12} {:>12} {:>12} {:>7}\n",
+            "Operation", "Calls", "Total (ms)", "Mean (us)", "Sync (ms)", "Share"
+        );
+
+        for summary in self.summary().iter().take(limit) {
+            table.push_str(&format!(
+                "{:<32} {:>8} {:>12.3} {:>12.1} {:>12.3} {:>6.1}%\n",
+                summary.name,
+                summary.calls,
+                summary.total.as_secs_f64() * 1e3,
+                summary.mean().as_secs_f64() * 1e6,
+                summary.sync.as_secs_f64() * 1e3,
+                summary.share * 100.0,
+            ));
+        }
+
+        table
+    }
+
+    /// The operations in the Chrome trace event format, which can be opened with
+    /// `chrome://tracing` or Perfetto.
+    pub fn to_chrome_trace(&self) -> String {
+        let events = self
+            .records
+            .iter()
+            .map(|record| {
+                serde_json::json!({
+                    "name": record.name,
+                    "cat": "tensor",
+                    "ph": "X",
+                    "ts": record.start.as_secs_f64() * 1e6,
+                    "dur": record.wall.as_secs_f64() * 1e6,
+                    "pid": 0,
+                    "tid": record.thread,
+                    "args": {
+                        "shapes": record.shapes,
+                        "sync_us": record.sync.as_secs_f64() * 1e6,
+                    },
+                })
+            })
+            .collect::<Vec<_>>();
+
+        serde_json::json!({
+            "traceEvents": events,
+            "displayTimeUnit": "ms",
+        })
+        .to_string()
+    }
+
+    /// Save the operations as a [Chrome trace](Self::to_chrome_trace).
+    pub fn save_chrome_trace<P: AsRef<Path>>(&self, file_path: P) -> std::io::Result<()> {
+        std::fs::write(file_path, self.to_chrome_trace())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(name: &'static str, start_us: u64, wall_us: u64) -> OpRecord {
+        OpRecord {
+            name,
+            shapes: vec![vec![2, 3]],
+            start: Duration::from_micros(start_us),
+            wall: Duration::from_micros(wall_us),
+            sync: Duration::from_micros(wall_us / 2),
+            thread: 0,
+        }
+    }
+
+    #[test]
+    fn should_summarize_the_operations_by_total_time() {
+        let profile = Profile::new(
+            vec![
+                record("float_add", 0, 10),
+                record("float_matmul", 10, 60),
+                record("float_add", 70, 30),
+            ],
+            Duration::from_micros(100),
+        );
+
+        let summary = profile.summary();
+
+        assert_eq!(summary.len(), 2);
+        assert_eq!(summary[0].name, "float_matmul");
+        assert_eq!(summary[1].calls, 2);
+        assert_eq!(summary[1].mean(), Duration::from_micros(20));
+        assert_eq!(summary[1].sync, Duration::from_micros(20));
+        assert_eq!(summary[1].share, 0.4);
+
+        let table = profile.table(1);
+        assert_eq!(table.lines().count(), 2);
+        assert!(table.lines().nth(1).unwrap().starts_with("float_matmul"));
+    }
+
+    #[test]
+    fn should_export_chrome_trace_events() {
+        let profile = Profile::new(vec![record("conv2d", 5, 10)], Duration::from_micros(15));
+
+        let trace: serde_json::Value = serde_json::from_str(&profile.to_chrome_trace()).unwrap();
+        let event = &trace["traceEvents"][0];
+
+        assert_eq!(event["name"], "conv2d");
+        assert_eq!(event["ph"], "X");
+        assert_eq!(event["ts"], 5.0);
+        assert_eq!(event["dur"], 10.0);
+        assert_eq!(event["args"]["shapes"], serde_json::json!([[2, 3]]));
+    }
+}
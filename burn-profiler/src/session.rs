@@ -0,0 +1,93 @@
+use crate::{OpRecord, Profile};
+use burn_tensor::backend::Backend;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Instant;
+
+static RECORDING: AtomicBool = AtomicBool::new(false);
+static SESSION: Mutex<Option<Session>> = Mutex::new(None);
+static NEXT_THREAD: AtomicU64 = AtomicU64::new(0);
+
+thread_local! {
+    static THREAD: u64 = NEXT_THREAD.fetch_add(1, Ordering::Relaxed);
+}
+
+struct Session {
+    started: Instant,
+    records: Vec<OpRecord>,
+}
+
+/// Start recording the operations of the [profiled](crate::Profiler) backends on all threads,
+/// discarding the operations of an unfinished recording.
+pub fn start_recording() {
+    let mut session = SESSION.lock().unwrap();
+    *session = Some(Session {
+        started: Instant::now(),
+        records: Vec::new(),
+    });
+    RECORDING.store(true, Ordering::Release);
+}
+
+/// Stop recording, returning the recorded [profile](Profile), which is empty when not
+/// recording.
+pub fn stop_recording() -> Profile {
+    let mut session = SESSION.lock().unwrap();
+    RECORDING.store(false, Ordering::Release);
+
+    match session.take() {
+        Some(session) => Profile::new(session.records, session.started.elapsed()),
+        None => Profile::default(),
+    }
+}
+
+/// If the operations are being recorded.
+pub fn is_recording() -> bool {
+    RECORDING.load(Ordering::Acquire)
+}
+
+/// Run an operation, recording it when a session is in progress.
+pub(crate) fn record<B: Backend, T, F: FnOnce() -> T>(
+    name: &'static str,
+    shapes: Vec<Vec<usize>>,
+    device: Option<B::Device>,
+    operation: F,
+) -> T {
+    let started = Instant::now();
+    let output = operation();
+    let launched = Instant::now();
+    if let Some(device) = device {
+        B::sync(&device);
+    }
+    let ended = Instant::now();
+
+    if let Some(session) = SESSION.lock().unwrap().as_mut() {
+        session.records.push(OpRecord {
+            name,
+            shapes,
+            start: started.saturating_duration_since(session.started),
+            wall: ended - started,
+            sync: ended - launched,
+            thread: THREAD.with(|thread| *thread),
+        });
+    }
+
+    output
+}
+
+/// Forward an operation to the inner backend, recording it when a session is in progress.
+///
+/// The shapes and the device are only computed while recording.
+macro_rules! profile {
+    ($name:literal, $shapes:expr, $device:expr, $operation:expr) => {
+        match $crate::session::is_recording() {
+            false => $operation,
+            true => {
+                let shapes = $shapes;
+                let device = $device;
+                $crate::session::record::<B, _, _>($name, shapes, device, || $operation)
+            }
+        }
+    };
+}
+
+pub(crate) use profile;
@@ -3,6 +3,8 @@ use alloc::string::String;
 use crate::ops::*;
 use crate::tensor::Element;
 
+pub use burn_common::memory::MemoryUsage;
+
 /// This trait defines all types and functions needed for a backend to be used with burn.
 ///
 /// ## Design
@@ -97,6 +99,11 @@ pub trait Backend:
 
     /// Sync the backend, ensure that all computation are finished.
     fn sync(_device: &Self::Device) {}
+
+    /// The memory allocated and reserved on the device, for backends managing their own memory.
+    fn memory_usage(_device: &Self::Device) -> Option<MemoryUsage> {
+        None
+    }
 }
 
 /// Trait that allows a backend to support autodiff.
@@ -1,9 +1,11 @@
 mod async_checkpoint;
 mod base;
 mod file;
+mod state;
 mod strategy;
 
 pub use async_checkpoint::*;
 pub use base::*;
 pub use file::*;
+pub use state::*;
 pub use strategy::*;
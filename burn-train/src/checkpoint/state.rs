@@ -0,0 +1,85 @@
+use burn_core as burn;
+
+use burn_core::{
+    module::ParamId, optim::DynamicLossScalerRecord, record::Record, tensor::backend::Backend,
+    tensor::Tensor,
+};
+use hashbrown::HashMap;
+
+/// Record of the progress of the training, saved along with each checkpoint so that the training
+/// can be resumed exactly where it stopped, including in the middle of an epoch.
+///
+/// The state of the metrics isn't included, so the metrics of an epoch resumed in its middle only
+/// cover the iterations after the checkpoint.
+#[derive(Record, Clone)]
+pub struct TrainingStateRecord<B: Backend> {
+    /// The epoch of the checkpoint.
+    pub epoch: usize,
+    /// If the epoch was completed when the checkpoint was saved.
+    pub epoch_completed: bool,
+    /// The number of iterations done in the epoch when it wasn't completed, which is also the
+    /// number of batches consumed from the training data loader.
+    pub iteration: usize,
+    /// The number of iterators created from the training data loader of the epoch in the
+    /// previous epochs, which determines its shuffling.
+    pub dataloader_iterators: usize,
+    /// The number of items of the training data loader of the epoch, to detect a different data
+    /// loader when resuming.
+    pub dataloader_items: usize,
+    /// The seed used to reseed the backend at each checkpoint, when provided.
+    pub seed: Option<u64>,
+    /// The number of gradients accumulated since the last optimizer step.
+    pub accumulation: usize,
+    /// The gradients accumulated since the last optimizer step.
+    pub grads: HashMap<ParamId, Tensor<B, 1>>,
+    /// The state of the loss scaler when training with
+    /// [mixed precision](crate::LearnerBuilder::mixed_precision).
+    pub loss_scaler: Option<DynamicLossScalerRecord>,
+}
+
+impl<B: Backend> TrainingStateRecord<B> {
+    /// The state of a completed epoch.
+    pub fn completed(
+        epoch: usize,
+        seed: Option<u64>,
+        loss_scaler: Option<DynamicLossScalerRecord>,
+    ) -> Self {
+        Self {
+            epoch,
+            epoch_completed: true,
+            iteration: 0,
+            dataloader_iterators: 0,
+            dataloader_items: 0,
+            seed,
+            accumulation: 0,
+            grads: HashMap::new(),
+            loss_scaler,
+        }
+    }
+}
+
+/// The seed of the backend after the given iteration of an epoch, so that the random numbers
+/// generated after a checkpoint are the same when the training is resumed.
+pub(crate) fn iteration_seed(seed: u64, epoch: usize, iteration: usize) -> u64 {
+    // Mix the values with the constants of splitmix64 to avoid correlated seeds.
+    let mut value = seed
+        .wrapping_add((epoch as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15))
+        .wrapping_add((iteration as u64).wrapping_mul(0xBF58_476D_1CE4_E5B9));
+    value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+    value ^ (value >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn iteration_seed_should_be_deterministic_and_distinct() {
+        assert_eq!(iteration_seed(42, 1, 10), iteration_seed(42, 1, 10));
+        assert_ne!(iteration_seed(42, 1, 10), iteration_seed(42, 1, 11));
+        assert_ne!(iteration_seed(42, 1, 10), iteration_seed(42, 2, 10));
+        assert_ne!(iteration_seed(42, 1, 10), iteration_seed(43, 1, 10));
+    }
+}
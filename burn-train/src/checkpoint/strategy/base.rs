@@ -19,6 +19,23 @@ pub trait CheckpointingStrategy {
         epoch: usize,
         collector: &EventStoreClient,
     ) -> Vec<CheckpointingAction>;
+
+    /// Based on the iteration of the current epoch, determine if a checkpoint should be saved in
+    /// the middle of the epoch.
+    ///
+    /// Mid-epoch checkpoints are saved under the current epoch and are replaced by the following
+    /// ones. At the end of the epoch, the last one is replaced when
+    /// [checkpointing](CheckpointingStrategy::checkpointing) saves the epoch and deleted
+    /// otherwise, so strategies never have to delete them. By default, no mid-epoch checkpoint
+    /// is saved.
+    fn checkpointing_iteration(
+        &mut self,
+        _epoch: usize,
+        _iteration: usize,
+        _collector: &EventStoreClient,
+    ) -> bool {
+        false
+    }
 }
 
 // We make dyn box implement the checkpointing strategy so that it can be used with generic, but
@@ -31,4 +48,14 @@ impl CheckpointingStrategy for Box<dyn CheckpointingStrategy> {
     ) -> Vec<CheckpointingAction> {
         self.deref_mut().checkpointing(epoch, collector)
     }
+
+    fn checkpointing_iteration(
+        &mut self,
+        epoch: usize,
+        iteration: usize,
+        collector: &EventStoreClient,
+    ) -> bool {
+        self.deref_mut()
+            .checkpointing_iteration(epoch, iteration, collector)
+    }
 }
@@ -113,12 +113,31 @@ impl CheckpointingStrategy for ComposedCheckpointingStrategy {
 
         actions
     }
+
+    fn checkpointing_iteration(
+        &mut self,
+        epoch: usize,
+        iteration: usize,
+        collector: &EventStoreClient,
+    ) -> bool {
+        // Every strategy is called, so that they can all track the iterations.
+        let mut save = false;
+
+        for strategy in self.strategies.iter_mut() {
+            save |= strategy.checkpointing_iteration(epoch, iteration, collector);
+        }
+
+        save
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{checkpoint::KeepLastNCheckpoints, metric::store::LogEventStore};
+    use crate::{
+        checkpoint::{IterationCheckpointingStrategy, KeepLastNCheckpoints},
+        metric::store::LogEventStore,
+    };
 
     #[test]
     fn should_delete_when_both_deletes() {
@@ -143,4 +162,16 @@ mod tests {
             strategy.checkpointing(3, &store)
         );
     }
+
+    #[test]
+    fn should_save_mid_epoch_when_any_strategy_saves() {
+        let store = EventStoreClient::new(LogEventStore::default());
+        let mut strategy = ComposedCheckpointingStrategy::builder()
+            .add(KeepLastNCheckpoints::new(1))
+            .add(IterationCheckpointingStrategy::new(2))
+            .build();
+
+        assert!(!strategy.checkpointing_iteration(1, 1, &store));
+        assert!(strategy.checkpointing_iteration(1, 2, &store));
+    }
 }
@@ -0,0 +1,51 @@
+use super::CheckpointingStrategy;
+use crate::{checkpoint::CheckpointingAction, metric::store::EventStoreClient};
+
+/// Save a checkpoint every N iterations, in the middle of the epochs.
+///
+/// Useful with long epochs, so that the training can be resumed without losing most of an epoch
+/// when something goes wrong. It doesn't save checkpoints at the end of the epochs, so it should
+/// be [composed](crate::checkpoint::ComposedCheckpointingStrategy) with another strategy, such
+/// as [keeping the last N checkpoints](crate::checkpoint::KeepLastNCheckpoints).
+#[derive(new)]
+pub struct IterationCheckpointingStrategy {
+    interval: usize,
+}
+
+impl CheckpointingStrategy for IterationCheckpointingStrategy {
+    fn checkpointing(
+        &mut self,
+        _epoch: usize,
+        _store: &EventStoreClient,
+    ) -> Vec<CheckpointingAction> {
+        Vec::new()
+    }
+
+    fn checkpointing_iteration(
+        &mut self,
+        _epoch: usize,
+        iteration: usize,
+        _store: &EventStoreClient,
+    ) -> bool {
+        iteration.checked_rem(self.interval) == Some(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metric::store::LogEventStore;
+
+    #[test]
+    fn should_save_every_interval() {
+        let mut strategy = IterationCheckpointingStrategy::new(3);
+        let store = EventStoreClient::new(LogEventStore::default());
+
+        let saved = (1..=7)
+            .filter(|iteration| strategy.checkpointing_iteration(1, *iteration, &store))
+            .collect::<Vec<_>>();
+
+        assert_eq!(saved, vec![3, 6]);
+        assert!(strategy.checkpointing(1, &store).is_empty());
+    }
+}
@@ -25,7 +25,7 @@ impl MetricCheckpointingStrategy {
         Self {
             current: None,
             name: M::NAME.to_string(),
-            aggregate,
+            aggregate: aggregate.chosen_for::<M>(),
             direction,
             split,
         }
@@ -72,7 +72,7 @@ mod tests {
                 Metrics, MinimalEventProcessor,
             },
             store::LogEventStore,
-            LossMetric,
+            LossMetric, RootMeanSquaredErrorMetric,
         },
         TestBackend,
     };
@@ -130,4 +130,22 @@ mod tests {
         // new one.
         assert!(strategy.checkpointing(epoch, &store).is_empty());
     }
+
+    #[test]
+    fn use_the_chosen_aggregate_of_running_metrics() {
+        type Rmse = RootMeanSquaredErrorMetric<TestBackend>;
+        let strategy = MetricCheckpointingStrategy::new::<Rmse>(
+            Rmse::AGGREGATE,
+            Direction::Lowest,
+            Split::Valid,
+        );
+        assert_eq!(strategy.aggregate, Aggregate::Last);
+
+        let strategy = MetricCheckpointingStrategy::new::<Rmse>(
+            Aggregate::Mean,
+            Direction::Lowest,
+            Split::Valid,
+        );
+        assert_eq!(strategy.aggregate, Aggregate::Mean);
+    }
 }
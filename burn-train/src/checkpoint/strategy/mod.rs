@@ -1,9 +1,11 @@
 mod base;
 mod composed;
+mod iteration;
 mod lastn;
 mod metric;
 
 pub use base::*;
 pub use composed::*;
+pub use iteration::*;
 pub use lastn::*;
 pub use metric::*;
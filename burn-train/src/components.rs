@@ -1,11 +1,11 @@
 use crate::{
-    checkpoint::{Checkpointer, CheckpointingStrategy},
+    checkpoint::{Checkpointer, CheckpointingStrategy, TrainingStateRecord},
     metric::processor::EventProcessor,
 };
 use burn_core::{
     lr_scheduler::LrScheduler,
     module::{AutodiffModule, Module},
-    optim::Optimizer,
+    optim::{AveragedModuleRecordOf, Optimizer},
     tensor::backend::AutodiffBackend,
 };
 use std::marker::PhantomData;
@@ -35,13 +35,23 @@ pub trait LearnerComponents {
         <Self::LrScheduler as LrScheduler<Self::Backend>>::Record,
         Self::Backend,
     >;
+    /// The checkpointer used for the averaged model.
+    type CheckpointerModelAveraged: Checkpointer<
+        AveragedModuleRecordOf<Self::Backend, Self::Model>,
+        <Self::Backend as AutodiffBackend>::InnerBackend,
+    >;
+    /// The checkpointer used for the training state.
+    type CheckpointerTrainingState: Checkpointer<
+        TrainingStateRecord<<Self::Backend as AutodiffBackend>::InnerBackend>,
+        <Self::Backend as AutodiffBackend>::InnerBackend,
+    >;
     type EventProcessor: EventProcessor + 'static;
     /// The strategy to save and delete checkpoints.
     type CheckpointerStrategy: CheckpointingStrategy;
 }
 
 /// Concrete type that implements [training components trait](TrainingComponents).
-pub struct LearnerComponentsMarker<B, LR, M, O, CM, CO, CS, EP, S> {
+pub struct LearnerComponentsMarker<B, LR, M, O, CM, CO, CS, CA, CT, EP, S> {
     _backend: PhantomData<B>,
     _lr_scheduler: PhantomData<LR>,
     _model: PhantomData<M>,
@@ -49,12 +59,14 @@ pub struct LearnerComponentsMarker<B, LR, M, O, CM, CO, CS, EP, S> {
     _checkpointer_model: PhantomData<CM>,
     _checkpointer_optim: PhantomData<CO>,
     _checkpointer_scheduler: PhantomData<CS>,
+    _checkpointer_averaged: PhantomData<CA>,
+    _checkpointer_training_state: PhantomData<CT>,
     _event_processor: PhantomData<EP>,
     _strategy: S,
 }
 
-impl<B, LR, M, O, CM, CO, CS, EP, S> LearnerComponents
-    for LearnerComponentsMarker<B, LR, M, O, CM, CO, CS, EP, S>
+impl<B, LR, M, O, CM, CO, CS, CA, CT, EP, S> LearnerComponents
+    for LearnerComponentsMarker<B, LR, M, O, CM, CO, CS, CA, CT, EP, S>
 where
     B: AutodiffBackend,
     LR: LrScheduler<B>,
@@ -63,6 +75,8 @@ where
     CM: Checkpointer<M::Record, B>,
     CO: Checkpointer<O::Record, B>,
     CS: Checkpointer<LR::Record, B>,
+    CA: Checkpointer<AveragedModuleRecordOf<B, M>, B::InnerBackend>,
+    CT: Checkpointer<TrainingStateRecord<B::InnerBackend>, B::InnerBackend>,
     EP: EventProcessor + 'static,
     S: CheckpointingStrategy,
 {
@@ -73,6 +87,8 @@ where
     type CheckpointerModel = CM;
     type CheckpointerOptimizer = CO;
     type CheckpointerLrScheduler = CS;
+    type CheckpointerModelAveraged = CA;
+    type CheckpointerTrainingState = CT;
     type EventProcessor = EP;
     type CheckpointerStrategy = S;
 }
@@ -1,11 +1,15 @@
-use crate::checkpoint::{Checkpointer, CheckpointingAction, CheckpointingStrategy};
+use crate::checkpoint::{
+    Checkpointer, CheckpointingAction, CheckpointingStrategy, TrainingStateRecord,
+};
 use crate::components::LearnerComponents;
-use crate::learner::EarlyStoppingStrategy;
+use crate::learner::provider::AnyDataLoaderProvider;
+use crate::learner::{EarlyStoppingStrategy, LearnerCallback};
+use crate::manifest::ManifestRecorder;
 use crate::metric::store::EventStoreClient;
 use burn_core::lr_scheduler::LrScheduler;
 use burn_core::module::Module;
-use burn_core::optim::Optimizer;
-use burn_core::tensor::backend::Backend;
+use burn_core::optim::{DynamicLossScaler, ModuleAveraging, Optimizer};
+use burn_core::tensor::backend::{AutodiffBackend, Backend};
 use burn_core::tensor::Device;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
@@ -20,10 +24,17 @@ pub struct Learner<LC: LearnerComponents> {
     pub(crate) num_epochs: usize,
     pub(crate) checkpoint: Option<usize>,
     pub(crate) grad_accumulation: Option<usize>,
+    pub(crate) seed: Option<u64>,
     pub(crate) checkpointer: Option<LearnerCheckpointer<LC>>,
     pub(crate) devices: Vec<<LC::Backend as Backend>::Device>,
     pub(crate) interrupter: TrainingInterrupter,
     pub(crate) early_stopping: Option<Box<dyn EarlyStoppingStrategy>>,
+    pub(crate) averaging: Option<Box<dyn ModuleAveraging<LC::Backend, LC::Model>>>,
+    pub(crate) loss_scaler: Option<DynamicLossScaler>,
+    pub(crate) callbacks: Vec<LearnerCallback<LC>>,
+    pub(crate) provider_train: Option<AnyDataLoaderProvider>,
+    pub(crate) provider_valid: Option<AnyDataLoaderProvider>,
+    pub(crate) manifest: ManifestRecorder,
     pub(crate) event_processor: LC::EventProcessor,
     pub(crate) event_store: Arc<EventStoreClient>,
 }
@@ -33,53 +44,143 @@ pub(crate) struct LearnerCheckpointer<LC: LearnerComponents> {
     model: LC::CheckpointerModel,
     optim: LC::CheckpointerOptimizer,
     lr_scheduler: LC::CheckpointerLrScheduler,
+    model_averaged: LC::CheckpointerModelAveraged,
+    training_state: LC::CheckpointerTrainingState,
     strategy: LC::CheckpointerStrategy,
+    /// The epoch of the last mid-epoch checkpoint, if it wasn't replaced by the checkpoint of the
+    /// completed epoch yet.
+    #[new(default)]
+    mid_epoch: Option<usize>,
 }
 
+type InnerBackend<LC> = <<LC as LearnerComponents>::Backend as AutodiffBackend>::InnerBackend;
+
 impl<LC: LearnerComponents> LearnerCheckpointer<LC> {
+    /// Save and delete checkpoints at the end of an epoch.
+    ///
+    /// A mid-epoch checkpoint of the epoch is either replaced by the checkpoint of the completed
+    /// epoch or deleted when the strategy doesn't save it, so that resuming from it doesn't
+    /// replay a part of the epoch and the strategy doesn't have to track it.
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn checkpoint(
         &mut self,
         model: &LC::Model,
         optim: &LC::Optimizer,
         scheduler: &LC::LrScheduler,
+        averaging: Option<&dyn ModuleAveraging<LC::Backend, LC::Model>>,
+        loss_scaler: Option<&DynamicLossScaler>,
         epoch: usize,
+        seed: Option<u64>,
         store: &EventStoreClient,
     ) {
-        let actions = self.strategy.checkpointing(epoch, store);
+        let mut actions = self.strategy.checkpointing(epoch, store);
+
+        if self.mid_epoch.take() == Some(epoch) && !actions.contains(&CheckpointingAction::Save) {
+            actions.insert(0, CheckpointingAction::Delete(epoch));
+        }
 
         for action in actions {
             match action {
-                CheckpointingAction::Delete(epoch) => {
-                    self.model
-                        .delete(epoch)
-                        .expect("Can delete model checkpoint.");
-                    self.optim
-                        .delete(epoch)
-                        .expect("Can delete optimizer checkpoint.");
-                    self.lr_scheduler
-                        .delete(epoch)
-                        .expect("Can delete learning rate scheduler checkpoint.");
-                }
-                CheckpointingAction::Save => {
-                    self.model
-                        .save(epoch, model.clone().into_record())
-                        .expect("Can save model checkpoint.");
-                    self.optim
-                        .save(epoch, optim.to_record())
-                        .expect("Can save optimizer checkpoint.");
-                    self.lr_scheduler
-                        .save(epoch, scheduler.to_record())
-                        .expect("Can save learning rate scheduler checkpoint.");
-                }
+                CheckpointingAction::Delete(epoch) => self.delete(epoch),
+                CheckpointingAction::Save => self.save(
+                    model,
+                    optim,
+                    scheduler,
+                    averaging,
+                    TrainingStateRecord::completed(
+                        epoch,
+                        seed,
+                        loss_scaler.map(DynamicLossScaler::to_record),
+                    ),
+                ),
             }
         }
     }
 
+    /// Whether a checkpoint should be saved after the given iteration of the epoch.
+    pub(crate) fn should_checkpoint_iteration(
+        &mut self,
+        epoch: usize,
+        iteration: usize,
+        store: &EventStoreClient,
+    ) -> bool {
+        self.strategy
+            .checkpointing_iteration(epoch, iteration, store)
+    }
+
+    /// Save a checkpoint in the middle of an epoch, replacing the previous one of the same epoch.
+    pub(crate) fn checkpoint_iteration(
+        &mut self,
+        model: &LC::Model,
+        optim: &LC::Optimizer,
+        scheduler: &LC::LrScheduler,
+        averaging: Option<&dyn ModuleAveraging<LC::Backend, LC::Model>>,
+        state: TrainingStateRecord<InnerBackend<LC>>,
+    ) {
+        log::info!(
+            "Saving checkpoint at iteration {} of epoch {}",
+            state.iteration,
+            state.epoch
+        );
+        self.mid_epoch = Some(state.epoch);
+        self.save(model, optim, scheduler, averaging, state);
+    }
+
+    fn delete(&self, epoch: usize) {
+        self.model
+            .delete(epoch)
+            .expect("Can delete model checkpoint.");
+        self.optim
+            .delete(epoch)
+            .expect("Can delete optimizer checkpoint.");
+        self.lr_scheduler
+            .delete(epoch)
+            .expect("Can delete learning rate scheduler checkpoint.");
+        self.model_averaged
+            .delete(epoch)
+            .expect("Can delete averaged model checkpoint.");
+        self.training_state
+            .delete(epoch)
+            .expect("Can delete training state checkpoint.");
+    }
+
+    fn save(
+        &self,
+        model: &LC::Model,
+        optim: &LC::Optimizer,
+        scheduler: &LC::LrScheduler,
+        averaging: Option<&dyn ModuleAveraging<LC::Backend, LC::Model>>,
+        state: TrainingStateRecord<InnerBackend<LC>>,
+    ) {
+        let epoch = state.epoch;
+
+        self.model
+            .save(epoch, model.clone().into_record())
+            .expect("Can save model checkpoint.");
+        self.optim
+            .save(epoch, optim.to_record())
+            .expect("Can save optimizer checkpoint.");
+        self.lr_scheduler
+            .save(epoch, scheduler.to_record())
+            .expect("Can save learning rate scheduler checkpoint.");
+
+        if let Some(averaging) = averaging {
+            self.model_averaged
+                .save(epoch, averaging.to_record())
+                .expect("Can save averaged model checkpoint.");
+        }
+
+        self.training_state
+            .save(epoch, state)
+            .expect("Can save training state checkpoint.");
+    }
+
     pub(crate) fn load_checkpoint(
         &self,
         model: LC::Model,
         optim: LC::Optimizer,
         scheduler: LC::LrScheduler,
+        averaging: &mut Option<Box<dyn ModuleAveraging<LC::Backend, LC::Model>>>,
         device: &Device<LC::Backend>,
         epoch: usize,
     ) -> (LC::Model, LC::Optimizer, LC::LrScheduler) {
@@ -101,8 +202,33 @@ impl<LC: LearnerComponents> LearnerCheckpointer<LC> {
             .expect("Can load learning rate scheduler checkpoint.");
         let scheduler = scheduler.load_record(record);
 
+        if let Some(averaging) = averaging.as_mut() {
+            match self.model_averaged.restore(epoch, device) {
+                Ok(record) => averaging.load_record(&model, record),
+                Err(err) => log::warn!("Can't load averaged model checkpoint: {:?}", err),
+            }
+        }
+
         (model, optim, scheduler)
     }
+
+    /// Load the training state of the checkpoint of the given epoch.
+    ///
+    /// Returns `None` when the checkpoint has no training state, such as checkpoints saved by
+    /// previous versions, in which case the epoch should be considered completed.
+    pub(crate) fn load_training_state(
+        &self,
+        epoch: usize,
+        device: &Device<LC::Backend>,
+    ) -> Option<TrainingStateRecord<InnerBackend<LC>>> {
+        match self.training_state.restore(epoch, device) {
+            Ok(record) => Some(record),
+            Err(err) => {
+                log::warn!("Can't load training state checkpoint: {:?}", err);
+                None
+            }
+        }
+    }
 }
 
 #[derive(Clone, Default)]
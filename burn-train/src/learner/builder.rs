@@ -4,22 +4,28 @@ use super::log::install_file_logger;
 use super::Learner;
 use crate::checkpoint::{
     AsyncCheckpointer, CheckpointingStrategy, ComposedCheckpointingStrategy, FileCheckpointer,
-    KeepLastNCheckpoints, MetricCheckpointingStrategy,
+    KeepLastNCheckpoints, MetricCheckpointingStrategy, TrainingStateRecord,
 };
 use crate::components::LearnerComponentsMarker;
 use crate::learner::base::TrainingInterrupter;
-use crate::learner::EarlyStoppingStrategy;
+use crate::learner::provider::{erase_provider, AnyDataLoaderProvider};
+use crate::learner::{Callback, DataLoaderProvider, EarlyStoppingStrategy};
 use crate::logger::{FileMetricLogger, MetricLogger};
+use crate::manifest::{DatasetSizes, ManifestRecorder, RunManifest};
 use crate::metric::processor::{FullEventProcessor, Metrics};
 use crate::metric::store::{Aggregate, Direction, EventStoreClient, LogEventStore, Split};
 use crate::metric::{Adaptor, LossMetric, Metric};
 use crate::renderer::{default_renderer, MetricsRenderer};
 use crate::LearnerCheckpointer;
+use burn_core::config::Config;
 use burn_core::lr_scheduler::LrScheduler;
 use burn_core::module::AutodiffModule;
-use burn_core::optim::Optimizer;
+use burn_core::optim::{
+    AveragedModuleRecordOf, DynamicLossScaler, DynamicLossScalerConfig, ModuleAveraging, Optimizer,
+};
 use burn_core::record::FileRecorder;
 use burn_core::tensor::backend::AutodiffBackend;
+use std::collections::BTreeMap;
 
 /// Struct to configure and create a [learner](Learner).
 pub struct LearnerBuilder<B, T, V, M, O, S>
@@ -39,11 +45,14 @@ where
         AsyncCheckpointer<M::Record, B>,
         AsyncCheckpointer<O::Record, B>,
         AsyncCheckpointer<S::Record, B>,
+        AsyncCheckpointer<AveragedModuleRecordOf<B, M>, B::InnerBackend>,
+        AsyncCheckpointer<TrainingStateRecord<B::InnerBackend>, B::InnerBackend>,
     )>,
     num_epochs: usize,
     checkpoint: Option<usize>,
     directory: String,
     grad_accumulation: Option<usize>,
+    seed: Option<u64>,
     devices: Vec<B::Device>,
     renderer: Option<Box<dyn MetricsRenderer + 'static>>,
     metrics: Metrics<T, V>,
@@ -53,6 +62,14 @@ where
     num_loggers: usize,
     checkpointer_strategy: Box<dyn CheckpointingStrategy>,
     early_stopping: Option<Box<dyn EarlyStoppingStrategy>>,
+    averaging: Option<Box<dyn ModuleAveraging<B, M>>>,
+    loss_scaler: Option<DynamicLossScaler>,
+    callbacks: Vec<Box<dyn Callback<B, M, O>>>,
+    provider_train: Option<AnyDataLoaderProvider>,
+    provider_valid: Option<AnyDataLoaderProvider>,
+    config: Option<serde_json::Value>,
+    versions: BTreeMap<String, String>,
+    manifest_metrics: Vec<(String, Split)>,
 }
 
 impl<B, T, V, M, O, S> LearnerBuilder<B, T, V, M, O, S>
@@ -76,6 +93,7 @@ where
             checkpointers: None,
             directory: directory.to_string(),
             grad_accumulation: None,
+            seed: None,
             devices: vec![B::Device::default()],
             metrics: Metrics::default(),
             event_store: LogEventStore::default(),
@@ -94,10 +112,29 @@ where
                     .build(),
             ),
             early_stopping: None,
+            averaging: None,
+            loss_scaler: None,
+            callbacks: Vec::new(),
+            provider_train: None,
+            provider_valid: None,
+            config: None,
+            versions: BTreeMap::from([(
+                "burn-train".to_string(),
+                env!("CARGO_PKG_VERSION").to_string(),
+            )]),
+            manifest_metrics: Vec::new(),
         }
     }
 
-    /// Replace the default metric loggers with the provided ones.
+    /// Replace the default metric loggers with the provided ones, such as a
+    /// [TensorBoard logger](crate::logger::TensorBoardMetricLogger) or a
+    /// [CSV or JSONL logger](crate::logger::TabularMetricLogger).
+    ///
+    /// # Notes
+    ///
+    /// This method can be called multiple times to register multiple loggers. The values used by
+    /// the checkpointing and early stopping strategies are read back from the first logger that
+    /// can read them.
     ///
     /// # Arguments
     ///
@@ -168,12 +205,25 @@ where
         self
     }
 
+    /// Seed the backend at the beginning of each epoch and after each mid-epoch checkpoint.
+    ///
+    /// # Notes
+    ///
+    /// The seed is saved with the checkpoints, so that the random numbers generated by the backend
+    /// (e.g. dropout masks) are the same when the training is resumed from a checkpoint.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
     /// Register a [numeric](crate::metric::Numeric) training [metric](Metric).
     pub fn metric_train_numeric<Me>(mut self, metric: Me) -> Self
     where
         Me: Metric + crate::metric::Numeric + 'static,
         T: Adaptor<Me::Input>,
     {
+        self.manifest_metrics
+            .push((Me::NAME.to_string(), Split::Train));
         self.metrics.register_train_metric_numeric(metric);
         self
     }
@@ -186,6 +236,8 @@ where
     where
         V: Adaptor<Me::Input>,
     {
+        self.manifest_metrics
+            .push((Me::NAME.to_string(), Split::Valid));
         self.metrics.register_valid_metric_numeric(metric);
         self
     }
@@ -203,6 +255,13 @@ where
     }
 
     /// The epoch from which the training must resume.
+    ///
+    /// # Notes
+    ///
+    /// When the checkpoint was saved in the middle of the epoch, the training resumes after the
+    /// batches already trained on, with the same shuffling of the training data loader. The
+    /// metrics of the resumed epoch aren't saved in the checkpoint, so they only cover the
+    /// iterations after it.
     pub fn checkpoint(mut self, checkpoint: usize) -> Self {
         self.checkpoint = Some(checkpoint);
         self
@@ -223,6 +282,118 @@ where
         self
     }
 
+    /// Keep an averaged copy of the model weights, such as an
+    /// [exponential moving average](burn_core::optim::EmaModule), updated after each optimizer
+    /// step.
+    ///
+    /// # Notes
+    ///
+    /// When registered, the averaged model is used for validation and saved along with the other
+    /// checkpoints.
+    pub fn module_averaging<A>(mut self, averaging: A) -> Self
+    where
+        A: ModuleAveraging<B, M> + 'static,
+    {
+        self.averaging = Some(Box::new(averaging));
+        self
+    }
+
+    /// Train with mixed precision, scaling the loss with a
+    /// [dynamic loss scaler](burn_core::optim::DynamicLossScaler) so that small gradients don't
+    /// underflow in half precision. The optimizer steps with infinite or NaN gradients are skipped.
+    ///
+    /// # Notes
+    ///
+    /// The model should be trained on a half precision backend with an optimizer created using
+    /// `init_mixed_precision`, so that the master weights and the optimizer state stay in full
+    /// precision. The train step should implement
+    /// [step_with_context](crate::TrainStep::step_with_context) and create its output with
+    /// [from_loss](crate::TrainOutput::from_loss) for the loss to be scaled, the training panics
+    /// otherwise.
+    pub fn mixed_precision(mut self, config: DynamicLossScalerConfig) -> Self {
+        self.loss_scaler = Some(config.init());
+        self
+    }
+
+    /// Register a [callback](Callback) with hooks called during the training loop.
+    ///
+    /// # Notes
+    ///
+    /// This method can be called multiple times to register multiple callbacks, which are called
+    /// in the order they are registered.
+    pub fn callback<C>(mut self, callback: C) -> Self
+    where
+        C: Callback<B, M, O> + 'static,
+    {
+        self.callbacks.push(Box::new(callback));
+        self
+    }
+
+    /// Profile the tensor operations of `num_iterations` training iterations after the `warmup`
+    /// first ones with a [profiler callback](ProfilerCallback), saving the trace and the summary
+    /// of the operations in the `profile` directory.
+    ///
+    /// # Notes
+    ///
+    /// The backend must be wrapped with the [profiler](burn_profiler::Profiler), such as
+    /// `Autodiff<Profiler<Wgpu>>`.
+    #[cfg(feature = "profiler")]
+    pub fn profile(self, warmup: usize, num_iterations: usize) -> Self {
+        let directory = format!("{}/profile", self.directory);
+        self.callback(crate::learner::ProfilerCallback::new(
+            directory,
+            warmup,
+            num_iterations,
+        ))
+    }
+
+    /// Provide the training dataloader of each epoch with a [provider](DataLoaderProvider), such
+    /// as a closure receiving the epoch and the event store, instead of using the dataloader
+    /// given to [fit](Learner::fit) for all epochs.
+    ///
+    /// # Panics
+    ///
+    /// When fitting, if the provider doesn't provide dataloaders with the input type of the
+    /// training dataloader.
+    pub fn dataloader_train_provider<I, P>(mut self, provider: P) -> Self
+    where
+        I: 'static,
+        P: DataLoaderProvider<I> + 'static,
+    {
+        self.provider_train = Some(erase_provider(provider));
+        self
+    }
+
+    /// Provide the validation dataloader of each epoch with a [provider](DataLoaderProvider),
+    /// instead of using the dataloader given to [fit](Learner::fit) for all epochs.
+    ///
+    /// # Panics
+    ///
+    /// When fitting, if the provider doesn't provide dataloaders with the input type of the
+    /// validation dataloader.
+    pub fn dataloader_valid_provider<I, P>(mut self, provider: P) -> Self
+    where
+        I: 'static,
+        P: DataLoaderProvider<I> + 'static,
+    {
+        self.provider_valid = Some(erase_provider(provider));
+        self
+    }
+
+    /// Save the training [config](Config) in the [manifest](crate::manifest::RunManifest) of
+    /// the run.
+    pub fn config<C: Config>(mut self, config: &C) -> Self {
+        self.config = Some(serde_json::to_value(config).expect("Can serialize the config."));
+        self
+    }
+
+    /// Record the version of a crate in the [manifest](crate::manifest::RunManifest) of the
+    /// run, such as the version of the crate of the model with `env!("CARGO_PKG_VERSION")`.
+    pub fn crate_version(mut self, name: &str, version: &str) -> Self {
+        self.versions.insert(name.to_string(), version.to_string());
+        self
+    }
+
     /// By default, Rust logs are captured and written into
     /// `experiment.log`. If disabled, standard Rust log handling
     /// will apply.
@@ -252,15 +423,27 @@ where
             "optim",
         );
         let checkpointer_scheduler = FileCheckpointer::new(
-            recorder,
+            recorder.clone(),
             format!("{}/checkpoint", self.directory).as_str(),
             "scheduler",
         );
+        let checkpointer_averaged = FileCheckpointer::new(
+            recorder.clone(),
+            format!("{}/checkpoint", self.directory).as_str(),
+            "model-averaged",
+        );
+        let checkpointer_training_state = FileCheckpointer::new(
+            recorder,
+            format!("{}/checkpoint", self.directory).as_str(),
+            "training-state",
+        );
 
         self.checkpointers = Some((
             AsyncCheckpointer::new(checkpointer_model),
             AsyncCheckpointer::new(checkpointer_optimizer),
             AsyncCheckpointer::new(checkpointer_scheduler),
+            AsyncCheckpointer::new(checkpointer_averaged),
+            AsyncCheckpointer::new(checkpointer_training_state),
         ));
 
         self
@@ -285,6 +468,8 @@ where
             AsyncCheckpointer<M::Record, B>,
             AsyncCheckpointer<O::Record, B>,
             AsyncCheckpointer<S::Record, B>,
+            AsyncCheckpointer<AveragedModuleRecordOf<B, M>, B::InnerBackend>,
+            AsyncCheckpointer<TrainingStateRecord<B::InnerBackend>, B::InnerBackend>,
             FullEventProcessor<T, V>,
             Box<dyn CheckpointingStrategy>,
         >,
@@ -313,12 +498,42 @@ where
                 ));
         }
 
+        let manifest = ManifestRecorder::new(
+            RunManifest {
+                directory: self.directory.clone(),
+                config: self.config,
+                backend: B::name(),
+                devices: self
+                    .devices
+                    .iter()
+                    .map(|device| format!("{device:?}"))
+                    .collect(),
+                seed: self.seed,
+                versions: self.versions,
+                num_epochs: self.num_epochs,
+                checkpoint: self.checkpoint,
+                dataset: DatasetSizes::default(),
+                started_at: 0,
+                ended_at: None,
+                metrics: Vec::new(),
+            },
+            self.manifest_metrics,
+        );
         let event_store = Arc::new(EventStoreClient::new(self.event_store));
         let event_processor = FullEventProcessor::new(self.metrics, renderer, event_store.clone());
 
-        let checkpointer = self.checkpointers.map(|(model, optim, scheduler)| {
-            LearnerCheckpointer::new(model, optim, scheduler, self.checkpointer_strategy)
-        });
+        let checkpointer =
+            self.checkpointers
+                .map(|(model, optim, scheduler, averaged, training_state)| {
+                    LearnerCheckpointer::new(
+                        model,
+                        optim,
+                        scheduler,
+                        averaged,
+                        training_state,
+                        self.checkpointer_strategy,
+                    )
+                });
 
         Learner {
             model,
@@ -330,9 +545,16 @@ where
             event_store,
             checkpoint: self.checkpoint,
             grad_accumulation: self.grad_accumulation,
+            seed: self.seed,
             devices: self.devices,
             interrupter: self.interrupter,
             early_stopping: self.early_stopping,
+            averaging: self.averaging,
+            loss_scaler: self.loss_scaler,
+            callbacks: self.callbacks,
+            provider_train: self.provider_train,
+            provider_valid: self.provider_valid,
+            manifest,
         }
     }
 
@@ -0,0 +1,348 @@
+use crate::components::LearnerComponents;
+use crate::learner::EarlyStoppingStrategy;
+use crate::metric::store::EventStoreClient;
+use burn_core::module::AutodiffModule;
+use burn_core::optim::{GradientsParams, Optimizer};
+use burn_core::tensor::backend::AutodiffBackend;
+use burn_core::LearningRate;
+
+/// The state of the training loop given to the [callbacks](Callback).
+pub struct CallbackContext<'a, M, O> {
+    /// The model being trained.
+    pub model: &'a M,
+    /// The optimizer.
+    pub optim: &'a O,
+    /// The learning rate of the current iteration, if any.
+    pub lr: Option<LearningRate>,
+    /// The current epoch.
+    pub epoch: usize,
+    /// The current iteration in the epoch, which is `0` outside of the training batches.
+    pub iteration: usize,
+    /// The event store, to read the metrics collected so far.
+    pub store: &'a EventStoreClient,
+}
+
+/// Hooks called by the [learner](crate::Learner) at different points of the training loop, such
+/// as to log the gradients or to update a curriculum schedule shared with the dataset.
+///
+/// All hooks do nothing by default.
+///
+/// # Notes
+///
+/// When training on multiple devices, the items processed in parallel form a single batch.
+///
+/// A callback can stop the training with the [interrupter](crate::LearnerBuilder::interrupter)
+/// of the learner, which is checked after each batch and at the end of each epoch.
+pub trait Callback<B, M, O>
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B>,
+    O: Optimizer<M, B>,
+{
+    /// Called once before the first epoch, after the checkpoint is loaded.
+    fn on_train_begin(&mut self, _context: &CallbackContext<'_, M, O>) {}
+
+    /// Called before the training of each epoch.
+    fn on_epoch_begin(&mut self, _context: &CallbackContext<'_, M, O>) {}
+
+    /// Called before the training step of each batch.
+    fn on_batch_begin(&mut self, _context: &CallbackContext<'_, M, O>) {}
+
+    /// Called after the backward pass of each batch, before the gradients are accumulated or
+    /// used by the optimizer.
+    ///
+    /// The gradients are unscaled when training with mixed precision, and the hook isn't called
+    /// when the optimizer step is skipped because they aren't finite, see
+    /// [on_non_finite_gradients](Callback::on_non_finite_gradients).
+    fn on_after_backward(
+        &mut self,
+        _context: &CallbackContext<'_, M, O>,
+        _grads: &mut GradientsParams,
+    ) {
+    }
+
+    /// Called instead of [on_after_backward](Callback::on_after_backward) when training with
+    /// mixed precision and the unscaled gradients contain NaN or infinite values, before the
+    /// optimizer step is skipped and the accumulated gradients are discarded.
+    fn on_non_finite_gradients(
+        &mut self,
+        _context: &CallbackContext<'_, M, O>,
+        _grads: &GradientsParams,
+    ) {
+    }
+
+    /// Called after each batch, once the optimizer step is performed and the metrics are updated.
+    fn on_batch_end(&mut self, _context: &CallbackContext<'_, M, O>) {}
+
+    /// Called after the validation of each epoch.
+    fn on_validation_end(&mut self, _context: &CallbackContext<'_, M, O>) {}
+
+    /// Called at the end of each epoch, after the checkpoint is saved and before the
+    /// [early stopping strategy](crate::EarlyStoppingStrategy) is checked.
+    fn on_epoch_end(&mut self, _context: &CallbackContext<'_, M, O>) {}
+}
+
+pub(crate) type LearnerCallback<LC> = Box<
+    dyn Callback<
+        <LC as LearnerComponents>::Backend,
+        <LC as LearnerComponents>::Model,
+        <LC as LearnerComponents>::Optimizer,
+    >,
+>;
+
+/// The registered [callbacks](Callback) with the event store given to them, along with the
+/// [early stopping strategy](EarlyStoppingStrategy) checked during the training loop.
+pub(crate) struct LearnerCallbacks<'a, LC: LearnerComponents> {
+    callbacks: &'a mut [LearnerCallback<LC>],
+    store: Option<&'a EventStoreClient>,
+    early_stopping: Option<&'a mut (dyn EarlyStoppingStrategy + 'static)>,
+}
+
+impl<'a, LC: LearnerComponents> LearnerCallbacks<'a, LC> {
+    pub(crate) fn new(
+        callbacks: &'a mut [LearnerCallback<LC>],
+        store: &'a EventStoreClient,
+    ) -> Self {
+        Self {
+            callbacks,
+            store: Some(store),
+            early_stopping: None,
+        }
+    }
+
+    pub(crate) fn with_early_stopping(
+        mut self,
+        early_stopping: Option<&'a mut (dyn EarlyStoppingStrategy + 'static)>,
+    ) -> Self {
+        self.early_stopping = early_stopping;
+        self
+    }
+
+    pub(crate) fn disabled() -> Self {
+        Self {
+            callbacks: &mut [],
+            store: None,
+            early_stopping: None,
+        }
+    }
+
+    /// Whether the early stopping strategy requests to stop the training after an iteration.
+    pub(crate) fn should_stop_iteration(&mut self, epoch: usize, iteration: usize) -> bool {
+        match (&mut self.early_stopping, self.store) {
+            (Some(early_stopping), Some(store)) => {
+                early_stopping.should_stop_iteration(epoch, iteration, store)
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether the early stopping strategy requests to stop the training after an epoch.
+    pub(crate) fn should_stop_epoch(&mut self, epoch: usize) -> bool {
+        match (&mut self.early_stopping, self.store) {
+            (Some(early_stopping), Some(store)) => early_stopping.should_stop(epoch, store),
+            _ => false,
+        }
+    }
+
+    /// Call a hook of every callback with the given state of the training loop.
+    pub(crate) fn call<F>(
+        &mut self,
+        model: &LC::Model,
+        optim: &LC::Optimizer,
+        lr: Option<LearningRate>,
+        epoch: usize,
+        iteration: usize,
+        mut hook: F,
+    ) where
+        F: FnMut(&mut LearnerCallback<LC>, &CallbackContext<'_, LC::Model, LC::Optimizer>),
+    {
+        let store = match self.store {
+            Some(store) => store,
+            None => return,
+        };
+        let context = CallbackContext {
+            model,
+            optim,
+            lr,
+            epoch,
+            iteration,
+            store,
+        };
+
+        for callback in self.callbacks.iter_mut() {
+            hook(callback, &context);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logger::InMemoryMetricLogger;
+    use crate::test_utils::{NoRenderer, TestBatcher, TestModel};
+    use crate::{IterationBudget, LearnerBuilder, TestAutodiffBackend};
+    use burn_core::data::dataloader::DataLoaderBuilder;
+    use burn_core::data::dataset::InMemDataset;
+    use burn_core::optim::SgdConfig;
+    use std::sync::{Arc, Mutex};
+
+    type TB = TestAutodiffBackend;
+
+    #[derive(Clone, Default)]
+    struct Recorder {
+        hooks: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl Recorder {
+        fn record<O>(&self, hook: &str, context: &CallbackContext<'_, TestModel<TB>, O>) {
+            self.hooks
+                .lock()
+                .unwrap()
+                .push(format!("{hook} {} {}", context.epoch, context.iteration));
+        }
+    }
+
+    impl<O: Optimizer<TestModel<TB>, TB>> Callback<TB, TestModel<TB>, O> for Recorder {
+        fn on_train_begin(&mut self, context: &CallbackContext<'_, TestModel<TB>, O>) {
+            self.record("train_begin", context);
+        }
+
+        fn on_epoch_begin(&mut self, context: &CallbackContext<'_, TestModel<TB>, O>) {
+            self.record("epoch_begin", context);
+        }
+
+        fn on_batch_begin(&mut self, context: &CallbackContext<'_, TestModel<TB>, O>) {
+            assert!(context.lr.is_some());
+            self.record("batch_begin", context);
+        }
+
+        fn on_after_backward(
+            &mut self,
+            context: &CallbackContext<'_, TestModel<TB>, O>,
+            grads: &mut GradientsParams,
+        ) {
+            assert!(!grads.is_empty());
+            self.record("after_backward", context);
+        }
+
+        fn on_batch_end(&mut self, context: &CallbackContext<'_, TestModel<TB>, O>) {
+            self.record("batch_end", context);
+        }
+
+        fn on_validation_end(&mut self, context: &CallbackContext<'_, TestModel<TB>, O>) {
+            self.record("validation_end", context);
+        }
+
+        fn on_epoch_end(&mut self, context: &CallbackContext<'_, TestModel<TB>, O>) {
+            self.record("epoch_end", context);
+        }
+    }
+
+    #[test]
+    fn should_call_hooks_in_order() {
+        let device = Default::default();
+        let model = TestModel::<TB>::new(&device);
+        let dataloader_train = DataLoaderBuilder::new(TestBatcher)
+            .batch_size(2)
+            .build(InMemDataset::new(vec![1.0, 2.0, 3.0]));
+        let dataloader_valid = DataLoaderBuilder::new(TestBatcher)
+            .batch_size(2)
+            .build(InMemDataset::new(vec![1.0]));
+        let recorder = Recorder::default();
+        let directory = tempfile::tempdir().unwrap();
+
+        let learner = LearnerBuilder::new(directory.path().to_str().unwrap())
+            .metric_loggers(InMemoryMetricLogger::new(), InMemoryMetricLogger::new())
+            .renderer(NoRenderer)
+            .log_to_file(false)
+            .num_epochs(2)
+            .callback(recorder.clone())
+            .build(model, SgdConfig::new().init(), 1e-2);
+        learner.fit(dataloader_train, dataloader_valid);
+
+        let epoch = |epoch: usize| {
+            vec![
+                format!("epoch_begin {epoch} 0"),
+                format!("batch_begin {epoch} 1"),
+                format!("after_backward {epoch} 1"),
+                format!("batch_end {epoch} 1"),
+                format!("batch_begin {epoch} 2"),
+                format!("after_backward {epoch} 2"),
+                format!("batch_end {epoch} 2"),
+                format!("validation_end {epoch} 0"),
+                format!("epoch_end {epoch} 0"),
+            ]
+        };
+        let mut expected = vec!["train_begin 1 0".to_string()];
+        expected.extend(epoch(1));
+        expected.extend(epoch(2));
+
+        assert_eq!(*recorder.hooks.lock().unwrap(), expected);
+    }
+
+    #[test]
+    fn should_call_batch_hooks_once_per_step_on_multiple_devices() {
+        let device = Default::default();
+        let model = TestModel::<TB>::new(&device);
+        let dataloader_train = DataLoaderBuilder::new(TestBatcher)
+            .batch_size(2)
+            .build(InMemDataset::new(vec![1.0, 2.0, 3.0]));
+        let dataloader_valid = DataLoaderBuilder::new(TestBatcher)
+            .batch_size(2)
+            .build(InMemDataset::new(vec![1.0]));
+        let recorder = Recorder::default();
+        let directory = tempfile::tempdir().unwrap();
+
+        let learner = LearnerBuilder::new(directory.path().to_str().unwrap())
+            .metric_loggers(InMemoryMetricLogger::new(), InMemoryMetricLogger::new())
+            .renderer(NoRenderer)
+            .log_to_file(false)
+            .num_epochs(1)
+            .devices(vec![device, device])
+            .callback(recorder.clone())
+            .build(model, SgdConfig::new().init(), 1e-2);
+        learner.fit(dataloader_train, dataloader_valid);
+
+        // Both batches are processed in parallel, and no batch begins once the data is exhausted.
+        assert_eq!(
+            *recorder.hooks.lock().unwrap(),
+            vec![
+                "train_begin 1 0",
+                "epoch_begin 1 0",
+                "batch_begin 1 1",
+                "after_backward 1 2",
+                "batch_end 1 2",
+                "validation_end 1 0",
+                "epoch_end 1 0",
+            ]
+        );
+    }
+
+    #[test]
+    fn should_stop_in_the_middle_of_an_epoch() {
+        let device = Default::default();
+        let model = TestModel::<TB>::new(&device);
+        let dataloader_train = DataLoaderBuilder::new(TestBatcher)
+            .batch_size(2)
+            .build(InMemDataset::new(vec![1.0, 2.0, 3.0]));
+        let dataloader_valid = DataLoaderBuilder::new(TestBatcher)
+            .batch_size(2)
+            .build(InMemDataset::new(vec![1.0]));
+        let recorder = Recorder::default();
+        let directory = tempfile::tempdir().unwrap();
+
+        let learner = LearnerBuilder::new(directory.path().to_str().unwrap())
+            .metric_loggers(InMemoryMetricLogger::new(), InMemoryMetricLogger::new())
+            .renderer(NoRenderer)
+            .log_to_file(false)
+            .num_epochs(3)
+            .early_stopping(IterationBudget::new(3))
+            .callback(recorder.clone())
+            .build(model, SgdConfig::new().init(), 1e-2);
+        learner.fit(dataloader_train, dataloader_valid);
+
+        let hooks = recorder.hooks.lock().unwrap();
+        assert_eq!(hooks.last().unwrap(), "batch_end 2 1");
+        assert!(!hooks.contains(&"validation_end 2 0".to_string()));
+    }
+}
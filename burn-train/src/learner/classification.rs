@@ -1,4 +1,8 @@
-use crate::metric::{AccuracyInput, Adaptor, LossInput};
+use crate::metric::{
+    AccuracyInput, Adaptor, AurocInput, ConfusionMatrixInput, ConfusionStatsInput,
+    HammingScoreInput, LossInput, PerplexityInput, TopKAccuracyInput,
+};
+use burn_core::tensor::activation::{sigmoid, softmax};
 use burn_core::tensor::backend::Backend;
 use burn_core::tensor::{Int, Tensor};
 
@@ -26,3 +30,101 @@ impl<B: Backend> Adaptor<LossInput<B>> for ClassificationOutput<B> {
         LossInput::new(self.loss.clone())
     }
 }
+
+impl<B: Backend> Adaptor<PerplexityInput<B>> for ClassificationOutput<B> {
+    fn adapt(&self) -> PerplexityInput<B> {
+        PerplexityInput::new(self.loss.clone(), self.targets.dims()[0])
+    }
+}
+
+impl<B: Backend> Adaptor<TopKAccuracyInput<B>> for ClassificationOutput<B> {
+    fn adapt(&self) -> TopKAccuracyInput<B> {
+        TopKAccuracyInput::new(self.output.clone(), self.targets.clone())
+    }
+}
+
+impl<B: Backend> Adaptor<AurocInput<B>> for ClassificationOutput<B> {
+    fn adapt(&self) -> AurocInput<B> {
+        AurocInput::new(self.output.clone(), self.targets.clone())
+    }
+}
+
+impl<B: Backend> Adaptor<ConfusionMatrixInput<B>> for ClassificationOutput<B> {
+    fn adapt(&self) -> ConfusionMatrixInput<B> {
+        ConfusionMatrixInput::new(self.output.clone(), self.targets.clone())
+    }
+}
+
+impl<B: Backend> Adaptor<ConfusionStatsInput<B>> for ClassificationOutput<B> {
+    fn adapt(&self) -> ConfusionStatsInput<B> {
+        let [batch_size, num_classes] = self.output.dims();
+        let device = self.output.device();
+
+        // One-hot encoding of the targets.
+        let targets = Tensor::<B, 2, Int>::zeros([batch_size, num_classes], &device)
+            .scatter(
+                1,
+                self.targets.clone().reshape([batch_size, 1]),
+                Tensor::ones([batch_size, 1], &device),
+            )
+            .equal_elem(1);
+
+        ConfusionStatsInput::new(softmax(self.output.clone(), 1), targets)
+    }
+}
+
+/// Multilabel classification output adapted for multiple metrics, where each item can belong to
+/// any number of classes.
+#[derive(new)]
+pub struct MultiLabelClassificationOutput<B: Backend> {
+    /// The loss.
+    pub loss: Tensor<B, 1>,
+
+    /// The output, with the logits of each label.
+    pub output: Tensor<B, 2>,
+
+    /// The targets, with `1` for the labels of each item and `0` otherwise.
+    pub targets: Tensor<B, 2, Int>,
+}
+
+impl<B: Backend> Adaptor<LossInput<B>> for MultiLabelClassificationOutput<B> {
+    fn adapt(&self) -> LossInput<B> {
+        LossInput::new(self.loss.clone())
+    }
+}
+
+impl<B: Backend> Adaptor<HammingScoreInput<B>> for MultiLabelClassificationOutput<B> {
+    fn adapt(&self) -> HammingScoreInput<B> {
+        HammingScoreInput::new(self.output.clone(), self.targets.clone())
+    }
+}
+
+impl<B: Backend> Adaptor<ConfusionStatsInput<B>> for MultiLabelClassificationOutput<B> {
+    fn adapt(&self) -> ConfusionStatsInput<B> {
+        ConfusionStatsInput::new(
+            sigmoid(self.output.clone()),
+            self.targets.clone().equal_elem(1),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metric::{ClassReduction, Metric, MetricMetadata, Numeric, RecallMetric};
+    use crate::TestBackend;
+
+    #[test]
+    fn should_adapt_targets_to_one_hot() {
+        let device = Default::default();
+        let output = ClassificationOutput::<TestBackend>::new(
+            Tensor::zeros([1], &device),
+            Tensor::from_data([[0.1, 0.9, 0.0], [0.8, 0.1, 0.1]], &device),
+            Tensor::from_data([1, 2], &device),
+        );
+        let mut metric = RecallMetric::multiclass(1, ClassReduction::Micro);
+
+        metric.update(&output.adapt(), &MetricMetadata::fake());
+        assert_eq!(metric.value(), 50.0);
+    }
+}
@@ -0,0 +1,318 @@
+use crate::metric::{
+    AccuracyInput, Adaptor, AurocInput, ConfusionMatrixInput, ConfusionStatsInput, LossInput,
+    PerplexityInput, TopKAccuracyInput,
+};
+use crate::ClassificationOutput;
+use burn_core as burn;
+use burn_core::config::Config;
+use burn_core::module::{AutodiffModule, Module};
+use burn_core::nn::loss::CrossEntropyLossConfig;
+use burn_core::tensor::activation::log_softmax;
+use burn_core::tensor::backend::{AutodiffBackend, Backend};
+use burn_core::tensor::{Data, Int, Tensor};
+
+/// A frozen teacher model, possibly on a different backend and device than the student, such as
+/// a backend without autodiff.
+///
+/// The teacher is a constant module, so it can be a field of the student model without being
+/// trained, moved to the devices of the student or saved in its checkpoints.
+#[derive(Clone, Debug)]
+pub struct Teacher<TB: Backend, T> {
+    module: T,
+    device: TB::Device,
+}
+
+impl<TB: Backend, T: Module<TB>> Teacher<TB, T> {
+    /// Create a teacher from a trained model, moved to the given device.
+    pub fn new(module: T, device: &TB::Device) -> Self {
+        Self {
+            module: module.to_device(device).no_grad(),
+            device: device.clone(),
+        }
+    }
+
+    /// The teacher model.
+    pub fn module(&self) -> &T {
+        &self.module
+    }
+
+    /// The device of the teacher model.
+    pub fn device(&self) -> &TB::Device {
+        &self.device
+    }
+
+    /// Run the forward pass of the teacher, and move its output to the backend and device of the
+    /// student, without gradients.
+    pub fn forward<B: Backend, const D: usize, F>(
+        &self,
+        forward: F,
+        device: &B::Device,
+    ) -> Tensor<B, D>
+    where
+        F: FnOnce(&T) -> Tensor<TB, D>,
+    {
+        let output = forward(&self.module).into_data();
+        let output: Data<B::FloatElem, D> = output.convert();
+
+        Tensor::from_data(output, device)
+    }
+
+    /// Move a float input of the student to the backend and device of the teacher.
+    pub fn float_input<B: Backend, const D: usize>(&self, input: Tensor<B, D>) -> Tensor<TB, D> {
+        let input: Data<TB::FloatElem, D> = input.into_data().convert();
+
+        Tensor::from_data(input, &self.device)
+    }
+
+    /// Move an int input of the student, such as token ids, to the backend and device of the
+    /// teacher.
+    pub fn int_input<B: Backend, const D: usize>(
+        &self,
+        input: Tensor<B, D, Int>,
+    ) -> Tensor<TB, D, Int> {
+        let input: Data<TB::IntElem, D> = input.into_data().convert();
+
+        Tensor::from_data(input, &self.device)
+    }
+}
+
+impl<B: Backend, TB: Backend, T: Module<TB>> Module<B> for Teacher<TB, T> {
+    burn_core::constant!(module);
+}
+
+impl<B: AutodiffBackend, TB: Backend, T: Module<TB>> AutodiffModule<B> for Teacher<TB, T> {
+    burn_core::constant!(ad_module, Teacher<TB, T>);
+}
+
+/// Configuration to create a [distillation loss](DistillationLoss).
+#[derive(Config, Debug)]
+pub struct DistillationLossConfig {
+    /// The temperature softening the probabilities of the teacher and the student.
+    #[config(default = 2.0)]
+    pub temperature: f64,
+
+    /// The weight of the soft loss, with `1 - alpha` the weight of the hard loss.
+    #[config(default = 0.5)]
+    pub alpha: f64,
+}
+
+impl DistillationLossConfig {
+    /// Initialize the [distillation loss](DistillationLoss).
+    pub fn init(&self) -> DistillationLoss {
+        assert!(
+            self.temperature > 0.0,
+            "The temperature should be positive, got {}.",
+            self.temperature
+        );
+        assert!(
+            (0.0..=1.0).contains(&self.alpha),
+            "Alpha should be between 0 and 1, got {}.",
+            self.alpha
+        );
+
+        DistillationLoss {
+            temperature: self.temperature,
+            alpha: self.alpha,
+        }
+    }
+}
+
+/// Knowledge distillation loss, combining the cross-entropy with the targets (hard loss) and the
+/// KL divergence between the temperature-scaled probabilities of the teacher and the student
+/// (soft loss).
+///
+/// The soft loss is scaled by the squared temperature, so that its gradients keep the same
+/// magnitude when changing the temperature.
+#[derive(Module, Clone, Debug)]
+pub struct DistillationLoss {
+    temperature: f64,
+    alpha: f64,
+}
+
+impl DistillationLoss {
+    /// Compute the loss from the logits of the student and of the teacher.
+    ///
+    /// # Shapes
+    ///
+    /// - student_logits: `[batch_size, num_classes]`
+    /// - teacher_logits: `[batch_size, num_classes]`
+    /// - targets: `[batch_size]`
+    pub fn forward<B: Backend>(
+        &self,
+        student_logits: Tensor<B, 2>,
+        teacher_logits: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+    ) -> DistillationOutput<B> {
+        let device = student_logits.device();
+        let temperature = self.temperature;
+
+        let log_student = log_softmax(student_logits.clone().div_scalar(temperature), 1);
+        let log_teacher = log_softmax(teacher_logits.detach().div_scalar(temperature), 1);
+        let soft_loss = log_teacher
+            .clone()
+            .exp()
+            .mul(log_teacher.sub(log_student))
+            .sum_dim(1)
+            .mean()
+            .mul_scalar(temperature * temperature);
+        let hard_loss = CrossEntropyLossConfig::new()
+            .init(&device)
+            .forward(student_logits.clone(), targets.clone());
+        let loss = hard_loss.clone().mul_scalar(1.0 - self.alpha)
+            + soft_loss.clone().mul_scalar(self.alpha);
+
+        DistillationOutput {
+            loss,
+            hard_loss,
+            soft_loss,
+            output: student_logits,
+            targets,
+        }
+    }
+}
+
+/// Distillation output adapted for the same metrics as the
+/// [classification output](ClassificationOutput), with the logits of the student.
+#[derive(new)]
+pub struct DistillationOutput<B: Backend> {
+    /// The loss to optimize, combining the hard and the soft losses.
+    pub loss: Tensor<B, 1>,
+
+    /// The cross-entropy with the targets.
+    pub hard_loss: Tensor<B, 1>,
+
+    /// The KL divergence with the probabilities of the teacher.
+    pub soft_loss: Tensor<B, 1>,
+
+    /// The output of the student.
+    pub output: Tensor<B, 2>,
+
+    /// The targets.
+    pub targets: Tensor<B, 1, Int>,
+}
+
+impl<B: Backend> DistillationOutput<B> {
+    /// The classification output of the student, with the combined loss.
+    pub fn classification(&self) -> ClassificationOutput<B> {
+        ClassificationOutput::new(self.loss.clone(), self.output.clone(), self.targets.clone())
+    }
+}
+
+impl<B: Backend> Adaptor<AccuracyInput<B>> for DistillationOutput<B> {
+    fn adapt(&self) -> AccuracyInput<B> {
+        AccuracyInput::new(self.output.clone(), self.targets.clone())
+    }
+}
+
+impl<B: Backend> Adaptor<LossInput<B>> for DistillationOutput<B> {
+    fn adapt(&self) -> LossInput<B> {
+        LossInput::new(self.loss.clone())
+    }
+}
+
+impl<B: Backend> Adaptor<PerplexityInput<B>> for DistillationOutput<B> {
+    fn adapt(&self) -> PerplexityInput<B> {
+        // The perplexity is the one of the student on the targets.
+        PerplexityInput::new(self.hard_loss.clone(), self.targets.dims()[0])
+    }
+}
+
+impl<B: Backend> Adaptor<TopKAccuracyInput<B>> for DistillationOutput<B> {
+    fn adapt(&self) -> TopKAccuracyInput<B> {
+        TopKAccuracyInput::new(self.output.clone(), self.targets.clone())
+    }
+}
+
+impl<B: Backend> Adaptor<AurocInput<B>> for DistillationOutput<B> {
+    fn adapt(&self) -> AurocInput<B> {
+        AurocInput::new(self.output.clone(), self.targets.clone())
+    }
+}
+
+impl<B: Backend> Adaptor<ConfusionMatrixInput<B>> for DistillationOutput<B> {
+    fn adapt(&self) -> ConfusionMatrixInput<B> {
+        ConfusionMatrixInput::new(self.output.clone(), self.targets.clone())
+    }
+}
+
+impl<B: Backend> Adaptor<ConfusionStatsInput<B>> for DistillationOutput<B> {
+    fn adapt(&self) -> ConfusionStatsInput<B> {
+        self.classification().adapt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metric::{AccuracyMetric, Metric, MetricMetadata, Numeric};
+    use crate::{TestAutodiffBackend, TestBackend};
+    use burn_core::nn::{Linear, LinearConfig};
+    use burn_core::tensor::ElementConversion;
+
+    type TB = TestAutodiffBackend;
+
+    #[derive(Module, Debug)]
+    struct Student<B: Backend> {
+        linear: Linear<B>,
+        teacher: Teacher<TestBackend, Linear<TestBackend>>,
+        loss: DistillationLoss,
+    }
+
+    #[test]
+    fn soft_loss_should_be_the_scaled_kl_divergence() {
+        let device = Default::default();
+        let loss = DistillationLossConfig::new()
+            .with_temperature(2.0)
+            .with_alpha(0.5)
+            .init();
+        let logits = Tensor::<TestBackend, 2>::from_data([[1.0, 3.0], [2.0, 0.0]], &device);
+        let targets = Tensor::from_data([1, 0], &device);
+
+        let same = loss.forward(logits.clone(), logits.clone(), targets.clone());
+        assert_eq!(same.soft_loss.into_scalar(), 0.0);
+        same.loss
+            .into_data()
+            .assert_approx_eq(&same.hard_loss.mul_scalar(0.5).into_data(), 5);
+
+        let output = loss.forward(
+            Tensor::<TestBackend, 2>::from_data([[0.0, 0.0]], &device),
+            Tensor::from_data([[0.0, 4.0]], &device),
+            Tensor::from_data([1], &device),
+        );
+        // Teacher probabilities with a temperature of 2: softmax([0, 2]).
+        let p = 1.0 / (1.0 + 2.0f64.exp());
+        let kl = p * (2.0 * p).ln() + (1.0 - p) * (2.0 * (1.0 - p)).ln();
+        let soft = output.soft_loss.into_scalar().elem::<f64>();
+        assert!((soft - 4.0 * kl).abs() < 1e-5, "{soft}");
+    }
+
+    #[test]
+    fn should_train_the_student_only() {
+        let device = Default::default();
+        let teacher = LinearConfig::new(2, 3).init::<TestBackend>(&device);
+        let student = Student::<TB> {
+            linear: LinearConfig::new(2, 3).init(&device),
+            teacher: Teacher::new(teacher.clone(), &device),
+            loss: DistillationLossConfig::new().init(),
+        };
+        assert_eq!(student.num_params(), teacher.num_params());
+
+        let input = Tensor::<TB, 2>::from_data([[1.0, 2.0], [0.5, -1.0]], &device);
+        let teacher_input = student.teacher.float_input(input.clone());
+        let teacher_logits = student
+            .teacher
+            .forward(|teacher| teacher.forward(teacher_input), &device);
+        let output = student.loss.forward(
+            student.linear.forward(input),
+            teacher_logits,
+            Tensor::from_data([2, 0], &device),
+        );
+        let grads = output.loss.backward();
+
+        assert!(student.linear.weight.grad(&grads).is_some());
+
+        let mut metric = AccuracyMetric::new();
+        metric.update(&output.adapt(), &MetricMetadata::fake());
+        assert!((0.0..=100.0).contains(&metric.value()));
+    }
+}
@@ -2,20 +2,64 @@ use crate::metric::{
     store::{Aggregate, Direction, EventStoreClient, Split},
     Metric,
 };
+use std::time::{Duration, Instant};
 
 /// The condition that [early stopping strategies](EarlyStoppingStrategy) should follow.
 pub enum StoppingCondition {
     /// When no improvement has happened since the given number of epochs.
     NoImprovementSince {
-        /// The number of epochs allowed to worsen before it gets better.
+        /// The number of epochs allowed to worsen before it gets better, or the number of
+        /// evaluations when the strategy is evaluated at the
+        /// [iteration granularity](StoppingGranularity::Iteration).
         n_epochs: usize,
     },
+    /// When the metric reaches the target value, or a better one.
+    Target {
+        /// The target value.
+        value: f64,
+    },
+    /// When the metric is NaN or infinite.
+    NonFinite,
+    /// When the metric is NaN or infinite, or worse than its best value by the given factor,
+    /// such as a loss more than `4` times larger than its lowest value.
+    ///
+    /// The metric is expected to be positive.
+    Diverged {
+        /// The factor, greater than `1`.
+        factor: f64,
+    },
+}
+
+/// When a [metric early stopping strategy](MetricEarlyStoppingStrategy) is evaluated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StoppingGranularity {
+    /// At the end of each epoch.
+    Epoch,
+    /// Every given number of training iterations, with the value of the metric for the current
+    /// epoch so far, which should be a training metric.
+    Iteration {
+        /// The number of iterations between two evaluations.
+        interval: usize,
+    },
 }
 
 /// A strategy that checks if the training should be stopped.
 pub trait EarlyStoppingStrategy {
     /// Update its current state and returns if the training should be stopped.
     fn should_stop(&mut self, epoch: usize, store: &EventStoreClient) -> bool;
+
+    /// Update its current state after each training iteration and returns if the training
+    /// should be stopped in the middle of the epoch, which isn't validated.
+    ///
+    /// By default, the strategy is only checked at the end of each epoch.
+    fn should_stop_iteration(
+        &mut self,
+        _epoch: usize,
+        _iteration: usize,
+        _store: &EventStoreClient,
+    ) -> bool {
+        false
+    }
 }
 
 /// An [early stopping strategy](EarlyStoppingStrategy) based on a metrics collected
@@ -26,58 +70,41 @@ pub struct MetricEarlyStoppingStrategy {
     aggregate: Aggregate,
     direction: Direction,
     split: Split,
-    best_epoch: usize,
+    min_delta: f64,
+    granularity: StoppingGranularity,
+    num_iterations: usize,
+    num_evaluations: usize,
+    best_step: usize,
     best_value: f64,
 }
 
 impl EarlyStoppingStrategy for MetricEarlyStoppingStrategy {
     fn should_stop(&mut self, epoch: usize, store: &EventStoreClient) -> bool {
-        let current_value =
-            match store.find_metric(&self.metric_name, epoch, self.aggregate, self.split) {
-                Some(value) => value,
-                None => {
-                    log::warn!("Can't find metric for early stopping.");
-                    return false;
-                }
-            };
+        match self.granularity {
+            StoppingGranularity::Epoch => self.evaluate(epoch, epoch, store),
+            StoppingGranularity::Iteration { .. } => false,
+        }
+    }
 
-        let is_best = match self.direction {
-            Direction::Lowest => current_value < self.best_value,
-            Direction::Highest => current_value > self.best_value,
+    fn should_stop_iteration(
+        &mut self,
+        epoch: usize,
+        _iteration: usize,
+        store: &EventStoreClient,
+    ) -> bool {
+        let interval = match self.granularity {
+            StoppingGranularity::Iteration { interval } => interval.max(1),
+            StoppingGranularity::Epoch => return false,
         };
 
-        if is_best {
-            log::info!(
-                "New best epoch found {} {}: {}",
-                epoch,
-                self.metric_name,
-                current_value
-            );
-            self.best_value = current_value;
-            self.best_epoch = epoch;
+        self.num_iterations += 1;
+        if self.num_iterations < interval {
             return false;
         }
+        self.num_iterations = 0;
+        self.num_evaluations += 1;
 
-        match self.condition {
-            StoppingCondition::NoImprovementSince { n_epochs } => {
-                let should_stop = epoch - self.best_epoch >= n_epochs;
-
-                if should_stop {
-                    log::info!(
-                        "Stopping training loop, no improvement since epoch {}, {}: {},  current \
-                         epoch {}, {}: {}",
-                        self.best_epoch,
-                        self.metric_name,
-                        self.best_value,
-                        epoch,
-                        self.metric_name,
-                        current_value
-                    );
-                }
-
-                should_stop
-            }
-        }
+        self.evaluate(epoch, self.num_evaluations, store)
     }
 }
 
@@ -102,13 +129,266 @@ impl MetricEarlyStoppingStrategy {
         Self {
             metric_name: Me::NAME.to_string(),
             condition,
-            aggregate,
+            aggregate: aggregate.chosen_for::<Me>(),
             direction,
             split,
-            best_epoch: 1,
+            min_delta: 0.0,
+            granularity: StoppingGranularity::Epoch,
+            num_iterations: 0,
+            num_evaluations: 0,
+            best_step: 1,
             best_value: init_value,
         }
     }
+
+    /// The minimum change of the metric to be considered an improvement.
+    pub fn with_min_delta(mut self, min_delta: f64) -> Self {
+        self.min_delta = min_delta;
+        self
+    }
+
+    /// When the strategy is evaluated, at the end of each epoch by default.
+    pub fn with_granularity(mut self, granularity: StoppingGranularity) -> Self {
+        self.granularity = granularity;
+        if let StoppingGranularity::Iteration { .. } = granularity {
+            self.best_step = 0;
+        }
+        self
+    }
+
+    /// Evaluate the condition with the value of the metric at the given epoch, where the step
+    /// is the epoch or the number of evaluations.
+    fn evaluate(&mut self, epoch: usize, step: usize, store: &EventStoreClient) -> bool {
+        let current_value =
+            match store.find_metric(&self.metric_name, epoch, self.aggregate, self.split) {
+                Some(value) => value,
+                None => {
+                    log::warn!("Can't find metric for early stopping.");
+                    return false;
+                }
+            };
+
+        let has_best = self.best_value != f64::MAX && self.best_value != f64::MIN;
+        let is_best = match self.direction {
+            Direction::Lowest => current_value < self.best_value - self.min_delta,
+            Direction::Highest => current_value > self.best_value + self.min_delta,
+        };
+        let best_value = self.best_value;
+
+        if is_best {
+            log::info!(
+                "New best epoch found {} {}: {}",
+                epoch,
+                self.metric_name,
+                current_value
+            );
+            self.best_value = current_value;
+            self.best_step = step;
+        }
+
+        let should_stop = match self.condition {
+            StoppingCondition::NoImprovementSince { n_epochs } => {
+                !is_best && step.saturating_sub(self.best_step) >= n_epochs
+            }
+            StoppingCondition::Target { value } => match self.direction {
+                Direction::Lowest => current_value <= value,
+                Direction::Highest => current_value >= value,
+            },
+            StoppingCondition::NonFinite => !current_value.is_finite(),
+            StoppingCondition::Diverged { factor } => {
+                let diverged = has_best
+                    && match self.direction {
+                        Direction::Lowest => current_value > best_value * factor,
+                        Direction::Highest => current_value < best_value / factor,
+                    };
+
+                !current_value.is_finite() || diverged
+            }
+        };
+
+        if should_stop {
+            log::info!(
+                "Stopping training loop at epoch {}, {}: {}, best {}: {}",
+                epoch,
+                self.metric_name,
+                current_value,
+                self.metric_name,
+                self.best_value,
+            );
+        }
+
+        should_stop
+    }
+}
+
+/// An [early stopping strategy](EarlyStoppingStrategy) stopping the training once the given
+/// duration has elapsed since its first training iteration.
+pub struct TimeBudget {
+    budget: Duration,
+    started: Option<Instant>,
+}
+
+impl TimeBudget {
+    /// Create the strategy with the duration of the training.
+    pub fn new(budget: Duration) -> Self {
+        Self {
+            budget,
+            started: None,
+        }
+    }
+
+    fn is_exceeded(&mut self) -> bool {
+        let exceeded = self.started.get_or_insert_with(Instant::now).elapsed() >= self.budget;
+        if exceeded {
+            log::info!(
+                "Stopping training loop, time budget of {:?} exceeded",
+                self.budget
+            );
+        }
+
+        exceeded
+    }
+}
+
+impl EarlyStoppingStrategy for TimeBudget {
+    fn should_stop(&mut self, _epoch: usize, _store: &EventStoreClient) -> bool {
+        self.is_exceeded()
+    }
+
+    fn should_stop_iteration(
+        &mut self,
+        _epoch: usize,
+        _iteration: usize,
+        _store: &EventStoreClient,
+    ) -> bool {
+        self.is_exceeded()
+    }
+}
+
+/// An [early stopping strategy](EarlyStoppingStrategy) stopping the training after the given
+/// number of training iterations, counted from the start of the training or of its resumption.
+pub struct IterationBudget {
+    num_iterations: usize,
+    current: usize,
+}
+
+impl IterationBudget {
+    /// Create the strategy with the number of training iterations.
+    pub fn new(num_iterations: usize) -> Self {
+        Self {
+            num_iterations,
+            current: 0,
+        }
+    }
+}
+
+impl EarlyStoppingStrategy for IterationBudget {
+    fn should_stop(&mut self, _epoch: usize, _store: &EventStoreClient) -> bool {
+        self.current >= self.num_iterations
+    }
+
+    fn should_stop_iteration(
+        &mut self,
+        _epoch: usize,
+        _iteration: usize,
+        _store: &EventStoreClient,
+    ) -> bool {
+        self.current += 1;
+        let exceeded = self.current >= self.num_iterations;
+        if exceeded {
+            log::info!(
+                "Stopping training loop, iteration budget of {} reached",
+                self.num_iterations
+            );
+        }
+
+        exceeded
+    }
+}
+
+/// How the [composed early stopping strategy](ComposedEarlyStoppingStrategy) combines its
+/// strategies.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StoppingCombination {
+    /// Stop as soon as any strategy requests it.
+    Any,
+    /// Stop once every strategy has requested it, at the same or at previous evaluations.
+    All,
+}
+
+/// Compose multiple [early stopping strategies](EarlyStoppingStrategy), which are all updated at
+/// each evaluation.
+pub struct ComposedEarlyStoppingStrategy {
+    strategies: Vec<Box<dyn EarlyStoppingStrategy>>,
+    combination: StoppingCombination,
+    stopped: Vec<bool>,
+}
+
+/// Help building an [early stopping strategy](EarlyStoppingStrategy) by combining multiple ones.
+#[derive(Default)]
+pub struct ComposedEarlyStoppingStrategyBuilder {
+    strategies: Vec<Box<dyn EarlyStoppingStrategy>>,
+}
+
+impl ComposedEarlyStoppingStrategyBuilder {
+    /// Add a new [early stopping strategy](EarlyStoppingStrategy).
+    #[allow(clippy::should_implement_trait)]
+    pub fn add<S>(mut self, strategy: S) -> Self
+    where
+        S: EarlyStoppingStrategy + 'static,
+    {
+        self.strategies.push(Box::new(strategy));
+        self
+    }
+
+    /// Create a new [composed early stopping strategy](ComposedEarlyStoppingStrategy).
+    pub fn build(self, combination: StoppingCombination) -> ComposedEarlyStoppingStrategy {
+        ComposedEarlyStoppingStrategy {
+            stopped: vec![false; self.strategies.len()],
+            strategies: self.strategies,
+            combination,
+        }
+    }
+}
+
+impl ComposedEarlyStoppingStrategy {
+    /// Create a new builder which help compose multiple
+    /// [early stopping strategies](EarlyStoppingStrategy).
+    pub fn builder() -> ComposedEarlyStoppingStrategyBuilder {
+        ComposedEarlyStoppingStrategyBuilder::default()
+    }
+
+    fn combine<F>(&mut self, mut should_stop: F) -> bool
+    where
+        F: FnMut(&mut Box<dyn EarlyStoppingStrategy>) -> bool,
+    {
+        let mut any = false;
+        for (strategy, stopped) in self.strategies.iter_mut().zip(self.stopped.iter_mut()) {
+            let stop = should_stop(strategy);
+            any |= stop;
+            *stopped |= stop;
+        }
+
+        match self.combination {
+            StoppingCombination::Any => any,
+            StoppingCombination::All => self.stopped.iter().all(|stopped| *stopped),
+        }
+    }
+}
+
+impl EarlyStoppingStrategy for ComposedEarlyStoppingStrategy {
+    fn should_stop(&mut self, epoch: usize, store: &EventStoreClient) -> bool {
+        self.combine(|strategy| strategy.should_stop(epoch, store))
+    }
+
+    fn should_stop_iteration(
+        &mut self,
+        epoch: usize,
+        iteration: usize,
+        store: &EventStoreClient,
+    ) -> bool {
+        self.combine(|strategy| strategy.should_stop_iteration(epoch, iteration, store))
+    }
 }
 
 #[cfg(test)]
@@ -184,13 +464,145 @@ mod tests {
         );
     }
 
-    fn test_early_stopping(n_epochs: usize, data: &[(&[f64], bool, &str)]) {
+    #[test]
+    fn early_stop_when_improvement_below_min_delta() {
+        test_strategy(
+            loss_strategy(StoppingCondition::NoImprovementSince { n_epochs: 1 })
+                .with_min_delta(0.1),
+            &[
+                (&[1.0], false, "Should not stop first epoch"),
+                (
+                    &[0.8],
+                    false,
+                    "Should not stop when improving more than min delta",
+                ),
+                (
+                    &[0.75],
+                    true,
+                    "Should stop when improving less than min delta",
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn early_stop_when_target_reached() {
+        test_strategy(
+            loss_strategy(StoppingCondition::Target { value: 0.3 }),
+            &[
+                (&[0.5], false, "Should not stop above the target"),
+                (&[0.2], true, "Should stop when the target is reached"),
+            ],
+        );
+    }
+
+    #[test]
+    fn early_stop_when_diverged() {
+        test_strategy(
+            loss_strategy(StoppingCondition::Diverged { factor: 2.0 }),
+            &[
+                (&[1.0], false, "Should not stop first epoch"),
+                (&[0.5], false, "Should not stop when improving"),
+                (&[0.9], false, "Should not stop below the factor"),
+                (&[1.5], true, "Should stop when worse than the factor"),
+            ],
+        );
+        test_strategy(
+            loss_strategy(StoppingCondition::NonFinite),
+            &[
+                (&[1.0], false, "Should not stop when finite"),
+                (&[f64::NAN], true, "Should stop when NaN"),
+            ],
+        );
+    }
+
+    #[test]
+    fn early_stop_at_iteration_granularity() {
         let mut early_stopping = MetricEarlyStoppingStrategy::new::<LossMetric<TestBackend>>(
+            Aggregate::Last,
+            Direction::Lowest,
+            Split::Train,
+            StoppingCondition::Target { value: 0.3 },
+        )
+        .with_granularity(StoppingGranularity::Iteration { interval: 2 });
+        let (store, mut processor) = processor();
+
+        let mut stops = Vec::new();
+        for (iteration, point) in [1.0, 0.2, 0.5, 0.1].into_iter().enumerate() {
+            process_train(&mut processor, point, 1);
+            stops.push(early_stopping.should_stop_iteration(1, iteration + 1, &store));
+        }
+
+        assert_eq!(stops, vec![false, true, false, true]);
+        assert!(!early_stopping.should_stop(1, &store));
+    }
+
+    #[test]
+    fn early_stop_with_the_latest_value_of_the_epoch() {
+        let mut early_stopping = MetricEarlyStoppingStrategy::new::<LossMetric<TestBackend>>(
+            Aggregate::Last,
+            Direction::Lowest,
+            Split::Train,
+            StoppingCondition::Diverged { factor: 2.0 },
+        )
+        .with_granularity(StoppingGranularity::Iteration { interval: 1 });
+        let (store, mut processor) = processor();
+
+        // The loss improves, then diverges within the same epoch.
+        let mut stops = Vec::new();
+        for (iteration, point) in [1.0, 0.5, 2.0].into_iter().enumerate() {
+            process_train(&mut processor, point, 1);
+            stops.push(early_stopping.should_stop_iteration(1, iteration + 1, &store));
+        }
+
+        assert_eq!(stops, vec![false, false, true]);
+    }
+
+    #[test]
+    fn early_stop_when_budget_exceeded() {
+        let store = EventStoreClient::new(LogEventStore::default());
+        let mut iterations = IterationBudget::new(2);
+
+        assert!(!iterations.should_stop_iteration(1, 1, &store));
+        assert!(!iterations.should_stop(1, &store));
+        assert!(iterations.should_stop_iteration(2, 1, &store));
+        assert!(TimeBudget::new(Duration::ZERO).should_stop_iteration(1, 1, &store));
+        assert!(!TimeBudget::new(Duration::from_secs(3600)).should_stop(1, &store));
+    }
+
+    #[test]
+    fn early_stop_when_any_or_all_strategies_stop() {
+        let store = EventStoreClient::new(LogEventStore::default());
+        let composed = |combination| {
+            ComposedEarlyStoppingStrategy::builder()
+                .add(IterationBudget::new(1))
+                .add(IterationBudget::new(3))
+                .build(combination)
+        };
+        let mut any = composed(StoppingCombination::Any);
+        let mut all = composed(StoppingCombination::All);
+
+        let any = (1..4)
+            .map(|iteration| any.should_stop_iteration(1, iteration, &store))
+            .collect::<Vec<_>>();
+        let all = (1..4)
+            .map(|iteration| all.should_stop_iteration(1, iteration, &store))
+            .collect::<Vec<_>>();
+
+        assert_eq!(any, vec![true, true, true]);
+        assert_eq!(all, vec![false, false, true]);
+    }
+
+    fn loss_strategy(condition: StoppingCondition) -> MetricEarlyStoppingStrategy {
+        MetricEarlyStoppingStrategy::new::<LossMetric<TestBackend>>(
             Aggregate::Mean,
             Direction::Lowest,
             Split::Train,
-            StoppingCondition::NoImprovementSince { n_epochs },
-        );
+            condition,
+        )
+    }
+
+    fn processor() -> (Arc<EventStoreClient>, MinimalEventProcessor<f64, f64>) {
         let mut store = LogEventStore::default();
         let mut metrics = Metrics::<f64, f64>::default();
 
@@ -198,7 +610,23 @@ mod tests {
         metrics.register_train_metric_numeric(LossMetric::<TestBackend>::new());
 
         let store = Arc::new(EventStoreClient::new(store));
-        let mut processor = MinimalEventProcessor::new(metrics, store.clone());
+        let processor = MinimalEventProcessor::new(metrics, store.clone());
+
+        (store, processor)
+    }
+
+    fn test_early_stopping(n_epochs: usize, data: &[(&[f64], bool, &str)]) {
+        test_strategy(
+            loss_strategy(StoppingCondition::NoImprovementSince { n_epochs }),
+            data,
+        );
+    }
+
+    fn test_strategy(
+        mut early_stopping: impl EarlyStoppingStrategy,
+        data: &[(&[f64], bool, &str)],
+    ) {
+        let (store, mut processor) = processor();
 
         let mut epoch = 1;
         for (points, should_start, comment) in data {
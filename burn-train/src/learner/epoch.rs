@@ -1,12 +1,22 @@
 use burn_core::{
-    data::dataloader::DataLoader, lr_scheduler::LrScheduler, module::AutodiffModule,
-    optim::GradientsAccumulator, tensor::backend::Backend,
+    data::dataloader::{DataLoader, DataLoaderIterator, DataLoaderState},
+    lr_scheduler::LrScheduler,
+    module::AutodiffModule,
+    optim::{DynamicLossScaler, GradientsAccumulator, GradientsParams, ModuleAveraging, Optimizer},
+    tensor::backend::{AutodiffBackend, Backend},
 };
 use std::sync::Arc;
+use std::time::Instant;
 
+use crate::checkpoint::{iteration_seed, TrainingStateRecord};
 use crate::metric::processor::{Event, EventProcessor, LearnerItem};
+use crate::metric::store::EventStoreClient;
+use crate::metric::{GradientNorm, IterationTiming};
 use crate::{components::LearnerComponents, learner::base::TrainingInterrupter};
-use crate::{MultiDevicesTrainStep, TrainStep, ValidStep};
+use crate::{
+    LearnerCallbacks, LearnerCheckpointer, MultiDevicesTrainStep, TrainStep, TrainStepContext,
+    ValidStep,
+};
 
 /// A validation epoch.
 #[derive(new)]
@@ -23,6 +33,95 @@ pub struct TrainEpoch<TI> {
     epoch: usize,
     epoch_total: usize,
     grad_accumulation: Option<usize>,
+    #[new(default)]
+    dataloader_state: Option<DataLoaderState>,
+}
+
+type InnerBackend<LC> = <<LC as LearnerComponents>::Backend as AutodiffBackend>::InnerBackend;
+
+/// Mid-epoch checkpointing of a [training epoch](TrainEpoch).
+pub(crate) struct EpochCheckpointing<'a, LC: LearnerComponents> {
+    /// The checkpointer saving the mid-epoch checkpoints, with the event store used by its
+    /// strategy.
+    pub(crate) checkpointer: Option<(&'a mut LearnerCheckpointer<LC>, &'a EventStoreClient)>,
+    /// The seed of the backend, reseeded after each mid-epoch checkpoint.
+    pub(crate) seed: Option<u64>,
+    /// The number of iterators created from the training data loader in the previous epochs.
+    pub(crate) dataloader_iterators: usize,
+    /// The state of the checkpoint the epoch is resumed from.
+    pub(crate) resume: Option<TrainingStateRecord<InnerBackend<LC>>>,
+}
+
+impl<'a, LC: LearnerComponents> EpochCheckpointing<'a, LC> {
+    fn disabled() -> Self {
+        Self {
+            checkpointer: None,
+            seed: None,
+            dataloader_iterators: 0,
+            resume: None,
+        }
+    }
+
+    /// Resume the iteration count and the gradients accumulation of the epoch.
+    fn resume(
+        &mut self,
+        model: &LC::Model,
+        accumulator: &mut GradientsAccumulator<LC::Model>,
+    ) -> (usize, usize) {
+        match self.resume.take() {
+            Some(state) => {
+                accumulator.load_record(model, state.grads);
+                (state.iteration, state.accumulation)
+            }
+            None => (0, 0),
+        }
+    }
+
+    fn should_checkpoint(&mut self, epoch: usize, iteration: usize) -> bool {
+        match &mut self.checkpointer {
+            Some((checkpointer, store)) => {
+                checkpointer.should_checkpoint_iteration(epoch, iteration, store)
+            }
+            None => false,
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn checkpoint(
+        &mut self,
+        model: &LC::Model,
+        optim: &LC::Optimizer,
+        scheduler: &LC::LrScheduler,
+        averaging: Option<&dyn ModuleAveraging<LC::Backend, LC::Model>>,
+        accumulator: &GradientsAccumulator<LC::Model>,
+        accumulation: usize,
+        loss_scaler: Option<&DynamicLossScaler>,
+        dataloader_items: usize,
+        epoch: usize,
+        iteration: usize,
+    ) {
+        let Some((checkpointer, _)) = &mut self.checkpointer else {
+            return;
+        };
+
+        let state = TrainingStateRecord {
+            epoch,
+            epoch_completed: false,
+            iteration,
+            dataloader_iterators: self.dataloader_iterators,
+            dataloader_items,
+            seed: self.seed,
+            accumulation,
+            grads: accumulator.to_record(model),
+            loss_scaler: loss_scaler.map(DynamicLossScaler::to_record),
+        };
+        checkpointer.checkpoint_iteration(model, optim, scheduler, averaging, state);
+
+        // Reseed the backend the same way it is when the training is resumed from this checkpoint.
+        if let Some(seed) = self.seed {
+            LC::Backend::seed(iteration_seed(seed, epoch, iteration));
+        }
+    }
 }
 
 impl<VI> ValidEpoch<VI> {
@@ -40,9 +139,27 @@ impl<VI> ValidEpoch<VI> {
     ) where
         LC::EventProcessor: EventProcessor<ItemValid = VO>,
         <LC::Model as AutodiffModule<LC::Backend>>::InnerModule: ValidStep<VI, VO>,
+    {
+        self.run_module::<LC, VO>(model.valid(), processor, interrupter)
+    }
+
+    /// Runs the validation epoch with a model already on the inner backend, such as an
+    /// [averaged model](ModuleAveraging).
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The model to validate.
+    /// * `processor` - The event processor to use.
+    pub fn run_module<LC: LearnerComponents, VO>(
+        &self,
+        model: <LC::Model as AutodiffModule<LC::Backend>>::InnerModule,
+        processor: &mut LC::EventProcessor,
+        interrupter: &TrainingInterrupter,
+    ) where
+        LC::EventProcessor: EventProcessor<ItemValid = VO>,
+        <LC::Model as AutodiffModule<LC::Backend>>::InnerModule: ValidStep<VI, VO>,
     {
         log::info!("Executing validation step for epoch {}", self.epoch);
-        let model = model.valid();
 
         let mut iterator = self.dataloader.iter();
         let mut iteration = 0;
@@ -81,17 +198,66 @@ impl<TI> TrainEpoch<TI> {
     /// * `optim` - The optimizer to use.
     /// * `scheduler` - The learning rate scheduler to use.
     /// * `processor` - The event processor to use.
+    /// * `averaging` - The module averaging to update after each optimizer step.
     ///
     /// # Returns
     ///
     /// The trained model and the optimizer.
     pub fn run<LC: LearnerComponents, TO>(
+        &self,
+        model: LC::Model,
+        optim: LC::Optimizer,
+        scheduler: &mut LC::LrScheduler,
+        processor: &mut LC::EventProcessor,
+        averaging: &mut Option<Box<dyn ModuleAveraging<LC::Backend, LC::Model>>>,
+        interrupter: &TrainingInterrupter,
+    ) -> (LC::Model, LC::Optimizer)
+    where
+        LC::EventProcessor: EventProcessor<ItemTrain = TO>,
+        LC::Model: TrainStep<TI, TO>,
+    {
+        self.run_checkpointed::<LC, TO>(
+            model,
+            optim,
+            scheduler,
+            processor,
+            averaging,
+            &mut None,
+            interrupter,
+            EpochCheckpointing::disabled(),
+            &mut LearnerCallbacks::disabled(),
+        )
+    }
+
+    /// Resume the epoch from the given [data loader state](DataLoaderState), skipping the
+    /// batches already consumed.
+    pub(crate) fn with_dataloader_state(mut self, state: DataLoaderState) -> Self {
+        self.dataloader_state = Some(state);
+        self
+    }
+
+    fn iter(&self) -> Box<dyn DataLoaderIterator<TI> + '_> {
+        match &self.dataloader_state {
+            Some(state) => self.dataloader.iter_from(state),
+            None => self.dataloader.iter(),
+        }
+    }
+
+    /// Runs the training epoch, saving mid-epoch checkpoints when requested by the
+    /// checkpointing strategy, scaling the loss when training with mixed precision, and calling
+    /// the batch hooks of the [callbacks](crate::Callback).
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn run_checkpointed<LC: LearnerComponents, TO>(
         &self,
         mut model: LC::Model,
         mut optim: LC::Optimizer,
         scheduler: &mut LC::LrScheduler,
         processor: &mut LC::EventProcessor,
+        averaging: &mut Option<Box<dyn ModuleAveraging<LC::Backend, LC::Model>>>,
+        loss_scaler: &mut Option<DynamicLossScaler>,
         interrupter: &TrainingInterrupter,
+        mut checkpointing: EpochCheckpointing<'_, LC>,
+        callbacks: &mut LearnerCallbacks<'_, LC>,
     ) -> (LC::Model, LC::Optimizer)
     where
         LC::EventProcessor: EventProcessor<ItemTrain = TO>,
@@ -99,31 +265,89 @@ impl<TI> TrainEpoch<TI> {
     {
         log::info!("Executing training step for epoch {}", self.epoch,);
 
-        let mut iterator = self.dataloader.iter();
-        let mut iteration = 0;
+        let mut iterator = self.iter();
         let mut accumulator = GradientsAccumulator::new();
-        let mut accumulation_current = 0;
+        let (mut iteration, mut accumulation_current) =
+            checkpointing.resume(&model, &mut accumulator);
 
-        while let Some(item) = iterator.next() {
+        loop {
+            let loading_started = Instant::now();
+            let Some(item) = iterator.next() else {
+                break;
+            };
+            let data_loading = loading_started.elapsed();
             iteration += 1;
             let lr = scheduler.step();
             log::info!("Iteration {}", iteration);
+            callbacks.call(
+                &model,
+                &optim,
+                Some(lr),
+                self.epoch,
+                iteration,
+                |callback, context| callback.on_batch_begin(context),
+            );
 
             let progress = iterator.progress();
-            let item = model.step(item);
+            let step_started = Instant::now();
+            let context = TrainStepContext::new(loss_scaler.as_ref().map(DynamicLossScaler::scale));
+            let item = model.step_with_context(item, &context);
+            let mut grad_norm = None;
+
+            let grads = match unscale::<LC>(loss_scaler, &model, item.grads, item.loss_scale) {
+                Ok(mut grads) => {
+                    callbacks.call(
+                        &model,
+                        &optim,
+                        Some(lr),
+                        self.epoch,
+                        iteration,
+                        |callback, context| callback.on_after_backward(context, &mut grads),
+                    );
+                    Some(grads)
+                }
+                Err(grads) => {
+                    callbacks.call(
+                        &model,
+                        &optim,
+                        Some(lr),
+                        self.epoch,
+                        iteration,
+                        |callback, context| callback.on_non_finite_gradients(context, &grads),
+                    );
+                    None
+                }
+            };
 
-            match self.grad_accumulation {
-                Some(accumulation) => {
-                    accumulator.accumulate(&model, item.grads);
+            match (grads, self.grad_accumulation) {
+                (None, _) => {
+                    // The accumulated gradients are discarded along with the current ones.
+                    accumulator.grads();
+                    accumulation_current = 0;
+                }
+                (Some(grads), Some(accumulation)) => {
+                    accumulator.accumulate(&model, grads);
                     accumulation_current += 1;
 
                     if accumulation <= accumulation_current {
                         let grads = accumulator.grads();
                         model = model.optimize(&mut optim, lr, grads);
+                        grad_norm = last_grad_norm::<LC>(&optim);
                         accumulation_current = 0;
+
+                        if let Some(averaging) = averaging.as_mut() {
+                            averaging.update(&model);
+                        }
+                    }
+                }
+                (Some(grads), None) => {
+                    model = model.optimize(&mut optim, lr, grads);
+                    grad_norm = last_grad_norm::<LC>(&optim);
+
+                    if let Some(averaging) = averaging.as_mut() {
+                        averaging.update(&model);
                     }
                 }
-                None => model = model.optimize(&mut optim, lr, item.grads),
             }
 
             let item = LearnerItem::new(
@@ -133,9 +357,40 @@ impl<TI> TrainEpoch<TI> {
                 self.epoch_total,
                 iteration,
                 Some(lr),
-            );
+            )
+            .with_grad_norm(grad_norm)
+            .with_timing(Some(IterationTiming::new(
+                data_loading,
+                step_started.elapsed(),
+            )));
 
             processor.process_train(Event::ProcessedItem(item));
+            callbacks.call(
+                &model,
+                &optim,
+                Some(lr),
+                self.epoch,
+                iteration,
+                |callback, context| callback.on_batch_end(context),
+            );
+            if callbacks.should_stop_iteration(self.epoch, iteration) {
+                interrupter.stop();
+            }
+
+            if checkpointing.should_checkpoint(self.epoch, iteration) {
+                checkpointing.checkpoint(
+                    &model,
+                    &optim,
+                    scheduler,
+                    averaging.as_deref(),
+                    &accumulator,
+                    accumulation_current,
+                    loss_scaler.as_ref(),
+                    self.dataloader.num_items(),
+                    self.epoch,
+                    iteration,
+                );
+            }
 
             if interrupter.should_stop() {
                 log::info!("Training interrupted.");
@@ -157,19 +412,62 @@ impl<TI> TrainEpoch<TI> {
     /// * `optim` - The optimizer to use.
     /// * `lr_scheduler` - The learning rate scheduler to use.
     /// * `processor` - The event processor to use.
+    /// * `averaging` - The module averaging to update after each optimizer step.
     /// * `devices` - The devices to use.
     ///
     /// # Returns
     ///
     /// The trained model and the optimizer.
+    #[allow(clippy::too_many_arguments)]
     pub fn run_multi_device<LC: LearnerComponents, TO>(
+        &self,
+        model: LC::Model,
+        optim: LC::Optimizer,
+        lr_scheduler: &mut LC::LrScheduler,
+        processor: &mut LC::EventProcessor,
+        averaging: &mut Option<Box<dyn ModuleAveraging<LC::Backend, LC::Model>>>,
+        devices: Vec<<LC::Backend as Backend>::Device>,
+        interrupter: &TrainingInterrupter,
+    ) -> (LC::Model, LC::Optimizer)
+    where
+        LC::EventProcessor: EventProcessor<ItemTrain = TO>,
+        LC::Model: TrainStep<TI, TO>,
+        TO: Send + 'static,
+        TI: Send + 'static,
+    {
+        self.run_multi_device_checkpointed::<LC, TO>(
+            model,
+            optim,
+            lr_scheduler,
+            processor,
+            averaging,
+            &mut None,
+            devices,
+            interrupter,
+            EpochCheckpointing::disabled(),
+            &mut LearnerCallbacks::disabled(),
+        )
+    }
+
+    /// Runs the training epoch on multiple devices, saving mid-epoch checkpoints when requested
+    /// by the checkpointing strategy.
+    ///
+    /// Checkpoints are only saved once the items processed in parallel are all applied, since
+    /// they are computed with the same model. The items processed in parallel are a single batch
+    /// for the [callbacks](crate::Callback).
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn run_multi_device_checkpointed<LC: LearnerComponents, TO>(
         &self,
         mut model: LC::Model,
         mut optim: LC::Optimizer,
         lr_scheduler: &mut LC::LrScheduler,
         processor: &mut LC::EventProcessor,
+        averaging: &mut Option<Box<dyn ModuleAveraging<LC::Backend, LC::Model>>>,
+        loss_scaler: &mut Option<DynamicLossScaler>,
         devices: Vec<<LC::Backend as Backend>::Device>,
         interrupter: &TrainingInterrupter,
+        mut checkpointing: EpochCheckpointing<'_, LC>,
+        callbacks: &mut LearnerCallbacks<'_, LC>,
     ) -> (LC::Model, LC::Optimizer)
     where
         LC::EventProcessor: EventProcessor<ItemTrain = TO>,
@@ -183,50 +481,109 @@ impl<TI> TrainEpoch<TI> {
             devices
         );
 
-        let mut iterator = self.dataloader.iter();
-        let mut iteration = 0;
+        let mut iterator = self.iter();
         let mut accumulator = GradientsAccumulator::new();
-        let mut accumulation_current = 0;
+        let (mut iteration, mut accumulation_current) =
+            checkpointing.resume(&model, &mut accumulator);
 
         let accumulation = self.grad_accumulation.unwrap_or(1) * devices.len();
-        let step = MultiDevicesTrainStep::new(&devices);
-
-        // The main device is always the first in the list.
-        let device_main = devices.first().expect("A minimum of one device.").clone();
+        let step = MultiDevicesTrainStep::new(&devices, &model);
         let mut interrupted = false;
 
         loop {
-            let items = step.step(&mut iterator, &model);
-            if items.is_empty() {
+            let loading_started = Instant::now();
+            let items = step.load(&mut iterator);
+            let data_loading = loading_started.elapsed();
+            let num_items = items.iter().flatten().count();
+            if num_items == 0 {
                 break;
             }
 
-            for item in items {
-                iteration += 1;
-                let lr = lr_scheduler.step();
-                let progress = iterator.progress();
+            let progress = iterator.progress();
+            let lrs = (0..num_items)
+                .map(|_| lr_scheduler.step())
+                .collect::<Vec<_>>();
+            let lr = *lrs.last().expect("At least one item.");
+            callbacks.call(
+                &model,
+                &optim,
+                Some(lr),
+                self.epoch,
+                iteration + 1,
+                |callback, context| callback.on_batch_begin(context),
+            );
 
-                let grads = item.grads.to_device(&device_main, &model);
+            // The gradients are already summed over the devices, on the main device.
+            let step_started = Instant::now();
+            let context = TrainStepContext::new(loss_scaler.as_ref().map(DynamicLossScaler::scale));
+            let (items, grads, loss_scale) = step.run(items, &context);
+            let mut grad_norm = None;
+
+            match unscale::<LC>(loss_scaler, &model, grads, loss_scale) {
+                Ok(mut grads) => {
+                    callbacks.call(
+                        &model,
+                        &optim,
+                        Some(lr),
+                        self.epoch,
+                        iteration + items.len(),
+                        |callback, context| callback.on_after_backward(context, &mut grads),
+                    );
+
+                    accumulator.accumulate(&model, grads);
+                    accumulation_current += items.len();
+                }
+                Err(grads) => {
+                    callbacks.call(
+                        &model,
+                        &optim,
+                        Some(lr),
+                        self.epoch,
+                        iteration + items.len(),
+                        |callback, context| callback.on_non_finite_gradients(context, &grads),
+                    );
+
+                    accumulator.grads();
+                    accumulation_current = 0;
+                }
+            }
 
-                accumulator.accumulate(&model, grads);
-                accumulation_current += 1;
+            if accumulation_current > 0 && accumulation <= accumulation_current {
+                let grads = accumulator.grads();
+                model = model.optimize(&mut optim, lr, grads);
+                grad_norm = last_grad_norm::<LC>(&optim);
+                accumulation_current = 0;
+                step.sync(&model);
 
-                if accumulation <= accumulation_current {
-                    let grads = accumulator.grads();
-                    model = model.optimize(&mut optim, lr, grads);
-                    accumulation_current = 0;
+                if let Some(averaging) = averaging.as_mut() {
+                    averaging.update(&model);
                 }
+            }
 
+            // The items are processed in parallel, so they share the time of the step.
+            let timing = IterationTiming::new(
+                data_loading / num_items as u32,
+                step_started.elapsed() / num_items as u32,
+            );
+            let mut should_checkpoint = false;
+
+            for (index, (item, lr)) in items.into_iter().zip(lrs).enumerate() {
+                iteration += 1;
+
+                // The optimizer step is reported with the last item of the step.
                 let item = LearnerItem::new(
-                    item.item,
-                    progress,
+                    item,
+                    progress.clone(),
                     self.epoch,
                     self.epoch_total,
                     iteration,
                     Some(lr),
-                );
+                )
+                .with_grad_norm(grad_norm.clone().filter(|_| index + 1 == num_items))
+                .with_timing(Some(timing));
 
                 processor.process_train(Event::ProcessedItem(item));
+                should_checkpoint |= checkpointing.should_checkpoint(self.epoch, iteration);
 
                 if interrupter.should_stop() {
                     log::info!("Training interrupted.");
@@ -235,13 +592,82 @@ impl<TI> TrainEpoch<TI> {
                 }
             }
 
+            callbacks.call(
+                &model,
+                &optim,
+                Some(lr),
+                self.epoch,
+                iteration,
+                |callback, context| callback.on_batch_end(context),
+            );
+            if callbacks.should_stop_iteration(self.epoch, iteration) {
+                log::info!("Training interrupted.");
+                interrupter.stop();
+                interrupted = true;
+            }
+
+            if should_checkpoint && !interrupted {
+                checkpointing.checkpoint(
+                    &model,
+                    &optim,
+                    lr_scheduler,
+                    averaging.as_deref(),
+                    &accumulator,
+                    accumulation_current,
+                    loss_scaler.as_ref(),
+                    self.dataloader.num_items(),
+                    self.epoch,
+                    iteration,
+                );
+            }
+
             if interrupted {
                 break;
             }
         }
-
         processor.process_train(Event::EndEpoch(self.epoch));
 
         (model, optim)
     }
 }
+
+/// Unscale the gradients when training with mixed precision, returning an error with the
+/// unscaled gradients when the optimizer step should be skipped because they aren't finite.
+///
+/// # Panics
+///
+/// When training with mixed precision, if the loss wasn't scaled by
+/// [from_loss](crate::TrainOutput::from_loss).
+fn unscale<LC: LearnerComponents>(
+    loss_scaler: &mut Option<DynamicLossScaler>,
+    model: &LC::Model,
+    grads: GradientsParams,
+    loss_scale: Option<f64>,
+) -> Result<GradientsParams, GradientsParams> {
+    let Some(loss_scaler) = loss_scaler else {
+        return Ok(grads);
+    };
+    assert!(
+        loss_scale.is_some(),
+        "The loss isn't scaled by the training step, which is required to train with mixed \
+         precision. Implement `TrainStep::step_with_context` and create the training output with \
+         `TrainOutput::from_loss`."
+    );
+
+    let grads = loss_scaler.unscale(model, grads);
+    if grads.is_err() {
+        log::warn!(
+            "Skipping the optimizer step, the gradients are not finite. Loss scale reduced to {}.",
+            loss_scaler.scale()
+        );
+    }
+
+    grads
+}
+
+/// The gradient norm of the last optimizer step, when reported by the optimizer.
+///
+/// The norm is only read from the device by the metrics using it.
+fn last_grad_norm<LC: LearnerComponents>(optim: &LC::Optimizer) -> Option<GradientNorm> {
+    optim.grad_norm().map(GradientNorm::new)
+}
@@ -0,0 +1,389 @@
+use std::sync::Arc;
+
+use super::log::install_file_logger;
+use crate::checkpoint::{Checkpointer, FileCheckpointer};
+use crate::learner::base::TrainingInterrupter;
+use crate::logger::{FileMetricLogger, MetricLogger};
+use crate::metric::processor::{LearnerItem, Metrics};
+use crate::metric::store::{Event, EventStoreClient, LogEventStore};
+use crate::metric::{Adaptor, Metric, MetricEntry, Numeric};
+use crate::renderer::{default_renderer, MetricState, MetricsRenderer, TrainingProgress};
+use crate::ValidStep;
+use burn_core::data::dataloader::DataLoader;
+use burn_core::module::Module;
+use burn_core::record::FileRecorder;
+use burn_core::tensor::backend::Backend;
+
+/// The epoch under which the test metrics are logged.
+const TEST_EPOCH: usize = 1;
+
+/// Struct to configure and create an [evaluator](Evaluator).
+pub struct EvaluatorBuilder<B, V, M>
+where
+    B: Backend,
+    V: Send + Sync + 'static,
+    M: Module<B>,
+{
+    checkpointer: Option<Box<dyn Checkpointer<M::Record, B>>>,
+    checkpoint: Option<usize>,
+    directory: String,
+    device: B::Device,
+    renderer: Option<Box<dyn MetricsRenderer + 'static>>,
+    metrics: Metrics<(), V>,
+    event_store: LogEventStore,
+    interrupter: TrainingInterrupter,
+    log_to_file: bool,
+    num_loggers: usize,
+}
+
+impl<B, V, M> EvaluatorBuilder<B, V, M>
+where
+    B: Backend,
+    V: Send + Sync + 'static,
+    M: Module<B>,
+{
+    /// Creates a new evaluator builder.
+    ///
+    /// # Arguments
+    ///
+    /// * `directory` - The directory of the experiment, where the test metrics and the summary
+    ///   are saved.
+    pub fn new(directory: &str) -> Self {
+        Self {
+            checkpointer: None,
+            checkpoint: None,
+            directory: directory.to_string(),
+            device: B::Device::default(),
+            renderer: None,
+            metrics: Metrics::default(),
+            event_store: LogEventStore::default(),
+            interrupter: TrainingInterrupter::new(),
+            log_to_file: true,
+            num_loggers: 0,
+        }
+    }
+
+    /// Replace the default metric logger with the provided one.
+    ///
+    /// # Notes
+    ///
+    /// This method can be called multiple times to register multiple loggers.
+    pub fn metric_logger<ML>(mut self, logger: ML) -> Self
+    where
+        ML: MetricLogger + 'static,
+    {
+        self.event_store.register_logger_test(logger);
+        self.num_loggers += 1;
+        self
+    }
+
+    /// Replace the default CLI renderer with a custom one.
+    ///
+    /// # Arguments
+    ///
+    /// * `renderer` - The custom renderer.
+    pub fn renderer<MR>(mut self, renderer: MR) -> Self
+    where
+        MR: MetricsRenderer + 'static,
+    {
+        self.renderer = Some(Box::new(renderer));
+        self
+    }
+
+    /// Register a test metric.
+    pub fn metric_test<Me: Metric + 'static>(mut self, metric: Me) -> Self
+    where
+        V: Adaptor<Me::Input>,
+    {
+        self.metrics.register_test_metric(metric);
+        self
+    }
+
+    /// Register a [numeric](crate::metric::Numeric) test [metric](Metric).
+    pub fn metric_test_numeric<Me>(mut self, metric: Me) -> Self
+    where
+        Me: Metric + Numeric + 'static,
+        V: Adaptor<Me::Input>,
+    {
+        self.metrics.register_test_metric_numeric(metric);
+        self
+    }
+
+    /// The epoch of the checkpoint to evaluate.
+    pub fn checkpoint(mut self, checkpoint: usize) -> Self {
+        self.checkpoint = Some(checkpoint);
+        self
+    }
+
+    /// The device on which the model is loaded.
+    pub fn device(mut self, device: B::Device) -> Self {
+        self.device = device;
+        self
+    }
+
+    /// Provides a handle that can be used to interrupt the evaluation.
+    pub fn interrupter(&self) -> TrainingInterrupter {
+        self.interrupter.clone()
+    }
+
+    /// By default, Rust logs are captured and written into
+    /// `evaluation.log`. If disabled, standard Rust log handling
+    /// will apply.
+    pub fn log_to_file(mut self, enabled: bool) -> Self {
+        self.log_to_file = enabled;
+        self
+    }
+
+    /// Register the checkpointer used to load the [model](Module).
+    pub fn with_checkpointer<C>(mut self, checkpointer: C) -> Self
+    where
+        C: Checkpointer<M::Record, B> + 'static,
+    {
+        self.checkpointer = Some(Box::new(checkpointer));
+        self
+    }
+
+    /// Register a checkpointer that loads the [model](Module) from the files saved by the
+    /// [learner](crate::Learner) in the same directory.
+    pub fn with_file_checkpointer<FR>(self, recorder: FR) -> Self
+    where
+        FR: FileRecorder<B> + 'static,
+        M::Record: 'static,
+    {
+        let checkpointer = FileCheckpointer::new(
+            recorder,
+            format!("{}/checkpoint", self.directory).as_str(),
+            "model",
+        );
+
+        self.with_checkpointer(checkpointer)
+    }
+
+    /// Create the [evaluator](Evaluator) from a [model](Module), loading the registered
+    /// checkpoint if any.
+    pub fn build(mut self, model: M) -> Evaluator<V, M> {
+        if self.log_to_file {
+            self.init_logger();
+        }
+        let renderer = self
+            .renderer
+            .unwrap_or_else(|| Box::new(default_renderer(self.interrupter.clone(), None)));
+        let directory = &self.directory;
+
+        if self.num_loggers == 0 {
+            self.event_store
+                .register_logger_test(FileMetricLogger::new(format!("{directory}/test").as_str()));
+        }
+
+        let model = match (self.checkpoint, &self.checkpointer) {
+            (Some(epoch), Some(checkpointer)) => {
+                let record = checkpointer
+                    .restore(epoch, &self.device)
+                    .expect("Can load model checkpoint.");
+                model.load_record(record)
+            }
+            (Some(epoch), None) => {
+                panic!("A checkpointer must be registered to load the checkpoint {epoch}.")
+            }
+            (None, _) => model,
+        };
+
+        Evaluator {
+            model,
+            metrics: self.metrics,
+            renderer,
+            event_store: Arc::new(EventStoreClient::new(self.event_store)),
+            checkpoint: self.checkpoint,
+            directory: self.directory,
+            interrupter: self.interrupter,
+        }
+    }
+
+    fn init_logger(&self) {
+        let file_path = format!("{}/evaluation.log", self.directory);
+        install_file_logger(file_path.as_str());
+    }
+}
+
+/// Evaluates a model on a test split with metrics, a renderer and loggers, without training.
+///
+/// To create an evaluator, use the [builder](EvaluatorBuilder) struct.
+pub struct Evaluator<V, M> {
+    model: M,
+    metrics: Metrics<(), V>,
+    renderer: Box<dyn MetricsRenderer>,
+    event_store: Arc<EventStoreClient>,
+    checkpoint: Option<usize>,
+    directory: String,
+    interrupter: TrainingInterrupter,
+}
+
+impl<V, M> Evaluator<V, M> {
+    /// Runs the [validation step](ValidStep) of the model over the test dataloader.
+    ///
+    /// The test metrics are recorded to the [test split](crate::metric::store::Split::Test) and
+    /// the summary is saved to `test/summary.txt` in the directory of the experiment.
+    pub fn eval<VI>(mut self, dataloader: Arc<dyn DataLoader<VI>>) -> EvaluationSummary
+    where
+        M: ValidStep<VI, V>,
+    {
+        log::info!("Executing evaluation on the test split");
+
+        let mut iterator = dataloader.iter();
+        let mut iteration = 0;
+        let mut num_items = 0;
+        let mut metrics = Vec::new();
+
+        while let Some(item) = iterator.next() {
+            let progress = iterator.progress();
+            iteration += 1;
+            num_items = progress.items_processed;
+
+            let item = self.model.step(item);
+            let item = LearnerItem::new(item, progress, TEST_EPOCH, TEST_EPOCH, iteration, None);
+            let metadata = (&item).into();
+
+            let update = self.metrics.update_test(&item, &metadata);
+
+            self.event_store
+                .add_event_test(Event::MetricsUpdate(update.clone()));
+
+            metrics = update
+                .entries
+                .iter()
+                .chain(update.entries_numeric.iter().map(|(entry, _value)| entry))
+                .cloned()
+                .collect();
+
+            update
+                .entries
+                .into_iter()
+                .for_each(|entry| self.renderer.update_test(MetricState::Generic(entry)));
+
+            update
+                .entries_numeric
+                .into_iter()
+                .for_each(|(entry, value)| {
+                    self.renderer
+                        .update_test(MetricState::Numeric(entry, value))
+                });
+
+            self.renderer.render_test(TrainingProgress::from(&item));
+
+            if self.interrupter.should_stop() {
+                log::info!("Evaluation interrupted.");
+                break;
+            }
+        }
+
+        self.metrics.end_epoch_test();
+        self.event_store.add_event_test(Event::EndEpoch(TEST_EPOCH));
+
+        let summary = EvaluationSummary {
+            checkpoint: self.checkpoint,
+            num_items,
+            metrics,
+        };
+        summary.save(format!("{}/test", self.directory).as_str());
+
+        summary
+    }
+}
+
+/// The final state of the test metrics computed by an [evaluator](Evaluator).
+#[derive(Debug, Clone)]
+pub struct EvaluationSummary {
+    /// The epoch of the evaluated checkpoint, if any.
+    pub checkpoint: Option<usize>,
+    /// The number of items processed.
+    pub num_items: usize,
+    /// The last entry of each test metric.
+    pub metrics: Vec<MetricEntry>,
+}
+
+impl EvaluationSummary {
+    /// The last entry of the test metric with the given name.
+    pub fn metric(&self, name: &str) -> Option<&MetricEntry> {
+        self.metrics.iter().find(|entry| entry.name == name)
+    }
+
+    fn save(&self, directory: &str) {
+        let file_path = format!("{directory}/summary.txt");
+        log::info!("Saving evaluation summary to {}", file_path);
+
+        std::fs::create_dir_all(directory).ok();
+        std::fs::write(file_path, self.to_string()).expect("Can save the evaluation summary.");
+    }
+}
+
+impl core::fmt::Display for EvaluationSummary {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.checkpoint {
+            Some(epoch) => writeln!(f, "Checkpoint: {epoch}")?,
+            None => writeln!(f, "Checkpoint: none")?,
+        }
+        writeln!(f, "Items: {}", self.num_items)?;
+
+        for entry in self.metrics.iter() {
+            // Multi-line metrics, such as a confusion matrix, are written under their name.
+            match entry.formatted.contains('\n') {
+                true => {
+                    writeln!(f, "{}:", entry.name)?;
+                    for line in entry.formatted.lines() {
+                        writeln!(f, "    {line}")?;
+                    }
+                }
+                false => writeln!(f, "{}: {}", entry.name, entry.formatted)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logger::InMemoryMetricLogger;
+    use crate::metric::LossMetric;
+    use crate::test_utils::{NoRenderer, TestBatcher, TestModel};
+    use crate::TestBackend;
+    use burn_core::data::dataloader::DataLoaderBuilder;
+    use burn_core::data::dataset::InMemDataset;
+    use burn_core::module::Param;
+    use burn_core::tensor::Tensor;
+
+    #[test]
+    fn should_evaluate_test_split_and_save_summary() {
+        let directory = tempfile::tempdir().unwrap();
+        let directory = directory.path().to_str().unwrap();
+        let device = Default::default();
+        let mut model = TestModel::<TestBackend>::new(&device);
+        // The output of the model is the first value of the items.
+        model.linear.weight = Param::from(Tensor::from_floats([[1.0], [0.0]], &device));
+        model.linear.bias = None;
+        let dataloader = DataLoaderBuilder::new(TestBatcher)
+            .batch_size(2)
+            .build(InMemDataset::new(vec![1.0, 2.0, 3.0, 4.0, 5.0]));
+
+        let summary = EvaluatorBuilder::new(directory)
+            .metric_test_numeric(LossMetric::<TestBackend>::new())
+            .metric_logger(InMemoryMetricLogger::new())
+            .renderer(NoRenderer)
+            .log_to_file(false)
+            .build(model)
+            .eval(dataloader);
+
+        // The batches have a loss of 2.5, 12.5 and 25.
+        assert_eq!(summary.num_items, 5);
+        assert_eq!(
+            summary
+                .metric(LossMetric::<TestBackend>::NAME)
+                .unwrap()
+                .formatted,
+            "epoch 13.33 - batch 25.00"
+        );
+        let saved = std::fs::read_to_string(format!("{directory}/test/summary.txt")).unwrap();
+        assert_eq!(saved, summary.to_string());
+    }
+}
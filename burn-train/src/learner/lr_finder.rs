@@ -0,0 +1,346 @@
+use crate::metric::processor::{Event, EventProcessor, FullEventProcessor, LearnerItem, Metrics};
+use crate::metric::store::{EventStoreClient, LogEventStore};
+use crate::metric::{Adaptor, LossInput, LossMetric, Metric, MetricMetadata, Numeric};
+use crate::renderer::{default_renderer, MetricsRenderer};
+use crate::{TrainStep, TrainingInterrupter};
+use burn_core::data::dataloader::{DataLoader, Progress};
+use burn_core::module::AutodiffModule;
+use burn_core::optim::Optimizer;
+use burn_core::tensor::backend::AutodiffBackend;
+use std::path::Path;
+use std::sync::Arc;
+
+/// The heuristic used to [suggest](LrFinderResult::suggestion) a learning rate from the
+/// [learning rate range test](LrFinder).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LrSuggestion {
+    /// The learning rate where the smoothed loss decreases the fastest.
+    SteepestDescent,
+    /// A tenth of the learning rate with the lowest smoothed loss.
+    MinLoss,
+}
+
+/// The loss at a learning rate of the [learning rate range test](LrFinder).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LrPoint {
+    /// The learning rate.
+    pub lr: f64,
+    /// The loss of the iteration.
+    pub loss: f64,
+    /// The loss smoothed with an exponential moving average.
+    pub smoothed_loss: f64,
+}
+
+/// The result of the [learning rate range test](LrFinder).
+#[derive(Clone, Debug, Default)]
+pub struct LrFinderResult {
+    /// The loss at each learning rate, until the test stopped.
+    pub points: Vec<LrPoint>,
+}
+
+impl LrFinderResult {
+    /// Suggest a learning rate with the given heuristic, if enough points were recorded.
+    pub fn suggestion(&self, method: LrSuggestion) -> Option<f64> {
+        let min_index = self
+            .points
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.smoothed_loss.total_cmp(&b.smoothed_loss))
+            .map(|(index, _)| index)?;
+
+        match method {
+            LrSuggestion::MinLoss => Some(self.points[min_index].lr / 10.0),
+            LrSuggestion::SteepestDescent => {
+                // The central difference of the smoothed loss with respect to the logarithm of
+                // the learning rate, before the loss reaches its minimum.
+                let points = &self.points[..(min_index + 2).min(self.points.len())];
+                (1..points.len().saturating_sub(1))
+                    .map(|i| {
+                        let (before, after) = (points[i - 1], points[i + 1]);
+                        let slope = (after.smoothed_loss - before.smoothed_loss)
+                            / (after.lr.ln() - before.lr.ln());
+                        (points[i].lr, slope)
+                    })
+                    .min_by(|(_, a), (_, b)| a.total_cmp(b))
+                    .map(|(lr, _)| lr)
+            }
+        }
+    }
+
+    /// The points as CSV, with the columns `lr`, `loss` and `smoothed_loss`.
+    pub fn to_csv(&self) -> String {
+        let mut content = "lr,loss,smoothed_loss\n".to_string();
+        for point in self.points.iter() {
+            content.push_str(&format!(
+                "{},{},{}\n",
+                point.lr, point.loss, point.smoothed_loss
+            ));
+        }
+
+        content
+    }
+
+    /// Save the points as CSV.
+    pub fn save_csv<P: AsRef<Path>>(&self, file_path: P) -> std::io::Result<()> {
+        std::fs::write(file_path, self.to_csv())
+    }
+}
+
+/// The learning rate range test, which trains a copy of the model for a few iterations with a
+/// learning rate increasing exponentially, recording the loss at each learning rate.
+///
+/// The test stops early when the loss diverges. The iterations are rendered as a single epoch in
+/// the [renderer](MetricsRenderer), along with their learning rate.
+pub struct LrFinder {
+    start_lr: f64,
+    end_lr: f64,
+    num_iterations: usize,
+    smoothing: f64,
+    divergence_threshold: f64,
+    renderer: Option<Box<dyn MetricsRenderer>>,
+    interrupter: TrainingInterrupter,
+}
+
+impl Default for LrFinder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LrFinder {
+    /// Creates the test, with 100 iterations from `1e-7` to `10`.
+    pub fn new() -> Self {
+        Self {
+            start_lr: 1e-7,
+            end_lr: 10.0,
+            num_iterations: 100,
+            smoothing: 0.98,
+            divergence_threshold: 4.0,
+            renderer: None,
+            interrupter: TrainingInterrupter::new(),
+        }
+    }
+
+    /// The range of the learning rates.
+    pub fn lr_range(mut self, start_lr: f64, end_lr: f64) -> Self {
+        self.start_lr = start_lr;
+        self.end_lr = end_lr;
+        self
+    }
+
+    /// The number of iterations, which cycles over the dataloader if it has fewer batches.
+    pub fn num_iterations(mut self, num_iterations: usize) -> Self {
+        self.num_iterations = num_iterations;
+        self
+    }
+
+    /// The factor of the exponential moving average smoothing the loss, `0.98` by default.
+    pub fn smoothing(mut self, smoothing: f64) -> Self {
+        self.smoothing = smoothing;
+        self
+    }
+
+    /// Stop when the smoothed loss is larger than its lowest value by the given factor, `4` by
+    /// default.
+    pub fn divergence_threshold(mut self, threshold: f64) -> Self {
+        self.divergence_threshold = threshold;
+        self
+    }
+
+    /// Replace the default CLI renderer with a custom one.
+    pub fn renderer<MR>(mut self, renderer: MR) -> Self
+    where
+        MR: MetricsRenderer + 'static,
+    {
+        self.renderer = Some(Box::new(renderer));
+        self
+    }
+
+    /// Provides a handle that can be used to interrupt the test.
+    pub fn interrupter(&self) -> TrainingInterrupter {
+        self.interrupter.clone()
+    }
+
+    /// The learning rate of the given iteration.
+    fn lr(&self, iteration: usize) -> f64 {
+        let position = match self.num_iterations {
+            0 | 1 => 0.0,
+            num_iterations => iteration as f64 / (num_iterations - 1) as f64,
+        };
+
+        self.start_lr * (self.end_lr / self.start_lr).powf(position)
+    }
+
+    /// Run the test with a copy of the model, so that the weights of the model are unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The model.
+    /// * `optim` - A new optimizer for the model.
+    /// * `dataloader` - The training dataloader.
+    pub fn run<B, M, O, TI, TO>(
+        mut self,
+        model: &M,
+        mut optim: O,
+        dataloader: Arc<dyn DataLoader<TI>>,
+    ) -> LrFinderResult
+    where
+        B: AutodiffBackend,
+        M: AutodiffModule<B> + TrainStep<TI, TO>,
+        O: Optimizer<M, B>,
+        TO: Adaptor<LossInput<B>> + 'static,
+    {
+        let store = Arc::new(EventStoreClient::new(LogEventStore::default()));
+        let mut metrics = Metrics::<TO, TO>::default();
+        metrics.register_train_metric_numeric(LossMetric::<B>::new());
+        let renderer = self
+            .renderer
+            .take()
+            .unwrap_or_else(|| Box::new(default_renderer(self.interrupter.clone(), None)));
+        let mut processor = FullEventProcessor::new(metrics, renderer, store.clone());
+
+        let mut model = model.clone();
+        let mut iterator = dataloader.iter();
+        let mut loss_metric = LossMetric::<B>::new();
+        let mut result = LrFinderResult::default();
+        let mut average = 0.0;
+        let mut best = f64::INFINITY;
+
+        for iteration in 0..self.num_iterations {
+            let item = match iterator.next() {
+                Some(item) => item,
+                None => {
+                    iterator = dataloader.iter();
+                    match iterator.next() {
+                        Some(item) => item,
+                        None => break,
+                    }
+                }
+            };
+
+            let lr = self.lr(iteration);
+            let output = model.step(item);
+            model = model.optimize(&mut optim, lr, output.grads);
+
+            let item = LearnerItem::new(
+                output.item,
+                Progress::new(iteration + 1, self.num_iterations),
+                1,
+                1,
+                iteration + 1,
+                Some(lr),
+            );
+            loss_metric.update(&item.item.adapt(), &MetricMetadata::from(&item));
+            let loss = loss_metric.value();
+            processor.process_train(Event::ProcessedItem(item));
+
+            if !loss.is_finite() {
+                log::info!("Stopping the learning rate finder, the loss isn't finite.");
+                break;
+            }
+
+            average = self.smoothing * average + (1.0 - self.smoothing) * loss;
+            let smoothed_loss = average / (1.0 - self.smoothing.powi(iteration as i32 + 1));
+            result.points.push(LrPoint {
+                lr,
+                loss,
+                smoothed_loss,
+            });
+
+            if smoothed_loss > best * self.divergence_threshold {
+                log::info!("Stopping the learning rate finder, the loss diverged at lr {lr}.");
+                break;
+            }
+            best = best.min(smoothed_loss);
+
+            if self.interrupter.should_stop() {
+                break;
+            }
+        }
+        processor.process_train(Event::EndEpoch(1));
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{NoRenderer, TestBatcher, TestModel};
+    use crate::TestAutodiffBackend;
+    use burn_core::data::dataloader::DataLoaderBuilder;
+    use burn_core::data::dataset::InMemDataset;
+    use burn_core::optim::SgdConfig;
+
+    type TB = TestAutodiffBackend;
+
+    fn result(points: &[(f64, f64)]) -> LrFinderResult {
+        LrFinderResult {
+            points: points
+                .iter()
+                .map(|(lr, loss)| LrPoint {
+                    lr: *lr,
+                    loss: *loss,
+                    smoothed_loss: *loss,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn should_suggest_steepest_descent_and_min_loss() {
+        let result = result(&[
+            (1e-4, 2.0),
+            (1e-3, 1.9),
+            (1e-2, 1.0),
+            (1e-1, 0.5),
+            (1e0, 0.4),
+            (1e1, 5.0),
+        ]);
+
+        assert_eq!(result.suggestion(LrSuggestion::SteepestDescent), Some(1e-2));
+        assert_eq!(result.suggestion(LrSuggestion::MinLoss), Some(1e-1));
+        assert!(result
+            .to_csv()
+            .starts_with("lr,loss,smoothed_loss\n0.0001,2,2\n"));
+        assert_eq!(
+            LrFinderResult::default().suggestion(LrSuggestion::MinLoss),
+            None
+        );
+    }
+
+    #[test]
+    fn should_sweep_learning_rates_exponentially() {
+        let finder = LrFinder::new().lr_range(1e-4, 1e-1).num_iterations(4);
+
+        let lrs = (0..4).map(|i| finder.lr(i)).collect::<Vec<_>>();
+
+        for (lr, expected) in lrs.iter().zip([1e-4, 1e-3, 1e-2, 1e-1]) {
+            assert!((lr / expected - 1.0).abs() < 1e-9, "{lr} != {expected}");
+        }
+    }
+
+    #[test]
+    fn should_record_losses_without_changing_the_model() {
+        let device = Default::default();
+        let model = TestModel::<TB>::new(&device);
+        let weights = model.linear.weight.val().into_data();
+        let dataloader = DataLoaderBuilder::new(TestBatcher)
+            .batch_size(2)
+            .build(InMemDataset::new(vec![1.0, 2.0, 3.0, 4.0]));
+
+        let result = LrFinder::new()
+            .lr_range(1e-4, 1e3)
+            .num_iterations(30)
+            .renderer(NoRenderer)
+            .run(&model, SgdConfig::new().init(), dataloader);
+
+        assert!(!result.points.is_empty());
+        assert!(result.points.len() < 30, "The loss should diverge.");
+        assert!(result.points.windows(2).all(|p| p[0].lr < p[1].lr));
+        assert!((result.points[0].loss - result.points[0].smoothed_loss).abs() < 1e-9);
+        assert!(result.suggestion(LrSuggestion::MinLoss).is_some());
+        assert_eq!(result.to_csv().lines().count(), result.points.len() + 1);
+        assert_eq!(model.linear.weight.val().into_data(), weights);
+    }
+}
@@ -1,9 +1,17 @@
 mod base;
 mod builder;
+mod callback;
 mod classification;
+mod distillation;
 mod early_stopping;
 mod epoch;
+mod evaluator;
+mod lr_finder;
+#[cfg(feature = "profiler")]
+mod profiler;
+mod provider;
 mod regression;
+mod sequence;
 mod step;
 mod train_val;
 
@@ -11,10 +19,19 @@ pub(crate) mod log;
 
 pub use base::*;
 pub use builder::*;
+pub use callback::*;
 pub use classification::*;
+pub use collective::*;
+pub use distillation::*;
 pub use early_stopping::*;
 pub use epoch::*;
+pub use evaluator::*;
+pub use lr_finder::*;
+#[cfg(feature = "profiler")]
+pub use profiler::*;
+pub use provider::*;
 pub use regression::*;
+pub use sequence::*;
 pub use step::*;
 pub use train::*;
 pub use train_val::*;
@@ -0,0 +1,185 @@
+use crate::learner::{Callback, CallbackContext};
+use burn_core::module::AutodiffModule;
+use burn_core::optim::Optimizer;
+use burn_core::tensor::backend::AutodiffBackend;
+use burn_profiler::{start_recording, stop_recording, Profile};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// The number of operations of the summary logged when the profile is saved.
+const NUM_LOGGED_OPERATIONS: usize = 20;
+
+/// A [callback](Callback) recording the tensor operations of the training steps of some
+/// iterations with the [profiler](burn_profiler::Profiler).
+///
+/// Once the iterations are recorded, the [Chrome trace](Profile::to_chrome_trace) is saved to
+/// `trace.json` and the [summary](Profile::table) of the operations to `summary.txt` in the
+/// directory, and the operations taking the most time are logged.
+///
+/// # Notes
+///
+/// The backend of the model must be wrapped with the [profiler](burn_profiler::Profiler), such as
+/// `Autodiff<Profiler<Wgpu>>`, for its operations to be recorded. Only the training steps are
+/// recorded, not the loading of the batches nor the validation.
+pub struct ProfilerCallback {
+    directory: PathBuf,
+    warmup: usize,
+    num_iterations: usize,
+    iteration: usize,
+    offset: Duration,
+    profile: Profile,
+    saved: bool,
+}
+
+impl ProfilerCallback {
+    /// Creates the callback, recording `num_iterations` iterations after the `warmup` first ones.
+    pub fn new<P: Into<PathBuf>>(directory: P, warmup: usize, num_iterations: usize) -> Self {
+        Self {
+            directory: directory.into(),
+            warmup,
+            num_iterations,
+            iteration: 0,
+            offset: Duration::ZERO,
+            profile: Profile::default(),
+            saved: false,
+        }
+    }
+
+    fn is_profiled(&self) -> bool {
+        self.iteration > self.warmup && self.iteration <= self.warmup + self.num_iterations
+    }
+
+    /// Append the profile of an iteration, placing it after the previous ones in the trace.
+    fn append(&mut self, profile: Profile) {
+        for mut record in profile.records {
+            record.start += self.offset;
+            self.profile.records.push(record);
+        }
+        self.offset += profile.duration;
+        self.profile.duration += profile.duration;
+    }
+
+    fn save(&mut self) {
+        self.saved = true;
+
+        if self.profile.records.is_empty() {
+            log::warn!(
+                "No tensor operation was profiled, the backend must be wrapped with the profiler."
+            );
+            return;
+        }
+
+        let result = std::fs::create_dir_all(&self.directory)
+            .and_then(|_| {
+                self.profile
+                    .save_chrome_trace(self.directory.join("trace.json"))
+            })
+            .and_then(|_| {
+                std::fs::write(
+                    self.directory.join("summary.txt"),
+                    self.profile.table(usize::MAX),
+                )
+            });
+        if let Err(err) = result {
+            log::error!(
+                "Can't save the profile to {}: {err}",
+                self.directory.display()
+            );
+        }
+
+        log::info!(
+            "Profiled {} operations over {} iterations in {:?}:\n{}",
+            self.profile.records.len(),
+            self.num_iterations,
+            self.profile.duration,
+            self.profile.table(NUM_LOGGED_OPERATIONS)
+        );
+    }
+}
+
+impl<B, M, O> Callback<B, M, O> for ProfilerCallback
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B>,
+    O: Optimizer<M, B>,
+{
+    fn on_batch_begin(&mut self, _context: &CallbackContext<'_, M, O>) {
+        self.iteration += 1;
+
+        if self.is_profiled() {
+            start_recording();
+        }
+    }
+
+    fn on_batch_end(&mut self, _context: &CallbackContext<'_, M, O>) {
+        if !self.is_profiled() {
+            return;
+        }
+
+        let profile = stop_recording();
+        self.append(profile);
+
+        if self.iteration == self.warmup + self.num_iterations {
+            self.save();
+        }
+    }
+}
+
+impl Drop for ProfilerCallback {
+    fn drop(&mut self) {
+        // The training ended before all the iterations were recorded.
+        if !self.saved && self.iteration > self.warmup {
+            self.save();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::logger::InMemoryMetricLogger;
+    use crate::test_utils::{NoRenderer, TestBatcher, TestModel};
+    use crate::LearnerBuilder;
+    use burn_core::data::dataloader::DataLoaderBuilder;
+    use burn_core::data::dataset::InMemDataset;
+    use burn_core::optim::SgdConfig;
+    use burn_profiler::Profiler;
+
+    type TB = burn_autodiff::Autodiff<Profiler<crate::TestBackend>>;
+
+    #[test]
+    fn should_save_the_profile_of_the_training_steps() {
+        let device = Default::default();
+        let model = TestModel::<TB>::new(&device);
+        let dataloader_train = DataLoaderBuilder::new(TestBatcher)
+            .batch_size(3)
+            .build(InMemDataset::new(vec![1.0; 9]));
+        let dataloader_valid = DataLoaderBuilder::new(TestBatcher)
+            .batch_size(2)
+            .build(InMemDataset::new(vec![1.0]));
+        let directory = tempfile::tempdir().unwrap();
+        let directory = directory.path();
+
+        let learner = LearnerBuilder::new(directory.to_str().unwrap())
+            .metric_loggers(InMemoryMetricLogger::new(), InMemoryMetricLogger::new())
+            .renderer(NoRenderer)
+            .log_to_file(false)
+            .num_epochs(1)
+            .profile(1, 2)
+            .build(model, SgdConfig::new().init(), 1e-2);
+        learner.fit(dataloader_train, dataloader_valid);
+
+        let trace = std::fs::read_to_string(directory.join("profile/trace.json")).unwrap();
+        let trace: serde_json::Value = serde_json::from_str(&trace).unwrap();
+        let events = trace["traceEvents"].as_array().unwrap();
+        let summary = std::fs::read_to_string(directory.join("profile/summary.txt")).unwrap();
+
+        // The two profiled iterations each compute the linear layer of a batch of 3 items.
+        let matmuls = events
+            .iter()
+            .filter(|event| event["name"] == "float_matmul")
+            .filter(|event| event["args"]["shapes"] == serde_json::json!([[3, 2], [2, 1]]))
+            .count();
+        assert_eq!(matmuls, 2);
+        assert!(summary.contains("float_matmul"));
+    }
+}
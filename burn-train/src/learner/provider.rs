@@ -0,0 +1,225 @@
+use crate::metric::store::EventStoreClient;
+use burn_core::data::dataloader::DataLoader;
+use std::any::Any;
+use std::sync::Arc;
+
+/// Provides the dataloader of each epoch, to change the dataset mixture, the batch size or the
+/// sequence length between epochs, such as for curriculum learning or progressive resizing.
+///
+/// It's implemented for closures receiving the epoch and the event store, to read the metrics
+/// of the previous epochs.
+///
+/// # Notes
+///
+/// When resuming from a checkpoint in the middle of an epoch, the dataloader of the epoch is
+/// provided again and the batches already trained on are skipped, so the provider should return
+/// the same dataloader for the same epoch and metrics. The shuffling is restored from the number
+/// of iterators created from that dataloader before the checkpoint, which counts the consecutive
+/// epochs the provider returned the same dataloader instance.
+pub trait DataLoaderProvider<I>: Send {
+    /// The dataloader of the given epoch, starting at `1`.
+    fn dataloader(&mut self, epoch: usize, store: &EventStoreClient) -> Arc<dyn DataLoader<I>>;
+}
+
+impl<I, F> DataLoaderProvider<I> for F
+where
+    F: FnMut(usize, &EventStoreClient) -> Arc<dyn DataLoader<I>> + Send,
+{
+    fn dataloader(&mut self, epoch: usize, store: &EventStoreClient) -> Arc<dyn DataLoader<I>> {
+        self(epoch, store)
+    }
+}
+
+/// A [provider](DataLoaderProvider) registered with the builder, which doesn't know the input
+/// type of the dataloaders.
+pub(crate) type AnyDataLoaderProvider = Box<dyn Any + Send>;
+
+/// Erase the input type of the provider.
+pub(crate) fn erase_provider<I: 'static, P: DataLoaderProvider<I> + 'static>(
+    provider: P,
+) -> AnyDataLoaderProvider {
+    let provider: Box<dyn DataLoaderProvider<I>> = Box::new(provider);
+    Box::new(provider)
+}
+
+/// The dataloaders of the epochs, from the provider if any, or the dataloader given to
+/// [fit](crate::Learner::fit) otherwise.
+pub(crate) struct EpochDataLoaders<I> {
+    default: Arc<dyn DataLoader<I>>,
+    provider: Option<Box<dyn DataLoaderProvider<I>>>,
+    /// The epoch, its dataloader and the number of iterators created from the dataloader in
+    /// the previous epochs.
+    current: Option<(usize, Arc<dyn DataLoader<I>>, usize)>,
+}
+
+impl<I: 'static> EpochDataLoaders<I> {
+    /// Restore the input type of the provider registered with the builder.
+    ///
+    /// # Panics
+    ///
+    /// If the provider doesn't provide dataloaders of the given input type.
+    pub(crate) fn new(
+        default: Arc<dyn DataLoader<I>>,
+        provider: Option<AnyDataLoaderProvider>,
+        split: &str,
+    ) -> Self {
+        let provider = provider.map(|provider| {
+            *provider
+                .downcast::<Box<dyn DataLoaderProvider<I>>>()
+                .unwrap_or_else(|_| {
+                    panic!(
+                        "The {split} dataloader provider should provide the dataloader given to fit."
+                    )
+                })
+        });
+
+        Self {
+            default,
+            provider,
+            current: None,
+        }
+    }
+
+    /// The dataloader of the epoch, with the provider called once per epoch.
+    pub(crate) fn epoch(
+        &mut self,
+        epoch: usize,
+        store: &EventStoreClient,
+    ) -> Arc<dyn DataLoader<I>> {
+        if let Some((current, dataloader, _)) = &self.current {
+            if *current == epoch {
+                return dataloader.clone();
+            }
+        }
+
+        let (dataloader, num_iterators) = match self.provider.as_mut() {
+            // The default dataloader is iterated once per epoch since the first one.
+            None => (self.default.clone(), epoch.saturating_sub(1)),
+            Some(provider) => {
+                let dataloader = provider.dataloader(epoch, store);
+                let num_iterators = match &self.current {
+                    Some((_, previous, num_iterators)) if Arc::ptr_eq(previous, &dataloader) => {
+                        num_iterators + 1
+                    }
+                    _ => 0,
+                };
+                (dataloader, num_iterators)
+            }
+        };
+        self.current = Some((epoch, dataloader.clone(), num_iterators));
+
+        dataloader
+    }
+
+    /// The number of iterators created from the dataloader of the current epoch in the previous
+    /// epochs, which determines its shuffling.
+    pub(crate) fn num_iterators(&self) -> usize {
+        self.current
+            .as_ref()
+            .map(|(_, _, num_iterators)| *num_iterators)
+            .unwrap_or(0)
+    }
+
+    /// Restore the number of iterators created from the dataloader of the current epoch, saved
+    /// in the [training state](crate::checkpoint::TrainingStateRecord) of a checkpoint.
+    pub(crate) fn resume(&mut self, num_iterators: usize) {
+        if let Some((_, _, current)) = &mut self.current {
+            *current = num_iterators;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logger::InMemoryMetricLogger;
+    use crate::metric::store::LogEventStore;
+    use crate::test_utils::{NoRenderer, TestBatcher, TestModel};
+    use crate::{Callback, CallbackContext, LearnerBuilder, TestAutodiffBackend};
+    use burn_core::data::dataloader::DataLoaderBuilder;
+    use burn_core::data::dataset::InMemDataset;
+    use burn_core::optim::{Optimizer, SgdConfig};
+    use burn_core::tensor::Tensor;
+    use std::sync::Mutex;
+
+    type TB = TestAutodiffBackend;
+
+    /// Record the number of iterations of each epoch.
+    #[derive(Clone, Default)]
+    struct IterationRecorder {
+        iterations: Arc<Mutex<Vec<(usize, usize)>>>,
+    }
+
+    impl<O: Optimizer<TestModel<TB>, TB>> Callback<TB, TestModel<TB>, O> for IterationRecorder {
+        fn on_batch_end(&mut self, context: &CallbackContext<'_, TestModel<TB>, O>) {
+            let mut iterations = self.iterations.lock().unwrap();
+            match iterations.last_mut() {
+                Some((epoch, count)) if *epoch == context.epoch => *count += 1,
+                _ => iterations.push((context.epoch, 1)),
+            }
+        }
+    }
+
+    fn dataloader(batch_size: usize) -> Arc<dyn DataLoader<Tensor<TB, 2>>> {
+        DataLoaderBuilder::new(TestBatcher)
+            .batch_size(batch_size)
+            .build(InMemDataset::new(vec![1.0, 2.0, 3.0, 4.0]))
+    }
+
+    #[test]
+    fn should_count_the_iterators_created_from_the_dataloader_of_the_epoch() {
+        let store = EventStoreClient::new(LogEventStore::default());
+        let provider =
+            erase_provider::<Tensor<TB, 2>, _>(|epoch: usize, _store: &EventStoreClient| {
+                dataloader(epoch)
+            });
+        let mut provided = EpochDataLoaders::new(dataloader(4), Some(provider), "training");
+        let mut default = EpochDataLoaders::new(dataloader(4), None, "training");
+
+        let mut counts = Vec::new();
+        for epoch in [2, 3, 3] {
+            provided.epoch(epoch, &store);
+            default.epoch(epoch, &store);
+            counts.push((provided.num_iterators(), default.num_iterators()));
+        }
+        provided.resume(5);
+
+        // A new dataloader is provided each epoch, while the default one is iterated since the
+        // first epoch.
+        assert_eq!(counts, vec![(0, 1), (0, 2), (0, 2)]);
+        assert_eq!(provided.num_iterators(), 5);
+    }
+
+    #[test]
+    fn should_provide_the_dataloader_of_each_epoch() {
+        let device = Default::default();
+        let model = TestModel::<TB>::new(&device);
+        let epochs = Arc::new(Mutex::new(Vec::new()));
+        let recorder = IterationRecorder::default();
+        let directory = tempfile::tempdir().unwrap();
+
+        let provided = epochs.clone();
+        let learner = LearnerBuilder::new(directory.path().to_str().unwrap())
+            .metric_loggers(InMemoryMetricLogger::new(), InMemoryMetricLogger::new())
+            .renderer(NoRenderer)
+            .log_to_file(false)
+            .num_epochs(3)
+            .callback(recorder.clone())
+            .dataloader_train_provider(move |epoch: usize, _store: &EventStoreClient| {
+                provided.lock().unwrap().push(epoch);
+                // Progressively increase the batch size.
+                dataloader(epoch)
+            })
+            .build(model, SgdConfig::new().init(), 1e-2);
+        let dataloader_valid = DataLoaderBuilder::new(TestBatcher)
+            .batch_size(2)
+            .build(InMemDataset::new(vec![1.0]));
+        learner.fit(dataloader(4), dataloader_valid);
+
+        assert_eq!(*epochs.lock().unwrap(), vec![1, 2, 3]);
+        assert_eq!(
+            *recorder.iterations.lock().unwrap(),
+            vec![(1, 4), (2, 2), (3, 2)]
+        );
+    }
+}
@@ -1,4 +1,6 @@
-use crate::metric::{Adaptor, LossInput};
+use crate::metric::{
+    Adaptor, LossInput, MeanAbsoluteErrorInput, RSquaredInput, RootMeanSquaredErrorInput,
+};
 use burn_core::tensor::backend::Backend;
 use burn_core::tensor::Tensor;
 
@@ -20,3 +22,21 @@ impl<B: Backend> Adaptor<LossInput<B>> for RegressionOutput<B> {
         LossInput::new(self.loss.clone())
     }
 }
+
+impl<B: Backend> Adaptor<MeanAbsoluteErrorInput<B>> for RegressionOutput<B> {
+    fn adapt(&self) -> MeanAbsoluteErrorInput<B> {
+        MeanAbsoluteErrorInput::new(self.output.clone(), self.targets.clone())
+    }
+}
+
+impl<B: Backend> Adaptor<RootMeanSquaredErrorInput<B>> for RegressionOutput<B> {
+    fn adapt(&self) -> RootMeanSquaredErrorInput<B> {
+        RootMeanSquaredErrorInput::new(self.output.clone(), self.targets.clone())
+    }
+}
+
+impl<B: Backend> Adaptor<RSquaredInput<B>> for RegressionOutput<B> {
+    fn adapt(&self) -> RSquaredInput<B> {
+        RSquaredInput::new(self.output.clone(), self.targets.clone())
+    }
+}
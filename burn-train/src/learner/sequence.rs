@@ -0,0 +1,120 @@
+use crate::metric::{Adaptor, BleuInput, ErrorRateInput, LossInput, PerplexityInput};
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{Int, Tensor};
+
+/// Sequence output adapted for multiple metrics, such as the output of a sequence-to-sequence
+/// model trained with teacher forcing.
+#[derive(new)]
+pub struct SequenceOutput<B: Backend> {
+    /// The loss, which is the mean cross-entropy per token.
+    pub loss: Tensor<B, 1>,
+
+    /// The output, with the shape `[batch_size, seq_length, vocab_size]`.
+    pub output: Tensor<B, 3>,
+
+    /// The targets, with the shape `[batch_size, seq_length]`.
+    pub targets: Tensor<B, 2, Int>,
+
+    /// The padding token, ignored by the metrics.
+    #[new(default)]
+    pub pad_token: Option<usize>,
+}
+
+impl<B: Backend> SequenceOutput<B> {
+    /// Sets the pad token.
+    pub fn with_pad_token(mut self, index: usize) -> Self {
+        self.pad_token = Some(index);
+        self
+    }
+
+    /// The predicted and target tokens of each sequence, without the positions where the target
+    /// is padding.
+    fn sequences(&self) -> (Vec<Vec<usize>>, Vec<Vec<usize>>) {
+        let [batch_size, seq_length, _vocab_size] = self.output.dims();
+        let predictions = self
+            .output
+            .clone()
+            .argmax(2)
+            .into_data()
+            .convert::<i64>()
+            .value;
+        let targets = self.targets.clone().into_data().convert::<i64>().value;
+
+        (0..batch_size)
+            .map(|row| {
+                (row * seq_length..(row + 1) * seq_length)
+                    .filter(|index| Some(targets[*index] as usize) != self.pad_token)
+                    .map(|index| (predictions[index] as usize, targets[index] as usize))
+                    .unzip::<_, _, Vec<_>, Vec<_>>()
+            })
+            .unzip()
+    }
+
+    fn num_tokens(&self) -> usize {
+        match self.pad_token {
+            Some(pad_token) => {
+                let targets = self.targets.clone().into_data().convert::<i64>().value;
+                targets
+                    .into_iter()
+                    .filter(|target| *target != pad_token as i64)
+                    .count()
+            }
+            None => self.targets.shape().num_elements(),
+        }
+    }
+}
+
+impl<B: Backend> Adaptor<LossInput<B>> for SequenceOutput<B> {
+    fn adapt(&self) -> LossInput<B> {
+        LossInput::new(self.loss.clone())
+    }
+}
+
+impl<B: Backend> Adaptor<PerplexityInput<B>> for SequenceOutput<B> {
+    fn adapt(&self) -> PerplexityInput<B> {
+        PerplexityInput::new(self.loss.clone(), self.num_tokens())
+    }
+}
+
+impl<B: Backend> Adaptor<BleuInput> for SequenceOutput<B> {
+    fn adapt(&self) -> BleuInput {
+        let (predictions, references) = self.sequences();
+        BleuInput::new(predictions, references)
+    }
+}
+
+impl<B: Backend> Adaptor<ErrorRateInput> for SequenceOutput<B> {
+    fn adapt(&self) -> ErrorRateInput {
+        let (predictions, references) = self.sequences();
+        ErrorRateInput::new(predictions, references)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn should_remove_padding_from_sequences() {
+        let device = Default::default();
+        let output = SequenceOutput::<TestBackend>::new(
+            Tensor::zeros([1], &device),
+            Tensor::from_data(
+                [
+                    [[0.9, 0.1, 0.0], [0.1, 0.0, 0.9], [0.9, 0.0, 0.1]],
+                    [[0.0, 0.9, 0.1], [0.9, 0.0, 0.1], [0.0, 0.1, 0.9]],
+                ],
+                &device,
+            ),
+            Tensor::from_data([[0, 1, 2], [1, 2, 2]], &device),
+        )
+        .with_pad_token(2);
+
+        let (predictions, references) = output.sequences();
+
+        assert_eq!(predictions, vec![vec![0, 2], vec![1]]);
+        assert_eq!(references, vec![vec![0, 1], vec![1]]);
+        assert_eq!(output.num_tokens(), 3);
+    }
+}
@@ -0,0 +1,244 @@
+use burn_core::{
+    module::{AutodiffModule, ModuleVisitor, ParamId},
+    optim::GradientsParams,
+    tensor::{
+        backend::{AutodiffBackend, Backend},
+        Tensor,
+    },
+};
+use hashbrown::HashSet;
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::Mutex;
+
+type InnerBackend<B> = <B as AutodiffBackend>::InnerBackend;
+
+/// Collective operations used to synchronize the gradients of the model replicas trained on
+/// different devices.
+///
+/// Each replica is identified by its rank, which is the index of its device, the rank `0` being
+/// the main device. Every rank must call the operation for it to complete.
+pub trait Collective<B: AutodiffBackend>: Send + Sync {
+    /// The number of replicas taking part in the collective operations.
+    fn num_ranks(&self) -> usize;
+
+    /// Sum the gradients of all ranks on the main device.
+    ///
+    /// # Returns
+    ///
+    /// The sum of the gradients on the rank `0`, and `None` on the other ranks.
+    ///
+    /// # Notes
+    ///
+    /// The gradients of the parameters missing on a rank are considered to be zeros.
+    fn reduce<M: AutodiffModule<B>>(
+        &self,
+        rank: usize,
+        module: &M,
+        grads: GradientsParams,
+    ) -> Option<GradientsParams>;
+}
+
+/// In-process ring [reduce](Collective::reduce).
+///
+/// The gradients of each rank are flattened into a single buffer, which is summed while passing
+/// around the ring from the rank `1` to the rank `0`, so that each rank sends and receives the
+/// gradients at most once.
+pub struct RingCollective<B: AutodiffBackend> {
+    senders: Vec<Sender<Buffer<B>>>,
+    receivers: Vec<Mutex<Receiver<Buffer<B>>>>,
+}
+
+type Buffer<B> = Tensor<InnerBackend<B>, 1>;
+
+impl<B: AutodiffBackend> RingCollective<B> {
+    /// Create a ring between the given number of ranks.
+    pub fn new(num_ranks: usize) -> Self {
+        let (senders, receivers) = (0..num_ranks)
+            .map(|_| {
+                let (sender, receiver) = channel();
+                (sender, Mutex::new(receiver))
+            })
+            .unzip();
+
+        Self { senders, receivers }
+    }
+
+    /// Send a buffer to the next rank of the ring.
+    fn send(&self, rank: usize, buffer: Buffer<B>) {
+        self.senders[rank]
+            .send(buffer)
+            .expect("The next rank of the ring should be alive.");
+    }
+
+    /// Receive a buffer from the previous rank of the ring.
+    fn recv(&self, rank: usize, device: &<InnerBackend<B> as Backend>::Device) -> Buffer<B> {
+        let previous = (rank + self.num_ranks() - 1) % self.num_ranks();
+        let receiver = self.receivers[previous]
+            .lock()
+            .expect("The ring should not be poisoned.");
+
+        receiver
+            .recv()
+            .expect("The previous rank of the ring should be alive.")
+            .to_device(device)
+    }
+}
+
+impl<B: AutodiffBackend> Collective<B> for RingCollective<B> {
+    fn num_ranks(&self) -> usize {
+        self.senders.len()
+    }
+
+    fn reduce<M: AutodiffModule<B>>(
+        &self,
+        rank: usize,
+        module: &M,
+        mut grads: GradientsParams,
+    ) -> Option<GradientsParams> {
+        let mut flattener = GradientsFlattener::<B> {
+            grads: &mut grads,
+            ids: Vec::new(),
+            tensors: Vec::new(),
+        };
+        module.visit(&mut flattener);
+        let GradientsFlattener { ids, tensors, .. } = flattener;
+
+        let Some(device) = tensors.first().map(Tensor::device) else {
+            return (rank == 0).then_some(grads);
+        };
+        let mut buffer = Tensor::cat(tensors, 0);
+
+        // The rank `1` starts the ring, which ends on the rank `0`.
+        if self.num_ranks() > 1 && rank != 1 {
+            buffer = buffer + self.recv(rank, &device);
+        }
+        if rank != 0 {
+            self.send(rank, buffer);
+            return None;
+        }
+
+        Some(unflatten(module, ids, buffer))
+    }
+}
+
+/// Register the gradients of the given parameters from a flattened buffer.
+fn unflatten<B: AutodiffBackend, M: AutodiffModule<B>>(
+    module: &M,
+    ids: Vec<ParamId>,
+    buffer: Tensor<InnerBackend<B>, 1>,
+) -> GradientsParams {
+    let mut unflattener = GradientsUnflattener::<B> {
+        grads: GradientsParams::new(),
+        ids: ids.into_iter().collect(),
+        buffer,
+        offset: 0,
+    };
+    module.visit(&mut unflattener);
+
+    unflattener.grads
+}
+
+/// Flatten the gradients of all the float parameters of a module in the visit order, using zeros
+/// for the missing gradients so that all ranks have the same layout.
+struct GradientsFlattener<'a, B: AutodiffBackend> {
+    grads: &'a mut GradientsParams,
+    ids: Vec<ParamId>,
+    tensors: Vec<Tensor<InnerBackend<B>, 1>>,
+}
+
+impl<'a, B: AutodiffBackend> ModuleVisitor<B> for GradientsFlattener<'a, B> {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        let grad = match self.grads.remove::<InnerBackend<B>, D>(id) {
+            Some(grad) => {
+                self.ids.push(id.clone());
+                grad
+            }
+            None => Tensor::zeros(tensor.shape(), &tensor.device()),
+        };
+
+        self.tensors.push(grad.flatten(0, D - 1));
+    }
+}
+
+/// Register the gradients of the parameters that had one before being flattened.
+struct GradientsUnflattener<B: AutodiffBackend> {
+    grads: GradientsParams,
+    ids: HashSet<ParamId>,
+    buffer: Tensor<InnerBackend<B>, 1>,
+    offset: usize,
+}
+
+impl<B: AutodiffBackend> ModuleVisitor<B> for GradientsUnflattener<B> {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        let shape = tensor.shape();
+        let start = self.offset;
+        self.offset += shape.num_elements();
+
+        if self.ids.contains(id) {
+            let grad = self.buffer.clone().narrow(0, start, self.offset - start);
+            self.grads
+                .register::<InnerBackend<B>, D>(id.clone(), grad.reshape(shape));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestAutodiffBackend;
+    use burn_core::{
+        module::list_param_ids,
+        nn::{Linear, LinearConfig},
+        tensor::Distribution,
+    };
+    use std::sync::Arc;
+
+    type TB = TestAutodiffBackend;
+
+    #[test]
+    fn ring_reduce_should_sum_gradients_of_all_ranks_on_the_main_rank() {
+        for num_ranks in [1, 2, 3, 5] {
+            let device = Default::default();
+            let layer: Linear<TB> = LinearConfig::new(3, 2).with_bias(true).init(&device);
+            let weight_id = list_param_ids(&layer)[0].clone();
+            let grads = (0..num_ranks)
+                .map(|_| {
+                    let input = Tensor::<TB, 2>::random([4, 3], Distribution::Default, &device);
+                    let loss = layer.forward(input).sum();
+                    GradientsParams::from_grads(loss.backward(), &layer)
+                })
+                .collect::<Vec<_>>();
+            let expected = grads
+                .iter()
+                .map(|grads| grads.get::<InnerBackend<TB>, 2>(&weight_id).unwrap())
+                .reduce(|a, b| a + b)
+                .unwrap();
+
+            let ring = Arc::new(RingCollective::<TB>::new(num_ranks));
+            let handles = grads
+                .into_iter()
+                .enumerate()
+                .map(|(rank, grads)| {
+                    let ring = ring.clone();
+                    let layer = layer.clone();
+                    std::thread::spawn(move || ring.reduce(rank, &layer, grads))
+                })
+                .collect::<Vec<_>>();
+
+            for (rank, handle) in handles.into_iter().enumerate() {
+                let Some(reduced) = handle.join().unwrap() else {
+                    assert_ne!(rank, 0);
+                    continue;
+                };
+
+                assert_eq!(rank, 0);
+                assert_eq!(reduced.len(), 2);
+                reduced
+                    .get::<InnerBackend<TB>, 2>(&weight_id)
+                    .unwrap()
+                    .into_data()
+                    .assert_approx_eq(&expected.clone().into_data(), 5);
+            }
+        }
+    }
+}
@@ -1,2 +1,4 @@
+/// The collective module.
+pub mod collective;
 /// The trainer module.
 pub mod train;
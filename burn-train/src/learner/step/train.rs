@@ -1,19 +1,37 @@
-use crate::{TrainOutput, TrainStep};
+use crate::{Collective, RingCollective, TrainStep, TrainStepContext};
 use burn_core::{
-    data::dataloader::DataLoaderIterator, module::AutodiffModule, tensor::backend::AutodiffBackend,
+    data::dataloader::DataLoaderIterator, module::AutodiffModule, optim::GradientsParams,
+    tensor::backend::AutodiffBackend,
 };
 use std::sync::mpsc::{Receiver, Sender};
+use std::sync::Arc;
 use std::thread::spawn;
 
 /// Multi devices train step.
+///
+/// Each device has its own replica of the model, kept by a worker thread between steps. The
+/// gradients computed by the replicas are summed on the main device with a
+/// [collective](Collective) [reduce](Collective::reduce), where the model is updated by the
+/// optimizer. The replicas are then synchronized with the updated model, using
+/// [sync](MultiDevicesTrainStep::sync).
 pub struct MultiDevicesTrainStep<B: AutodiffBackend, M, TI, TO> {
     workers: Vec<Worker<B, M, TI>>,
-    receiver: Receiver<TrainOutput<TO>>,
+    receiver: Receiver<Output<TO>>,
 }
 
-struct Message<M, TI> {
-    item: TI,
-    model: M,
+enum Message<M, TI> {
+    /// Train the replica on an item with the context of the step, or only take part in the
+    /// reduce when there is no item left for the worker.
+    Step(Option<TI>, TrainStepContext),
+    /// Replace the replica with the given model.
+    Sync(M),
+}
+
+struct Output<TO> {
+    rank: usize,
+    item: Option<TO>,
+    grads: Option<GradientsParams>,
+    loss_scale: Option<f64>,
 }
 
 struct Worker<B: AutodiffBackend, M, TI> {
@@ -26,36 +44,58 @@ where
     B: AutodiffBackend,
     M: AutodiffModule<B>,
 {
-    fn register(&self, item: TI, model: &M) {
-        let message = Message {
-            item,
-            model: model.clone(),
-        };
+    fn register(&self, message: Message<M, TI>) {
         self.sender_input.send(message).unwrap();
     }
 
-    fn start<TO>(
+    fn start<TO, C>(
         &self,
-        sender_output: Sender<TrainOutput<TO>>,
+        rank: usize,
+        collective: Arc<C>,
+        sender_output: Sender<Output<TO>>,
         receiver_input: Receiver<Message<M, TI>>,
     ) where
         TI: Send + 'static,
         TO: Send + 'static,
         M: TrainStep<TI, TO> + Send + 'static,
+        C: Collective<B> + 'static,
     {
         let device = self.device.clone();
 
-        spawn(move || loop {
-            match receiver_input.recv() {
-                Ok(item) => {
-                    let step = item.model.fork(&device);
-                    let output = step.step(item.item);
+        spawn(move || {
+            let mut replica: Option<M> = None;
 
-                    sender_output.send(output).unwrap();
-                }
-                Err(_err) => {
-                    log::info!("Closing thread on device {:?}", device);
-                    break;
+            loop {
+                match receiver_input.recv() {
+                    Ok(Message::Sync(model)) => {
+                        replica = Some(model.fork(&device));
+                    }
+                    Ok(Message::Step(item, context)) => {
+                        let model = replica
+                            .as_ref()
+                            .expect("The replica should be synchronized before training.");
+                        let (item, grads, loss_scale) = match item {
+                            Some(item) => {
+                                let output = model.step_with_context(item, &context);
+                                (Some(output.item), output.grads, output.loss_scale)
+                            }
+                            None => (None, GradientsParams::new(), None),
+                        };
+                        let grads = collective.reduce(rank, model, grads);
+
+                        sender_output
+                            .send(Output {
+                                rank,
+                                item,
+                                grads,
+                                loss_scale,
+                            })
+                            .unwrap();
+                    }
+                    Err(_err) => {
+                        log::info!("Closing thread on device {:?}", device);
+                        break;
+                    }
                 }
             }
         });
@@ -69,37 +109,80 @@ where
     TI: Send + 'static,
     TO: Send + 'static,
 {
-    /// Create a new multi devices train step.
+    /// Create a new multi devices train step using a [ring reduce](RingCollective).
     ///
     /// # Arguments
     ///
-    /// * `devices` - Devices.
+    /// * `devices` - Devices, the first one being the main device.
+    /// * `model` - The model replicated on each device.
     ///
     /// # Returns
     ///
     /// MultiDevicesTrainStep instance.
-    pub fn new(devices: &[B::Device]) -> Self
+    pub fn new(devices: &[B::Device], model: &M) -> Self
     where
         TI: Send + 'static,
     {
+        Self::with_collective(devices, model, RingCollective::new(devices.len()))
+    }
+
+    /// Create a new multi devices train step using the given [collective](Collective) to sum the
+    /// gradients of the replicas.
+    ///
+    /// # Arguments
+    ///
+    /// * `devices` - Devices, the first one being the main device.
+    /// * `model` - The model replicated on each device.
+    /// * `collective` - The collective, with one rank per device.
+    ///
+    /// # Returns
+    ///
+    /// MultiDevicesTrainStep instance.
+    pub fn with_collective<C>(devices: &[B::Device], model: &M, collective: C) -> Self
+    where
+        C: Collective<B> + 'static,
+    {
+        assert_eq!(
+            collective.num_ranks(),
+            devices.len(),
+            "The collective should have one rank per device."
+        );
+
+        let collective = Arc::new(collective);
         let (sender_output, receiver_output) = std::sync::mpsc::channel();
         let workers = devices
             .iter()
-            .map(|device| {
+            .enumerate()
+            .map(|(rank, device)| {
                 let (sender_input, receiver_input) = std::sync::mpsc::channel();
                 let worker = Worker {
                     sender_input,
                     device: device.clone(),
                 };
 
-                worker.start(sender_output.clone(), receiver_input);
+                worker.start(
+                    rank,
+                    collective.clone(),
+                    sender_output.clone(),
+                    receiver_input,
+                );
                 worker
             })
             .collect();
 
-        Self {
+        let step = Self {
             workers,
             receiver: receiver_output,
+        };
+        step.sync(model);
+        step
+    }
+
+    /// Synchronize the replicas with the given model, which should be done after each
+    /// optimizer step.
+    pub fn sync(&self, model: &M) {
+        for worker in self.workers.iter() {
+            worker.register(Message::Sync(model.clone()));
         }
     }
 
@@ -108,32 +191,150 @@ where
     /// # Arguments
     ///
     /// * `dataloader` - Dataloader.
-    /// * `model` - Model.
+    /// * `context` - The context of the [training step](TrainStep::step_with_context).
     ///
     /// # Returns
     ///
-    /// Outputs.
+    /// The outputs of each device in the order of the devices, along with the sum of their
+    /// gradients on the main device and the [scale of the loss](crate::TrainOutput::loss_scale)
+    /// of the main device. The outputs are empty when the dataloader is exhausted.
     pub fn step<'a>(
         &self,
         dataloader: &mut Box<dyn DataLoaderIterator<TI> + 'a>,
-        model: &M,
-    ) -> Vec<TrainOutput<TO>> {
-        let mut num_send = 0;
+        context: &TrainStepContext,
+    ) -> (Vec<TO>, GradientsParams, Option<f64>) {
+        let items = self.load(dataloader);
+        self.run(items, context)
+    }
+
+    /// Load the items of one step from the dataloader, one for each device.
+    pub fn load<'a>(
+        &self,
+        dataloader: &mut Box<dyn DataLoaderIterator<TI> + 'a>,
+    ) -> Vec<Option<TI>> {
+        self.workers.iter().map(|_| dataloader.next()).collect()
+    }
 
-        for worker in self.workers.iter() {
-            if let Some(item) = dataloader.next() {
-                worker.register(item, model);
-                num_send += 1;
-            }
+    /// Run one step with the [loaded](MultiDevicesTrainStep::load) items, see
+    /// [step](MultiDevicesTrainStep::step).
+    pub fn run(
+        &self,
+        items: Vec<Option<TI>>,
+        context: &TrainStepContext,
+    ) -> (Vec<TO>, GradientsParams, Option<f64>) {
+        if items.iter().all(Option::is_none) {
+            return (Vec::new(), GradientsParams::new(), None);
         }
 
-        let mut outputs = Vec::with_capacity(num_send);
+        // Every worker takes part in the reduce, even without an item.
+        for (worker, item) in self.workers.iter().zip(items) {
+            worker.register(Message::Step(item, *context));
+        }
+
+        let mut outputs = (0..self.workers.len())
+            .map(|_| self.receiver.recv().unwrap())
+            .collect::<Vec<_>>();
+        outputs.sort_by_key(|output| output.rank);
+
+        // The main device always has an item, since the items are distributed in order.
+        let mut grads = GradientsParams::new();
+        let mut loss_scale = None;
+        let items = outputs
+            .into_iter()
+            .filter_map(|output| {
+                if let Some(reduced) = output.grads {
+                    grads = reduced;
+                    loss_scale = output.loss_scale;
+                }
+                output.item
+            })
+            .collect();
 
-        for _ in 0..num_send {
-            let output = self.receiver.recv().unwrap();
-            outputs.push(output);
+        (items, grads, loss_scale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestModel;
+    use crate::TestAutodiffBackend;
+    use burn_core::{
+        data::dataloader::{DataLoader, Progress},
+        module::list_param_ids,
+        tensor::{backend::Backend, Tensor},
+    };
+
+    type TB = TestAutodiffBackend;
+    type Inner = <TB as AutodiffBackend>::InnerBackend;
+
+    struct Items<B: Backend>(Vec<Tensor<B, 2>>);
+
+    impl<B: Backend> DataLoader<Tensor<B, 2>> for Items<B> {
+        fn iter<'a>(&'a self) -> Box<dyn DataLoaderIterator<Tensor<B, 2>> + 'a> {
+            Box::new(ItemsIterator(self.0.clone().into_iter()))
         }
 
-        outputs
+        fn num_items(&self) -> usize {
+            self.0.len()
+        }
+    }
+
+    struct ItemsIterator<B: Backend>(std::vec::IntoIter<Tensor<B, 2>>);
+
+    impl<B: Backend> Iterator for ItemsIterator<B> {
+        type Item = Tensor<B, 2>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            self.0.next()
+        }
+    }
+
+    impl<B: Backend> DataLoaderIterator<Tensor<B, 2>> for ItemsIterator<B> {
+        fn progress(&self) -> Progress {
+            Progress::new(0, 0)
+        }
+    }
+
+    #[test]
+    fn should_sum_gradients_of_all_devices() {
+        let device = Default::default();
+        let model = TestModel::<TB>::new(&device);
+        let items = (0..3)
+            .map(|i| Tensor::<TB, 2>::from_floats([[i as f32, 1.0]], &device))
+            .collect::<Vec<_>>();
+        let weight_id = list_param_ids(&model)[0].clone();
+        let expected = items
+            .iter()
+            .map(|item| {
+                GradientsParams::from_grads(model.loss(item.clone()).backward(), &model)
+                    .get::<Inner, 2>(&weight_id)
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        let step = MultiDevicesTrainStep::new(&[device, device], &model);
+        let dataloader = Items(items);
+        let mut iterator = dataloader.iter();
+
+        let (outputs, grads, _) = step.step(&mut iterator, &TrainStepContext::default());
+        assert_eq!(outputs.len(), 2);
+        grads
+            .get::<Inner, 2>(&weight_id)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&(expected[0].clone() + expected[1].clone()).into_data(), 5);
+
+        // The last step only has an item for the first device.
+        let (outputs, grads, _) = step.step(&mut iterator, &TrainStepContext::default());
+        assert_eq!(outputs.len(), 1);
+        grads
+            .get::<Inner, 2>(&weight_id)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&expected[2].clone().into_data(), 5);
+
+        let (outputs, _, _) = step.step(&mut iterator, &TrainStepContext::default());
+        assert!(outputs.is_empty());
     }
 }
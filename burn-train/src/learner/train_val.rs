@@ -1,12 +1,33 @@
+use crate::checkpoint::iteration_seed;
 use crate::components::LearnerComponents;
+use crate::learner::epoch::EpochCheckpointing;
+use crate::learner::provider::EpochDataLoaders;
+use crate::manifest::DatasetSizes;
 use crate::metric::processor::EventProcessor;
-use crate::{Learner, TrainEpoch, ValidEpoch};
-use burn_core::data::dataloader::DataLoader;
+use crate::{Learner, LearnerCallbacks, TrainEpoch, ValidEpoch};
+use burn_core::data::dataloader::{DataLoader, DataLoaderState};
 use burn_core::module::{AutodiffModule, Module};
 use burn_core::optim::{GradientsParams, Optimizer};
-use burn_core::tensor::backend::AutodiffBackend;
+use burn_core::tensor::backend::{AutodiffBackend, Backend};
+use burn_core::tensor::Tensor;
 use std::sync::Arc;
 
+/// The context of a [training step](TrainStep::step_with_context), given by the
+/// [learner](Learner) at each iteration.
+#[derive(new, Clone, Copy, Debug, Default)]
+pub struct TrainStepContext {
+    loss_scale: Option<f64>,
+}
+
+impl TrainStepContext {
+    /// The scale to apply to the loss before the backward pass when training with
+    /// [mixed precision](crate::LearnerBuilder::mixed_precision), which is done by
+    /// [from_loss](TrainOutput::from_loss).
+    pub fn loss_scale(&self) -> Option<f64> {
+        self.loss_scale
+    }
+}
+
 /// A training output.
 pub struct TrainOutput<TO> {
     /// The gradients.
@@ -14,11 +35,19 @@ pub struct TrainOutput<TO> {
 
     /// The item.
     pub item: TO,
+
+    /// The scale applied to the loss before the backward pass, when the output is created
+    /// [from the loss](TrainOutput::from_loss) with [mixed precision](crate::LearnerBuilder::mixed_precision).
+    pub loss_scale: Option<f64>,
 }
 
 impl<TO> TrainOutput<TO> {
     /// Creates a new training output.
     ///
+    /// The gradients aren't scaled, so the output can't be used to train with
+    /// [mixed precision](crate::LearnerBuilder::mixed_precision), which requires
+    /// [from_loss](TrainOutput::from_loss).
+    ///
     /// # Arguments
     ///
     /// * `module` - The module.
@@ -34,7 +63,46 @@ impl<TO> TrainOutput<TO> {
         item: TO,
     ) -> Self {
         let grads = GradientsParams::from_grads(grads, module);
-        Self { grads, item }
+        Self {
+            grads,
+            item,
+            loss_scale: None,
+        }
+    }
+
+    /// Creates a new training output from the loss, running the backward pass.
+    ///
+    /// When training with [mixed precision](crate::LearnerBuilder::mixed_precision), the loss
+    /// is scaled by the [loss scale](TrainStepContext::loss_scale) of the context before the
+    /// backward pass, so that small gradients don't underflow. The learner unscales the gradients
+    /// before the optimizer step.
+    ///
+    /// # Arguments
+    ///
+    /// * `module` - The module.
+    /// * `loss` - The loss.
+    /// * `item` - The item.
+    /// * `context` - The context of the [training step](TrainStep::step_with_context).
+    ///
+    /// # Returns
+    ///
+    /// A new training output.
+    pub fn from_loss<B: AutodiffBackend, M: AutodiffModule<B>>(
+        module: &M,
+        loss: Tensor<B, 1>,
+        item: TO,
+        context: &TrainStepContext,
+    ) -> Self {
+        let scale = context.loss_scale();
+        let loss = match scale {
+            Some(scale) => loss.mul_scalar(scale),
+            None => loss,
+        };
+
+        Self {
+            loss_scale: scale,
+            ..Self::new(module, loss.backward(), item)
+        }
     }
 }
 
@@ -62,6 +130,24 @@ pub trait TrainStep<TI, TO> {
     ///
     /// The training output containing the model output and the gradients.
     fn step(&self, item: TI) -> TrainOutput<TO>;
+    /// Runs the training step with the [context](TrainStepContext) of the learner, which is
+    /// called by the learner instead of [step](TrainStep::step).
+    ///
+    /// Override it to train with [mixed precision](crate::LearnerBuilder::mixed_precision),
+    /// creating the output [from the loss](TrainOutput::from_loss) with the context. By default,
+    /// the context is ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `item` - The training input for the model.
+    /// * `context` - The context of the step.
+    ///
+    /// # Returns
+    ///
+    /// The training output containing the model output and the gradients.
+    fn step_with_context(&self, item: TI, _context: &TrainStepContext) -> TrainOutput<TO> {
+        self.step(item)
+    }
     /// Optimize the current module with the provided gradients and learning rate.
     ///
     /// # Arguments
@@ -97,25 +183,100 @@ pub trait ValidStep<VI, VO> {
     fn step(&self, item: VI) -> VO;
 }
 
+/// The error returned when [fitting](Learner::try_fit) a model fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LearnerError {
+    /// The training data loader of the epoch resumed from a mid-epoch checkpoint doesn't have the
+    /// same number of items as the one of the checkpoint, so the batches already trained on can't
+    /// be skipped.
+    DataLoaderMismatch {
+        /// The epoch resumed.
+        epoch: usize,
+        /// The number of items of the training data loader.
+        num_items: usize,
+        /// The number of items of the training data loader of the checkpoint.
+        num_items_checkpoint: usize,
+    },
+}
+
+impl core::fmt::Display for LearnerError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::DataLoaderMismatch {
+                epoch,
+                num_items,
+                num_items_checkpoint,
+            } => write!(
+                f,
+                "The training data loader of epoch {epoch} has {num_items} items, but the one of \
+                 the checkpoint had {num_items_checkpoint}."
+            ),
+        }
+    }
+}
+
+impl std::error::Error for LearnerError {}
+
 impl<LC: LearnerComponents> Learner<LC> {
     /// Fits the model.
     ///
+    /// See [try_fit](Learner::try_fit) to handle the errors.
+    ///
     /// # Arguments
     ///
-    /// * `dataloader_train` - The training dataloader.
-    /// * `dataloader_valid` - The validation dataloader.
+    /// * `dataloader_train` - The training dataloader, unless
+    ///   [provided per epoch](crate::LearnerBuilder::dataloader_train_provider).
+    /// * `dataloader_valid` - The validation dataloader, unless
+    ///   [provided per epoch](crate::LearnerBuilder::dataloader_valid_provider).
     ///
     /// # Returns
     ///
     /// The fitted model.
+    ///
+    /// # Panics
+    ///
+    /// When the training can't be resumed from the checkpoint, see [LearnerError].
     pub fn fit<InputTrain, InputValid, OutputTrain, OutputValid>(
-        mut self,
+        self,
         dataloader_train: Arc<dyn DataLoader<InputTrain>>,
         dataloader_valid: Arc<dyn DataLoader<InputValid>>,
     ) -> LC::Model
     where
         InputTrain: Send + 'static,
-        InputValid: Send,
+        InputValid: Send + 'static,
+        OutputTrain: Send + 'static,
+        OutputValid: Send,
+        LC::Model: TrainStep<InputTrain, OutputTrain>,
+        <LC::Model as AutodiffModule<LC::Backend>>::InnerModule: ValidStep<InputValid, OutputValid>,
+        LC::EventProcessor: EventProcessor<ItemTrain = OutputTrain, ItemValid = OutputValid>,
+    {
+        match self.try_fit(dataloader_train, dataloader_valid) {
+            Ok(model) => model,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Fits the model, returning an error when the training can't be resumed from the
+    /// checkpoint.
+    ///
+    /// # Arguments
+    ///
+    /// * `dataloader_train` - The training dataloader, unless
+    ///   [provided per epoch](crate::LearnerBuilder::dataloader_train_provider).
+    /// * `dataloader_valid` - The validation dataloader, unless
+    ///   [provided per epoch](crate::LearnerBuilder::dataloader_valid_provider).
+    ///
+    /// # Returns
+    ///
+    /// The fitted model.
+    pub fn try_fit<InputTrain, InputValid, OutputTrain, OutputValid>(
+        mut self,
+        dataloader_train: Arc<dyn DataLoader<InputTrain>>,
+        dataloader_valid: Arc<dyn DataLoader<InputValid>>,
+    ) -> Result<LC::Model, LearnerError>
+    where
+        InputTrain: Send + 'static,
+        InputValid: Send + 'static,
         OutputTrain: Send + 'static,
         OutputValid: Send,
         LC::Model: TrainStep<InputTrain, OutputTrain>,
@@ -128,6 +289,7 @@ impl<LC: LearnerComponents> Learner<LC> {
             self.model = self.model.fork(device);
         }
 
+        let mut resume = None;
         let starting_epoch = match self.checkpoint {
             Some(checkpoint) => {
                 if let Some(checkpointer) = &mut self.checkpointer {
@@ -135,39 +297,155 @@ impl<LC: LearnerComponents> Learner<LC> {
                         self.model,
                         self.optim,
                         self.lr_scheduler,
+                        &mut self.averaging,
                         &Default::default(), // Load the checkpoint on the default device.
                         checkpoint,
                     );
+                    resume = checkpointer.load_training_state(checkpoint, &Default::default());
+                }
+
+                match &resume {
+                    // Resume in the middle of the epoch of the checkpoint.
+                    Some(state) if !state.epoch_completed => checkpoint,
+                    _ => checkpoint + 1,
                 }
-                checkpoint + 1
             }
             None => 1,
         };
+        if let Some(record) = resume.as_ref().and_then(|state| state.loss_scaler.clone()) {
+            self.loss_scaler = self
+                .loss_scaler
+                .take()
+                .map(|loss_scaler| loss_scaler.load_record(record));
+        }
+        let seed = self
+            .seed
+            .or_else(|| resume.as_ref().and_then(|state| state.seed));
+        let mut dataloaders_train =
+            EpochDataLoaders::new(dataloader_train, self.provider_train.take(), "training");
+        let mut dataloaders_valid =
+            EpochDataLoaders::new(dataloader_valid, self.provider_valid.take(), "validation");
+        // The batches already trained on can only be skipped with the same data.
+        if let Some(state) = resume.as_ref().filter(|state| !state.epoch_completed) {
+            let num_items = dataloaders_train
+                .epoch(starting_epoch, &self.event_store)
+                .num_items();
+            if state.dataloader_items != num_items {
+                return Err(LearnerError::DataLoaderMismatch {
+                    epoch: starting_epoch,
+                    num_items,
+                    num_items_checkpoint: state.dataloader_items,
+                });
+            }
+        }
+        // The dataset sizes of the run are the ones of the starting epoch.
+        let manifest_epoch = starting_epoch.min(self.num_epochs).max(1);
+        self.manifest.start(
+            DatasetSizes {
+                train: dataloaders_train
+                    .epoch(manifest_epoch, &self.event_store)
+                    .num_items(),
+                valid: dataloaders_valid
+                    .epoch(manifest_epoch, &self.event_store)
+                    .num_items(),
+            },
+            seed,
+        );
+        if self.checkpoint.is_some() {
+            self.event_store.resume(starting_epoch);
+        }
+        let mut last_epoch = starting_epoch.saturating_sub(1);
+
+        let mut callbacks = LearnerCallbacks::new(&mut self.callbacks, &self.event_store)
+            .with_early_stopping(self.early_stopping.as_deref_mut());
+        callbacks.call(
+            &self.model,
+            &self.optim,
+            None,
+            starting_epoch,
+            0,
+            |callback, context| callback.on_train_begin(context),
+        );
 
         for epoch in starting_epoch..self.num_epochs + 1 {
-            let epoch_train = TrainEpoch::new(
-                dataloader_train.clone(),
+            last_epoch = epoch;
+            let dataloader_train = dataloaders_train.epoch(epoch, &self.event_store);
+
+            // Only the state of the first epoch is resumed.
+            let resume = resume.take().filter(|state| !state.epoch_completed);
+            let iteration = resume.as_ref().map(|state| state.iteration).unwrap_or(0);
+
+            if let Some(state) = &resume {
+                dataloaders_train.resume(state.dataloader_iterators);
+                log::warn!(
+                    "Resuming epoch {epoch} at iteration {iteration}, the training metrics of the \
+                     epoch only cover the iterations after the checkpoint."
+                );
+            }
+
+            let dataloader_iterators = dataloaders_train.num_iterators();
+            let mut epoch_train = TrainEpoch::new(
+                dataloader_train,
                 epoch,
                 self.num_epochs,
                 self.grad_accumulation,
             );
 
+            if self.checkpoint.is_some() && epoch == starting_epoch {
+                // Restore the shuffling of the data loader and skip the batches already trained
+                // on, since the iterators of the previous epochs weren't created.
+                epoch_train = epoch_train
+                    .with_dataloader_state(DataLoaderState::new(dataloader_iterators, iteration));
+            }
+
+            if let Some(seed) = seed {
+                LC::Backend::seed(iteration_seed(seed, epoch, iteration));
+            }
+
+            callbacks.call(
+                &self.model,
+                &self.optim,
+                None,
+                epoch,
+                iteration,
+                |callback, context| callback.on_epoch_begin(context),
+            );
+
+            let checkpointing = EpochCheckpointing {
+                checkpointer: self
+                    .checkpointer
+                    .as_mut()
+                    .map(|checkpointer| (checkpointer, self.event_store.as_ref())),
+                seed,
+                dataloader_iterators,
+                resume,
+            };
+
             if self.devices.len() > 1 {
-                (self.model, self.optim) = epoch_train.run_multi_device::<LC, OutputTrain>(
-                    self.model,
-                    self.optim,
-                    &mut self.lr_scheduler,
-                    &mut self.event_processor,
-                    self.devices.clone(),
-                    &self.interrupter,
-                )
+                (self.model, self.optim) = epoch_train
+                    .run_multi_device_checkpointed::<LC, OutputTrain>(
+                        self.model,
+                        self.optim,
+                        &mut self.lr_scheduler,
+                        &mut self.event_processor,
+                        &mut self.averaging,
+                        &mut self.loss_scaler,
+                        self.devices.clone(),
+                        &self.interrupter,
+                        checkpointing,
+                        &mut callbacks,
+                    )
             } else {
-                (self.model, self.optim) = epoch_train.run::<LC, OutputTrain>(
+                (self.model, self.optim) = epoch_train.run_checkpointed::<LC, OutputTrain>(
                     self.model,
                     self.optim,
                     &mut self.lr_scheduler,
                     &mut self.event_processor,
+                    &mut self.averaging,
+                    &mut self.loss_scaler,
                     &self.interrupter,
+                    checkpointing,
+                    &mut callbacks,
                 );
             }
 
@@ -175,11 +453,35 @@ impl<LC: LearnerComponents> Learner<LC> {
                 break;
             }
 
-            let epoch_valid = ValidEpoch::new(dataloader_valid.clone(), epoch, self.num_epochs);
-            epoch_valid.run::<LC, OutputValid>(
+            let epoch_valid = ValidEpoch::new(
+                dataloaders_valid.epoch(epoch, &self.event_store),
+                epoch,
+                self.num_epochs,
+            );
+            // Validate the averaged model when available, since it's the one to be evaluated.
+            match self
+                .averaging
+                .as_ref()
+                .and_then(|averaging| averaging.module())
+            {
+                Some(model) => epoch_valid.run_module::<LC, OutputValid>(
+                    model,
+                    &mut self.event_processor,
+                    &self.interrupter,
+                ),
+                None => epoch_valid.run::<LC, OutputValid>(
+                    &self.model,
+                    &mut self.event_processor,
+                    &self.interrupter,
+                ),
+            }
+            callbacks.call(
                 &self.model,
-                &mut self.event_processor,
-                &self.interrupter,
+                &self.optim,
+                None,
+                epoch,
+                0,
+                |callback, context| callback.on_validation_end(context),
             );
 
             if let Some(checkpointer) = &mut self.checkpointer {
@@ -187,18 +489,135 @@ impl<LC: LearnerComponents> Learner<LC> {
                     &self.model,
                     &self.optim,
                     &self.lr_scheduler,
+                    self.averaging.as_deref(),
+                    self.loss_scaler.as_ref(),
                     epoch,
+                    seed,
                     &self.event_store,
                 );
             }
+            callbacks.call(
+                &self.model,
+                &self.optim,
+                None,
+                epoch,
+                0,
+                |callback, context| callback.on_epoch_end(context),
+            );
 
-            if let Some(early_stopping) = &mut self.early_stopping {
-                if early_stopping.should_stop(epoch, &self.event_store) {
-                    break;
-                }
+            // A callback can stop the training with the interrupter.
+            if self.interrupter.should_stop() {
+                break;
             }
+
+            if callbacks.should_stop_epoch(epoch) {
+                break;
+            }
+        }
+
+        self.manifest.end(last_epoch, &self.event_store);
+
+        Ok(self.model)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logger::InMemoryMetricLogger;
+    use crate::test_utils::{NoRenderer, TestBatcher, TestModel};
+    use crate::{Callback, CallbackContext, LearnerBuilder};
+    use burn_core::data::dataloader::DataLoaderBuilder;
+    use burn_core::data::dataset::InMemDataset;
+    use burn_core::optim::{AdamConfig, DynamicLossScalerConfig};
+    use burn_core::tensor::{f16, Data};
+    use std::sync::Mutex;
+
+    type TB = burn_autodiff::Autodiff<burn_candle::Candle<f16>>;
+
+    #[derive(Clone, Default)]
+    struct Recorder {
+        hooks: Arc<Mutex<Vec<String>>>,
+        weights: Arc<Mutex<Option<Data<f32, 2>>>>,
+    }
+
+    impl Recorder {
+        fn weights(model: &TestModel<TB>) -> Data<f32, 2> {
+            model.linear.weight.val().into_data().convert()
         }
+    }
+
+    impl<O: Optimizer<TestModel<TB>, TB>> Callback<TB, TestModel<TB>, O> for Recorder {
+        fn on_batch_begin(&mut self, context: &CallbackContext<'_, TestModel<TB>, O>) {
+            *self.weights.lock().unwrap() = Some(Self::weights(context.model));
+        }
+
+        fn on_after_backward(
+            &mut self,
+            context: &CallbackContext<'_, TestModel<TB>, O>,
+            _grads: &mut GradientsParams,
+        ) {
+            let hook = format!("after_backward {}", context.iteration);
+            self.hooks.lock().unwrap().push(hook);
+        }
+
+        fn on_non_finite_gradients(
+            &mut self,
+            context: &CallbackContext<'_, TestModel<TB>, O>,
+            _grads: &GradientsParams,
+        ) {
+            let hook = format!("non_finite_gradients {}", context.iteration);
+            self.hooks.lock().unwrap().push(hook);
+        }
+
+        fn on_batch_end(&mut self, context: &CallbackContext<'_, TestModel<TB>, O>) {
+            let before = self.weights.lock().unwrap().take().unwrap();
+            let updated = before != Self::weights(context.model);
+            let hook = format!("batch_end {} updated={updated}", context.iteration);
+            self.hooks.lock().unwrap().push(hook);
+        }
+    }
+
+    #[test]
+    fn should_skip_steps_with_overflowing_gradients_in_half_precision() {
+        let device = Default::default();
+        let model = TestModel::<TB>::new(&device);
+        let dataloader_train = DataLoaderBuilder::new(TestBatcher)
+            .batch_size(1)
+            .build(InMemDataset::new(vec![1.0, 2.0, 3.0]));
+        let dataloader_valid = DataLoaderBuilder::new(TestBatcher)
+            .batch_size(1)
+            .build(InMemDataset::new(vec![1.0]));
+        let recorder = Recorder::default();
+        let directory = tempfile::tempdir().unwrap();
+
+        // The initial scale overflows in half precision, while the scale after the backoff
+        // doesn't.
+        let loss_scaler = DynamicLossScalerConfig::new()
+            .with_init_scale(1e6)
+            .with_backoff_factor(1e-3);
+        let learner = LearnerBuilder::new(directory.path().to_str().unwrap())
+            .metric_loggers(InMemoryMetricLogger::new(), InMemoryMetricLogger::new())
+            .renderer(NoRenderer)
+            .log_to_file(false)
+            .num_epochs(1)
+            .mixed_precision(loss_scaler)
+            .callback(recorder.clone())
+            .build(model, AdamConfig::new().init_mixed_precision(), 1e-2);
+        let model = learner.fit(dataloader_train, dataloader_valid);
 
-        self.model
+        assert_eq!(
+            *recorder.hooks.lock().unwrap(),
+            vec![
+                "non_finite_gradients 1",
+                "batch_end 1 updated=false",
+                "after_backward 2",
+                "batch_end 2 updated=true",
+                "after_backward 3",
+                "batch_end 3 updated=true",
+            ]
+        );
+        let weights = Recorder::weights(&model);
+        assert!(weights.value.iter().all(|weight| weight.is_finite()));
     }
 }
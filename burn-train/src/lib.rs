@@ -19,9 +19,24 @@ pub mod logger;
 /// The metric module.
 pub mod metric;
 
+/// The run manifest module.
+pub mod manifest;
+
+/// The reinforcement learning module.
+pub mod rl;
+
+/// The hyperparameter sweep module.
+pub mod sweep;
+
 mod learner;
 
 pub use learner::*;
 
 #[cfg(test)]
 pub(crate) type TestBackend = burn_ndarray::NdArray<f32>;
+
+#[cfg(test)]
+pub(crate) type TestAutodiffBackend = burn_autodiff::Autodiff<TestBackend>;
+
+#[cfg(test)]
+pub(crate) mod test_utils;
@@ -27,6 +27,26 @@ impl FileLogger {
 
         Self { file }
     }
+
+    /// Create a new file logger, appending to the file if it already exists.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path.
+    ///
+    /// # Returns
+    ///
+    /// The file logger.
+    pub fn append(path: &str) -> Self {
+        let mut options = std::fs::File::options();
+        let file = options
+            .append(true)
+            .create(true)
+            .open(path)
+            .unwrap_or_else(|err| panic!("Should be able to open the file '{path}': {err}"));
+
+        Self { file }
+    }
 }
 
 impl<T> Logger<T> for FileLogger
@@ -18,6 +18,14 @@ pub trait MetricLogger: Send {
     /// * `epoch` - The epoch.
     fn end_epoch(&mut self, epoch: usize);
 
+    /// Continue logging at the given epoch, called when the training is resumed from a
+    /// checkpoint so that the values aren't logged under the first epoch.
+    ///
+    /// # Arguments
+    ///
+    /// * `epoch` - The epoch the training is resumed at.
+    fn resume(&mut self, _epoch: usize) {}
+
     /// Read the logs for an epoch.
     fn read_numeric(&mut self, name: &str, epoch: usize) -> Result<Vec<f64>, String>;
 }
@@ -88,6 +96,11 @@ impl MetricLogger for FileMetricLogger {
         self.epoch = epoch + 1;
     }
 
+    fn resume(&mut self, epoch: usize) {
+        self.loggers.clear();
+        self.epoch = epoch;
+    }
+
     fn read_numeric(&mut self, name: &str, epoch: usize) -> Result<Vec<f64>, String> {
         if let Some(value) = self.loggers.get(name) {
             value.sync()
@@ -3,9 +3,14 @@ mod base;
 mod file;
 mod in_memory;
 mod metric;
+mod tabular;
+mod tensorboard;
+mod tfrecord;
 
 pub use async_logger::*;
 pub use base::*;
 pub use file::*;
 pub use in_memory::*;
 pub use metric::*;
+pub use tabular::*;
+pub use tensorboard::*;
@@ -0,0 +1,357 @@
+use super::{AsyncLogger, FileLogger, Logger, MetricLogger};
+use crate::metric::MetricEntry;
+use std::collections::HashMap;
+
+/// The format of the file written by the [tabular metric logger](TabularMetricLogger).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TabularFormat {
+    /// Comma-separated values, with the `epoch,iteration,metric,value` header.
+    Csv,
+    /// One JSON object per line, with the `epoch`, `iteration`, `metric` and `value` fields.
+    Jsonl,
+}
+
+impl TabularFormat {
+    fn extension(&self) -> &'static str {
+        match self {
+            TabularFormat::Csv => "csv",
+            TabularFormat::Jsonl => "jsonl",
+        }
+    }
+}
+
+/// Metric logger writing all the metrics in a single CSV or JSONL file, with one row for each
+/// logged value, which can easily be loaded by other tools.
+///
+/// # Notes
+///
+/// The file of a previous run is overwritten, unless the training is resumed from a checkpoint,
+/// in which case the file is continued after removing the rows of the resumed epoch and the
+/// following ones, logged before the training stopped.
+pub struct TabularMetricLogger {
+    /// The logger of the file, opened when the first row is logged or when resuming.
+    logger: Option<AsyncLogger<String>>,
+    path: String,
+    format: TabularFormat,
+    epoch: usize,
+    iterations: HashMap<String, usize>,
+}
+
+impl TabularMetricLogger {
+    /// Create a new tabular metric logger.
+    ///
+    /// # Arguments
+    ///
+    /// * `directory` - The directory of the `metrics.csv` or `metrics.jsonl` file.
+    /// * `format` - The format of the file.
+    ///
+    /// # Returns
+    ///
+    /// The tabular metric logger.
+    pub fn new(directory: &str, format: TabularFormat) -> Self {
+        std::fs::create_dir_all(directory).ok();
+
+        Self {
+            logger: None,
+            path: format!("{directory}/metrics.{}", format.extension()),
+            format,
+            epoch: 1,
+            iterations: HashMap::new(),
+        }
+    }
+
+    /// Create a new CSV metric logger, see [new](TabularMetricLogger::new).
+    pub fn csv(directory: &str) -> Self {
+        Self::new(directory, TabularFormat::Csv)
+    }
+
+    /// Create a new JSONL metric logger, see [new](TabularMetricLogger::new).
+    pub fn jsonl(directory: &str) -> Self {
+        Self::new(directory, TabularFormat::Jsonl)
+    }
+
+    /// The logger of the file, overwriting the file of a previous run when the training isn't
+    /// resumed.
+    fn logger(&mut self) -> &mut AsyncLogger<String> {
+        let (path, format) = (&self.path, self.format);
+        self.logger.get_or_insert_with(|| {
+            let mut logger = AsyncLogger::new(FileLogger::new(path));
+            if format == TabularFormat::Csv {
+                logger.log(CSV_HEADER.to_string());
+            }
+            logger
+        })
+    }
+
+    fn row(&self, iteration: usize, item: &MetricEntry) -> String {
+        match self.format {
+            TabularFormat::Csv => format!(
+                "{},{iteration},{},{}",
+                self.epoch,
+                escape_csv(&item.name),
+                escape_csv(&item.serialize)
+            ),
+            TabularFormat::Jsonl => {
+                let value = match item.serialize.parse::<f64>() {
+                    Ok(value) if value.is_finite() => serde_json::json!(value),
+                    _ => serde_json::json!(item.serialize),
+                };
+
+                serde_json::json!({
+                    "epoch": self.epoch,
+                    "iteration": iteration,
+                    "metric": item.name,
+                    "value": value,
+                })
+                .to_string()
+            }
+        }
+    }
+
+    /// The number of header rows.
+    fn header(&self) -> usize {
+        match self.format {
+            TabularFormat::Csv => 1,
+            TabularFormat::Jsonl => 0,
+        }
+    }
+
+    /// Parse a row into its epoch, metric and value.
+    fn parse(&self, row: &str) -> Result<(usize, String, String), String> {
+        match self.format {
+            TabularFormat::Csv => {
+                let fields = split_csv(row)?;
+                let [epoch, _iteration, metric, value] = <[String; 4]>::try_from(fields)
+                    .map_err(|fields| format!("Expected 4 fields, got {}", fields.len()))?;
+                let epoch = epoch.parse::<usize>().map_err(|err| err.to_string())?;
+
+                Ok((epoch, metric, value))
+            }
+            TabularFormat::Jsonl => {
+                let row: serde_json::Value =
+                    serde_json::from_str(row).map_err(|err| err.to_string())?;
+                let epoch = row["epoch"].as_u64().ok_or("Missing epoch")? as usize;
+                let metric = row["metric"].as_str().ok_or("Missing metric")?.to_string();
+                let value = match &row["value"] {
+                    serde_json::Value::String(value) => value.clone(),
+                    value => value.to_string(),
+                };
+
+                Ok((epoch, metric, value))
+            }
+        }
+    }
+}
+
+impl MetricLogger for TabularMetricLogger {
+    fn log(&mut self, item: &MetricEntry) {
+        let iteration = self.iterations.entry(item.name.clone()).or_insert(0);
+        *iteration += 1;
+        let iteration = *iteration;
+
+        let row = self.row(iteration, item);
+        self.logger().log(row);
+    }
+
+    fn end_epoch(&mut self, epoch: usize) {
+        self.iterations.clear();
+        self.epoch = epoch + 1;
+    }
+
+    /// Remove the rows of the resumed epoch and the following ones, logged before the training
+    /// stopped, so that they aren't mixed with the rows of the resumed training.
+    fn resume(&mut self, epoch: usize) {
+        if let Some(logger) = self.logger.take() {
+            logger.sync();
+        }
+        self.iterations.clear();
+        self.epoch = epoch;
+
+        let content = std::fs::read_to_string(&self.path).unwrap_or_default();
+        let mut kept = String::new();
+        if self.format == TabularFormat::Csv {
+            kept.push_str(CSV_HEADER);
+            kept.push('\n');
+        }
+        for row in content.lines().skip(self.header()) {
+            match self.parse(row) {
+                Ok((row_epoch, _, _)) if row_epoch >= epoch => {}
+                _ => {
+                    kept.push_str(row);
+                    kept.push('\n');
+                }
+            }
+        }
+
+        // The logger appends to the file, so it keeps writing after the rows kept.
+        std::fs::write(&self.path, kept).expect("Can rewrite the metrics file.");
+        self.logger = Some(AsyncLogger::new(FileLogger::append(&self.path)));
+    }
+
+    fn read_numeric(&mut self, name: &str, epoch: usize) -> Result<Vec<f64>, String> {
+        // Nothing is logged yet, and the file of a previous run is going to be overwritten.
+        let Some(logger) = self.logger.as_ref() else {
+            return Ok(Vec::new());
+        };
+        logger.sync();
+
+        let content = std::fs::read_to_string(&self.path).unwrap_or_default();
+
+        let mut values = Vec::new();
+        for row in content
+            .lines()
+            .skip(self.header())
+            .filter(|row| !row.is_empty())
+        {
+            let (row_epoch, metric, value) = self.parse(row)?;
+
+            if row_epoch == epoch && metric == name {
+                values.push(value.parse::<f64>().map_err(|err| err.to_string())?);
+            }
+        }
+
+        Ok(values)
+    }
+}
+
+const CSV_HEADER: &str = "epoch,iteration,metric,value";
+
+fn escape_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn split_csv(row: &str) -> Result<Vec<String>, String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = row.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        match (char, quoted) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            ('"', _) => quoted = !quoted,
+            (',', false) => fields.push(core::mem::take(&mut field)),
+            (char, _) => field.push(char),
+        }
+    }
+
+    if quoted {
+        return Err(format!("Unterminated quoted field in row '{row}'"));
+    }
+    fields.push(field);
+
+    Ok(fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(name: &str, value: &str) -> MetricEntry {
+        MetricEntry::new(name.to_string(), value.to_string(), value.to_string())
+    }
+
+    fn log_two_epochs(logger: &mut TabularMetricLogger) {
+        logger.log(&entry("Loss", "1.5"));
+        logger.log(&entry("Loss, smoothed", "2"));
+        logger.log(&entry("Loss", "1"));
+        logger.end_epoch(1);
+        logger.log(&entry("Loss", "0.5"));
+    }
+
+    #[test]
+    fn should_read_values_of_each_epoch() {
+        for format in [TabularFormat::Csv, TabularFormat::Jsonl] {
+            let directory = tempfile::tempdir().unwrap();
+            let mut logger = TabularMetricLogger::new(&directory.path().to_string_lossy(), format);
+
+            log_two_epochs(&mut logger);
+
+            assert_eq!(logger.read_numeric("Loss", 1).unwrap(), vec![1.5, 1.0]);
+            assert_eq!(logger.read_numeric("Loss", 2).unwrap(), vec![0.5]);
+            assert_eq!(logger.read_numeric("Loss, smoothed", 1).unwrap(), vec![2.0]);
+        }
+    }
+
+    #[test]
+    fn should_write_csv_rows() {
+        let directory = tempfile::tempdir().unwrap();
+        let directory = directory.path();
+        let mut logger = TabularMetricLogger::csv(&directory.to_string_lossy());
+
+        log_two_epochs(&mut logger);
+        logger.logger.as_ref().unwrap().sync();
+
+        let content = std::fs::read_to_string(directory.join("metrics.csv")).unwrap();
+        assert_eq!(
+            content,
+            "epoch,iteration,metric,value\n\
+             1,1,Loss,1.5\n\
+             1,1,\"Loss, smoothed\",2\n\
+             1,2,Loss,1\n\
+             2,1,Loss,0.5\n"
+        );
+    }
+
+    #[test]
+    fn should_append_to_the_file_when_resumed() {
+        let directory = tempfile::tempdir().unwrap();
+        let directory = directory.path();
+        let mut logger = TabularMetricLogger::csv(&directory.to_string_lossy());
+        log_two_epochs(&mut logger);
+        logger.logger.as_ref().unwrap().sync();
+
+        let mut logger = TabularMetricLogger::csv(&directory.to_string_lossy());
+        logger.resume(2);
+        logger.log(&entry("Loss", "0.25"));
+        logger.end_epoch(2);
+        logger.log(&entry("Loss", "0.125"));
+        logger.logger.as_ref().unwrap().sync();
+
+        let content = std::fs::read_to_string(directory.join("metrics.csv")).unwrap();
+        assert_eq!(
+            content,
+            "epoch,iteration,metric,value\n\
+             1,1,Loss,1.5\n\
+             1,1,\"Loss, smoothed\",2\n\
+             1,2,Loss,1\n\
+             2,1,Loss,0.25\n\
+             3,1,Loss,0.125\n"
+        );
+        assert_eq!(logger.read_numeric("Loss", 2).unwrap(), vec![0.25]);
+    }
+
+    #[test]
+    fn should_overwrite_the_file_of_a_previous_run() {
+        for format in [TabularFormat::Csv, TabularFormat::Jsonl] {
+            let directory = tempfile::tempdir().unwrap();
+            let directory = directory.path().to_string_lossy();
+            let mut logger = TabularMetricLogger::new(&directory, format);
+            log_two_epochs(&mut logger);
+            logger.logger.as_ref().unwrap().sync();
+
+            let mut logger = TabularMetricLogger::new(&directory, format);
+            assert!(logger.read_numeric("Loss", 1).unwrap().is_empty());
+            logger.log(&entry("Loss", "0.25"));
+
+            assert_eq!(logger.read_numeric("Loss", 1).unwrap(), vec![0.25]);
+            assert!(logger.read_numeric("Loss", 2).unwrap().is_empty());
+        }
+    }
+
+    #[test]
+    fn should_split_quoted_csv_fields() {
+        assert_eq!(
+            split_csv("1,\"a, \"\"b\"\"\",c").unwrap(),
+            vec!["1", "a, \"b\"", "c"]
+        );
+        assert!(split_csv("1,\"a").is_err());
+    }
+}
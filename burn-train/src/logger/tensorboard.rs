@@ -0,0 +1,373 @@
+use super::tfrecord::{self, Event, HParamValue, SummaryValue};
+use super::{AsyncLogger, Logger, MetricLogger};
+use crate::metric::MetricEntry;
+use burn_core::config::Config;
+use std::collections::HashMap;
+use std::fs::File;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The tag of the scalar logged at the end of each epoch, used to read the values of an epoch.
+const EPOCH_TAG: &str = "burn/epoch";
+/// The tag of the scalar logged when the training is resumed, with the epoch it's resumed at.
+const RESUME_TAG: &str = "burn/resume";
+const HPARAMS_TAG: &str = "_hparams_/session_start_info";
+const HPARAMS_PLUGIN: &str = "hparams";
+
+/// Metric logger writing TensorBoard event files.
+///
+/// Each numeric metric is logged as a scalar, with the number of times it was logged as the
+/// step. The end of each epoch is also logged as a scalar, which is used to read the values of an
+/// epoch back.
+///
+/// # Notes
+///
+/// A new event file is created in the directory each time a logger is created, and the values
+/// read back are the ones logged by the logger. When the training is resumed from a checkpoint,
+/// the values of the previous epochs are read once from the event files of the resumed training,
+/// which are the latest event file of the directory and the ones it was itself resumed from, and
+/// the steps of the metrics continue from these files.
+pub struct TensorBoardMetricLogger {
+    logger: AsyncLogger<Vec<u8>>,
+    directory: String,
+    path: String,
+    epoch: usize,
+    steps: HashMap<String, i64>,
+    /// The values of each metric and epoch, logged by the logger or read from the event files of
+    /// the resumed training.
+    values: HashMap<(String, usize), Vec<f64>>,
+}
+
+impl TensorBoardMetricLogger {
+    /// Create a new TensorBoard metric logger.
+    ///
+    /// # Arguments
+    ///
+    /// * `directory` - The directory of the event files, which is a run in TensorBoard.
+    ///
+    /// # Returns
+    ///
+    /// The TensorBoard metric logger.
+    pub fn new(directory: &str) -> Self {
+        std::fs::create_dir_all(directory).ok();
+
+        let wall_time = wall_time();
+        // The loggers created in the same second by the process are numbered.
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+        let path = format!(
+            "{directory}/events.out.tfevents.{}.burn.{}.{}",
+            wall_time as u64,
+            std::process::id(),
+            COUNT.fetch_add(1, Ordering::Relaxed)
+        );
+        let mut file = File::create(&path)
+            .unwrap_or_else(|err| panic!("Should be able to create the new file '{path}': {err}"));
+        tfrecord::write_file_version(&mut file, wall_time).expect("Can write the event file.");
+
+        Self {
+            logger: AsyncLogger::new(RecordLogger { file }),
+            directory: directory.to_string(),
+            path,
+            epoch: 1,
+            steps: HashMap::new(),
+            values: HashMap::new(),
+        }
+    }
+
+    /// Read the values of the epochs before the given one from the event files of the resumed
+    /// training, from the latest event file of the directory to the first one of the training.
+    fn read_resumed(&mut self, epoch: usize) -> Result<(), String> {
+        let mut paths = std::fs::read_dir(&self.directory)
+            .map_err(|err| err.to_string())?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path().to_string_lossy().to_string())
+            .filter(|path| path.contains("tfevents") && *path != self.path)
+            .collect::<Vec<_>>();
+        // The file names contain the wall time at which the files are created, followed by the
+        // process and the number of the logger in the process.
+        paths.sort_by_key(|path| {
+            path.rsplit('/')
+                .next()
+                .unwrap_or_default()
+                .split('.')
+                .filter_map(|part| part.parse::<u64>().ok())
+                .collect::<Vec<_>>()
+        });
+
+        let mut until = epoch;
+        for path in paths.iter().rev() {
+            let (scalars, resumed) = read_event_file(path)?;
+            for scalar in scalars.into_iter().filter(|scalar| scalar.epoch < until) {
+                let step = self.steps.entry(scalar.tag.clone()).or_insert(0);
+                *step = (*step).max(scalar.step);
+                self.values
+                    .entry((scalar.tag, scalar.epoch))
+                    .or_default()
+                    .push(scalar.value);
+            }
+
+            // The previous epochs were logged by the training that was resumed, if any.
+            match resumed {
+                Some(resumed) if resumed > 1 => until = until.min(resumed),
+                _ => break,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Log the given config as the hyperparameters of the run, shown in the HParams dashboard.
+    ///
+    /// Nested fields are named with their path separated by dots.
+    pub fn with_hparams<C: Config>(mut self, config: &C) -> Self {
+        let mut hparams = Vec::new();
+        let value = serde_json::to_value(config).expect("Config should be serializable.");
+        flatten_hparams(String::new(), value, &mut hparams);
+
+        let event = Event {
+            wall_time: wall_time(),
+            step: 0,
+            values: vec![SummaryValue {
+                tag: HPARAMS_TAG.to_string(),
+                simple_value: None,
+                plugin: Some((
+                    HPARAMS_PLUGIN.to_string(),
+                    tfrecord::encode_hparams_session_start(&hparams),
+                )),
+            }],
+        };
+        self.logger.log(event.encode());
+        self
+    }
+}
+
+impl MetricLogger for TensorBoardMetricLogger {
+    fn log(&mut self, item: &MetricEntry) {
+        // Only numeric metrics can be logged as scalars.
+        let Ok(value) = item.serialize.parse::<f64>() else {
+            return;
+        };
+
+        let step = self.steps.entry(item.name.clone()).or_insert(0);
+        *step += 1;
+
+        let event = Event::scalar(wall_time(), *step, &item.name, value as f32);
+        self.logger.log(event.encode());
+        self.values
+            .entry((item.name.clone(), self.epoch))
+            .or_default()
+            .push(value);
+    }
+
+    fn end_epoch(&mut self, epoch: usize) {
+        let event = Event::scalar(wall_time(), epoch as i64, EPOCH_TAG, epoch as f32);
+        self.logger.log(event.encode());
+        self.epoch = epoch + 1;
+    }
+
+    fn resume(&mut self, epoch: usize) {
+        self.epoch = epoch;
+        self.steps.clear();
+        self.values.clear();
+        if let Err(err) = self.read_resumed(epoch) {
+            log::warn!("Can't read the event files of the resumed training: {err}");
+        }
+
+        let event = Event::scalar(wall_time(), epoch as i64, RESUME_TAG, epoch as f32);
+        self.logger.log(event.encode());
+    }
+
+    fn read_numeric(&mut self, name: &str, epoch: usize) -> Result<Vec<f64>, String> {
+        Ok(self
+            .values
+            .get(&(name.to_string(), epoch))
+            .cloned()
+            .unwrap_or_default())
+    }
+}
+
+/// Write the records to the event file.
+struct RecordLogger {
+    file: File,
+}
+
+impl Logger<Vec<u8>> for RecordLogger {
+    fn log(&mut self, item: Vec<u8>) {
+        tfrecord::write_record(&mut self.file, &item).expect("Can log an item.");
+    }
+}
+
+/// A scalar read from an event file.
+struct Scalar {
+    tag: String,
+    epoch: usize,
+    step: i64,
+    value: f64,
+}
+
+/// Read the scalars of the metrics from an event file, along with the epoch the training was
+/// resumed at if the file was written by a resumed training.
+fn read_event_file(path: &str) -> Result<(Vec<Scalar>, Option<usize>), String> {
+    let mut file = File::open(path).map_err(|err| err.to_string())?;
+    let mut scalars = Vec::new();
+    let mut current = 1;
+    let mut resumed = None;
+
+    for record in tfrecord::read_records(&mut file)? {
+        let event = Event::decode(&record)?;
+        for value in event.values {
+            match (value.tag.as_str(), value.simple_value) {
+                (EPOCH_TAG, Some(ended)) => current = ended as usize + 1,
+                (RESUME_TAG, Some(epoch)) => {
+                    current = epoch as usize;
+                    resumed = Some(current);
+                }
+                (_, Some(scalar)) => scalars.push(Scalar {
+                    tag: value.tag,
+                    epoch: current,
+                    step: event.step,
+                    value: scalar as f64,
+                }),
+                _ => {}
+            }
+        }
+    }
+
+    Ok((scalars, resumed))
+}
+
+fn wall_time() -> f64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs_f64())
+        .unwrap_or_default()
+}
+
+fn flatten_hparams(
+    prefix: String,
+    value: serde_json::Value,
+    hparams: &mut Vec<(String, HParamValue)>,
+) {
+    let name = |key: &str| match prefix.is_empty() {
+        true => key.to_string(),
+        false => format!("{prefix}.{key}"),
+    };
+
+    match value {
+        serde_json::Value::Object(fields) => {
+            for (key, value) in fields {
+                flatten_hparams(name(&key), value, hparams);
+            }
+        }
+        serde_json::Value::Null => {}
+        serde_json::Value::Bool(value) => hparams.push((prefix, HParamValue::Bool(value))),
+        serde_json::Value::Number(value) => {
+            if let Some(value) = value.as_f64() {
+                hparams.push((prefix, HParamValue::Number(value)))
+            }
+        }
+        serde_json::Value::String(value) => hparams.push((prefix, HParamValue::String(value))),
+        serde_json::Value::Array(_) => {
+            hparams.push((prefix, HParamValue::String(value.to_string())))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(name: &str, value: f64) -> MetricEntry {
+        MetricEntry::new(name.to_string(), value.to_string(), value.to_string())
+    }
+
+    #[test]
+    fn should_read_values_of_each_epoch() {
+        let directory = tempfile::tempdir().unwrap();
+        let mut logger = TensorBoardMetricLogger::new(&directory.path().to_string_lossy());
+
+        logger.log(&entry("Loss", 1.0));
+        logger.log(&entry("Loss", 2.0));
+        logger.log(&entry("Accuracy", 50.0));
+        logger.end_epoch(1);
+        logger.log(&entry("Loss", 0.5));
+
+        assert_eq!(logger.read_numeric("Loss", 1).unwrap(), vec![1.0, 2.0]);
+        assert_eq!(logger.read_numeric("Loss", 2).unwrap(), vec![0.5]);
+        assert_eq!(logger.read_numeric("Accuracy", 1).unwrap(), vec![50.0]);
+        assert!(logger.read_numeric("Accuracy", 2).unwrap().is_empty());
+    }
+
+    #[test]
+    fn should_replace_the_values_of_the_resumed_epochs() {
+        let directory = tempfile::tempdir().unwrap();
+        let directory = directory.path().to_string_lossy();
+        let mut logger = TensorBoardMetricLogger::new(&directory);
+        logger.log(&entry("Loss", 1.0));
+        logger.end_epoch(1);
+        logger.log(&entry("Loss", 2.0));
+        logger.logger.sync();
+
+        let mut logger = TensorBoardMetricLogger::new(&directory);
+        logger.resume(2);
+        logger.log(&entry("Loss", 3.0));
+        logger.end_epoch(2);
+        logger.log(&entry("Loss", 4.0));
+        logger.logger.sync();
+
+        // The logger resumed twice reads the values of both previous trainings.
+        let mut logger = TensorBoardMetricLogger::new(&directory);
+        logger.resume(3);
+        assert_eq!(logger.steps["Loss"], 2);
+        logger.log(&entry("Loss", 5.0));
+        assert_eq!(logger.steps["Loss"], 3);
+
+        assert_eq!(logger.read_numeric("Loss", 1).unwrap(), vec![1.0]);
+        assert_eq!(logger.read_numeric("Loss", 2).unwrap(), vec![3.0]);
+        assert_eq!(logger.read_numeric("Loss", 3).unwrap(), vec![5.0]);
+    }
+
+    #[test]
+    fn should_ignore_the_event_files_of_other_runs() {
+        let directory = tempfile::tempdir().unwrap();
+        let directory = directory.path().to_string_lossy();
+        let mut logger = TensorBoardMetricLogger::new(&directory);
+        logger.log(&entry("Loss", 1.0));
+        logger.logger.sync();
+
+        let mut logger = TensorBoardMetricLogger::new(&directory);
+        logger.log(&entry("Loss", 2.0));
+
+        assert_eq!(logger.read_numeric("Loss", 1).unwrap(), vec![2.0]);
+        assert_eq!(logger.steps["Loss"], 1);
+    }
+
+    #[test]
+    fn should_flatten_hparams() {
+        let value = serde_json::json!({
+            "lr": 0.1,
+            "optimizer": { "name": "adam", "amsgrad": false },
+            "layers": [1, 2],
+            "seed": null,
+        });
+        let mut hparams = Vec::new();
+        flatten_hparams(String::new(), value, &mut hparams);
+        hparams.sort_by(|a, b| a.0.cmp(&b.0));
+
+        assert_eq!(
+            hparams,
+            vec![
+                (
+                    "layers".to_string(),
+                    HParamValue::String("[1,2]".to_string())
+                ),
+                ("lr".to_string(), HParamValue::Number(0.1)),
+                ("optimizer.amsgrad".to_string(), HParamValue::Bool(false)),
+                (
+                    "optimizer.name".to_string(),
+                    HParamValue::String("adam".to_string())
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,380 @@
+//! Minimal encoding and decoding of the TensorBoard event files.
+//!
+//! Event files are a sequence of TFRecords, each containing an `Event` protobuf message. Only the
+//! fields used by the [TensorBoard logger](super::TensorBoardMetricLogger) are supported.
+
+use std::io::{Read, Write};
+
+/// The version written in the first event of each file.
+const FILE_VERSION: &str = "brain.Event:2";
+
+/// A scalar, or a summary value only containing metadata such as the hyperparameters.
+pub(crate) struct SummaryValue {
+    pub(crate) tag: String,
+    pub(crate) simple_value: Option<f32>,
+    pub(crate) plugin: Option<(String, Vec<u8>)>,
+}
+
+/// A decoded `Event` message.
+pub(crate) struct Event {
+    pub(crate) wall_time: f64,
+    pub(crate) step: i64,
+    pub(crate) values: Vec<SummaryValue>,
+}
+
+impl Event {
+    /// An event containing a single scalar.
+    pub(crate) fn scalar(wall_time: f64, step: i64, tag: &str, value: f32) -> Self {
+        Self {
+            wall_time,
+            step,
+            values: vec![SummaryValue {
+                tag: tag.to_string(),
+                simple_value: Some(value),
+                plugin: None,
+            }],
+        }
+    }
+
+    /// Encode the event as a protobuf message.
+    pub(crate) fn encode(&self) -> Vec<u8> {
+        let mut summary = Vec::new();
+        for value in self.values.iter() {
+            let mut message = Vec::new();
+            write_bytes(&mut message, 1, value.tag.as_bytes());
+            if let Some(simple_value) = value.simple_value {
+                write_key(&mut message, 2, WIRE_FIXED32);
+                message.extend_from_slice(&simple_value.to_le_bytes());
+            }
+            if let Some((name, content)) = &value.plugin {
+                let mut plugin_data = Vec::new();
+                write_bytes(&mut plugin_data, 1, name.as_bytes());
+                write_bytes(&mut plugin_data, 2, content);
+                let mut metadata = Vec::new();
+                write_bytes(&mut metadata, 1, &plugin_data);
+                write_bytes(&mut message, 9, &metadata);
+            }
+            write_bytes(&mut summary, 1, &message);
+        }
+
+        let mut event = Vec::new();
+        write_double(&mut event, 1, self.wall_time);
+        write_varint_field(&mut event, 2, self.step as u64);
+        write_bytes(&mut event, 5, &summary);
+        event
+    }
+
+    /// Decode an event, ignoring the unsupported fields.
+    pub(crate) fn decode(bytes: &[u8]) -> Result<Self, String> {
+        let mut event = Event {
+            wall_time: 0.0,
+            step: 0,
+            values: Vec::new(),
+        };
+
+        for field in Fields::new(bytes) {
+            match field? {
+                (1, Value::Fixed64(value)) => event.wall_time = f64::from_bits(value),
+                (2, Value::Varint(value)) => event.step = value as i64,
+                (5, Value::Bytes(summary)) => {
+                    for field in Fields::new(summary) {
+                        if let (1, Value::Bytes(value)) = field? {
+                            event.values.push(decode_value(value)?);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(event)
+    }
+}
+
+fn decode_value(bytes: &[u8]) -> Result<SummaryValue, String> {
+    let mut value = SummaryValue {
+        tag: String::new(),
+        simple_value: None,
+        plugin: None,
+    };
+
+    for field in Fields::new(bytes) {
+        match field? {
+            (1, Value::Bytes(tag)) => {
+                value.tag = String::from_utf8(tag.to_vec()).map_err(|err| err.to_string())?
+            }
+            (2, Value::Fixed32(bits)) => value.simple_value = Some(f32::from_bits(bits)),
+            _ => {}
+        }
+    }
+
+    Ok(value)
+}
+
+/// Write the version event that must start every event file.
+pub(crate) fn write_file_version<W: Write>(writer: &mut W, wall_time: f64) -> std::io::Result<()> {
+    let mut event = Vec::new();
+    write_double(&mut event, 1, wall_time);
+    write_bytes(&mut event, 3, FILE_VERSION.as_bytes());
+    write_record(writer, &event)
+}
+
+/// Write a TFRecord containing the given data.
+pub(crate) fn write_record<W: Write>(writer: &mut W, data: &[u8]) -> std::io::Result<()> {
+    let length = (data.len() as u64).to_le_bytes();
+
+    writer.write_all(&length)?;
+    writer.write_all(&masked_crc32c(&length).to_le_bytes())?;
+    writer.write_all(data)?;
+    writer.write_all(&masked_crc32c(data).to_le_bytes())
+}
+
+/// Read all the TFRecords of a reader, checking their checksums.
+pub(crate) fn read_records<R: Read>(reader: &mut R) -> Result<Vec<Vec<u8>>, String> {
+    let mut bytes = Vec::new();
+    reader
+        .read_to_end(&mut bytes)
+        .map_err(|err| err.to_string())?;
+
+    let mut records = Vec::new();
+    let mut offset = 0;
+
+    while offset < bytes.len() {
+        let header = bytes
+            .get(offset..offset + 12)
+            .ok_or("Truncated record header")?;
+        let length = u64::from_le_bytes(header[0..8].try_into().unwrap());
+        if masked_crc32c(&header[0..8]) != u32::from_le_bytes(header[8..12].try_into().unwrap()) {
+            return Err("Invalid record length checksum".to_string());
+        }
+
+        let start = offset + 12;
+        let end = usize::try_from(length)
+            .ok()
+            .and_then(|length| start.checked_add(length))
+            .ok_or("Invalid record length")?;
+        let data = bytes.get(start..end).ok_or("Truncated record data")?;
+        let crc = end
+            .checked_add(4)
+            .and_then(|crc_end| bytes.get(end..crc_end))
+            .ok_or("Truncated record checksum")?;
+        if masked_crc32c(data) != u32::from_le_bytes(crc.try_into().unwrap()) {
+            return Err("Invalid record data checksum".to_string());
+        }
+
+        records.push(data.to_vec());
+        offset = end + 4;
+    }
+
+    Ok(records)
+}
+
+/// Encode the content of the hyperparameters plugin for a session start, with the given
+/// hyperparameters.
+pub(crate) fn encode_hparams_session_start(hparams: &[(String, HParamValue)]) -> Vec<u8> {
+    let mut session_start_info = Vec::new();
+    for (name, value) in hparams {
+        // `google.protobuf.Value`
+        let mut encoded = Vec::new();
+        match value {
+            HParamValue::Number(number) => write_double(&mut encoded, 2, *number),
+            HParamValue::String(string) => write_bytes(&mut encoded, 3, string.as_bytes()),
+            HParamValue::Bool(boolean) => write_varint_field(&mut encoded, 4, *boolean as u64),
+        }
+
+        let mut entry = Vec::new();
+        write_bytes(&mut entry, 1, name.as_bytes());
+        write_bytes(&mut entry, 2, &encoded);
+        write_bytes(&mut session_start_info, 1, &entry);
+    }
+
+    let mut plugin_data = Vec::new();
+    write_bytes(&mut plugin_data, 3, &session_start_info);
+    plugin_data
+}
+
+/// The value of a hyperparameter.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum HParamValue {
+    Number(f64),
+    String(String),
+    Bool(bool),
+}
+
+const WIRE_VARINT: u8 = 0;
+const WIRE_FIXED64: u8 = 1;
+const WIRE_BYTES: u8 = 2;
+const WIRE_FIXED32: u8 = 5;
+
+fn write_varint(buffer: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buffer.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    buffer.push(value as u8);
+}
+
+fn write_key(buffer: &mut Vec<u8>, field: u32, wire_type: u8) {
+    write_varint(buffer, ((field as u64) << 3) | wire_type as u64);
+}
+
+fn write_varint_field(buffer: &mut Vec<u8>, field: u32, value: u64) {
+    write_key(buffer, field, WIRE_VARINT);
+    write_varint(buffer, value);
+}
+
+fn write_double(buffer: &mut Vec<u8>, field: u32, value: f64) {
+    write_key(buffer, field, WIRE_FIXED64);
+    buffer.extend_from_slice(&value.to_le_bytes());
+}
+
+fn write_bytes(buffer: &mut Vec<u8>, field: u32, bytes: &[u8]) {
+    write_key(buffer, field, WIRE_BYTES);
+    write_varint(buffer, bytes.len() as u64);
+    buffer.extend_from_slice(bytes);
+}
+
+enum Value<'a> {
+    Varint(u64),
+    Fixed64(u64),
+    Bytes(&'a [u8]),
+    Fixed32(u32),
+}
+
+/// Iterator over the fields of a protobuf message.
+struct Fields<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Fields<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, offset: 0 }
+    }
+
+    fn varint(&mut self) -> Result<u64, String> {
+        let mut value = 0;
+        for shift in (0..64).step_by(7) {
+            let byte = *self.bytes.get(self.offset).ok_or("Truncated varint")?;
+            self.offset += 1;
+            value |= ((byte & 0x7F) as u64) << shift;
+
+            if byte < 0x80 {
+                return Ok(value);
+            }
+        }
+
+        Err("Invalid varint".to_string())
+    }
+
+    fn take(&mut self, length: usize) -> Result<&'a [u8], String> {
+        let bytes = self
+            .bytes
+            .get(self.offset..self.offset + length)
+            .ok_or("Truncated field")?;
+        self.offset += length;
+        Ok(bytes)
+    }
+
+    fn field(&mut self) -> Result<(u32, Value<'a>), String> {
+        let key = self.varint()?;
+        let value = match (key & 0x7) as u8 {
+            WIRE_VARINT => Value::Varint(self.varint()?),
+            WIRE_FIXED64 => Value::Fixed64(u64::from_le_bytes(self.take(8)?.try_into().unwrap())),
+            WIRE_BYTES => {
+                let length = self.varint()? as usize;
+                Value::Bytes(self.take(length)?)
+            }
+            WIRE_FIXED32 => Value::Fixed32(u32::from_le_bytes(self.take(4)?.try_into().unwrap())),
+            wire_type => return Err(format!("Unsupported wire type {wire_type}")),
+        };
+
+        Ok(((key >> 3) as u32, value))
+    }
+}
+
+impl<'a> Iterator for Fields<'a> {
+    type Item = Result<(u32, Value<'a>), String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.bytes.len() {
+            return None;
+        }
+
+        let field = self.field();
+        if field.is_err() {
+            // Stop at the first error.
+            self.offset = self.bytes.len();
+        }
+        Some(field)
+    }
+}
+
+/// The CRC-32C checksum masked as done by TensorFlow.
+fn masked_crc32c(data: &[u8]) -> u32 {
+    let crc = crc32c(data);
+    crc.rotate_right(15).wrapping_add(0xa282_ead8)
+}
+
+fn crc32c(data: &[u8]) -> u32 {
+    // Reversed Castagnoli polynomial.
+    const POLYNOMIAL: u32 = 0x82f6_3b78;
+
+    let mut crc = !0u32;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (POLYNOMIAL & mask);
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crc32c_should_match_known_values() {
+        assert_eq!(crc32c(b""), 0);
+        assert_eq!(crc32c(b"123456789"), 0xe306_9283);
+    }
+
+    #[test]
+    fn should_read_written_records() {
+        let mut bytes = Vec::new();
+        write_file_version(&mut bytes, 1.5).unwrap();
+        let event = Event::scalar(2.5, 7, "Loss", 0.25);
+        write_record(&mut bytes, &event.encode()).unwrap();
+
+        let records = read_records(&mut bytes.as_slice()).unwrap();
+        assert_eq!(records.len(), 2);
+
+        let event = Event::decode(&records[1]).unwrap();
+        assert_eq!(event.wall_time, 2.5);
+        assert_eq!(event.step, 7);
+        assert_eq!(event.values.len(), 1);
+        assert_eq!(event.values[0].tag, "Loss");
+        assert_eq!(event.values[0].simple_value, Some(0.25));
+    }
+
+    #[test]
+    fn should_detect_corrupted_records() {
+        let mut bytes = Vec::new();
+        write_record(&mut bytes, &Event::scalar(0.0, 1, "Loss", 1.0).encode()).unwrap();
+        let last = bytes.len() - 5;
+        bytes[last] ^= 1;
+
+        assert!(read_records(&mut bytes.as_slice()).is_err());
+    }
+
+    #[test]
+    fn should_reject_records_with_an_invalid_length() {
+        let length = u64::MAX.to_le_bytes();
+        let mut bytes = length.to_vec();
+        bytes.extend(masked_crc32c(&length).to_le_bytes());
+
+        assert!(read_records(&mut bytes.as_slice()).is_err());
+    }
+}
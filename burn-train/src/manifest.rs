@@ -0,0 +1,359 @@
+use crate::metric::store::{Aggregate, Direction, EventStoreClient, Split};
+use burn_core::config::Config;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The name of the manifest file written in the directory of each training run.
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+/// The number of items of the datasets of a training run.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DatasetSizes {
+    /// The number of training items.
+    pub train: usize,
+    /// The number of validation items.
+    pub valid: usize,
+}
+
+/// The value of a numeric metric at the last epoch it was recorded.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FinalMetric {
+    /// The name of the metric.
+    pub name: String,
+    /// The split of the metric.
+    pub split: Split,
+    /// The epoch of the values.
+    pub epoch: usize,
+    /// The mean of the values of the epoch.
+    pub mean: f64,
+    /// The last value of the epoch.
+    pub last: f64,
+}
+
+/// The record of what produced the artifacts of a training run, saved in
+/// [`manifest.json`](MANIFEST_FILE) in the directory of the [learner](crate::Learner).
+///
+/// The manifest is written when the training starts, and written again with the end timestamp
+/// and the final metrics when it ends, so a manifest without an end timestamp is a run that
+/// crashed or is still running.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RunManifest {
+    /// The directory of the run.
+    pub directory: String,
+    /// The serialized training [config](crate::LearnerBuilder::with_config), if provided.
+    pub config: Option<Value>,
+    /// The name of the backend.
+    pub backend: String,
+    /// The devices the model is trained on.
+    pub devices: Vec<String>,
+    /// The seed of the backend, if any.
+    pub seed: Option<u64>,
+    /// The version of the crates, with the version of `burn-train` and the ones
+    /// [registered](crate::LearnerBuilder::crate_version) by the user.
+    pub versions: BTreeMap<String, String>,
+    /// The number of epochs the training should last.
+    pub num_epochs: usize,
+    /// The epoch of the checkpoint the training resumed from, if any.
+    pub checkpoint: Option<usize>,
+    /// The number of items of the datasets.
+    pub dataset: DatasetSizes,
+    /// The start of the training, in seconds since the Unix epoch.
+    pub started_at: u64,
+    /// The end of the training, in seconds since the Unix epoch.
+    pub ended_at: Option<u64>,
+    /// The final value of the numeric metrics.
+    pub metrics: Vec<FinalMetric>,
+}
+
+impl RunManifest {
+    /// Load the manifest from a file.
+    pub fn load<P: AsRef<Path>>(file: P) -> std::io::Result<Self> {
+        let content = std::fs::read_to_string(file)?;
+        serde_json::from_str(&content)
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+    }
+
+    /// Save the manifest to a file.
+    pub fn save<P: AsRef<Path>>(&self, file: P) -> std::io::Result<()> {
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))?;
+        std::fs::write(file, content)
+    }
+
+    /// Deserialize the training config of the run.
+    pub fn config<C: Config>(&self) -> Option<C> {
+        self.config
+            .clone()
+            .and_then(|config| serde_json::from_value(config).ok())
+    }
+
+    /// The final value of a metric.
+    pub fn metric(&self, name: &str, split: Split) -> Option<&FinalMetric> {
+        self.metrics
+            .iter()
+            .find(|metric| metric.name == name && metric.split == split)
+    }
+
+    /// The duration of the training in seconds, if it ended.
+    pub fn duration(&self) -> Option<u64> {
+        self.ended_at
+            .map(|ended_at| ended_at.saturating_sub(self.started_at))
+    }
+
+    /// The fields of the training config that differ from the config of another run, identified
+    /// by their path with the fields of nested configs separated by dots, with the value in this
+    /// run and in the other run.
+    pub fn config_diff(&self, other: &RunManifest) -> Vec<(String, Option<Value>, Option<Value>)> {
+        let mut differences = Vec::new();
+        diff_values(
+            String::new(),
+            self.config.as_ref(),
+            other.config.as_ref(),
+            &mut differences,
+        );
+        differences
+    }
+}
+
+fn diff_values(
+    path: String,
+    a: Option<&Value>,
+    b: Option<&Value>,
+    differences: &mut Vec<(String, Option<Value>, Option<Value>)>,
+) {
+    match (a, b) {
+        (Some(Value::Object(a)), Some(Value::Object(b))) => {
+            let mut keys = a.keys().chain(b.keys()).collect::<Vec<_>>();
+            keys.sort();
+            keys.dedup();
+
+            for key in keys {
+                let path = match path.is_empty() {
+                    true => key.clone(),
+                    false => format!("{path}.{key}"),
+                };
+                diff_values(path, a.get(key), b.get(key), differences);
+            }
+        }
+        (a, b) if a != b => differences.push((path, a.cloned(), b.cloned())),
+        _ => {}
+    }
+}
+
+/// List the runs in a directory, with the manifests found in the directory and its
+/// subdirectories ordered by their start.
+pub fn list_runs<P: AsRef<Path>>(directory: P) -> Vec<RunManifest> {
+    let mut runs = Vec::new();
+    find_manifests(directory.as_ref(), &mut runs);
+    runs.sort_by(|a, b| {
+        a.started_at
+            .cmp(&b.started_at)
+            .then_with(|| a.directory.cmp(&b.directory))
+    });
+
+    runs
+}
+
+fn find_manifests(directory: &Path, runs: &mut Vec<RunManifest>) {
+    let file = directory.join(MANIFEST_FILE);
+    if file.is_file() {
+        match RunManifest::load(&file) {
+            Ok(manifest) => runs.push(manifest),
+            Err(err) => log::warn!("Can't load the manifest {}: {err}", file.display()),
+        }
+    }
+
+    let entries = match std::fs::read_dir(directory) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            find_manifests(&path, runs);
+        }
+    }
+}
+
+/// Rank the runs by the final value of a metric, from the best to the worst, skipping the runs
+/// without the metric.
+pub fn compare_runs<'a>(
+    runs: &'a [RunManifest],
+    name: &str,
+    aggregate: Aggregate,
+    split: Split,
+    direction: Direction,
+) -> Vec<(&'a RunManifest, f64)> {
+    let mut ranked = runs
+        .iter()
+        .filter_map(|run| {
+            run.metric(name, split).map(|metric| match aggregate {
+                Aggregate::Mean => (run, metric.mean),
+                Aggregate::Last => (run, metric.last),
+            })
+        })
+        .collect::<Vec<_>>();
+    ranked.sort_by(|(_, a), (_, b)| match direction {
+        Direction::Lowest => a.total_cmp(b),
+        Direction::Highest => b.total_cmp(a),
+    });
+
+    ranked
+}
+
+/// Write the manifest of a training run, created by the [learner builder](crate::LearnerBuilder).
+pub(crate) struct ManifestRecorder {
+    manifest: RunManifest,
+    metrics: Vec<(String, Split)>,
+}
+
+impl ManifestRecorder {
+    pub(crate) fn new(manifest: RunManifest, metrics: Vec<(String, Split)>) -> Self {
+        Self { manifest, metrics }
+    }
+
+    /// Record the start of the training.
+    pub(crate) fn start(&mut self, dataset: DatasetSizes, seed: Option<u64>) {
+        self.manifest.dataset = dataset;
+        self.manifest.seed = seed;
+        self.manifest.started_at = timestamp();
+        self.save();
+    }
+
+    /// Record the end of the training with the value of the metrics at the last epoch they were
+    /// recorded, up to the given epoch.
+    pub(crate) fn end(&mut self, epoch: usize, store: &EventStoreClient) {
+        self.manifest.ended_at = Some(timestamp());
+        self.manifest.metrics = self
+            .metrics
+            .iter()
+            .filter_map(|(name, split)| {
+                (1..epoch + 1).rev().find_map(|epoch| {
+                    let mean = store.find_metric(name, epoch, Aggregate::Mean, *split)?;
+                    let last = store.find_metric(name, epoch, Aggregate::Last, *split)?;
+                    Some(FinalMetric {
+                        name: name.clone(),
+                        split: *split,
+                        epoch,
+                        mean,
+                        last,
+                    })
+                })
+            })
+            .collect();
+        self.save();
+    }
+
+    fn save(&self) {
+        std::fs::create_dir_all(&self.manifest.directory).ok();
+        let file = Path::new(&self.manifest.directory).join(MANIFEST_FILE);
+        if let Err(err) = self.manifest.save(&file) {
+            log::warn!("Can't save the manifest {}: {err}", file.display());
+        }
+    }
+}
+
+fn timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logger::InMemoryMetricLogger;
+    use crate::metric::LossMetric;
+    use crate::test_utils::{NoRenderer, TestBatcher, TestModel};
+    use crate::{LearnerBuilder, TestAutodiffBackend};
+    use burn_core as burn;
+    use burn_core::data::dataloader::DataLoaderBuilder;
+    use burn_core::data::dataset::InMemDataset;
+    use burn_core::optim::SgdConfig;
+    use burn_core::tensor::backend::Backend;
+
+    type TB = TestAutodiffBackend;
+
+    #[derive(Config)]
+    struct TrainingConfig {
+        lr: f64,
+    }
+
+    fn train(directory: &str, config: TrainingConfig) {
+        let device = Default::default();
+        let model = TestModel::<TB>::new(&device);
+        let dataloader_train = DataLoaderBuilder::new(TestBatcher)
+            .batch_size(2)
+            .build(InMemDataset::new(vec![1.0, 2.0, 3.0]));
+        let dataloader_valid = DataLoaderBuilder::new(TestBatcher)
+            .batch_size(2)
+            .build(InMemDataset::new(vec![1.0]));
+
+        let learner = LearnerBuilder::new(directory)
+            .metric_loggers(InMemoryMetricLogger::new(), InMemoryMetricLogger::new())
+            .metric_train_numeric(LossMetric::<TB>::new())
+            .metric_valid_numeric(LossMetric::<TB>::new())
+            .renderer(NoRenderer)
+            .log_to_file(false)
+            .num_epochs(2)
+            .seed(42)
+            .config(&config)
+            .crate_version("my-model", "1.2.3")
+            .build(model, SgdConfig::new().init(), config.lr);
+        learner.fit(dataloader_train, dataloader_valid);
+    }
+
+    #[test]
+    fn should_record_and_compare_runs() {
+        let directory = tempfile::tempdir().unwrap();
+        let directory = directory.path();
+        let run = |name: &str| directory.join(name).to_str().unwrap().to_string();
+        train(&run("run-a"), TrainingConfig::new(1e-2));
+        train(&run("run-b"), TrainingConfig::new(1e-1));
+
+        let runs = list_runs(directory);
+
+        assert_eq!(runs.len(), 2);
+        let manifest = &runs[0];
+        assert_eq!(manifest.directory, run("run-a"));
+        assert_eq!(manifest.backend, TB::name());
+        assert_eq!(manifest.devices, vec!["Cpu".to_string()]);
+        assert_eq!(manifest.seed, Some(42));
+        assert_eq!(manifest.versions["my-model"], "1.2.3");
+        assert_eq!(
+            manifest.versions["burn-train"],
+            env!("CARGO_PKG_VERSION").to_string()
+        );
+        assert_eq!(manifest.dataset, DatasetSizes { train: 3, valid: 1 });
+        assert!(manifest.duration().is_some());
+        assert_eq!(
+            manifest.config::<TrainingConfig>().map(|config| config.lr),
+            Some(1e-2)
+        );
+        let loss = manifest.metric("Loss", Split::Valid).unwrap();
+        assert_eq!(loss.epoch, 2);
+        assert!(manifest.metric("Loss", Split::Train).is_some());
+
+        assert_eq!(
+            runs[0].config_diff(&runs[1]),
+            vec![(
+                "lr".to_string(),
+                Some(Value::from(1e-2)),
+                Some(Value::from(1e-1))
+            )]
+        );
+        let ranked = compare_runs(
+            &runs,
+            "Loss",
+            Aggregate::Mean,
+            Split::Valid,
+            Direction::Lowest,
+        );
+        assert_eq!(ranked.len(), 2);
+        assert!(ranked[0].1 <= ranked[1].1);
+    }
+}
@@ -0,0 +1,203 @@
+use super::state::{format_entry, FormatOptions};
+use super::{MetricEntry, MetricMetadata};
+use crate::metric::store::Aggregate;
+use crate::metric::{Metric, Numeric};
+use burn_core::tensor::activation::softmax;
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{Int, Tensor};
+
+/// The area under the receiver operating characteristic curve (AUROC) metric.
+///
+/// For multiclass classification, the AUROC of each class against the others is computed from
+/// the softmax probabilities, then averaged over the classes with both positive and negative
+/// items.
+///
+/// The probabilities and targets are accumulated over the epoch, so the value is the AUROC of all
+/// the items of the epoch, which is also the serialized value.
+#[derive(Default)]
+pub struct AurocMetric<B: Backend> {
+    probabilities: Vec<f64>,
+    targets: Vec<i64>,
+    num_classes: usize,
+    _b: B,
+}
+
+/// The [AUROC metric](AurocMetric) input type.
+#[derive(new)]
+pub struct AurocInput<B: Backend> {
+    outputs: Tensor<B, 2>,
+    targets: Tensor<B, 1, Int>,
+}
+
+impl<B: Backend> AurocMetric<B> {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<B: Backend> Metric for AurocMetric<B> {
+    const NAME: &'static str = "AUROC";
+    const AGGREGATE: Aggregate = Aggregate::Last;
+
+    type Input = AurocInput<B>;
+
+    fn update(&mut self, input: &AurocInput<B>, _metadata: &MetricMetadata) -> MetricEntry {
+        let [_batch_size, num_classes] = input.outputs.dims();
+
+        let probabilities = softmax(input.outputs.clone(), 1)
+            .into_data()
+            .convert::<f64>()
+            .value;
+        let targets = input.targets.clone().into_data().convert::<i64>().value;
+
+        // The batch value is undefined when no class has both positive and negative items.
+        let current = mean_auroc(&probabilities, &targets, num_classes).unwrap_or(f64::NAN);
+        self.probabilities.extend(probabilities);
+        self.targets.extend(targets);
+        self.num_classes = num_classes;
+        let running = self.value();
+
+        format_entry(
+            FormatOptions::new(Self::NAME).unit("%").precision(2),
+            current,
+            running,
+            running,
+        )
+    }
+
+    fn clear(&mut self) {
+        self.probabilities.clear();
+        self.targets.clear();
+    }
+}
+
+impl<B: Backend> Numeric for AurocMetric<B> {
+    fn value(&self) -> f64 {
+        mean_auroc(&self.probabilities, &self.targets, self.num_classes).unwrap_or(f64::NAN)
+    }
+}
+
+/// The AUROC in percent, averaged over the classes for which it's defined.
+///
+/// Returns `None` when no class has both positive and negative items.
+fn mean_auroc(probabilities: &[f64], targets: &[i64], num_classes: usize) -> Option<f64> {
+    // With two classes, the AUROC of both classes is the same.
+    let classes = match num_classes {
+        2 => 1..2,
+        _ => 0..num_classes,
+    };
+    let values = classes
+        .filter_map(|class| {
+            let items = targets
+                .iter()
+                .enumerate()
+                .map(|(row, target)| {
+                    (
+                        probabilities[row * num_classes + class],
+                        *target == class as i64,
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            auroc(items)
+        })
+        .collect::<Vec<_>>();
+
+    match values.len() {
+        0 => None,
+        num_values => Some(100.0 * values.iter().sum::<f64>() / num_values as f64),
+    }
+}
+
+/// Compute the AUROC from the scores and labels of the items, which is the probability that a
+/// positive item has a higher score than a negative one.
+///
+/// Returns `None` when there are no positive or no negative items.
+fn auroc(mut items: Vec<(f64, bool)>) -> Option<f64> {
+    let num_positives = items.iter().filter(|(_, positive)| *positive).count();
+    let num_negatives = items.len() - num_positives;
+
+    if num_positives == 0 || num_negatives == 0 {
+        return None;
+    }
+
+    items.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    // Sum of the ranks of the positive items, using the average rank for ties.
+    let mut rank_sum = 0.0;
+    let mut start = 0;
+    while start < items.len() {
+        let mut end = start + 1;
+        while end < items.len() && items[end].0 == items[start].0 {
+            end += 1;
+        }
+
+        let rank = (start + end + 1) as f64 / 2.0;
+        let positives = items[start..end]
+            .iter()
+            .filter(|(_, positive)| *positive)
+            .count();
+        rank_sum += rank * positives as f64;
+        start = end;
+    }
+
+    let num_positives = num_positives as f64;
+    let num_negatives = num_negatives as f64;
+
+    Some((rank_sum - num_positives * (num_positives + 1.0) / 2.0) / (num_positives * num_negatives))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_auroc_with_ties() {
+        let items = vec![(0.1, false), (0.4, true), (0.4, false), (0.8, true)];
+
+        // 3 of the 4 positive-negative pairs are ordered, and 1 is tied.
+        assert_eq!(auroc(items), Some(0.875));
+        assert_eq!(auroc(vec![(0.1, true), (0.2, true)]), None);
+    }
+
+    #[test]
+    fn test_binary_auroc() {
+        let device = Default::default();
+        let mut metric = AurocMetric::<TestBackend>::new();
+        let input = AurocInput::new(
+            Tensor::from_data([[0.0, 1.0], [0.0, -1.0], [0.0, 0.5], [0.0, 2.0]], &device),
+            Tensor::from_data([1, 0, 1, 0], &device),
+        );
+
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+        assert_eq!(50.0, metric.value());
+    }
+
+    #[test]
+    fn test_auroc_across_batches() {
+        let device = Default::default();
+        let mut metric = AurocMetric::<TestBackend>::new();
+
+        // The AUROC of the first batch is undefined, with only positive items.
+        metric.update(
+            &AurocInput::new(
+                Tensor::from_data([[0.0, 1.0], [0.0, 2.0]], &device),
+                Tensor::from_data([1, 1], &device),
+            ),
+            &MetricMetadata::fake(),
+        );
+        let entry = metric.update(
+            &AurocInput::new(
+                Tensor::from_data([[0.0, -1.0], [0.0, 1.5]], &device),
+                Tensor::from_data([0, 0], &device),
+            ),
+            &MetricMetadata::fake(),
+        );
+
+        // 3 of the 4 positive-negative pairs of the epoch are ordered.
+        assert_eq!(75.0, metric.value());
+        assert_eq!(entry.serialize, "75");
+    }
+}
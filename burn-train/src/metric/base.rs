@@ -1,6 +1,15 @@
+use super::store::Aggregate;
+use super::GradientNorm;
 use burn_core::{data::dataloader::Progress, LearningRate};
+use std::time::Duration;
 
 /// Metric metadata that can be used when computing metrics.
+///
+/// The [gradient norm](MetricMetadata::grad_norm) and the [timing](MetricMetadata::timing) of
+/// the iteration are set with [with_grad_norm](MetricMetadata::with_grad_norm) and
+/// [with_timing](MetricMetadata::with_timing) after creating the metadata with
+/// [new](MetricMetadata::new).
+#[derive(new)]
 pub struct MetricMetadata {
     /// The current progress.
     pub progress: Progress,
@@ -16,9 +25,54 @@ pub struct MetricMetadata {
 
     /// The current learning rate.
     pub lr: Option<LearningRate>,
+
+    #[new(default)]
+    pub(crate) grad_norm: Option<GradientNorm>,
+
+    #[new(default)]
+    pub(crate) timing: Option<IterationTiming>,
+}
+
+/// The time spent on each part of a training iteration.
+#[derive(new, Clone, Copy, Debug, Default, PartialEq)]
+pub struct IterationTiming {
+    /// The time spent waiting for the batch from the dataloader.
+    pub data_loading: Duration,
+    /// The time spent on the forward and backward passes and the optimizer step.
+    pub step: Duration,
+}
+
+impl IterationTiming {
+    /// The total time of the iteration.
+    pub fn total(&self) -> Duration {
+        self.data_loading + self.step
+    }
 }
 
 impl MetricMetadata {
+    /// Set the [global norm of the gradients](MetricMetadata::grad_norm).
+    pub fn with_grad_norm(mut self, grad_norm: Option<GradientNorm>) -> Self {
+        self.grad_norm = grad_norm;
+        self
+    }
+
+    /// Set the [time spent on the iteration](MetricMetadata::timing).
+    pub fn with_timing(mut self, timing: Option<IterationTiming>) -> Self {
+        self.timing = timing;
+        self
+    }
+
+    /// The global norm of the gradients before clipping, when an optimizer step was performed.
+    pub fn grad_norm(&self) -> Option<&GradientNorm> {
+        self.grad_norm.as_ref()
+    }
+
+    /// The time spent loading the batch and training on it, for the training iterations.
+    pub fn timing(&self) -> Option<IterationTiming> {
+        self.timing
+    }
+
+    /// Metadata of the first iteration of a single epoch, to test the metrics.
     #[cfg(test)]
     pub fn fake() -> Self {
         Self {
@@ -30,6 +84,8 @@ impl MetricMetadata {
             epoch_total: 1,
             iteration: 0,
             lr: None,
+            grad_norm: None,
+            timing: None,
         }
     }
 }
@@ -47,6 +103,14 @@ pub trait Metric: Send + Sync {
     /// This should be unique, so avoid using short generic names, prefer using the long name.
     const NAME: &'static str;
 
+    /// How the values of an epoch saved by the loggers are aggregated into the value of the
+    /// epoch.
+    ///
+    /// It's the [mean](Aggregate::Mean) for the metrics averaged over the batches, and the
+    /// [last](Aggregate::Last) value for the metrics computed from statistics accumulated over the
+    /// epoch, which save their running value.
+    const AGGREGATE: Aggregate = Aggregate::Mean;
+
     /// The input type of the metric.
     type Input;
 
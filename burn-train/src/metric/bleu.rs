@@ -0,0 +1,202 @@
+use super::state::{format_entry, FormatOptions};
+use super::{MetricEntry, MetricMetadata};
+use crate::metric::store::Aggregate;
+use crate::metric::{Metric, Numeric};
+use std::collections::HashMap;
+
+/// The maximum n-gram order used by default, as in BLEU-4.
+const DEFAULT_MAX_ORDER: usize = 4;
+
+/// The token-level corpus BLEU score metric.
+///
+/// The clipped n-gram matches and the lengths are accumulated over the epoch, so the value is the
+/// corpus BLEU of all the sequences of the epoch, which is also the serialized value.
+pub struct BleuMetric {
+    max_order: usize,
+    matches: Vec<usize>,
+    totals: Vec<usize>,
+    prediction_length: usize,
+    reference_length: usize,
+}
+
+/// The [BLEU metric](BleuMetric) input type, with the tokens of each sequence without padding.
+#[derive(new)]
+pub struct BleuInput {
+    predictions: Vec<Vec<usize>>,
+    references: Vec<Vec<usize>>,
+}
+
+/// The statistics of a corpus needed to compute its BLEU score.
+#[derive(Default)]
+struct BleuStats {
+    matches: Vec<usize>,
+    totals: Vec<usize>,
+    prediction_length: usize,
+    reference_length: usize,
+}
+
+impl BleuMetric {
+    /// Creates the metric, using n-grams up to 4 tokens.
+    pub fn new() -> Self {
+        Self::with_max_order(DEFAULT_MAX_ORDER)
+    }
+
+    /// Creates the metric, using n-grams up to the given number of tokens.
+    pub fn with_max_order(max_order: usize) -> Self {
+        Self {
+            max_order,
+            matches: vec![0; max_order],
+            totals: vec![0; max_order],
+            prediction_length: 0,
+            reference_length: 0,
+        }
+    }
+
+    fn stats(&self, input: &BleuInput) -> BleuStats {
+        let mut stats = BleuStats {
+            matches: vec![0; self.max_order],
+            totals: vec![0; self.max_order],
+            ..Default::default()
+        };
+
+        for (prediction, reference) in input.predictions.iter().zip(input.references.iter()) {
+            stats.prediction_length += prediction.len();
+            stats.reference_length += reference.len();
+
+            for order in 1..=self.max_order {
+                let reference_counts = ngram_counts(reference, order);
+
+                for (ngram, count) in ngram_counts(prediction, order) {
+                    let clipped = count.min(reference_counts.get(ngram).copied().unwrap_or(0));
+                    stats.matches[order - 1] += clipped;
+                    stats.totals[order - 1] += count;
+                }
+            }
+        }
+
+        stats
+    }
+}
+
+impl Default for BleuMetric {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BleuStats {
+    fn bleu(&self) -> f64 {
+        if self.prediction_length == 0 {
+            return 0.0;
+        }
+
+        let mut log_precision = 0.0;
+        for (matches, total) in self.matches.iter().zip(self.totals.iter()) {
+            if *matches == 0 {
+                return 0.0;
+            }
+            log_precision += (*matches as f64 / *total as f64).ln();
+        }
+        log_precision /= self.matches.len() as f64;
+
+        let brevity_penalty = match self.prediction_length < self.reference_length {
+            true => 1.0 - self.reference_length as f64 / self.prediction_length as f64,
+            false => 0.0,
+        };
+
+        (brevity_penalty + log_precision).exp()
+    }
+}
+
+fn ngram_counts(tokens: &[usize], order: usize) -> HashMap<&[usize], usize> {
+    let mut counts = HashMap::new();
+    for ngram in tokens.windows(order) {
+        *counts.entry(ngram).or_insert(0) += 1;
+    }
+    counts
+}
+
+impl Metric for BleuMetric {
+    const NAME: &'static str = "BLEU";
+    const AGGREGATE: Aggregate = Aggregate::Last;
+
+    type Input = BleuInput;
+
+    fn update(&mut self, input: &BleuInput, _metadata: &MetricMetadata) -> MetricEntry {
+        let batch = self.stats(input);
+
+        for order in 0..self.max_order {
+            self.matches[order] += batch.matches[order];
+            self.totals[order] += batch.totals[order];
+        }
+        self.prediction_length += batch.prediction_length;
+        self.reference_length += batch.reference_length;
+
+        let running = self.value();
+        format_entry(
+            FormatOptions::new(Self::NAME).unit("%").precision(2),
+            100.0 * batch.bleu(),
+            running,
+            running,
+        )
+    }
+
+    fn clear(&mut self) {
+        *self = Self::with_max_order(self.max_order);
+    }
+}
+
+impl Numeric for BleuMetric {
+    fn value(&self) -> f64 {
+        let stats = BleuStats {
+            matches: self.matches.clone(),
+            totals: self.totals.clone(),
+            prediction_length: self.prediction_length,
+            reference_length: self.reference_length,
+        };
+
+        100.0 * stats.bleu()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_perfect_bleu() {
+        let mut metric = BleuMetric::new();
+        let input = BleuInput::new(vec![vec![1, 2, 3, 4, 5]], vec![vec![1, 2, 3, 4, 5]]);
+
+        metric.update(&input, &MetricMetadata::fake());
+        assert!((metric.value() - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_bleu_with_clipping_and_brevity_penalty() {
+        let mut metric = BleuMetric::with_max_order(2);
+        // Unigrams: 2 of 3 matches after clipping, bigrams: 1 of 2 matches.
+        let input = BleuInput::new(vec![vec![1, 1, 2]], vec![vec![1, 2, 3, 4]]);
+
+        metric.update(&input, &MetricMetadata::fake());
+
+        let expected = 100.0 * (1.0 - 4.0 / 3.0 + ((2.0 / 3.0f64).ln() + 0.5f64.ln()) / 2.0).exp();
+        assert!((metric.value() - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_bleu_accumulated_over_batches() {
+        let mut metric = BleuMetric::with_max_order(1);
+
+        metric.update(
+            &BleuInput::new(vec![vec![1, 2]], vec![vec![1, 2]]),
+            &MetricMetadata::fake(),
+        );
+        metric.update(
+            &BleuInput::new(vec![vec![3, 4]], vec![vec![3, 5]]),
+            &MetricMetadata::fake(),
+        );
+
+        assert!((metric.value() - 75.0).abs() < 1e-9);
+    }
+}
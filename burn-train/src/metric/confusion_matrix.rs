@@ -0,0 +1,147 @@
+use super::{MetricEntry, MetricMetadata};
+use crate::metric::Metric;
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{Int, Tensor};
+
+/// The confusion matrix of a multiclass classifier, counting the items of each target class
+/// (rows) predicted as each class (columns) over the epoch.
+///
+/// The matrix is formatted on multiple lines and serialized as a JSON array of rows.
+#[derive(Default)]
+pub struct ConfusionMatrixMetric<B: Backend> {
+    counts: Vec<Vec<usize>>,
+    _b: B,
+}
+
+/// The [confusion matrix metric](ConfusionMatrixMetric) input type.
+#[derive(new)]
+pub struct ConfusionMatrixInput<B: Backend> {
+    outputs: Tensor<B, 2>,
+    targets: Tensor<B, 1, Int>,
+}
+
+impl<B: Backend> ConfusionMatrixMetric<B> {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of items of the target class predicted as the given class since the last
+    /// [clear](Metric::clear).
+    pub fn count(&self, target: usize, predicted: usize) -> usize {
+        self.counts
+            .get(target)
+            .and_then(|row| row.get(predicted))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    fn format(&self) -> String {
+        let width = self
+            .counts
+            .iter()
+            .flatten()
+            .map(|count| count.to_string().len())
+            .max()
+            .unwrap_or(1);
+
+        self.counts
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|count| format!("{count:>width$}"))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn serialize(&self) -> String {
+        let rows = self
+            .counts
+            .iter()
+            .map(|row| {
+                let row = row
+                    .iter()
+                    .map(|count| count.to_string())
+                    .collect::<Vec<_>>();
+                format!("[{}]", row.join(","))
+            })
+            .collect::<Vec<_>>();
+
+        format!("[{}]", rows.join(","))
+    }
+}
+
+impl<B: Backend> Metric for ConfusionMatrixMetric<B> {
+    const NAME: &'static str = "Confusion Matrix";
+
+    type Input = ConfusionMatrixInput<B>;
+
+    fn update(
+        &mut self,
+        input: &ConfusionMatrixInput<B>,
+        _metadata: &MetricMetadata,
+    ) -> MetricEntry {
+        let [_batch_size, num_classes] = input.outputs.dims();
+
+        if self.counts.len() < num_classes {
+            self.counts.resize(num_classes, Vec::new());
+        }
+        for row in self.counts.iter_mut() {
+            row.resize(num_classes.max(row.len()), 0);
+        }
+
+        let predictions = input
+            .outputs
+            .clone()
+            .argmax(1)
+            .into_data()
+            .convert::<i64>()
+            .value;
+        let targets = input.targets.clone().into_data().convert::<i64>().value;
+
+        for (target, predicted) in targets.into_iter().zip(predictions) {
+            self.counts[target as usize][predicted as usize] += 1;
+        }
+
+        MetricEntry::new(Self::NAME.to_string(), self.format(), self.serialize())
+    }
+
+    fn clear(&mut self) {
+        self.counts.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_confusion_matrix() {
+        let device = Default::default();
+        let mut metric = ConfusionMatrixMetric::<TestBackend>::new();
+        let input = ConfusionMatrixInput::new(
+            Tensor::from_data(
+                [
+                    [0.0, 0.2, 0.8], // 2
+                    [1.0, 2.0, 0.5], // 1
+                    [0.4, 0.1, 0.2], // 0
+                    [0.6, 0.7, 0.2], // 1
+                ],
+                &device,
+            ),
+            Tensor::from_data([2, 2, 1, 1], &device),
+        );
+
+        metric.update(&input, &MetricMetadata::fake());
+        let entry = metric.update(&input, &MetricMetadata::fake());
+
+        assert_eq!(metric.count(2, 1), 2);
+        assert_eq!(metric.count(1, 0), 2);
+        assert_eq!(entry.serialize, "[[0,0,0],[2,2,0],[0,2,2]]");
+        assert_eq!(entry.formatted, "0 0 0\n2 2 0\n0 2 2");
+    }
+}
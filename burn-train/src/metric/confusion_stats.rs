@@ -0,0 +1,188 @@
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{Bool, Tensor};
+
+/// The rule used to decide which classes are predicted from the scores of a classifier.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DecisionRule {
+    /// The classes with the `k` highest scores are predicted, `k = 1` being the usual multiclass
+    /// prediction.
+    TopK(usize),
+    /// The classes with a score greater or equal to the threshold are predicted, used for binary
+    /// and multilabel classification.
+    Threshold(f64),
+}
+
+/// How the values computed for each class are reduced into a single value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ClassReduction {
+    /// Compute the value from the counts summed over all classes.
+    #[default]
+    Micro,
+    /// Compute the value of each class, then average them with the same weight. The classes
+    /// without any target nor prediction in the batch are ignored.
+    Macro,
+}
+
+/// The input type of the metrics computed from the confusion statistics, such as the
+/// [precision](crate::metric::PrecisionMetric), the [recall](crate::metric::RecallMetric) and the
+/// [F1 score](crate::metric::F1ScoreMetric).
+#[derive(new)]
+pub struct ConfusionStatsInput<B: Backend> {
+    /// The scores of each class with the shape `[batch_size, num_classes]`, which should be
+    /// probabilities when using a [threshold](DecisionRule::Threshold).
+    predictions: Tensor<B, 2>,
+    /// The targets with the shape `[batch_size, num_classes]`, one-hot encoded for multiclass
+    /// classification.
+    targets: Tensor<B, 2, Bool>,
+}
+
+/// The true positives, false positives and false negatives of each class.
+#[derive(Default)]
+pub(crate) struct ConfusionStats {
+    true_positives: Vec<f64>,
+    false_positives: Vec<f64>,
+    false_negatives: Vec<f64>,
+}
+
+impl ConfusionStats {
+    pub(crate) fn new<B: Backend>(input: &ConfusionStatsInput<B>, rule: DecisionRule) -> Self {
+        let [batch_size, num_classes] = input.predictions.dims();
+        let scores = input.predictions.clone().into_data().convert::<f64>().value;
+        let targets = input.targets.clone().into_data().value;
+
+        let mut stats = Self {
+            true_positives: vec![0.0; num_classes],
+            false_positives: vec![0.0; num_classes],
+            false_negatives: vec![0.0; num_classes],
+        };
+
+        for row in 0..batch_size {
+            let scores = &scores[row * num_classes..(row + 1) * num_classes];
+            let targets = &targets[row * num_classes..(row + 1) * num_classes];
+            let predicted = predict(scores, rule);
+
+            for class in 0..num_classes {
+                match (predicted[class], targets[class]) {
+                    (true, true) => stats.true_positives[class] += 1.0,
+                    (true, false) => stats.false_positives[class] += 1.0,
+                    (false, true) => stats.false_negatives[class] += 1.0,
+                    (false, false) => {}
+                }
+            }
+        }
+
+        stats
+    }
+
+    /// Add the counts of other statistics, such as to accumulate the counts of an epoch.
+    pub(crate) fn add(&mut self, other: &Self) {
+        let num_classes = other.true_positives.len();
+        for counts in [
+            &mut self.true_positives,
+            &mut self.false_positives,
+            &mut self.false_negatives,
+        ] {
+            if counts.len() < num_classes {
+                counts.resize(num_classes, 0.0);
+            }
+        }
+
+        for class in 0..num_classes {
+            self.true_positives[class] += other.true_positives[class];
+            self.false_positives[class] += other.false_positives[class];
+            self.false_negatives[class] += other.false_negatives[class];
+        }
+    }
+
+    /// Reduce the value computed from the true positives, false positives and false negatives.
+    pub(crate) fn reduce<F>(&self, reduction: ClassReduction, value: F) -> f64
+    where
+        F: Fn(f64, f64, f64) -> f64,
+    {
+        match reduction {
+            ClassReduction::Micro => value(
+                self.true_positives.iter().sum(),
+                self.false_positives.iter().sum(),
+                self.false_negatives.iter().sum(),
+            ),
+            ClassReduction::Macro => {
+                let values = (0..self.true_positives.len())
+                    .map(|class| {
+                        (
+                            self.true_positives[class],
+                            self.false_positives[class],
+                            self.false_negatives[class],
+                        )
+                    })
+                    .filter(|(tp, fp, fn_)| tp + fp + fn_ > 0.0)
+                    .map(|(tp, fp, fn_)| value(tp, fp, fn_))
+                    .collect::<Vec<_>>();
+
+                divide(values.iter().sum(), values.len() as f64)
+            }
+        }
+    }
+}
+
+/// Divide, returning zero when the denominator is zero.
+pub(crate) fn divide(numerator: f64, denominator: f64) -> f64 {
+    if denominator == 0.0 {
+        0.0
+    } else {
+        numerator / denominator
+    }
+}
+
+fn predict(scores: &[f64], rule: DecisionRule) -> Vec<bool> {
+    match rule {
+        DecisionRule::Threshold(threshold) => {
+            scores.iter().map(|score| *score >= threshold).collect()
+        }
+        DecisionRule::TopK(k) => {
+            let mut classes = (0..scores.len()).collect::<Vec<_>>();
+            classes.sort_by(|a, b| scores[*b].total_cmp(&scores[*a]));
+
+            let mut predicted = vec![false; scores.len()];
+            for class in classes.into_iter().take(k) {
+                predicted[class] = true;
+            }
+            predicted
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn should_count_top_k_predictions() {
+        let device = Default::default();
+        let input = ConfusionStatsInput::<TestBackend>::new(
+            Tensor::from_data([[0.1, 0.7, 0.2], [0.5, 0.2, 0.3]], &device),
+            Tensor::from_data([[false, false, true], [true, false, false]], &device),
+        );
+
+        let stats = ConfusionStats::new(&input, DecisionRule::TopK(2));
+
+        assert_eq!(stats.true_positives, vec![1.0, 0.0, 1.0]);
+        assert_eq!(stats.false_positives, vec![0.0, 1.0, 1.0]);
+        assert_eq!(stats.false_negatives, vec![0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn macro_reduction_should_ignore_absent_classes() {
+        let device = Default::default();
+        let input = ConfusionStatsInput::<TestBackend>::new(
+            Tensor::from_data([[0.9, 0.0, 0.1], [0.2, 0.0, 0.8]], &device),
+            Tensor::from_data([[true, false, false], [true, false, false]], &device),
+        );
+        let stats = ConfusionStats::new(&input, DecisionRule::TopK(1));
+        let recall = |tp: f64, _fp: f64, fn_: f64| divide(tp, tp + fn_);
+
+        assert_eq!(stats.reduce(ClassReduction::Micro, recall), 0.5);
+        // The third class has a false positive and no target, so its recall is zero.
+        assert_eq!(stats.reduce(ClassReduction::Macro, recall), 0.25);
+    }
+}
@@ -0,0 +1,73 @@
+use super::{MetricMetadata, Numeric};
+use crate::metric::{Metric, MetricEntry};
+use burn_core::tensor::backend::{Backend, MemoryUsage};
+
+/// Track the memory allocated and reserved by the backend on a device, for backends managing
+/// their own memory such as the ones built on `burn-compute`.
+///
+/// The value of the metric is the allocated memory in GB.
+pub struct DeviceMemoryMetric<B: Backend> {
+    device: B::Device,
+    usage: Option<MemoryUsage>,
+}
+
+impl<B: Backend> DeviceMemoryMetric<B> {
+    /// Creates a new device memory metric for the given device.
+    pub fn new(device: B::Device) -> Self {
+        Self {
+            device,
+            usage: None,
+        }
+    }
+}
+
+impl<B: Backend> Metric for DeviceMemoryMetric<B> {
+    const NAME: &'static str = "Device Memory";
+
+    type Input = ();
+
+    fn update(&mut self, _item: &(), _metadata: &MetricMetadata) -> MetricEntry {
+        self.usage = B::memory_usage(&self.device);
+
+        let formatted = match self.usage {
+            Some(usage) => format!(
+                "Allocated: {:.2} Gb - Reserved: {:.2} Gb",
+                bytes2gb(usage.allocated),
+                bytes2gb(usage.reserved)
+            ),
+            None => "Unavailable".to_string(),
+        };
+
+        MetricEntry::new(Self::NAME.to_string(), formatted, self.value().to_string())
+    }
+
+    fn clear(&mut self) {}
+}
+
+impl<B: Backend> Numeric for DeviceMemoryMetric<B> {
+    fn value(&self) -> f64 {
+        self.usage
+            .map(|usage| bytes2gb(usage.allocated))
+            .unwrap_or(f64::NAN)
+    }
+}
+
+fn bytes2gb(bytes: usize) -> f64 {
+    bytes as f64 / 1e9
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn should_be_unavailable_without_memory_management() {
+        let mut metric = DeviceMemoryMetric::<TestBackend>::new(Default::default());
+
+        let entry = metric.update(&(), &MetricMetadata::fake());
+
+        assert_eq!(entry.formatted, "Unavailable");
+        assert!(metric.value().is_nan());
+    }
+}
@@ -0,0 +1,194 @@
+use super::state::{FormatOptions, NumericMetricState};
+use super::{MetricEntry, MetricMetadata};
+use crate::metric::{Metric, Numeric};
+
+/// The input type of the [character](CharErrorRateMetric) and [word](WordErrorRateMetric) error
+/// rate metrics, with the tokens of each sequence without padding.
+#[derive(new)]
+pub struct ErrorRateInput {
+    predictions: Vec<Vec<usize>>,
+    references: Vec<Vec<usize>>,
+}
+
+/// The character error rate (CER) metric, which is the edit distance between the predicted and
+/// the reference characters divided by the number of reference characters.
+///
+/// Each token is considered to be a character.
+#[derive(Default)]
+pub struct CharErrorRateMetric {
+    state: NumericMetricState,
+}
+
+/// The word error rate (WER) metric, which is the edit distance between the predicted and the
+/// reference words divided by the number of reference words.
+///
+/// The words are the sequences of tokens between the separator tokens.
+pub struct WordErrorRateMetric {
+    state: NumericMetricState,
+    separator: usize,
+}
+
+impl CharErrorRateMetric {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl WordErrorRateMetric {
+    /// Creates the metric, with the token separating the words.
+    pub fn new(separator: usize) -> Self {
+        Self {
+            state: NumericMetricState::default(),
+            separator,
+        }
+    }
+}
+
+/// Update the state with the error rate of the batch.
+///
+/// The rate is weighted by the number of reference units, so that the epoch value is the total
+/// edit distance divided by the total number of reference units of the epoch.
+fn update_error_rate<T: PartialEq>(
+    state: &mut NumericMetricState,
+    sequences: impl Iterator<Item = (Vec<T>, Vec<T>)>,
+    name: &str,
+) -> MetricEntry {
+    let mut distance = 0;
+    let mut length = 0;
+
+    for (prediction, reference) in sequences {
+        distance += edit_distance(&prediction, &reference);
+        length += reference.len();
+    }
+
+    let rate = match length {
+        0 => 0.0,
+        _ => distance as f64 / length as f64,
+    };
+
+    state.update(
+        100.0 * rate,
+        length,
+        FormatOptions::new(name).unit("%").precision(2),
+    )
+}
+
+impl Metric for CharErrorRateMetric {
+    const NAME: &'static str = "Character Error Rate";
+
+    type Input = ErrorRateInput;
+
+    fn update(&mut self, input: &ErrorRateInput, _metadata: &MetricMetadata) -> MetricEntry {
+        let sequences = input
+            .predictions
+            .iter()
+            .cloned()
+            .zip(input.references.iter().cloned());
+
+        update_error_rate(&mut self.state, sequences, Self::NAME)
+    }
+
+    fn clear(&mut self) {
+        self.state.reset()
+    }
+}
+
+impl Metric for WordErrorRateMetric {
+    const NAME: &'static str = "Word Error Rate";
+
+    type Input = ErrorRateInput;
+
+    fn update(&mut self, input: &ErrorRateInput, _metadata: &MetricMetadata) -> MetricEntry {
+        let separator = self.separator;
+        let words = move |tokens: &'_ [usize]| -> Vec<Vec<usize>> {
+            tokens
+                .split(|token| *token == separator)
+                .filter(|word| !word.is_empty())
+                .map(|word| word.to_vec())
+                .collect()
+        };
+        let sequences = input
+            .predictions
+            .iter()
+            .zip(input.references.iter())
+            .map(|(prediction, reference)| (words(prediction), words(reference)));
+
+        update_error_rate(&mut self.state, sequences, Self::NAME)
+    }
+
+    fn clear(&mut self) {
+        self.state.reset()
+    }
+}
+
+impl Numeric for CharErrorRateMetric {
+    fn value(&self) -> f64 {
+        self.state.value()
+    }
+}
+
+impl Numeric for WordErrorRateMetric {
+    fn value(&self) -> f64 {
+        self.state.value()
+    }
+}
+
+/// The Levenshtein distance, which is the minimum number of insertions, deletions and
+/// substitutions needed to transform the prediction into the reference.
+fn edit_distance<T: PartialEq>(prediction: &[T], reference: &[T]) -> usize {
+    let mut previous = (0..=reference.len()).collect::<Vec<_>>();
+    let mut current = vec![0; reference.len() + 1];
+
+    for (i, predicted) in prediction.iter().enumerate() {
+        current[0] = i + 1;
+
+        for (j, expected) in reference.iter().enumerate() {
+            let substitution = previous[j] + usize::from(predicted != expected);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+
+        core::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[reference.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance(b"kitten", b"sitting"), 3);
+        assert_eq!(edit_distance(b"", b"abc"), 3);
+        assert_eq!(edit_distance(b"abc", b"abc"), 0);
+    }
+
+    #[test]
+    fn test_char_error_rate_across_batches() {
+        let mut metric = CharErrorRateMetric::new();
+
+        metric.update(
+            &ErrorRateInput::new(vec![vec![1, 2, 3]], vec![vec![1, 2, 4]]),
+            &MetricMetadata::fake(),
+        );
+        let entry = metric.update(
+            &ErrorRateInput::new(vec![vec![1]], vec![vec![1]]),
+            &MetricMetadata::fake(),
+        );
+
+        // One error over the four reference characters of the epoch.
+        assert!(entry.formatted.starts_with("epoch 25.00 %"));
+    }
+
+    #[test]
+    fn test_word_error_rate() {
+        let mut metric = WordErrorRateMetric::new(0);
+        // Words [1 2] [3] [4] against [1 2] [5] [4].
+        let input = ErrorRateInput::new(vec![vec![1, 2, 0, 3, 0, 4]], vec![vec![1, 2, 0, 5, 0, 4]]);
+
+        metric.update(&input, &MetricMetadata::fake());
+        assert!((metric.value() - 100.0 / 3.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,141 @@
+use super::{MetricMetadata, Numeric};
+use crate::metric::{Metric, MetricEntry};
+use std::time::Instant;
+
+/// The time and the progress when an estimate started.
+type EstimateStart = (Instant, f64);
+
+/// Estimate the remaining time of the epoch and of the run in seconds, from the progress since
+/// the first update, which can be in the middle of the training when resumed from a checkpoint.
+///
+/// The value of the metric is the remaining time of the run, which includes the time spent on
+/// the validation when registered as a training metric.
+pub struct EtaMetric {
+    run: Option<EstimateStart>,
+    epoch: Option<(usize, EstimateStart)>,
+    remaining_run: Option<f64>,
+}
+
+impl EtaMetric {
+    /// Creates a new ETA metric.
+    pub fn new() -> Self {
+        Self {
+            run: None,
+            epoch: None,
+            remaining_run: None,
+        }
+    }
+}
+
+impl Default for EtaMetric {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The remaining time in seconds, when the progress changed since the start of the estimate.
+fn remaining((started, progress_started): EstimateStart, progress: f64) -> Option<f64> {
+    let progress_done = progress - progress_started;
+    if progress_done <= 0.0 {
+        return None;
+    }
+
+    Some((started.elapsed().as_secs_f64() / progress_done * (1.0 - progress)).max(0.0))
+}
+
+fn format_secs(secs: Option<f64>) -> String {
+    let secs = match secs {
+        Some(secs) => secs.round() as u64,
+        None => return "-".to_string(),
+    };
+    let (hours, minutes, seconds) = (secs / 3600, secs / 60 % 60, secs % 60);
+
+    match (hours, minutes) {
+        (0, 0) => format!("{seconds}s"),
+        (0, _) => format!("{minutes}m {seconds:02}s"),
+        _ => format!("{hours}h {minutes:02}m {seconds:02}s"),
+    }
+}
+
+impl Metric for EtaMetric {
+    const NAME: &'static str = "ETA";
+
+    type Input = ();
+
+    fn update(&mut self, _item: &(), metadata: &MetricMetadata) -> MetricEntry {
+        let now = Instant::now();
+        let epoch_progress = match metadata.progress.items_total {
+            0 => 0.0,
+            total => metadata.progress.items_processed as f64 / total as f64,
+        };
+        let run_progress = match metadata.epoch_total {
+            0 => 0.0,
+            total => (metadata.epoch.saturating_sub(1) as f64 + epoch_progress) / total as f64,
+        };
+
+        let run = *self.run.get_or_insert((now, run_progress));
+        let epoch = match self.epoch {
+            Some((epoch, start)) if epoch == metadata.epoch => start,
+            _ => {
+                self.epoch = Some((metadata.epoch, (now, epoch_progress)));
+                (now, epoch_progress)
+            }
+        };
+
+        let remaining_epoch = remaining(epoch, epoch_progress);
+        self.remaining_run = remaining(run, run_progress).or(self.remaining_run);
+
+        let formatted = format!(
+            "epoch {} - run {}",
+            format_secs(remaining_epoch),
+            format_secs(self.remaining_run)
+        );
+        let serialized = self.value().to_string();
+
+        MetricEntry::new(Self::NAME.to_string(), formatted, serialized)
+    }
+
+    fn clear(&mut self) {}
+}
+
+impl Numeric for EtaMetric {
+    fn value(&self) -> f64 {
+        self.remaining_run.unwrap_or(f64::NAN)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_core::data::dataloader::Progress;
+    use std::time::Duration;
+
+    fn metadata(epoch: usize, items_processed: usize) -> MetricMetadata {
+        MetricMetadata {
+            progress: Progress::new(items_processed, 10),
+            epoch,
+            epoch_total: 2,
+            ..MetricMetadata::fake()
+        }
+    }
+
+    #[test]
+    fn should_estimate_the_remaining_time_of_the_epoch_and_the_run() {
+        let mut metric = EtaMetric::new();
+
+        let entry = metric.update(&(), &metadata(1, 1));
+        assert_eq!(entry.formatted, "epoch - - run -");
+        assert!(metric.value().is_nan());
+
+        std::thread::sleep(Duration::from_millis(20));
+        metric.update(&(), &metadata(1, 6));
+        // Half of the epoch in 20 ms, then 14 items left.
+        let remaining = metric.value();
+        assert!(remaining >= 0.02 * 14.0 / 5.0, "{remaining}");
+        assert!(remaining < 10.0, "{remaining}");
+
+        assert_eq!(format_secs(Some(59.4)), "59s");
+        assert_eq!(format_secs(Some(61.0)), "1m 01s");
+        assert_eq!(format_secs(Some(3725.0)), "1h 02m 05s");
+    }
+}
@@ -0,0 +1,152 @@
+use super::confusion_stats::{divide, ConfusionStats};
+use super::state::{format_entry, FormatOptions};
+use super::{ClassReduction, ConfusionStatsInput, DecisionRule, MetricEntry, MetricMetadata};
+use crate::metric::store::Aggregate;
+use crate::metric::{Metric, Numeric};
+use burn_core::tensor::backend::Backend;
+
+/// The F1 score metric, which is the harmonic mean of the [precision](super::PrecisionMetric) and
+/// the [recall](super::RecallMetric).
+///
+/// The confusion statistics are accumulated over the epoch, so the value is the F1 score of all the
+/// items of the epoch, which is also the serialized value.
+pub struct F1ScoreMetric<B: Backend> {
+    stats: ConfusionStats,
+    rule: DecisionRule,
+    reduction: ClassReduction,
+    _b: B,
+}
+
+impl<B: Backend> F1ScoreMetric<B> {
+    /// Creates the metric for binary classification, with a single class predicted when its
+    /// probability is greater or equal to the threshold.
+    pub fn binary(threshold: f64) -> Self {
+        Self::new(DecisionRule::Threshold(threshold), ClassReduction::Micro)
+    }
+
+    /// Creates the metric for multiclass classification, with the `top_k` classes of highest
+    /// score being predicted.
+    pub fn multiclass(top_k: usize, reduction: ClassReduction) -> Self {
+        Self::new(DecisionRule::TopK(top_k), reduction)
+    }
+
+    /// Creates the metric for multilabel classification, with the classes predicted when their
+    /// probability is greater or equal to the threshold.
+    pub fn multilabel(threshold: f64, reduction: ClassReduction) -> Self {
+        Self::new(DecisionRule::Threshold(threshold), reduction)
+    }
+
+    fn new(rule: DecisionRule, reduction: ClassReduction) -> Self {
+        Self {
+            stats: ConfusionStats::default(),
+            rule,
+            reduction,
+            _b: B::default(),
+        }
+    }
+}
+
+impl<B: Backend> Metric for F1ScoreMetric<B> {
+    const NAME: &'static str = "F1 Score";
+    const AGGREGATE: Aggregate = Aggregate::Last;
+
+    type Input = ConfusionStatsInput<B>;
+
+    fn update(
+        &mut self,
+        input: &ConfusionStatsInput<B>,
+        _metadata: &MetricMetadata,
+    ) -> MetricEntry {
+        let stats = ConfusionStats::new(input, self.rule);
+        let current = f1_score(&stats, self.reduction);
+        self.stats.add(&stats);
+        let running = self.value();
+
+        format_entry(
+            FormatOptions::new(Self::NAME).unit("%").precision(2),
+            current,
+            running,
+            running,
+        )
+    }
+
+    fn clear(&mut self) {
+        self.stats = ConfusionStats::default();
+    }
+}
+
+impl<B: Backend> Numeric for F1ScoreMetric<B> {
+    fn value(&self) -> f64 {
+        f1_score(&self.stats, self.reduction)
+    }
+}
+
+/// The F1 score in percent.
+fn f1_score(stats: &ConfusionStats, reduction: ClassReduction) -> f64 {
+    100.0
+        * stats.reduce(reduction, |tp, fp, fn_| {
+            divide(2.0 * tp, 2.0 * tp + fp + fn_)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_core::tensor::Tensor;
+
+    fn input() -> ConfusionStatsInput<TestBackend> {
+        let device = Default::default();
+
+        ConfusionStatsInput::new(
+            Tensor::from_data(
+                [
+                    [0.8, 0.1, 0.1], // 0
+                    [0.6, 0.3, 0.1], // 0
+                    [0.1, 0.8, 0.1], // 1
+                    [0.1, 0.2, 0.7], // 2
+                ],
+                &device,
+            ),
+            Tensor::from_data(
+                [
+                    [true, false, false],
+                    [false, true, false],
+                    [false, true, false],
+                    [false, false, true],
+                ],
+                &device,
+            ),
+        )
+    }
+
+    #[test]
+    fn test_micro_f1_score() {
+        let mut metric = F1ScoreMetric::multiclass(1, ClassReduction::Micro);
+
+        let _entry = metric.update(&input(), &MetricMetadata::fake());
+        assert_eq!(75.0, metric.value());
+    }
+
+    #[test]
+    fn test_macro_f1_score() {
+        let mut metric = F1ScoreMetric::multiclass(1, ClassReduction::Macro);
+
+        let _entry = metric.update(&input(), &MetricMetadata::fake());
+        // Class 0: 2 / 3, class 1: 2 / 3, class 2: 1.
+        assert!((metric.value() - 700.0 / 9.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_binary_f1_score() {
+        let device = Default::default();
+        let mut metric = F1ScoreMetric::<TestBackend>::binary(0.5);
+        let input = ConfusionStatsInput::new(
+            Tensor::from_data([[0.9], [0.6], [0.2], [0.7]], &device),
+            Tensor::from_data([[true], [false], [true], [true]], &device),
+        );
+
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+        assert!((metric.value() - 200.0 / 3.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,134 @@
+use super::{
+    state::{FormatOptions, NumericMetricState},
+    MetricMetadata, Numeric,
+};
+use crate::metric::{Metric, MetricEntry};
+use burn_core::tensor::{backend::Backend, ElementConversion, Tensor};
+use std::sync::{Arc, OnceLock};
+
+/// The global norm of the gradients of an optimizer step.
+///
+/// The norm is only read from the device the first time its [value](GradientNorm::value) is
+/// used, since reading it waits for the optimizer step to be completed by the backend.
+#[derive(Clone)]
+pub struct GradientNorm {
+    value: Arc<OnceLock<f64>>,
+    read: Arc<dyn Fn() -> f64 + Send + Sync>,
+}
+
+impl GradientNorm {
+    /// Create the gradient norm from the tensor computed by the optimizer.
+    pub fn new<B: Backend>(norm: Tensor<B, 1>) -> Self {
+        Self {
+            value: Arc::new(OnceLock::new()),
+            read: Arc::new(move || norm.clone().into_scalar().elem::<f64>()),
+        }
+    }
+
+    /// The value of the norm.
+    pub fn value(&self) -> f64 {
+        *self.value.get_or_init(|| (self.read)())
+    }
+}
+
+impl From<f64> for GradientNorm {
+    fn from(value: f64) -> Self {
+        Self {
+            value: Arc::new(OnceLock::from(value)),
+            read: Arc::new(move || value),
+        }
+    }
+}
+
+impl core::fmt::Debug for GradientNorm {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.value.get() {
+            Some(value) => f.debug_tuple("GradientNorm").field(value).finish(),
+            None => f.write_str("GradientNorm(<not read>)"),
+        }
+    }
+}
+
+/// Track the global norm of the gradients before clipping across iterations.
+///
+/// The norm is only reported by optimizers with gradient clipping. Iterations without an
+/// optimizer step, such as when accumulating gradients, don't contribute to the epoch value.
+pub struct GradientNormMetric {
+    state: NumericMetricState,
+}
+
+impl GradientNormMetric {
+    /// Creates a new gradient norm metric.
+    pub fn new() -> Self {
+        Self {
+            state: NumericMetricState::new(),
+        }
+    }
+}
+
+impl Default for GradientNormMetric {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Metric for GradientNormMetric {
+    const NAME: &'static str = "Gradient Norm";
+
+    type Input = ();
+
+    fn update(&mut self, _item: &(), metadata: &MetricMetadata) -> MetricEntry {
+        let (grad_norm, batch_size) = match &metadata.grad_norm {
+            Some(grad_norm) => (grad_norm.value(), 1),
+            None => (self.state.value(), 0),
+        };
+
+        self.state.update(
+            grad_norm,
+            batch_size,
+            FormatOptions::new(Self::NAME).precision(3),
+        )
+    }
+
+    fn clear(&mut self) {
+        self.state.reset()
+    }
+}
+
+impl Numeric for GradientNormMetric {
+    fn value(&self) -> f64 {
+        self.state.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn should_read_the_norm_when_used() {
+        let norm = GradientNorm::new(Tensor::<TestBackend, 1>::from_floats(
+            [1.5],
+            &Default::default(),
+        ));
+
+        assert_eq!(format!("{norm:?}"), "GradientNorm(<not read>)");
+        assert_eq!(norm.clone().value(), 1.5);
+        assert_eq!(format!("{norm:?}"), "GradientNorm(1.5)");
+    }
+
+    #[test]
+    fn should_keep_last_norm_without_optimizer_step() {
+        let mut metric = GradientNormMetric::new();
+        let mut metadata = MetricMetadata::fake();
+
+        metadata.grad_norm = Some(2.5.into());
+        metric.update(&(), &metadata);
+        metadata.grad_norm = None;
+        let entry = metric.update(&(), &metadata);
+
+        assert_eq!(metric.value(), 2.5);
+        assert_eq!(entry.serialize, "2.5");
+    }
+}
@@ -0,0 +1,109 @@
+use super::state::{FormatOptions, NumericMetricState};
+use super::{MetricEntry, MetricMetadata};
+use crate::metric::{Metric, Numeric};
+use burn_core::tensor::activation::sigmoid;
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{ElementConversion, Int, Tensor};
+
+/// The Hamming score metric for multilabel classification, which is the fraction of the labels
+/// correctly predicted, i.e. one minus the Hamming loss.
+pub struct HammingScoreMetric<B: Backend> {
+    state: NumericMetricState,
+    threshold: f64,
+    _b: B,
+}
+
+/// The [Hamming score metric](HammingScoreMetric) input type.
+#[derive(new)]
+pub struct HammingScoreInput<B: Backend> {
+    outputs: Tensor<B, 2>,
+    targets: Tensor<B, 2, Int>,
+}
+
+impl<B: Backend> HammingScoreMetric<B> {
+    /// Creates the metric, with the labels predicted when their probability is at least `0.5`.
+    pub fn new() -> Self {
+        Self {
+            state: NumericMetricState::default(),
+            threshold: 0.5,
+            _b: B::default(),
+        }
+    }
+
+    /// Sets the probability threshold above which a label is predicted.
+    pub fn with_threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+}
+
+impl<B: Backend> Default for HammingScoreMetric<B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<B: Backend> Metric for HammingScoreMetric<B> {
+    const NAME: &'static str = "Hamming Score";
+
+    type Input = HammingScoreInput<B>;
+
+    fn update(&mut self, input: &HammingScoreInput<B>, _metadata: &MetricMetadata) -> MetricEntry {
+        let [batch_size, num_labels] = input.outputs.dims();
+
+        let targets = input.targets.clone().to_device(&B::Device::default());
+        let predictions = sigmoid(input.outputs.clone())
+            .greater_equal_elem(self.threshold)
+            .int()
+            .to_device(&B::Device::default());
+
+        let score = predictions
+            .equal(targets)
+            .int()
+            .sum()
+            .into_scalar()
+            .elem::<f64>()
+            / (batch_size * num_labels) as f64;
+
+        self.state.update(
+            100.0 * score,
+            batch_size,
+            FormatOptions::new(Self::NAME).unit("%").precision(2),
+        )
+    }
+
+    fn clear(&mut self) {
+        self.state.reset()
+    }
+}
+
+impl<B: Backend> Numeric for HammingScoreMetric<B> {
+    fn value(&self) -> f64 {
+        self.state.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_hamming_score() {
+        let device = Default::default();
+        let mut metric = HammingScoreMetric::<TestBackend>::new();
+        let input = HammingScoreInput::new(
+            Tensor::from_data(
+                [
+                    [2.0, -1.0, 0.5],  // 1, 0, 1
+                    [-3.0, 1.0, -0.5], // 0, 1, 0
+                ],
+                &device,
+            ),
+            Tensor::from_data([[1, 0, 0], [1, 1, 0]], &device),
+        );
+
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+        assert!((metric.value() - 400.0 / 6.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,130 @@
+use super::{
+    state::{FormatOptions, NumericMetricState},
+    IterationTiming, MetricMetadata, Numeric,
+};
+use crate::metric::{Metric, MetricEntry};
+use std::time::Duration;
+
+/// Update the state with a duration in milliseconds, when the iteration is timed.
+fn update_time(
+    state: &mut NumericMetricState,
+    name: &str,
+    duration: Option<Duration>,
+) -> MetricEntry {
+    let (millis, count) = match duration {
+        Some(duration) => (duration.as_secs_f64() * 1000.0, 1),
+        None => (state.value(), 0),
+    };
+
+    state.update(
+        millis,
+        count,
+        FormatOptions::new(name).unit("ms").precision(2),
+    )
+}
+
+macro_rules! iteration_time_metric {
+    ($metric:ident, $name:literal, $doc:literal, $duration:expr) => {
+        #[doc = $doc]
+        ///
+        /// Only the training iterations are timed. On multiple devices, the batches processed in
+        /// parallel share the time of their step.
+        pub struct $metric {
+            state: NumericMetricState,
+        }
+
+        impl $metric {
+            #[doc = concat!("Creates a new ", $name, " metric.")]
+            pub fn new() -> Self {
+                Self {
+                    state: NumericMetricState::new(),
+                }
+            }
+        }
+
+        impl Default for $metric {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl Metric for $metric {
+            const NAME: &'static str = $name;
+
+            type Input = ();
+
+            fn update(&mut self, _item: &(), metadata: &MetricMetadata) -> MetricEntry {
+                let duration: fn(&IterationTiming) -> Duration = $duration;
+                update_time(
+                    &mut self.state,
+                    Self::NAME,
+                    metadata.timing.as_ref().map(duration),
+                )
+            }
+
+            fn clear(&mut self) {
+                self.state.reset()
+            }
+        }
+
+        impl Numeric for $metric {
+            fn value(&self) -> f64 {
+                self.state.value()
+            }
+        }
+    };
+}
+
+iteration_time_metric!(
+    IterationTimeMetric,
+    "Iteration Time",
+    "Track the time of each iteration in milliseconds, which is the sum of the \
+     [data loading time](DataLoadingTimeMetric) and the [step time](StepTimeMetric).",
+    |timing| timing.total()
+);
+
+iteration_time_metric!(
+    DataLoadingTimeMetric,
+    "Data Loading Time",
+    "Track the time spent waiting for each batch from the dataloader in milliseconds.",
+    |timing| timing.data_loading
+);
+
+iteration_time_metric!(
+    StepTimeMetric,
+    "Step Time",
+    "Track the time of the forward and backward passes and of the optimizer step of each batch \
+     in milliseconds.",
+    |timing| timing.step
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_break_down_the_iteration_time() {
+        let mut metadata = MetricMetadata::fake();
+        metadata.timing = Some(IterationTiming::new(
+            Duration::from_millis(5),
+            Duration::from_millis(15),
+        ));
+        let mut total = IterationTimeMetric::new();
+        let mut data_loading = DataLoadingTimeMetric::new();
+        let mut step = StepTimeMetric::new();
+
+        let entry = total.update(&(), &metadata);
+        data_loading.update(&(), &metadata);
+        step.update(&(), &metadata);
+
+        assert_eq!(entry.name, "Iteration Time");
+        assert_eq!(total.value(), 20.0);
+        assert_eq!(data_loading.value(), 5.0);
+        assert_eq!(step.value(), 15.0);
+
+        // Iterations without timing don't change the epoch value.
+        metadata.timing = None;
+        let entry = total.update(&(), &metadata);
+        assert_eq!(entry.serialize, "20");
+    }
+}
@@ -0,0 +1,82 @@
+use super::state::{FormatOptions, NumericMetricState};
+use super::{MetricEntry, MetricMetadata};
+use crate::metric::{Metric, Numeric};
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{ElementConversion, Tensor};
+
+/// The mean absolute error metric.
+#[derive(Default)]
+pub struct MeanAbsoluteErrorMetric<B: Backend> {
+    state: NumericMetricState,
+    _b: B,
+}
+
+/// The [mean absolute error metric](MeanAbsoluteErrorMetric) input type.
+#[derive(new)]
+pub struct MeanAbsoluteErrorInput<B: Backend> {
+    outputs: Tensor<B, 2>,
+    targets: Tensor<B, 2>,
+}
+
+impl<B: Backend> MeanAbsoluteErrorMetric<B> {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<B: Backend> Metric for MeanAbsoluteErrorMetric<B> {
+    const NAME: &'static str = "Mean Absolute Error";
+
+    type Input = MeanAbsoluteErrorInput<B>;
+
+    fn update(
+        &mut self,
+        input: &MeanAbsoluteErrorInput<B>,
+        _metadata: &MetricMetadata,
+    ) -> MetricEntry {
+        let num_elements = input.outputs.shape().num_elements();
+        let targets = input.targets.clone().to_device(&B::Device::default());
+        let outputs = input.outputs.clone().to_device(&B::Device::default());
+
+        let error = (outputs - targets).abs().mean().into_scalar().elem::<f64>();
+
+        // Weighted by the number of elements, so that the epoch value is the mean over all the
+        // elements of the epoch.
+        self.state.update(
+            error,
+            num_elements,
+            FormatOptions::new(Self::NAME).precision(4),
+        )
+    }
+
+    fn clear(&mut self) {
+        self.state.reset()
+    }
+}
+
+impl<B: Backend> Numeric for MeanAbsoluteErrorMetric<B> {
+    fn value(&self) -> f64 {
+        self.state.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_mean_absolute_error() {
+        let device = Default::default();
+        let mut metric = MeanAbsoluteErrorMetric::<TestBackend>::new();
+        let input = MeanAbsoluteErrorInput::new(
+            Tensor::from_data([[1.0, 2.0], [3.0, 4.0]], &device),
+            Tensor::from_data([[1.5, 2.0], [1.0, 5.0]], &device),
+        );
+
+        let entry = metric.update(&input, &MetricMetadata::fake());
+        assert_eq!(0.875, metric.value());
+        assert_eq!(entry.serialize, "0.875");
+    }
+}
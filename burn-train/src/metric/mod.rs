@@ -2,30 +2,70 @@
 pub mod state;
 
 mod acc;
+mod auroc;
 mod base;
+mod bleu;
+mod confusion_matrix;
+mod confusion_stats;
 #[cfg(feature = "metrics")]
 mod cpu_temp;
 #[cfg(feature = "metrics")]
 mod cpu_use;
 #[cfg(feature = "metrics")]
 mod cuda;
+mod device_memory;
+mod error_rate;
+mod eta;
+mod f1_score;
+mod grad_norm;
+mod hamming;
+mod iteration_time;
 mod learning_rate;
 mod loss;
+mod mae;
 #[cfg(feature = "metrics")]
 mod memory_use;
+mod param_stats;
+mod perplexity;
+mod precision;
+mod r2;
+mod recall;
+mod rmse;
+mod throughput;
+mod top_k_acc;
 
 pub use acc::*;
+pub use auroc::*;
 pub use base::*;
+pub use bleu::*;
+pub use confusion_matrix::*;
+pub use confusion_stats::{ClassReduction, ConfusionStatsInput, DecisionRule};
 #[cfg(feature = "metrics")]
 pub use cpu_temp::*;
 #[cfg(feature = "metrics")]
 pub use cpu_use::*;
 #[cfg(feature = "metrics")]
 pub use cuda::*;
+pub use device_memory::*;
+pub use error_rate::*;
+pub use eta::*;
+pub use f1_score::*;
+pub use grad_norm::*;
+pub use hamming::*;
+pub use iteration_time::*;
 pub use learning_rate::*;
 pub use loss::*;
+pub use mae::*;
 #[cfg(feature = "metrics")]
 pub use memory_use::*;
+pub use param_stats::*;
+pub use perplexity::*;
+pub use precision::*;
+pub use r2::*;
+pub use recall::*;
+pub use rmse::*;
+pub use throughput::*;
+pub use top_k_acc::*;
 
 pub(crate) mod processor;
 /// Module responsible to save and exposes data collected during training.
@@ -0,0 +1,579 @@
+use super::{format_float, MetricMetadata};
+use crate::learner::{Callback, CallbackContext};
+use crate::metric::{Metric, MetricEntry};
+use burn_core::module::{AutodiffModule, ModuleVisitor, ParamId};
+use burn_core::optim::{GradientsParams, Optimizer};
+use burn_core::tensor::backend::{AutodiffBackend, Backend};
+use burn_core::tensor::{ElementConversion, Tensor};
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
+
+/// The quantiles reported in the [statistics](TensorStats) of a tensor.
+pub const QUANTILES: [f64; 5] = [0.05, 0.25, 0.5, 0.75, 0.95];
+
+/// The number of bins of the [histogram](Histogram) of a tensor.
+const NUM_BINS: usize = 10;
+
+/// The histogram of the finite values of a tensor, with bins of equal width between the minimum
+/// and the maximum.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Histogram {
+    /// The lower bound of the first bin.
+    pub min: f64,
+    /// The upper bound of the last bin.
+    pub max: f64,
+    /// The number of values in each bin.
+    pub counts: Vec<usize>,
+}
+
+/// The statistics of the values of a tensor.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TensorStats {
+    /// The L2 norm of the finite values.
+    pub norm: f64,
+    /// The mean of the finite values.
+    pub mean: f64,
+    /// The standard deviation of the finite values.
+    pub std: f64,
+    /// The minimum of the finite values.
+    pub min: f64,
+    /// The maximum of the finite values.
+    pub max: f64,
+    /// The [quantiles](QUANTILES) of the finite values.
+    pub quantiles: Vec<f64>,
+    /// The histogram of the finite values.
+    pub histogram: Histogram,
+    /// The number of NaN or infinite values.
+    pub non_finite: usize,
+}
+
+impl TensorStats {
+    /// Compute the statistics of the given values.
+    pub fn new(values: &[f32]) -> Self {
+        let non_finite = values.iter().filter(|value| !value.is_finite()).count();
+        let mut finite = values
+            .iter()
+            .filter(|value| value.is_finite())
+            .map(|value| *value as f64)
+            .collect::<Vec<_>>();
+        if finite.is_empty() {
+            return Self {
+                non_finite,
+                ..Default::default()
+            };
+        }
+        finite.sort_by(|a, b| a.total_cmp(b));
+
+        let count = finite.len() as f64;
+        let mean = finite.iter().sum::<f64>() / count;
+        let variance = finite
+            .iter()
+            .map(|value| (value - mean).powi(2))
+            .sum::<f64>()
+            / count;
+        let norm = finite.iter().map(|value| value * value).sum::<f64>().sqrt();
+        let (min, max) = (finite[0], finite[finite.len() - 1]);
+        let quantiles = QUANTILES
+            .iter()
+            .map(|quantile| finite[((count - 1.0) * quantile).round() as usize])
+            .collect();
+
+        let mut counts = vec![0; NUM_BINS];
+        let width = (max - min) / NUM_BINS as f64;
+        for value in finite.iter() {
+            let bin = match width > 0.0 {
+                true => ((value - min) / width) as usize,
+                false => 0,
+            };
+            counts[bin.min(NUM_BINS - 1)] += 1;
+        }
+
+        Self {
+            norm,
+            mean,
+            std: variance.sqrt(),
+            min,
+            max,
+            quantiles,
+            histogram: Histogram { min, max, counts },
+            non_finite,
+        }
+    }
+}
+
+/// The statistics of a parameter of the model.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ParameterStats {
+    /// The path of the parameter in the module, such as `layers.0.linear.weight`.
+    pub path: String,
+    /// The shape of the parameter.
+    pub shape: Vec<usize>,
+    /// The statistics of the weights, before the optimizer step.
+    pub weight: TensorStats,
+    /// The statistics of the gradients, if the parameter has gradients.
+    pub grad: Option<TensorStats>,
+    /// The norm of the update of the optimizer step divided by the norm of the weights.
+    pub update_ratio: Option<f64>,
+    /// The fraction of the units, along the last dimension, without any gradient, such as the
+    /// output features of a linear layer followed by a dead ReLU.
+    pub dead_fraction: Option<f64>,
+}
+
+/// The statistics of all the parameters of the model at an iteration.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ParameterStatsReport {
+    /// The epoch.
+    pub epoch: usize,
+    /// The iteration in the epoch.
+    pub iteration: usize,
+    /// The statistics of each parameter, in the order of the module.
+    pub params: Vec<ParameterStats>,
+}
+
+impl ParameterStatsReport {
+    /// The paths of the parameters with NaN or infinite gradients.
+    pub fn non_finite_gradients(&self) -> Vec<&str> {
+        self.params
+            .iter()
+            .filter(|param| param.grad.as_ref().is_some_and(|grad| grad.non_finite > 0))
+            .map(|param| param.path.as_str())
+            .collect()
+    }
+
+    /// A summary of the parameters with the largest gradient norm, update ratio and dead
+    /// fraction.
+    fn summary(&self) -> String {
+        let largest = |value: &dyn Fn(&ParameterStats) -> Option<f64>| {
+            self.params
+                .iter()
+                .filter_map(|param| value(param).map(|value| (value, param.path.as_str())))
+                .max_by(|(a, _), (b, _)| a.total_cmp(b))
+        };
+
+        let mut parts = Vec::new();
+        if let Some((value, path)) = largest(&|param| param.grad.as_ref().map(|grad| grad.norm)) {
+            parts.push(format!("grad norm {} ({path})", format_float(value, 3)));
+        }
+        if let Some((value, path)) = largest(&|param| param.update_ratio) {
+            parts.push(format!("update ratio {} ({path})", format_float(value, 3)));
+        }
+        if let Some((value, path)) = largest(&|param| param.dead_fraction) {
+            parts.push(format!("dead {:.1}% ({path})", value * 100.0));
+        }
+        let non_finite = self.non_finite_gradients();
+        if !non_finite.is_empty() {
+            parts.push(format!("NaN/inf gradients: {}", non_finite.join(", ")));
+        }
+
+        parts.join(" - ")
+    }
+}
+
+#[derive(Default)]
+struct SharedStats {
+    /// The number of iterations before the next statistics.
+    countdown: usize,
+    /// The statistics waiting for the update ratios, with the weights before the optimizer step.
+    pending: Option<(ParameterStatsReport, Vec<Vec<f32>>)>,
+    report: Option<ParameterStatsReport>,
+    /// Whether the report wasn't reported by the metric yet.
+    fresh: bool,
+}
+
+/// A [callback](Callback) computing the [statistics](ParameterStats) of the weights and the
+/// gradients of each parameter every `interval` iterations, to debug diverging trainings.
+///
+/// The statistics are reported by the [metric](ParameterStatsCallback::metric) sharing its state
+/// with the callback, and NaN or infinite gradients are logged as warnings with the paths of the
+/// offending parameters.
+///
+/// # Notes
+///
+/// The update ratios are only known after the optimizer step, so the statistics are reported
+/// with the metrics of the next iteration.
+#[derive(Clone)]
+pub struct ParameterStatsCallback {
+    interval: usize,
+    shared: Arc<Mutex<SharedStats>>,
+}
+
+impl ParameterStatsCallback {
+    /// Creates the callback, computing the statistics every `interval` iterations.
+    pub fn new(interval: usize) -> Self {
+        Self {
+            interval: interval.max(1),
+            shared: Arc::new(Mutex::new(SharedStats::default())),
+        }
+    }
+
+    /// The metric reporting the statistics computed by the callback.
+    pub fn metric(&self) -> ParameterStatsMetric {
+        ParameterStatsMetric {
+            shared: self.shared.clone(),
+            formatted: None,
+        }
+    }
+
+    /// The last statistics computed by the callback.
+    pub fn last_report(&self) -> Option<ParameterStatsReport> {
+        self.lock().report.clone()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, SharedStats> {
+        self.shared
+            .lock()
+            .expect("Can lock the parameter statistics.")
+    }
+}
+
+impl<B, M, O> Callback<B, M, O> for ParameterStatsCallback
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B>,
+    O: Optimizer<M, B>,
+{
+    fn on_after_backward(
+        &mut self,
+        context: &CallbackContext<'_, M, O>,
+        grads: &mut GradientsParams,
+    ) {
+        let interval = self.interval;
+        let mut shared = self.lock();
+        let due = shared.countdown == 0;
+        shared.countdown = match due {
+            true => interval - 1,
+            false => shared.countdown - 1,
+        };
+        if !due {
+            return;
+        }
+
+        let (report, weights) = collect_stats::<B, M, O>(context, grads);
+        shared.pending = Some((report, weights));
+    }
+
+    /// The statistics are computed whatever the interval, and reported without update ratios
+    /// since the optimizer step is skipped.
+    fn on_non_finite_gradients(
+        &mut self,
+        context: &CallbackContext<'_, M, O>,
+        grads: &GradientsParams,
+    ) {
+        let (report, _weights) = collect_stats::<B, M, O>(context, grads);
+        let mut shared = self.lock();
+        shared.pending = None;
+        shared.report = Some(report);
+        shared.fresh = true;
+    }
+
+    fn on_batch_end(&mut self, context: &CallbackContext<'_, M, O>) {
+        let mut shared = self.lock();
+        let Some((mut report, weights)) = shared.pending.take() else {
+            return;
+        };
+
+        let mut updates = UpdateRatios::<B> {
+            weights: weights.into_iter(),
+            ratios: Vec::new(),
+            _backend: Default::default(),
+        };
+        context.model.visit(&mut updates);
+        for (param, ratio) in report.params.iter_mut().zip(updates.ratios) {
+            param.update_ratio = ratio;
+        }
+
+        shared.report = Some(report);
+        shared.fresh = true;
+    }
+}
+
+/// Compute the statistics of the parameters of the model with the given gradients, along with
+/// the weights before the optimizer step, and warn about the NaN or infinite gradients.
+fn collect_stats<B, M, O>(
+    context: &CallbackContext<'_, M, O>,
+    grads: &GradientsParams,
+) -> (ParameterStatsReport, Vec<Vec<f32>>)
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B>,
+{
+    let mut collector = StatsCollector::<B> {
+        grads,
+        path: Vec::new(),
+        params: Vec::new(),
+        weights: Vec::new(),
+        _backend: Default::default(),
+    };
+    context.model.visit(&mut collector);
+
+    let report = ParameterStatsReport {
+        epoch: context.epoch,
+        iteration: context.iteration,
+        params: collector.params,
+    };
+    let non_finite = report.non_finite_gradients();
+    if !non_finite.is_empty() {
+        log::warn!(
+            "NaN or infinite gradients at epoch {} iteration {} for the parameters: {}",
+            context.epoch,
+            context.iteration,
+            non_finite.join(", ")
+        );
+    }
+
+    (report, collector.weights)
+}
+
+/// Report the [statistics of the parameters](ParameterStatsReport) computed by a
+/// [callback](ParameterStatsCallback).
+///
+/// The statistics are serialized as JSON when computed, and the value is empty for the other
+/// iterations.
+pub struct ParameterStatsMetric {
+    shared: Arc<Mutex<SharedStats>>,
+    formatted: Option<String>,
+}
+
+impl Metric for ParameterStatsMetric {
+    const NAME: &'static str = "Parameter Stats";
+
+    type Input = ();
+
+    fn update(&mut self, _item: &(), _metadata: &MetricMetadata) -> MetricEntry {
+        let mut shared = self
+            .shared
+            .lock()
+            .expect("Can lock the parameter statistics.");
+        let fresh = std::mem::take(&mut shared.fresh);
+        let report = match fresh {
+            true => shared.report.as_ref(),
+            false => None,
+        };
+
+        let serialize = match report {
+            Some(report) => {
+                self.formatted = Some(format!("{} {}", Self::NAME, report.summary()));
+                serde_json::to_string(report).expect("Can serialize the parameter statistics.")
+            }
+            None => String::new(),
+        };
+        let formatted = self
+            .formatted
+            .clone()
+            .unwrap_or_else(|| format!("{} -", Self::NAME));
+
+        MetricEntry::new(Self::NAME.to_string(), formatted, serialize)
+    }
+
+    fn clear(&mut self) {}
+}
+
+fn values<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Vec<f32> {
+    tensor
+        .into_data()
+        .value
+        .into_iter()
+        .map(|value| value.elem::<f32>())
+        .collect()
+}
+
+/// The fraction of the units along the last dimension where all the values are zero.
+fn dead_fraction(values: &[f32], units: usize) -> f64 {
+    let mut alive = vec![false; units];
+    for (index, value) in values.iter().enumerate() {
+        if *value != 0.0 {
+            alive[index % units] = true;
+        }
+    }
+
+    alive.iter().filter(|alive| !**alive).count() as f64 / units as f64
+}
+
+struct StatsCollector<'a, B: AutodiffBackend> {
+    grads: &'a GradientsParams,
+    path: Vec<String>,
+    params: Vec<ParameterStats>,
+    weights: Vec<Vec<f32>>,
+    _backend: core::marker::PhantomData<B>,
+}
+
+impl<'a, B: AutodiffBackend> ModuleVisitor<B> for StatsCollector<'a, B> {
+    fn enter_module(&mut self, name: &str) {
+        self.path.push(name.to_string());
+    }
+
+    fn exit_module(&mut self, _name: &str) {
+        self.path.pop();
+    }
+
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        let shape = tensor.shape().dims.to_vec();
+        let weights = values(tensor.clone().inner());
+        let grad = self.grads.get::<B::InnerBackend, D>(id).map(values);
+        let units = shape.last().copied().unwrap_or(1).max(1);
+
+        self.params.push(ParameterStats {
+            path: match self.path.is_empty() {
+                true => id.to_string(),
+                false => self.path.join("."),
+            },
+            shape,
+            weight: TensorStats::new(&weights),
+            grad: grad.as_deref().map(TensorStats::new),
+            update_ratio: None,
+            dead_fraction: grad.as_deref().map(|grad| dead_fraction(grad, units)),
+        });
+        self.weights.push(weights);
+    }
+}
+
+/// The norm of the difference with the weights before the optimizer step, divided by their norm.
+struct UpdateRatios<B: AutodiffBackend> {
+    weights: std::vec::IntoIter<Vec<f32>>,
+    ratios: Vec<Option<f64>>,
+    _backend: core::marker::PhantomData<B>,
+}
+
+impl<B: AutodiffBackend> ModuleVisitor<B> for UpdateRatios<B> {
+    fn visit_float<const D: usize>(&mut self, _id: &ParamId, tensor: &Tensor<B, D>) {
+        let Some(before) = self.weights.next() else {
+            return;
+        };
+        let after = values(tensor.clone().inner());
+
+        let (mut update, mut norm) = (0.0, 0.0);
+        for (before, after) in before.iter().zip(after.iter()) {
+            update += ((after - before) as f64).powi(2);
+            norm += (*before as f64).powi(2);
+        }
+
+        self.ratios.push(match norm > 0.0 {
+            true => Some(update.sqrt() / norm.sqrt()),
+            false => None,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metric::store::{EventStoreClient, LogEventStore};
+    use crate::test_utils::TestModel;
+    use crate::{TestAutodiffBackend, TestBackend};
+    use burn_core::module::list_param_ids;
+    use burn_core::nn::LinearConfig;
+    use burn_core::optim::SgdConfig;
+    use burn_core::tensor::Data;
+
+    type TB = TestAutodiffBackend;
+
+    #[test]
+    fn should_compute_quantiles_and_histogram() {
+        let values = (0..101).map(|value| value as f32).collect::<Vec<_>>();
+        let mut with_nan = values.clone();
+        with_nan.push(f32::NAN);
+
+        let stats = TensorStats::new(&with_nan);
+
+        assert_eq!(stats.non_finite, 1);
+        assert_eq!(stats.quantiles, vec![5.0, 25.0, 50.0, 75.0, 95.0]);
+        assert_eq!((stats.min, stats.max, stats.mean), (0.0, 100.0, 50.0));
+        assert_eq!(stats.histogram.counts.iter().sum::<usize>(), 101);
+        assert_eq!(stats.histogram.counts[0], 10);
+        assert_eq!(dead_fraction(&[0.0, 1.0, 0.0, 0.0, 2.0, 0.0], 3), 2.0 / 3.0);
+    }
+
+    #[test]
+    fn should_report_stats_and_flag_non_finite_gradients() {
+        let device = Default::default();
+        let model = TestModel::<TB> {
+            linear: LinearConfig::new(2, 3).init(&device),
+        };
+        let optim = SgdConfig::new().init::<TB, TestModel<TB>>();
+        let store = EventStoreClient::new(LogEventStore::default());
+        let mut callback = ParameterStatsCallback::new(2);
+        let mut metric = callback.metric();
+
+        let mut grads = GradientsParams::new();
+        let weight = Tensor::<TestBackend, 2>::from_data(
+            Data::<f32, 2>::from([[1.0, 0.0, f32::NAN], [1.0, 0.0, 2.0]]).convert(),
+            &device,
+        );
+        grads.register(list_param_ids(&model)[0].clone(), weight);
+        let context = CallbackContext {
+            model: &model,
+            optim: &optim,
+            lr: Some(0.1),
+            epoch: 1,
+            iteration: 1,
+            store: &store,
+        };
+        Callback::<TB, _, _>::on_after_backward(&mut callback, &context, &mut grads);
+        let updated = SgdConfig::new().init().step(0.1, model.clone(), grads);
+        let context = CallbackContext {
+            model: &updated,
+            ..context
+        };
+        Callback::<TB, _, _>::on_batch_end(&mut callback, &context);
+
+        let report = callback.last_report().unwrap();
+        let weight = &report.params[0];
+        assert_eq!(weight.path, "linear.weight");
+        assert_eq!(weight.shape, vec![2, 3]);
+        assert_eq!(weight.grad.as_ref().unwrap().non_finite, 1);
+        assert_eq!(weight.dead_fraction, Some(1.0 / 3.0));
+        assert!(weight.update_ratio.is_some());
+        assert_eq!(report.params[1].path, "linear.bias");
+        assert_eq!(report.params[1].grad, None);
+        assert_eq!(report.non_finite_gradients(), vec!["linear.weight"]);
+
+        let entry = metric.update(&(), &MetricMetadata::fake());
+        assert!(entry.formatted.contains("NaN/inf gradients: linear.weight"));
+        let logged: ParameterStatsReport = serde_json::from_str(&entry.serialize).unwrap();
+        assert_eq!(logged.params.len(), 2);
+        let entry = metric.update(&(), &MetricMetadata::fake());
+        assert!(entry.serialize.is_empty());
+
+        // The statistics are computed every two iterations.
+        let mut grads = GradientsParams::new();
+        let context = CallbackContext {
+            iteration: 2,
+            ..context
+        };
+        Callback::<TB, _, _>::on_after_backward(&mut callback, &context, &mut grads);
+        Callback::<TB, _, _>::on_batch_end(&mut callback, &context);
+        assert_eq!(callback.last_report().unwrap().iteration, 1);
+    }
+
+    #[test]
+    fn should_report_non_finite_gradients_of_skipped_steps() {
+        let device = Default::default();
+        let model = TestModel::<TB> {
+            linear: LinearConfig::new(2, 3).init(&device),
+        };
+        let optim = SgdConfig::new().init::<TB, TestModel<TB>>();
+        let store = EventStoreClient::new(LogEventStore::default());
+        let mut callback = ParameterStatsCallback::new(10);
+        let mut metric = callback.metric();
+
+        let mut grads = GradientsParams::new();
+        let bias = Tensor::<TestBackend, 1>::from_floats([f32::INFINITY, 0.0, 1.0], &device);
+        grads.register(list_param_ids(&model)[1].clone(), bias);
+        let context = CallbackContext {
+            model: &model,
+            optim: &optim,
+            lr: Some(0.1),
+            epoch: 1,
+            iteration: 3,
+            store: &store,
+        };
+        Callback::<TB, _, _>::on_non_finite_gradients(&mut callback, &context, &grads);
+
+        let report = callback.last_report().unwrap();
+        assert_eq!(report.iteration, 3);
+        assert_eq!(report.non_finite_gradients(), vec!["linear.bias"]);
+        assert_eq!(report.params[1].update_ratio, None);
+        let entry = metric.update(&(), &MetricMetadata::fake());
+        assert!(entry.formatted.contains("NaN/inf gradients: linear.bias"));
+    }
+}
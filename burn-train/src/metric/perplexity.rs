@@ -0,0 +1,89 @@
+use super::state::{format_entry, FormatOptions};
+use super::{MetricEntry, MetricMetadata};
+use crate::metric::store::Aggregate;
+use crate::metric::{Metric, Numeric};
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{ElementConversion, Tensor};
+
+/// The perplexity metric, which is the exponential of the mean cross-entropy loss per token.
+///
+/// The loss is weighted by the number of tokens of each batch and accumulated over the epoch, so
+/// the value is the perplexity of all the tokens of the epoch, which is also the serialized value.
+#[derive(Default)]
+pub struct PerplexityMetric<B: Backend> {
+    sum_loss: f64,
+    num_tokens: usize,
+    _b: B,
+}
+
+/// The [perplexity metric](PerplexityMetric) input type.
+#[derive(new)]
+pub struct PerplexityInput<B: Backend> {
+    /// The mean cross-entropy loss per token, in nats.
+    loss: Tensor<B, 1>,
+    /// The number of tokens the loss is averaged over.
+    num_tokens: usize,
+}
+
+impl<B: Backend> PerplexityMetric<B> {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<B: Backend> Metric for PerplexityMetric<B> {
+    const NAME: &'static str = "Perplexity";
+    const AGGREGATE: Aggregate = Aggregate::Last;
+
+    type Input = PerplexityInput<B>;
+
+    fn update(&mut self, input: &PerplexityInput<B>, _metadata: &MetricMetadata) -> MetricEntry {
+        let loss = input.loss.clone().mean().into_scalar().elem::<f64>();
+
+        self.sum_loss += loss * input.num_tokens as f64;
+        self.num_tokens += input.num_tokens;
+
+        let running = self.value();
+        format_entry(
+            FormatOptions::new(Self::NAME).precision(3),
+            loss.exp(),
+            running,
+            running,
+        )
+    }
+
+    fn clear(&mut self) {
+        self.sum_loss = 0.0;
+        self.num_tokens = 0;
+    }
+}
+
+impl<B: Backend> Numeric for PerplexityMetric<B> {
+    fn value(&self) -> f64 {
+        (self.sum_loss / self.num_tokens as f64).exp()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_perplexity_weighted_by_tokens() {
+        let device = Default::default();
+        let mut metric = PerplexityMetric::<TestBackend>::new();
+
+        metric.update(
+            &PerplexityInput::new(Tensor::from_data([1.0], &device), 3),
+            &MetricMetadata::fake(),
+        );
+        metric.update(
+            &PerplexityInput::new(Tensor::from_data([3.0], &device), 1),
+            &MetricMetadata::fake(),
+        );
+
+        assert!((metric.value() - 1.5f64.exp()).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,173 @@
+use super::confusion_stats::{divide, ConfusionStats};
+use super::state::{format_entry, FormatOptions};
+use super::{ClassReduction, ConfusionStatsInput, DecisionRule, MetricEntry, MetricMetadata};
+use crate::metric::store::Aggregate;
+use crate::metric::{Metric, Numeric};
+use burn_core::tensor::backend::Backend;
+
+/// The precision metric, which is the fraction of the predicted classes that are correct.
+///
+/// The confusion statistics are accumulated over the epoch, so the value is the precision of all the
+/// items of the epoch, which is also the serialized value.
+pub struct PrecisionMetric<B: Backend> {
+    stats: ConfusionStats,
+    rule: DecisionRule,
+    reduction: ClassReduction,
+    _b: B,
+}
+
+impl<B: Backend> PrecisionMetric<B> {
+    /// Creates the metric for binary classification, with a single class predicted when its
+    /// probability is greater or equal to the threshold.
+    pub fn binary(threshold: f64) -> Self {
+        Self::new(DecisionRule::Threshold(threshold), ClassReduction::Micro)
+    }
+
+    /// Creates the metric for multiclass classification, with the `top_k` classes of highest
+    /// score being predicted.
+    pub fn multiclass(top_k: usize, reduction: ClassReduction) -> Self {
+        Self::new(DecisionRule::TopK(top_k), reduction)
+    }
+
+    /// Creates the metric for multilabel classification, with the classes predicted when their
+    /// probability is greater or equal to the threshold.
+    pub fn multilabel(threshold: f64, reduction: ClassReduction) -> Self {
+        Self::new(DecisionRule::Threshold(threshold), reduction)
+    }
+
+    fn new(rule: DecisionRule, reduction: ClassReduction) -> Self {
+        Self {
+            stats: ConfusionStats::default(),
+            rule,
+            reduction,
+            _b: B::default(),
+        }
+    }
+}
+
+impl<B: Backend> Metric for PrecisionMetric<B> {
+    const NAME: &'static str = "Precision";
+    const AGGREGATE: Aggregate = Aggregate::Last;
+
+    type Input = ConfusionStatsInput<B>;
+
+    fn update(
+        &mut self,
+        input: &ConfusionStatsInput<B>,
+        _metadata: &MetricMetadata,
+    ) -> MetricEntry {
+        let stats = ConfusionStats::new(input, self.rule);
+        let current = precision(&stats, self.reduction);
+        self.stats.add(&stats);
+        let running = self.value();
+
+        format_entry(
+            FormatOptions::new(Self::NAME).unit("%").precision(2),
+            current,
+            running,
+            running,
+        )
+    }
+
+    fn clear(&mut self) {
+        self.stats = ConfusionStats::default();
+    }
+}
+
+impl<B: Backend> Numeric for PrecisionMetric<B> {
+    fn value(&self) -> f64 {
+        precision(&self.stats, self.reduction)
+    }
+}
+
+/// The precision in percent.
+fn precision(stats: &ConfusionStats, reduction: ClassReduction) -> f64 {
+    100.0 * stats.reduce(reduction, |tp, fp, _fn| divide(tp, tp + fp))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_core::tensor::Tensor;
+
+    fn input() -> ConfusionStatsInput<TestBackend> {
+        let device = Default::default();
+
+        ConfusionStatsInput::new(
+            Tensor::from_data(
+                [
+                    [0.8, 0.1, 0.1], // 0
+                    [0.6, 0.3, 0.1], // 0
+                    [0.1, 0.8, 0.1], // 1
+                    [0.1, 0.2, 0.7], // 2
+                ],
+                &device,
+            ),
+            Tensor::from_data(
+                [
+                    [true, false, false],
+                    [false, true, false],
+                    [false, true, false],
+                    [false, false, true],
+                ],
+                &device,
+            ),
+        )
+    }
+
+    #[test]
+    fn test_micro_precision() {
+        let mut metric = PrecisionMetric::multiclass(1, ClassReduction::Micro);
+
+        let _entry = metric.update(&input(), &MetricMetadata::fake());
+        assert_eq!(75.0, metric.value());
+    }
+
+    #[test]
+    fn test_macro_precision() {
+        let mut metric = PrecisionMetric::multiclass(1, ClassReduction::Macro);
+
+        let _entry = metric.update(&input(), &MetricMetadata::fake());
+        // Class 0: 1 / 2, class 1: 1 / 1, class 2: 1 / 1.
+        assert!((metric.value() - 250.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_binary_precision() {
+        let device = Default::default();
+        let mut metric = PrecisionMetric::<TestBackend>::binary(0.5);
+        let input = ConfusionStatsInput::new(
+            Tensor::from_data([[0.9], [0.6], [0.2], [0.7]], &device),
+            Tensor::from_data([[true], [false], [true], [true]], &device),
+        );
+
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+        assert!((metric.value() - 200.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_precision_across_batches() {
+        let device = Default::default();
+        let mut metric = PrecisionMetric::<TestBackend>::binary(0.5);
+
+        metric.update(
+            &ConfusionStatsInput::new(
+                Tensor::from_data([[0.9]], &device),
+                Tensor::from_data([[true]], &device),
+            ),
+            &MetricMetadata::fake(),
+        );
+        let entry = metric.update(
+            &ConfusionStatsInput::new(
+                Tensor::from_data([[0.9], [0.6], [0.7]], &device),
+                Tensor::from_data([[false], [false], [true]], &device),
+            ),
+            &MetricMetadata::fake(),
+        );
+
+        // 2 of the 4 predictions of the epoch are correct, not the mean of 100% and 33%.
+        assert_eq!(50.0, metric.value());
+        assert_eq!(entry.serialize, "50");
+    }
+}
@@ -1,3 +1,4 @@
+use crate::metric::{GradientNorm, IterationTiming};
 use burn_core::data::dataloader::Progress;
 use burn_core::LearningRate;
 
@@ -42,4 +43,34 @@ pub struct LearnerItem<T> {
 
     /// The learning rate.
     pub lr: Option<LearningRate>,
+
+    #[new(default)]
+    pub(crate) grad_norm: Option<GradientNorm>,
+
+    #[new(default)]
+    pub(crate) timing: Option<IterationTiming>,
+}
+
+impl<T> LearnerItem<T> {
+    /// Set the [global norm of the gradients](LearnerItem::grad_norm).
+    pub fn with_grad_norm(mut self, grad_norm: Option<GradientNorm>) -> Self {
+        self.grad_norm = grad_norm;
+        self
+    }
+
+    /// Set the [time spent on the iteration](LearnerItem::timing).
+    pub fn with_timing(mut self, timing: Option<IterationTiming>) -> Self {
+        self.timing = timing;
+        self
+    }
+
+    /// The global norm of the gradients before clipping, when an optimizer step was performed.
+    pub fn grad_norm(&self) -> Option<&GradientNorm> {
+        self.grad_norm.as_ref()
+    }
+
+    /// The time spent loading the batch and training on it, for the training iterations.
+    pub fn timing(&self) -> Option<IterationTiming> {
+        self.timing
+    }
 }
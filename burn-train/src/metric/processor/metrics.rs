@@ -9,6 +9,8 @@ pub(crate) struct Metrics<T, V> {
     valid: Vec<Box<dyn MetricUpdater<V>>>,
     train_numeric: Vec<Box<dyn NumericMetricUpdater<T>>>,
     valid_numeric: Vec<Box<dyn NumericMetricUpdater<V>>>,
+    test: Vec<Box<dyn MetricUpdater<V>>>,
+    test_numeric: Vec<Box<dyn NumericMetricUpdater<V>>>,
 }
 
 impl<T, V> Default for Metrics<T, V> {
@@ -18,6 +20,8 @@ impl<T, V> Default for Metrics<T, V> {
             valid: Vec::default(),
             train_numeric: Vec::default(),
             valid_numeric: Vec::default(),
+            test: Vec::default(),
+            test_numeric: Vec::default(),
         }
     }
 }
@@ -63,6 +67,26 @@ impl<T, V> Metrics<T, V> {
         self.valid_numeric.push(Box::new(metric))
     }
 
+    /// Register a test metric.
+    pub(crate) fn register_test_metric<Me: Metric + 'static>(&mut self, metric: Me)
+    where
+        V: Adaptor<Me::Input> + 'static,
+    {
+        let metric = MetricWrapper::new(metric);
+        self.test.push(Box::new(metric))
+    }
+
+    /// Register a numeric test metric.
+    pub(crate) fn register_test_metric_numeric<Me: Metric + Numeric + 'static>(
+        &mut self,
+        metric: Me,
+    ) where
+        V: Adaptor<Me::Input> + 'static,
+    {
+        let metric = MetricWrapper::new(metric);
+        self.test_numeric.push(Box::new(metric))
+    }
+
     /// Update the training information from the training item.
     pub(crate) fn update_train(
         &mut self,
@@ -107,6 +131,28 @@ impl<T, V> Metrics<T, V> {
         MetricsUpdate::new(entries, entries_numeric)
     }
 
+    /// Update the test information from the test item.
+    pub(crate) fn update_test(
+        &mut self,
+        item: &LearnerItem<V>,
+        metadata: &MetricMetadata,
+    ) -> MetricsUpdate {
+        let mut entries = Vec::with_capacity(self.test.len());
+        let mut entries_numeric = Vec::with_capacity(self.test_numeric.len());
+
+        for metric in self.test.iter_mut() {
+            let state = metric.update(item, metadata);
+            entries.push(state);
+        }
+
+        for metric in self.test_numeric.iter_mut() {
+            let (state, value) = metric.update(item, metadata);
+            entries_numeric.push((state, value));
+        }
+
+        MetricsUpdate::new(entries, entries_numeric)
+    }
+
     /// Signal the end of a training epoch.
     pub(crate) fn end_epoch_train(&mut self) {
         for metric in self.train.iter_mut() {
@@ -126,6 +172,16 @@ impl<T, V> Metrics<T, V> {
             metric.clear();
         }
     }
+
+    /// Signal the end of the test split.
+    pub(crate) fn end_epoch_test(&mut self) {
+        for metric in self.test.iter_mut() {
+            metric.clear();
+        }
+        for metric in self.test_numeric.iter_mut() {
+            metric.clear();
+        }
+    }
 }
 
 impl<T> From<&LearnerItem<T>> for TrainingProgress {
@@ -141,13 +197,15 @@ impl<T> From<&LearnerItem<T>> for TrainingProgress {
 
 impl<T> From<&LearnerItem<T>> for MetricMetadata {
     fn from(item: &LearnerItem<T>) -> Self {
-        Self {
-            progress: item.progress.clone(),
-            epoch: item.epoch,
-            epoch_total: item.epoch_total,
-            iteration: item.iteration,
-            lr: item.lr,
-        }
+        Self::new(
+            item.progress.clone(),
+            item.epoch,
+            item.epoch_total,
+            item.iteration,
+            item.lr,
+        )
+        .with_grad_norm(item.grad_norm.clone())
+        .with_timing(item.timing)
     }
 }
 
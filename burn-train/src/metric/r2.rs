@@ -0,0 +1,138 @@
+use super::state::{format_entry, FormatOptions};
+use super::{MetricEntry, MetricMetadata};
+use crate::metric::store::Aggregate;
+use crate::metric::{Metric, Numeric};
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::Tensor;
+
+/// The coefficient of determination (R²) metric.
+///
+/// The statistics of each output are accumulated over the epoch, so the value is the R² of all
+/// the items of the epoch, averaged over the outputs with the same weight. The serialized value is
+/// the value of the epoch so far.
+#[derive(Default)]
+pub struct RSquaredMetric<B: Backend> {
+    stats: Vec<RSquaredStats>,
+    _b: B,
+}
+
+/// The [R² metric](RSquaredMetric) input type.
+#[derive(new)]
+pub struct RSquaredInput<B: Backend> {
+    outputs: Tensor<B, 2>,
+    targets: Tensor<B, 2>,
+}
+
+/// The statistics of an output needed to compute its R².
+#[derive(Default, Clone, Copy)]
+struct RSquaredStats {
+    count: f64,
+    sum_targets: f64,
+    sum_squared_targets: f64,
+    sum_squared_residuals: f64,
+}
+
+impl RSquaredStats {
+    fn r_squared(&self) -> f64 {
+        let total = self.sum_squared_targets - self.sum_targets.powi(2) / self.count;
+
+        1.0 - self.sum_squared_residuals / total
+    }
+
+    fn add(&mut self, other: &Self) {
+        self.count += other.count;
+        self.sum_targets += other.sum_targets;
+        self.sum_squared_targets += other.sum_squared_targets;
+        self.sum_squared_residuals += other.sum_squared_residuals;
+    }
+}
+
+impl<B: Backend> RSquaredMetric<B> {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+fn mean_r_squared(stats: &[RSquaredStats]) -> f64 {
+    stats.iter().map(RSquaredStats::r_squared).sum::<f64>() / stats.len() as f64
+}
+
+impl<B: Backend> Metric for RSquaredMetric<B> {
+    const NAME: &'static str = "R²";
+    const AGGREGATE: Aggregate = Aggregate::Last;
+
+    type Input = RSquaredInput<B>;
+
+    fn update(&mut self, input: &RSquaredInput<B>, _metadata: &MetricMetadata) -> MetricEntry {
+        let [batch_size, num_outputs] = input.outputs.dims();
+        let outputs = input.outputs.clone().into_data().convert::<f64>().value;
+        let targets = input.targets.clone().into_data().convert::<f64>().value;
+
+        let mut batch = vec![RSquaredStats::default(); num_outputs];
+        for row in 0..batch_size {
+            for (index, stats) in batch.iter_mut().enumerate() {
+                let output = outputs[row * num_outputs + index];
+                let target = targets[row * num_outputs + index];
+
+                stats.count += 1.0;
+                stats.sum_targets += target;
+                stats.sum_squared_targets += target * target;
+                stats.sum_squared_residuals += (target - output).powi(2);
+            }
+        }
+
+        self.stats.resize(num_outputs, RSquaredStats::default());
+        for (stats, batch) in self.stats.iter_mut().zip(batch.iter()) {
+            stats.add(batch);
+        }
+
+        let running = self.value();
+        format_entry(
+            FormatOptions::new(Self::NAME).precision(4),
+            mean_r_squared(&batch),
+            running,
+            running,
+        )
+    }
+
+    fn clear(&mut self) {
+        self.stats.clear();
+    }
+}
+
+impl<B: Backend> Numeric for RSquaredMetric<B> {
+    fn value(&self) -> f64 {
+        mean_r_squared(&self.stats)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_r_squared_across_batches() {
+        let device = Default::default();
+        let mut metric = RSquaredMetric::<TestBackend>::new();
+
+        metric.update(
+            &RSquaredInput::new(
+                Tensor::from_data([[1.0], [2.0]], &device),
+                Tensor::from_data([[1.0], [3.0]], &device),
+            ),
+            &MetricMetadata::fake(),
+        );
+        metric.update(
+            &RSquaredInput::new(
+                Tensor::from_data([[3.0], [4.0]], &device),
+                Tensor::from_data([[2.0], [4.0]], &device),
+            ),
+            &MetricMetadata::fake(),
+        );
+
+        // Targets 1, 3, 2, 4 with mean 2.5: total 5, residuals 2.
+        assert!((metric.value() - 0.6).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,110 @@
+use super::state::{format_entry, FormatOptions};
+use super::{MetricEntry, MetricMetadata};
+use crate::metric::store::Aggregate;
+use crate::metric::{Metric, Numeric};
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{ElementConversion, Tensor};
+
+/// The root mean squared error metric.
+///
+/// The squared errors are accumulated over the epoch, so the value is the root of the mean
+/// squared error of all the elements of the epoch, which is also the serialized value.
+#[derive(Default)]
+pub struct RootMeanSquaredErrorMetric<B: Backend> {
+    sum_squared_error: f64,
+    count: usize,
+    _b: B,
+}
+
+/// The [root mean squared error metric](RootMeanSquaredErrorMetric) input type.
+#[derive(new)]
+pub struct RootMeanSquaredErrorInput<B: Backend> {
+    outputs: Tensor<B, 2>,
+    targets: Tensor<B, 2>,
+}
+
+impl<B: Backend> RootMeanSquaredErrorMetric<B> {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<B: Backend> Metric for RootMeanSquaredErrorMetric<B> {
+    const NAME: &'static str = "Root Mean Squared Error";
+    const AGGREGATE: Aggregate = Aggregate::Last;
+
+    type Input = RootMeanSquaredErrorInput<B>;
+
+    fn update(
+        &mut self,
+        input: &RootMeanSquaredErrorInput<B>,
+        _metadata: &MetricMetadata,
+    ) -> MetricEntry {
+        let num_elements = input.outputs.shape().num_elements();
+        let targets = input.targets.clone().to_device(&B::Device::default());
+        let outputs = input.outputs.clone().to_device(&B::Device::default());
+
+        let squared_error = (outputs - targets)
+            .powf_scalar(2.0)
+            .sum()
+            .into_scalar()
+            .elem::<f64>();
+
+        self.sum_squared_error += squared_error;
+        self.count += num_elements;
+
+        let current = (squared_error / num_elements as f64).sqrt();
+        let running = self.value();
+
+        format_entry(
+            FormatOptions::new(Self::NAME).precision(4),
+            current,
+            running,
+            running,
+        )
+    }
+
+    fn clear(&mut self) {
+        self.sum_squared_error = 0.0;
+        self.count = 0;
+    }
+}
+
+impl<B: Backend> Numeric for RootMeanSquaredErrorMetric<B> {
+    fn value(&self) -> f64 {
+        (self.sum_squared_error / self.count as f64).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_root_mean_squared_error_across_batches() {
+        let device = Default::default();
+        let mut metric = RootMeanSquaredErrorMetric::<TestBackend>::new();
+
+        metric.update(
+            &RootMeanSquaredErrorInput::new(
+                Tensor::from_data([[1.0, 2.0]], &device),
+                Tensor::from_data([[1.0, 4.0]], &device),
+            ),
+            &MetricMetadata::fake(),
+        );
+        let entry = metric.update(
+            &RootMeanSquaredErrorInput::new(
+                Tensor::from_data([[0.0, 0.0], [0.0, 0.0]], &device),
+                Tensor::from_data([[1.0, 1.0], [1.0, 1.0]], &device),
+            ),
+            &MetricMetadata::fake(),
+        );
+
+        // sqrt((4 + 4 * 1) / 6), not the mean of the batch values.
+        let expected = (8.0f64 / 6.0).sqrt();
+        assert!((metric.value() - expected).abs() < 1e-9);
+        assert_eq!(entry.serialize, expected.to_string());
+    }
+}
@@ -61,33 +61,50 @@ impl NumericMetricState {
 
     /// Update the state.
     pub fn update(&mut self, value: f64, batch_size: usize, format: FormatOptions) -> MetricEntry {
-        self.sum += value * batch_size as f64;
-        self.count += batch_size;
+        // An update without items only changes the current value.
+        if batch_size > 0 {
+            self.sum += value * batch_size as f64;
+            self.count += batch_size;
+        }
         self.current = value;
 
-        let value_current = value;
         let value_running = self.sum / self.count as f64;
-        let serialized = value_current.to_string();
-
-        let (formatted_current, formatted_running) = match format.precision {
-            Some(precision) => (
-                format_float(value_current, precision),
-                format_float(value_running, precision),
-            ),
-            None => (format!("{value_current}"), format!("{value_running}")),
-        };
-
-        let formatted = match format.unit {
-            Some(unit) => {
-                format!("epoch {formatted_running} {unit} - batch {formatted_current} {unit}")
-            }
-            None => format!("epoch {formatted_running} - batch {formatted_current}"),
-        };
-
-        MetricEntry::new(format.name, formatted, serialized)
+
+        format_entry(format, value, value_running, value)
     }
 }
 
+/// Create the entry of a numeric metric with its current and running values.
+///
+/// The serialized value is the one saved by the loggers, which is the current value for metrics
+/// averaged over the batches, and the running value for metrics computed from statistics
+/// accumulated over the epoch.
+pub(crate) fn format_entry(
+    format: FormatOptions,
+    value_current: f64,
+    value_running: f64,
+    value_serialized: f64,
+) -> MetricEntry {
+    let serialized = value_serialized.to_string();
+
+    let (formatted_current, formatted_running) = match format.precision {
+        Some(precision) => (
+            format_float(value_current, precision),
+            format_float(value_running, precision),
+        ),
+        None => (format!("{value_current}"), format!("{value_running}")),
+    };
+
+    let formatted = match format.unit {
+        Some(unit) => {
+            format!("epoch {formatted_running} {unit} - batch {formatted_current} {unit}")
+        }
+        None => format!("epoch {formatted_running} - batch {formatted_current}"),
+    };
+
+    MetricEntry::new(format.name, formatted, serialized)
+}
+
 impl Numeric for NumericMetricState {
     fn value(&self) -> f64 {
         self.current
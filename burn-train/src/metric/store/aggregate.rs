@@ -1,12 +1,47 @@
 use crate::logger::MetricLogger;
+use crate::metric::MetricEntry;
 use std::collections::HashMap;
 
 use super::{Aggregate, Direction};
 
 /// Type that can be used to fetch and use numeric metric aggregates.
-#[derive(Default, Debug)]
+#[derive(Debug)]
 pub(crate) struct NumericMetricsAggregate {
     value_for_each_epoch: HashMap<Key, f64>,
+    /// The last completed epoch, since only the values of completed epochs can be cached.
+    completed_epoch: usize,
+    /// The epoch of the values logged, numbered like the loggers number it.
+    epoch: usize,
+    /// The running aggregates of the values logged during the epoch in progress, so that they
+    /// aren't read again from the loggers at each iteration.
+    running: HashMap<String, RunningAggregate>,
+}
+
+impl Default for NumericMetricsAggregate {
+    fn default() -> Self {
+        Self {
+            value_for_each_epoch: HashMap::new(),
+            completed_epoch: 0,
+            epoch: 1,
+            running: HashMap::new(),
+        }
+    }
+}
+
+#[derive(Default, Debug)]
+struct RunningAggregate {
+    sum: f64,
+    count: usize,
+    last: f64,
+}
+
+impl RunningAggregate {
+    fn value(&self, aggregate: Aggregate) -> f64 {
+        match aggregate {
+            Aggregate::Mean => self.sum / self.count as f64,
+            Aggregate::Last => self.last,
+        }
+    }
 }
 
 #[derive(new, Hash, PartialEq, Eq, Debug)]
@@ -17,6 +52,27 @@ struct Key {
 }
 
 impl NumericMetricsAggregate {
+    /// Update the running aggregate of the epoch in progress with an entry given to the loggers.
+    pub(crate) fn log(&mut self, entry: &MetricEntry) {
+        // The loggers only read back the numeric values.
+        let Ok(value) = entry.serialize.parse::<f64>() else {
+            return;
+        };
+
+        let running = self.running.entry(entry.name.clone()).or_default();
+        running.sum += value;
+        running.count += 1;
+        running.last = value;
+    }
+
+    /// Mark the epoch as completed, so that its values don't change anymore, with the same epoch
+    /// as the one given to the loggers.
+    pub(crate) fn end_epoch(&mut self, epoch: usize) {
+        self.completed_epoch = self.completed_epoch.max(epoch);
+        self.epoch = epoch + 1;
+        self.running.clear();
+    }
+
     pub(crate) fn aggregate(
         &mut self,
         name: &str,
@@ -24,6 +80,12 @@ impl NumericMetricsAggregate {
         aggregate: Aggregate,
         loggers: &mut [Box<dyn MetricLogger>],
     ) -> Option<f64> {
+        if epoch == self.epoch {
+            if let Some(running) = self.running.get(name) {
+                return Some(running.value(aggregate));
+            }
+        }
+
         let key = Key::new(name.to_string(), epoch, aggregate);
 
         if let Some(value) = self.value_for_each_epoch.get(&key) {
@@ -49,12 +111,15 @@ impl NumericMetricsAggregate {
         }
 
         let num_points = points.len();
-        let sum = points.into_iter().sum::<f64>();
         let value = match aggregate {
-            Aggregate::Mean => sum / num_points as f64,
+            Aggregate::Mean => points.into_iter().sum::<f64>() / num_points as f64,
+            Aggregate::Last => points[num_points - 1],
         };
 
-        self.value_for_each_epoch.insert(key, value);
+        // The values of the epoch in progress change at each iteration.
+        if epoch <= self.completed_epoch {
+            self.value_for_each_epoch.insert(key, value);
+        }
         Some(value)
     }
 
@@ -159,4 +224,35 @@ mod tests {
 
         assert_eq!(value, 2);
     }
+
+    #[test]
+    fn should_aggregate_the_epoch_in_progress_without_reading_the_loggers() {
+        let mut aggregate = NumericMetricsAggregate::default();
+        let entry =
+            |value: f64| MetricEntry::new(NAME.into(), value.to_string(), value.to_string());
+
+        aggregate.log(&entry(1.0));
+        aggregate.log(&entry(2.0));
+        // Without any logger, reading the values would fail.
+        assert_eq!(
+            aggregate.aggregate(NAME, 1, Aggregate::Mean, &mut []),
+            Some(1.5)
+        );
+        aggregate.log(&entry(6.0));
+        assert_eq!(
+            aggregate.aggregate(NAME, 1, Aggregate::Mean, &mut []),
+            Some(3.0)
+        );
+        assert_eq!(
+            aggregate.aggregate(NAME, 1, Aggregate::Last, &mut []),
+            Some(6.0)
+        );
+
+        aggregate.end_epoch(1);
+        aggregate.log(&entry(4.0));
+        assert_eq!(
+            aggregate.aggregate(NAME, 2, Aggregate::Mean, &mut []),
+            Some(4.0)
+        );
+    }
 }
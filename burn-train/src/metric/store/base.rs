@@ -1,4 +1,5 @@
-use crate::metric::MetricEntry;
+use crate::metric::{Metric, MetricEntry};
+use serde::{Deserialize, Serialize};
 
 /// Event happening during the training/validation process.
 pub enum Event {
@@ -41,22 +42,53 @@ pub trait EventStore: Send {
         aggregate: Aggregate,
         split: Split,
     ) -> Option<f64>;
+
+    /// Continue collecting the events at the given epoch, when the training is resumed from a
+    /// checkpoint.
+    fn resume(&mut self, _epoch: usize) {}
 }
 
 #[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
 /// How to aggregate the metric.
+///
+/// The [aggregate declared by a metric](Metric::AGGREGATE) is the one to use by default, such as
+/// `RootMeanSquaredErrorMetric::<B>::AGGREGATE` for the strategies and objectives created from
+/// the metric.
 pub enum Aggregate {
     /// Compute the average.
     Mean,
+    /// Take the last value, which is the value of the whole epoch for the metrics computed from
+    /// statistics accumulated over the epoch, such as the
+    /// [root mean squared error](crate::metric::RootMeanSquaredErrorMetric).
+    Last,
 }
 
-#[derive(Copy, Clone)]
+impl Aggregate {
+    /// The aggregate chosen for the given metric, with a warning when it is the mean of a metric
+    /// [declaring](Metric::AGGREGATE) the [last](Aggregate::Last) value, since the mean of its
+    /// running values isn't meaningful.
+    pub(crate) fn chosen_for<M: Metric>(self) -> Self {
+        if self == Aggregate::Mean && M::AGGREGATE == Aggregate::Last {
+            log::warn!(
+                "The mean of the metric '{}' is used, although it declares its last value as its \
+                 aggregate. Use `AGGREGATE` of the metric to aggregate it by default.",
+                M::NAME
+            );
+        }
+
+        self
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
 /// The split to use.
 pub enum Split {
     /// The training split.
     Train,
     /// The validation split.
     Valid,
+    /// The test split, used by the [evaluator](crate::Evaluator).
+    Test,
 }
 
 #[derive(Copy, Clone)]
@@ -39,6 +39,13 @@ impl EventStoreClient {
             .expect("Can send event to event store thread.");
     }
 
+    /// Add a test event to the [event store](EventStore).
+    pub(crate) fn add_event_test(&self, event: Event) {
+        self.sender
+            .send(Message::OnEventTest(event))
+            .expect("Can send event to event store thread.");
+    }
+
     /// Find the epoch following the given criteria from the collected data.
     pub fn find_epoch(
         &self,
@@ -64,6 +71,14 @@ impl EventStoreClient {
         }
     }
 
+    /// Continue collecting the events at the given epoch, when the training is resumed from a
+    /// checkpoint.
+    pub(crate) fn resume(&self, epoch: usize) {
+        self.sender
+            .send(Message::Resume(epoch))
+            .expect("Can send event to event store thread.");
+    }
+
     /// Find the metric value for the current epoch following the given criteria.
     pub fn find_metric(
         &self,
@@ -120,6 +135,8 @@ where
                 }
                 Message::OnEventTrain(event) => self.store.add_event(event, Split::Train),
                 Message::OnEventValid(event) => self.store.add_event(event, Split::Valid),
+                Message::OnEventTest(event) => self.store.add_event(event, Split::Test),
+                Message::Resume(epoch) => self.store.resume(epoch),
             }
         }
     }
@@ -128,6 +145,8 @@ where
 enum Message {
     OnEventTrain(Event),
     OnEventValid(Event),
+    OnEventTest(Event),
+    Resume(usize),
     End,
     FindEpoch(
         String,
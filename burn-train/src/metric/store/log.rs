@@ -5,8 +5,10 @@ use crate::logger::MetricLogger;
 pub(crate) struct LogEventStore {
     loggers_train: Vec<Box<dyn MetricLogger>>,
     loggers_valid: Vec<Box<dyn MetricLogger>>,
+    loggers_test: Vec<Box<dyn MetricLogger>>,
     aggregate_train: NumericMetricsAggregate,
     aggregate_valid: NumericMetricsAggregate,
+    aggregate_test: NumericMetricsAggregate,
 }
 
 impl EventStore for LogEventStore {
@@ -19,6 +21,7 @@ impl EventStore for LogEventStore {
                         .iter()
                         .chain(update.entries_numeric.iter().map(|(entry, _value)| entry))
                         .for_each(|entry| {
+                            self.aggregate_train.log(entry);
                             self.loggers_train
                                 .iter_mut()
                                 .for_each(|logger| logger.log(entry));
@@ -30,21 +33,44 @@ impl EventStore for LogEventStore {
                         .iter()
                         .chain(update.entries_numeric.iter().map(|(entry, _value)| entry))
                         .for_each(|entry| {
+                            self.aggregate_valid.log(entry);
                             self.loggers_valid
                                 .iter_mut()
                                 .for_each(|logger| logger.log(entry));
                         });
                 }
+                Split::Test => {
+                    update
+                        .entries
+                        .iter()
+                        .chain(update.entries_numeric.iter().map(|(entry, _value)| entry))
+                        .for_each(|entry| {
+                            self.aggregate_test.log(entry);
+                            self.loggers_test
+                                .iter_mut()
+                                .for_each(|logger| logger.log(entry));
+                        });
+                }
             },
             Event::EndEpoch(epoch) => match split {
-                Split::Train => self
-                    .loggers_train
-                    .iter_mut()
-                    .for_each(|logger| logger.end_epoch(epoch)),
-                Split::Valid => self
-                    .loggers_valid
-                    .iter_mut()
-                    .for_each(|logger| logger.end_epoch(epoch + 1)),
+                Split::Train => {
+                    self.aggregate_train.end_epoch(epoch);
+                    self.loggers_train
+                        .iter_mut()
+                        .for_each(|logger| logger.end_epoch(epoch))
+                }
+                Split::Valid => {
+                    self.aggregate_valid.end_epoch(epoch + 1);
+                    self.loggers_valid
+                        .iter_mut()
+                        .for_each(|logger| logger.end_epoch(epoch + 1))
+                }
+                Split::Test => {
+                    self.aggregate_test.end_epoch(epoch);
+                    self.loggers_test
+                        .iter_mut()
+                        .for_each(|logger| logger.end_epoch(epoch))
+                }
             },
         }
     }
@@ -65,6 +91,10 @@ impl EventStore for LogEventStore {
                 self.aggregate_valid
                     .find_epoch(name, aggregate, direction, &mut self.loggers_valid)
             }
+            Split::Test => {
+                self.aggregate_test
+                    .find_epoch(name, aggregate, direction, &mut self.loggers_test)
+            }
         }
     }
 
@@ -84,8 +114,22 @@ impl EventStore for LogEventStore {
                 self.aggregate_valid
                     .aggregate(name, epoch, aggregate, &mut self.loggers_valid)
             }
+            Split::Test => {
+                self.aggregate_test
+                    .aggregate(name, epoch, aggregate, &mut self.loggers_test)
+            }
         }
     }
+
+    fn resume(&mut self, epoch: usize) {
+        let completed = epoch.saturating_sub(1);
+        self.aggregate_train.end_epoch(completed);
+        self.aggregate_valid.end_epoch(completed);
+        self.loggers_train
+            .iter_mut()
+            .chain(self.loggers_valid.iter_mut())
+            .for_each(|logger| logger.resume(epoch));
+    }
 }
 
 impl LogEventStore {
@@ -98,4 +142,9 @@ impl LogEventStore {
     pub(crate) fn register_logger_valid<ML: MetricLogger + 'static>(&mut self, logger: ML) {
         self.loggers_valid.push(Box::new(logger));
     }
+
+    /// Register a logger for test metrics.
+    pub(crate) fn register_logger_test<ML: MetricLogger + 'static>(&mut self, logger: ML) {
+        self.loggers_test.push(Box::new(logger));
+    }
 }
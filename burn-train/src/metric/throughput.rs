@@ -0,0 +1,191 @@
+use super::{
+    state::{FormatOptions, NumericMetricState},
+    MetricMetadata, Numeric,
+};
+use crate::metric::{Metric, MetricEntry};
+use std::time::{Duration, Instant};
+
+/// The time elapsed since the previous update of a rate metric.
+#[derive(Default)]
+struct RateClock {
+    last: Option<(Instant, usize)>,
+}
+
+impl RateClock {
+    /// The number of items processed and the time elapsed since the previous update.
+    ///
+    /// The first update of an epoch uses the [timing](crate::metric::IterationTiming) of the
+    /// iteration when available, since the previous update may be from the validation.
+    fn tick(&mut self, metadata: &MetricMetadata) -> Option<(usize, Duration)> {
+        let now = Instant::now();
+        let processed = metadata.progress.items_processed;
+        let previous = self.last.filter(|(_, last)| *last < processed);
+        self.last = Some((now, processed));
+
+        let (items, elapsed) = match previous {
+            Some((time, last)) => (processed - last, now - time),
+            None => (processed, metadata.timing?.total()),
+        };
+
+        match elapsed.is_zero() {
+            true => None,
+            false => Some((items, elapsed)),
+        }
+    }
+}
+
+/// Track the number of items processed per second.
+pub struct ThroughputMetric {
+    state: NumericMetricState,
+    clock: RateClock,
+}
+
+impl ThroughputMetric {
+    /// Creates a new throughput metric.
+    pub fn new() -> Self {
+        Self {
+            state: NumericMetricState::new(),
+            clock: RateClock::default(),
+        }
+    }
+}
+
+impl Default for ThroughputMetric {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Metric for ThroughputMetric {
+    const NAME: &'static str = "Throughput";
+
+    type Input = ();
+
+    fn update(&mut self, _item: &(), metadata: &MetricMetadata) -> MetricEntry {
+        let (throughput, items) = match self.clock.tick(metadata) {
+            Some((items, elapsed)) => (items as f64 / elapsed.as_secs_f64(), items),
+            None => (self.state.value(), 0),
+        };
+
+        self.state.update(
+            throughput,
+            items,
+            FormatOptions::new(Self::NAME).unit("items/s").precision(1),
+        )
+    }
+
+    fn clear(&mut self) {
+        self.state.reset();
+        self.clock = RateClock::default();
+    }
+}
+
+impl Numeric for ThroughputMetric {
+    fn value(&self) -> f64 {
+        self.state.value()
+    }
+}
+
+/// The [token throughput metric](TokenThroughputMetric) input type.
+#[derive(new)]
+pub struct TokenCountInput {
+    /// The number of tokens in the batch, such as the number of non-padding tokens.
+    pub count: usize,
+}
+
+/// Track the number of tokens processed per second, with the number of tokens of each batch
+/// provided by an [adaptor](crate::metric::Adaptor) of the model output.
+pub struct TokenThroughputMetric {
+    state: NumericMetricState,
+    clock: RateClock,
+}
+
+impl TokenThroughputMetric {
+    /// Creates a new token throughput metric.
+    pub fn new() -> Self {
+        Self {
+            state: NumericMetricState::new(),
+            clock: RateClock::default(),
+        }
+    }
+}
+
+impl Default for TokenThroughputMetric {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Metric for TokenThroughputMetric {
+    const NAME: &'static str = "Token Throughput";
+
+    type Input = TokenCountInput;
+
+    fn update(&mut self, item: &TokenCountInput, metadata: &MetricMetadata) -> MetricEntry {
+        let (throughput, tokens) = match self.clock.tick(metadata) {
+            Some((_items, elapsed)) => (item.count as f64 / elapsed.as_secs_f64(), item.count),
+            None => (self.state.value(), 0),
+        };
+
+        self.state.update(
+            throughput,
+            tokens,
+            FormatOptions::new(Self::NAME).unit("tokens/s").precision(1),
+        )
+    }
+
+    fn clear(&mut self) {
+        self.state.reset();
+        self.clock = RateClock::default();
+    }
+}
+
+impl Numeric for TokenThroughputMetric {
+    fn value(&self) -> f64 {
+        self.state.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metric::IterationTiming;
+    use burn_core::data::dataloader::Progress;
+
+    fn metadata(items_processed: usize, timing: Option<IterationTiming>) -> MetricMetadata {
+        MetricMetadata {
+            progress: Progress::new(items_processed, 100),
+            timing,
+            ..MetricMetadata::fake()
+        }
+    }
+
+    #[test]
+    fn should_compute_throughput_from_the_iteration_timing_and_the_elapsed_time() {
+        let mut metric = ThroughputMetric::new();
+        let timing = IterationTiming::new(Duration::from_millis(50), Duration::from_millis(150));
+
+        metric.update(&(), &metadata(10, Some(timing)));
+        assert_eq!(metric.value(), 50.0);
+
+        std::thread::sleep(Duration::from_millis(20));
+        metric.update(&(), &metadata(20, Some(timing)));
+        assert!(metric.value() > 0.0 && metric.value() <= 500.0);
+
+        // A new epoch without timing doesn't have a value.
+        metric.clear();
+        metric.update(&(), &metadata(10, None));
+        assert!(metric.value().is_nan());
+    }
+
+    #[test]
+    fn should_compute_token_throughput() {
+        let mut metric = TokenThroughputMetric::new();
+        let timing = IterationTiming::new(Duration::ZERO, Duration::from_millis(500));
+
+        let entry = metric.update(&TokenCountInput::new(64), &metadata(4, Some(timing)));
+
+        assert_eq!(metric.value(), 128.0);
+        assert_eq!(entry.serialize, "128");
+    }
+}
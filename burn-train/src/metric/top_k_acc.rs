@@ -0,0 +1,120 @@
+use super::state::{FormatOptions, NumericMetricState};
+use super::{MetricEntry, MetricMetadata};
+use crate::metric::{Metric, Numeric};
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{Int, Tensor};
+
+/// The top-k accuracy metric, where an item is correctly classified when its target is among the
+/// `k` classes of highest score.
+pub struct TopKAccuracyMetric<B: Backend> {
+    k: usize,
+    state: NumericMetricState,
+    pad_token: Option<usize>,
+    _b: B,
+}
+
+/// The [top-k accuracy metric](TopKAccuracyMetric) input type.
+#[derive(new)]
+pub struct TopKAccuracyInput<B: Backend> {
+    outputs: Tensor<B, 2>,
+    targets: Tensor<B, 1, Int>,
+}
+
+impl<B: Backend> TopKAccuracyMetric<B> {
+    /// Creates the metric.
+    pub fn new(k: usize) -> Self {
+        Self {
+            k,
+            state: NumericMetricState::default(),
+            pad_token: None,
+            _b: B::default(),
+        }
+    }
+
+    /// Sets the pad token.
+    pub fn with_pad_token(mut self, index: usize) -> Self {
+        self.pad_token = Some(index);
+        self
+    }
+}
+
+impl<B: Backend> Metric for TopKAccuracyMetric<B> {
+    const NAME: &'static str = "Top-K Accuracy";
+
+    type Input = TopKAccuracyInput<B>;
+
+    fn update(&mut self, input: &TopKAccuracyInput<B>, _metadata: &MetricMetadata) -> MetricEntry {
+        let [batch_size, num_classes] = input.outputs.dims();
+
+        let outputs = input.outputs.clone().into_data().convert::<f64>().value;
+        let targets = input.targets.clone().into_data().convert::<i64>().value;
+
+        let mut num_items = 0;
+        let mut num_correct = 0;
+
+        for (row, target) in targets.into_iter().enumerate() {
+            if self.pad_token.map(|pad| pad as i64) == Some(target) {
+                continue;
+            }
+
+            let scores = &outputs[row * num_classes..(row + 1) * num_classes];
+            let target_score = scores[target as usize];
+            // Classes with the same score as the target are ranked after it.
+            let rank = scores.iter().filter(|score| **score > target_score).count();
+
+            num_items += 1;
+            if rank < self.k {
+                num_correct += 1;
+            }
+        }
+
+        let accuracy = match num_items {
+            0 => 0.0,
+            _ => num_correct as f64 / num_items as f64,
+        };
+
+        self.state.update(
+            100.0 * accuracy,
+            batch_size,
+            FormatOptions::new(Self::NAME).unit("%").precision(2),
+        )
+    }
+
+    fn clear(&mut self) {
+        self.state.reset()
+    }
+}
+
+impl<B: Backend> Numeric for TopKAccuracyMetric<B> {
+    fn value(&self) -> f64 {
+        self.state.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_top_k_accuracy() {
+        let device = Default::default();
+        let mut metric = TopKAccuracyMetric::<TestBackend>::new(2).with_pad_token(3);
+        let input = TopKAccuracyInput::new(
+            Tensor::from_data(
+                [
+                    [0.0, 0.2, 0.8, 0.0], // 2, 1
+                    [1.0, 2.0, 0.5, 0.0], // 1, 0
+                    [0.4, 0.1, 0.2, 0.0], // 0, 2
+                    [0.6, 0.7, 0.2, 0.0], // 1, 0
+                    [0.0, 0.1, 0.2, 5.0], // Padding should not count
+                ],
+                &device,
+            ),
+            Tensor::from_data([2, 2, 1, 0, 3], &device),
+        );
+
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+        assert_eq!(50.0, metric.value());
+    }
+}
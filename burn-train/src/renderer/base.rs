@@ -31,6 +31,28 @@ pub trait MetricsRenderer: Send + Sync {
     ///
     /// * `item` - The validation progress.
     fn render_valid(&mut self, item: TrainingProgress);
+
+    /// Updates the test metric state.
+    ///
+    /// By default, the test metrics are rendered as validation metrics.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - The metric state.
+    fn update_test(&mut self, state: MetricState) {
+        self.update_valid(state)
+    }
+
+    /// Renders the test progress.
+    ///
+    /// By default, the test progress is rendered as the validation progress.
+    ///
+    /// # Arguments
+    ///
+    /// * `item` - The test progress.
+    fn render_test(&mut self, item: TrainingProgress) {
+        self.render_valid(item)
+    }
 }
 
 /// The state of a metric.
@@ -1,6 +1,9 @@
 mod base;
 pub use base::*;
 
+mod web;
+pub use web::*;
+
 #[cfg(not(feature = "tui"))]
 mod cli;
 #[cfg(not(feature = "tui"))]
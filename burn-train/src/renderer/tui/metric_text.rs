@@ -57,17 +57,22 @@ impl TextMetricView {
         let mut lines = Vec::with_capacity(names.len() * 4);
 
         let start_line = |title: &str| vec![Span::from(format!(" {title} ")).bold().yellow()];
-        let train_line = |formatted: &str| {
-            vec![
-                Span::from("   Train ").bold(),
-                Span::from(formatted.to_string()).italic(),
-            ]
-        };
-        let valid_line = |formatted: &str| {
-            vec![
-                Span::from("   Valid ").bold(),
-                Span::from(formatted.to_string()).italic(),
-            ]
+        // Multi-line entries, such as matrices, are shown below the split name.
+        let split_lines = |split: &'static str, formatted: &str| {
+            let mut formatted = formatted.lines();
+            let mut split_lines = vec![vec![
+                Span::from(split).bold(),
+                Span::from(formatted.next().unwrap_or_default().to_string()).italic(),
+            ]];
+
+            for line in formatted {
+                split_lines.push(vec![
+                    Span::from(" ".repeat(split.len())),
+                    Span::from(line.to_string()).italic(),
+                ]);
+            }
+
+            split_lines
         };
 
         for name in names {
@@ -76,11 +81,11 @@ impl TextMetricView {
             let entry = data.get(name).unwrap();
 
             if let Some(entry) = &entry.train {
-                lines.push(train_line(&entry.formatted));
+                lines.extend(split_lines("   Train ", &entry.formatted));
             }
 
             if let Some(entry) = &entry.valid {
-                lines.push(valid_line(&entry.formatted));
+                lines.extend(split_lines("   Valid ", &entry.formatted));
             }
 
             lines.push(vec![Span::from("")]);
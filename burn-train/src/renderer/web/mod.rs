@@ -0,0 +1,7 @@
+mod page;
+mod renderer;
+mod server;
+mod state;
+
+pub use renderer::*;
+pub use server::*;
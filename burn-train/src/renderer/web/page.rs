@@ -0,0 +1,137 @@
+/// The page of the dashboard, rendering the state received from the server-sent events.
+pub(crate) const PAGE: &str = r##"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>Burn Training Dashboard</title>
+<style>
+  body { font-family: sans-serif; margin: 1.5em; background: #fafafa; color: #222; }
+  h1 { font-size: 1.3em; margin: 0 0 0.5em; }
+  h2 { font-size: 1.1em; margin: 1.2em 0 0.5em; }
+  #status { font-weight: bold; }
+  .bar { background: #ddd; height: 0.8em; border-radius: 0.4em; overflow: hidden; margin: 0.4em 0; }
+  .bar > div { background: #e65100; height: 100%; width: 0; }
+  .grid { display: flex; flex-wrap: wrap; gap: 1em; }
+  .card { background: #fff; border: 1px solid #ddd; border-radius: 0.3em; padding: 0.6em; }
+  .card h3 { font-size: 0.95em; margin: 0 0 0.3em; }
+  .legend span { font-size: 0.8em; margin-right: 1em; }
+  canvas { display: block; }
+  table { border-collapse: collapse; font-size: 0.9em; }
+  td, th { border: 1px solid #ddd; padding: 0.2em 0.6em; text-align: left; }
+</style>
+</head>
+<body>
+<h1>Burn Training Dashboard <span id="status"></span></h1>
+<div id="progress"></div>
+<div class="bar"><div id="bar"></div></div>
+<h2>Metrics</h2>
+<div class="grid" id="metrics"></div>
+<h2>Latest values</h2>
+<table id="texts"></table>
+<h2>System</h2>
+<div class="grid" id="system"></div>
+<script>
+const COLORS = { train: "#1565c0", valid: "#e65100", test: "#2e7d32", system: "#6a1b9a" };
+const SPLITS = ["train", "valid", "test"];
+
+function formatSecs(secs) {
+  const h = Math.floor(secs / 3600), m = Math.floor(secs / 60) % 60, s = secs % 60;
+  return (h > 0 ? h + "h " : "") + (h > 0 || m > 0 ? m + "m " : "") + s + "s";
+}
+
+function card(container, id, title) {
+  let element = document.getElementById(id);
+  if (!element) {
+    element = document.createElement("div");
+    element.className = "card";
+    element.id = id;
+    element.innerHTML = "<h3></h3><canvas width='420' height='220'></canvas><div class='legend'></div>";
+    element.querySelector("h3").textContent = title;
+    container.appendChild(element);
+  }
+  return element;
+}
+
+function plot(element, series, xLabel) {
+  const canvas = element.querySelector("canvas");
+  const context = canvas.getContext("2d");
+  const [width, height, margin] = [canvas.width, canvas.height, 40];
+  context.clearRect(0, 0, width, height);
+
+  const points = series.flatMap(s => s.points).filter(p => p[1] !== null && isFinite(p[1]));
+  if (points.length === 0) return;
+  let [xMin, xMax] = [Math.min(...points.map(p => p[0])), Math.max(...points.map(p => p[0]))];
+  let [yMin, yMax] = [Math.min(...points.map(p => p[1])), Math.max(...points.map(p => p[1]))];
+  if (xMax === xMin) { xMax = xMin + 1; }
+  if (yMax === yMin) { yMax = yMin + 1; yMin = yMin - 1; }
+  const x = v => margin + (v - xMin) / (xMax - xMin) * (width - margin - 10);
+  const y = v => height - 20 - (v - yMin) / (yMax - yMin) * (height - 30);
+
+  context.strokeStyle = "#999";
+  context.fillStyle = "#555";
+  context.font = "10px sans-serif";
+  context.beginPath();
+  context.moveTo(margin, 10);
+  context.lineTo(margin, height - 20);
+  context.lineTo(width - 10, height - 20);
+  context.stroke();
+  context.fillText(yMax.toPrecision(4), 2, 14);
+  context.fillText(yMin.toPrecision(4), 2, height - 22);
+  context.fillText(xMin.toFixed(2), margin, height - 6);
+  context.fillText(xMax.toFixed(2) + " " + xLabel, width - 80, height - 6);
+
+  for (const s of series) {
+    context.strokeStyle = s.color;
+    context.beginPath();
+    s.points.forEach((p, i) => i === 0 ? context.moveTo(x(p[0]), y(p[1])) : context.lineTo(x(p[0]), y(p[1])));
+    context.stroke();
+  }
+  element.querySelector(".legend").innerHTML = series
+    .map(s => "<span style='color:" + s.color + "'>" + s.name + "</span>").join("");
+}
+
+function render(state) {
+  document.getElementById("status").textContent = "(" + state.status + ")";
+
+  const progress = state.progress;
+  if (progress) {
+    const total = progress.items_total * progress.epoch_total;
+    const done = progress.items_total * (progress.epoch - 1) + progress.items_processed;
+    document.getElementById("bar").style.width = (total > 0 ? 100 * done / total : 0) + "%";
+    document.getElementById("progress").textContent = progress.split + " - epoch " + progress.epoch
+      + "/" + progress.epoch_total + " - iteration " + progress.iteration + " - items "
+      + progress.items_processed + "/" + progress.items_total + " - elapsed "
+      + formatSecs(progress.elapsed_secs)
+      + (progress.eta_secs !== null ? " - ETA " + formatSecs(progress.eta_secs) : "");
+  } else if (state.status === "Replay") {
+    document.getElementById("bar").style.width = "100%";
+  }
+
+  const names = new Set(SPLITS.flatMap(split => Object.keys(state[split].metrics)));
+  const texts = [];
+  for (const name of [...names].sort()) {
+    const series = SPLITS
+      .filter(split => state[split].metrics[name])
+      .map(split => ({ name: split, color: COLORS[split], ...state[split].metrics[name] }));
+    series.forEach(s => texts.push([s.name, s.text]));
+    if (series.some(s => s.points.length > 0)) {
+      plot(card(document.getElementById("metrics"), "metric-" + name, name), series, "epochs");
+    }
+  }
+  document.getElementById("texts").innerHTML = texts
+    .map(([split, text]) => "<tr><th>" + split + "</th><td></td></tr>").join("");
+  document.querySelectorAll("#texts td").forEach((td, i) => td.textContent = texts[i][1]);
+
+  for (const [name, metric] of Object.entries(state.system.metrics)) {
+    const element = card(document.getElementById("system"), "system-" + name, name);
+    plot(element, [{ name: metric.text, color: COLORS.system, ...metric }], "secs");
+  }
+}
+
+fetch("api/state").then(response => response.json()).then(render);
+const events = new EventSource("events");
+events.addEventListener("state", event => render(JSON.parse(event.data)));
+</script>
+</body>
+</html>
+"##;
@@ -0,0 +1,278 @@
+use super::state::{epoch_position, DashboardState, ProgressState};
+use super::DashboardServer;
+use crate::metric::store::Split;
+use crate::renderer::{MetricState, MetricsRenderer, TrainingProgress};
+use std::net::{SocketAddr, ToSocketAddrs};
+use std::time::Instant;
+
+/// A renderer serving a local web dashboard of the training, to follow the training of detached
+/// sessions or share it with teammates.
+///
+/// # Notes
+///
+/// The dashboard is served by a [server](DashboardServer) that keeps running after the training,
+/// and a finished run can be viewed again with [replay](DashboardServer::replay).
+pub struct WebMetricsRenderer {
+    server: DashboardServer,
+    pending: Vec<MetricState>,
+    started: Instant,
+    estimate: Option<(Instant, f64)>,
+    #[cfg(feature = "metrics")]
+    system: SystemMetrics,
+}
+
+impl WebMetricsRenderer {
+    /// Creates the renderer, serving the dashboard at the given address, such as
+    /// `127.0.0.1:8000`.
+    pub fn new<A: ToSocketAddrs>(address: A) -> std::io::Result<Self> {
+        Ok(Self {
+            server: DashboardServer::start(address, DashboardState::default())?,
+            pending: Vec::new(),
+            started: Instant::now(),
+            estimate: None,
+            #[cfg(feature = "metrics")]
+            system: SystemMetrics::new(),
+        })
+    }
+
+    /// The address of the dashboard.
+    pub fn address(&self) -> SocketAddr {
+        self.server.address()
+    }
+
+    fn render(&mut self, split: Split, item: TrainingProgress) {
+        let x = epoch_position(&item);
+        let eta_secs = match split {
+            Split::Train => self.eta(&item),
+            _ => None,
+        };
+        let elapsed_secs = self.started.elapsed().as_secs();
+        let pending = std::mem::take(&mut self.pending);
+        #[cfg(feature = "metrics")]
+        let system = self.system.sample(&item, self.started);
+
+        self.server.update(|state| {
+            let metrics = state.split(split);
+            for metric in pending {
+                match metric {
+                    MetricState::Generic(entry) => {
+                        metrics.update_text(&entry.name, entry.formatted)
+                    }
+                    MetricState::Numeric(entry, value) => {
+                        metrics.update_numeric(&entry.name, entry.formatted, x, value)
+                    }
+                }
+            }
+
+            // Keep the estimate of the training while validating.
+            let eta_secs = eta_secs.or_else(|| {
+                state
+                    .progress
+                    .as_ref()
+                    .and_then(|progress| progress.eta_secs)
+            });
+            state.progress = Some(ProgressState {
+                split,
+                epoch: item.epoch,
+                epoch_total: item.epoch_total,
+                iteration: item.iteration,
+                items_processed: item.progress.items_processed,
+                items_total: item.progress.items_total,
+                eta_secs,
+                elapsed_secs,
+            });
+
+            #[cfg(feature = "metrics")]
+            for (name, text, x, value) in system {
+                state.system.update_numeric(&name, text, x, value);
+            }
+        });
+    }
+
+    /// Estimate the remaining time of the training from the progress since the first item,
+    /// which can be in the middle of the training when resumed from a checkpoint.
+    fn eta(&mut self, item: &TrainingProgress) -> Option<u64> {
+        if item.epoch_total == 0 {
+            return None;
+        }
+
+        let progress = epoch_position(item) / item.epoch_total as f64;
+        let (started, progress_started) = *self.estimate.get_or_insert((Instant::now(), progress));
+        let progress_done = progress - progress_started;
+        if progress_done <= 0.0 {
+            return None;
+        }
+
+        let secs = started.elapsed().as_secs_f64() / progress_done * (1.0 - progress);
+        Some(secs.max(0.0) as u64)
+    }
+}
+
+impl MetricsRenderer for WebMetricsRenderer {
+    fn update_train(&mut self, state: MetricState) {
+        self.pending.push(state);
+    }
+
+    fn update_valid(&mut self, state: MetricState) {
+        self.pending.push(state);
+    }
+
+    fn render_train(&mut self, item: TrainingProgress) {
+        self.render(Split::Train, item);
+    }
+
+    fn render_valid(&mut self, item: TrainingProgress) {
+        self.render(Split::Valid, item);
+    }
+
+    fn update_test(&mut self, state: MetricState) {
+        self.pending.push(state);
+    }
+
+    fn render_test(&mut self, item: TrainingProgress) {
+        self.render(Split::Test, item);
+    }
+}
+
+impl Drop for WebMetricsRenderer {
+    fn drop(&mut self) {
+        self.server.finish();
+    }
+}
+
+/// The system metrics, sampled at most once per second.
+#[cfg(feature = "metrics")]
+struct SystemMetrics {
+    cpu_use: crate::metric::CpuUse,
+    cpu_memory: crate::metric::CpuMemory,
+    last_sample: Option<Instant>,
+}
+
+#[cfg(feature = "metrics")]
+impl SystemMetrics {
+    fn new() -> Self {
+        Self {
+            cpu_use: crate::metric::CpuUse::new(),
+            cpu_memory: crate::metric::CpuMemory::new(),
+            last_sample: None,
+        }
+    }
+
+    fn sample(
+        &mut self,
+        item: &TrainingProgress,
+        started: Instant,
+    ) -> Vec<(String, String, f64, f64)> {
+        use crate::metric::{Metric, MetricMetadata, Numeric};
+
+        if let Some(last_sample) = self.last_sample {
+            if last_sample.elapsed() < std::time::Duration::from_secs(1) {
+                return Vec::new();
+            }
+        }
+        self.last_sample = Some(Instant::now());
+
+        let metadata = MetricMetadata::new(
+            item.progress.clone(),
+            item.epoch,
+            item.epoch_total,
+            item.iteration,
+            None,
+        );
+        let x = started.elapsed().as_secs_f64();
+        let cpu_use = self.cpu_use.update(&(), &metadata);
+        let cpu_memory = self.cpu_memory.update(&(), &metadata);
+
+        vec![
+            (cpu_use.name, cpu_use.formatted, x, self.cpu_use.value()),
+            (
+                cpu_memory.name,
+                cpu_memory.formatted,
+                x,
+                self.cpu_memory.value(),
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metric::MetricEntry;
+    use burn_core::data::dataloader::Progress;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpStream;
+
+    fn request(address: SocketAddr, path: &str) -> TcpStream {
+        let mut stream = TcpStream::connect(address).unwrap();
+        write!(stream, "GET {path} HTTP/1.1\r\nHost: localhost\r\n\r\n").unwrap();
+        stream
+    }
+
+    fn loss(value: f64) -> MetricState {
+        MetricState::Numeric(
+            MetricEntry::new(
+                "Loss".to_string(),
+                format!("Loss {value}"),
+                value.to_string(),
+            ),
+            value,
+        )
+    }
+
+    fn progress(epoch: usize, items_processed: usize) -> TrainingProgress {
+        TrainingProgress {
+            progress: Progress::new(items_processed, 4),
+            epoch,
+            epoch_total: 2,
+            iteration: items_processed,
+        }
+    }
+
+    #[test]
+    fn should_serve_state_and_events() {
+        let mut renderer = WebMetricsRenderer::new("127.0.0.1:0").unwrap();
+        let address = renderer.address();
+        let mut events = BufReader::new(request(address, "/events"));
+
+        renderer.update_train(loss(2.0));
+        renderer.render_train(progress(1, 2));
+        renderer.update_valid(loss(1.5));
+        renderer.render_valid(progress(1, 4));
+
+        let mut response = String::new();
+        request(address, "/api/state")
+            .read_to_string(&mut response)
+            .unwrap();
+        let body = response.split("\r\n\r\n").nth(1).unwrap();
+        let state: serde_json::Value = serde_json::from_str(body).unwrap();
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert_eq!(
+            state["train"]["metrics"]["Loss"]["points"],
+            serde_json::json!([[0.5, 2.0]])
+        );
+        assert_eq!(state["valid"]["metrics"]["Loss"]["text"], "Loss 1.5");
+        assert_eq!(state["progress"]["split"], "Valid");
+        assert_eq!(state["status"], "Running");
+
+        let mut line = String::new();
+        while !line.starts_with("data: ") {
+            line.clear();
+            events.read_line(&mut line).unwrap();
+        }
+        assert!(line.contains("\"train\""));
+
+        let mut response = String::new();
+        request(address, "/missing")
+            .read_to_string(&mut response)
+            .unwrap();
+        assert!(response.starts_with("HTTP/1.1 404"));
+
+        renderer.server.finish();
+        let mut response = String::new();
+        request(address, "/api/state")
+            .read_to_string(&mut response)
+            .unwrap();
+        assert!(response.contains("\"status\":\"Finished\""));
+    }
+}
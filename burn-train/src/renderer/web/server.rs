@@ -0,0 +1,297 @@
+use super::page::PAGE;
+use super::state::{DashboardState, RunStatus};
+use std::io::{BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+/// The minimum delay between two events sent to a client.
+const EVENT_INTERVAL: Duration = Duration::from_millis(250);
+/// The delay after which a comment is sent to a client to detect when it disconnects.
+const KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(5);
+
+/// The state of the dashboard with a version incremented on each update.
+pub(crate) struct Shared {
+    state: Mutex<(DashboardState, u64)>,
+    changed: Condvar,
+    stopped: AtomicBool,
+}
+
+/// A small HTTP server for the dashboard of a training run, serving:
+///
+/// - `/` - The dashboard, with the plots of the metrics of each split, the progress and the
+///   system metrics.
+/// - `/api/state` - The state of the run as JSON.
+/// - `/events` - The state of the run as [server-sent events](https://html.spec.whatwg.org/multipage/server-sent-events.html),
+///   sent each time it's updated.
+///
+/// The server runs in the background until it's [stopped](DashboardServer::stop) or the process
+/// exits, so that the dashboard of a finished training can still be viewed.
+pub struct DashboardServer {
+    address: SocketAddr,
+    shared: Arc<Shared>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl DashboardServer {
+    /// Serve the dashboard of a finished run from the files written by its
+    /// [file metric loggers](crate::logger::FileMetricLogger), with the directory of the
+    /// [learner](crate::LearnerBuilder::new) or of the [evaluator](crate::EvaluatorBuilder::new).
+    pub fn replay<P: AsRef<Path>, A: ToSocketAddrs>(
+        directory: P,
+        address: A,
+    ) -> std::io::Result<Self> {
+        let state = DashboardState::load(directory.as_ref())?;
+        Self::start(address, state)
+    }
+
+    pub(crate) fn start<A: ToSocketAddrs>(
+        address: A,
+        state: DashboardState,
+    ) -> std::io::Result<Self> {
+        let listener = TcpListener::bind(address)?;
+        let address = listener.local_addr()?;
+        let shared = Arc::new(Shared {
+            state: Mutex::new((state, 0)),
+            changed: Condvar::new(),
+            stopped: AtomicBool::new(false),
+        });
+
+        let shared_listener = shared.clone();
+        let handle = std::thread::Builder::new()
+            .name("dashboard".to_string())
+            .spawn(move || accept(listener, shared_listener))?;
+        log::info!("Serving the dashboard at http://{address}");
+
+        Ok(Self {
+            address,
+            shared,
+            handle: Some(handle),
+        })
+    }
+
+    /// The address the server listens on.
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// Block until the server is stopped from another thread, or the process exits.
+    pub fn wait(mut self) {
+        if let Some(handle) = self.handle.take() {
+            handle.join().ok();
+        }
+    }
+
+    /// Stop the server and close the connections of the clients.
+    pub fn stop(mut self) {
+        self.shared.stopped.store(true, Ordering::Relaxed);
+        self.shared.changed.notify_all();
+
+        // Wake up the listener blocked on accept.
+        let mut address = self.address;
+        if address.ip().is_unspecified() {
+            address.set_ip(std::net::Ipv4Addr::LOCALHOST.into());
+        }
+        TcpStream::connect_timeout(&address, Duration::from_secs(1)).ok();
+
+        if let Some(handle) = self.handle.take() {
+            handle.join().ok();
+        }
+    }
+
+    /// Update the state and notify the clients.
+    pub(crate) fn update<F: FnOnce(&mut DashboardState)>(&self, func: F) {
+        let mut state = self
+            .shared
+            .state
+            .lock()
+            .expect("Can lock the dashboard state.");
+        func(&mut state.0);
+        state.1 += 1;
+        self.shared.changed.notify_all();
+    }
+
+    pub(crate) fn finish(&self) {
+        self.update(|state| {
+            if state.status == RunStatus::Running {
+                state.status = RunStatus::Finished;
+            }
+        });
+    }
+}
+
+fn accept(listener: TcpListener, shared: Arc<Shared>) {
+    for stream in listener.incoming() {
+        if shared.stopped.load(Ordering::Relaxed) {
+            break;
+        }
+
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(err) => {
+                log::warn!("Can't accept the connection to the dashboard: {err}");
+                continue;
+            }
+        };
+        let shared = shared.clone();
+        std::thread::spawn(move || {
+            if let Err(err) = handle(stream, &shared) {
+                log::debug!("Dashboard connection closed: {err}");
+            }
+        });
+    }
+}
+
+fn handle(mut stream: TcpStream, shared: &Shared) -> std::io::Result<()> {
+    stream.set_read_timeout(Some(Duration::from_secs(5)))?;
+
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut request = String::new();
+    reader.read_line(&mut request)?;
+    // Skip the headers, the requests don't have a body.
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 || header.trim().is_empty() {
+            break;
+        }
+    }
+
+    let mut parts = request.split_whitespace();
+    let (method, target) = (parts.next(), parts.next().unwrap_or("/"));
+    let path = target.split('?').next().unwrap_or("/");
+
+    match (method, path) {
+        (Some("GET"), "/") | (Some("GET"), "/index.html") => respond(
+            &mut stream,
+            "200 OK",
+            "text/html; charset=utf-8",
+            PAGE.as_bytes(),
+        ),
+        (Some("GET"), "/api/state") => {
+            let content = {
+                let state = shared.state.lock().expect("Can lock the dashboard state.");
+                serialize(&state.0)
+            };
+            respond(
+                &mut stream,
+                "200 OK",
+                "application/json",
+                content.as_bytes(),
+            )
+        }
+        (Some("GET"), "/events") => stream_events(stream, shared),
+        (Some("GET"), _) => respond(&mut stream, "404 Not Found", "text/plain", b"Not Found"),
+        _ => respond(
+            &mut stream,
+            "405 Method Not Allowed",
+            "text/plain",
+            b"Method Not Allowed",
+        ),
+    }
+}
+
+fn respond(
+    stream: &mut TcpStream,
+    status: &str,
+    content_type: &str,
+    content: &[u8],
+) -> std::io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        content.len()
+    )?;
+    stream.write_all(content)?;
+    stream.flush()
+}
+
+/// Send the state each time it's updated, at most once per [interval](EVENT_INTERVAL).
+fn stream_events(mut stream: TcpStream, shared: &Shared) -> std::io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nCache-Control: no-cache\r\nConnection: keep-alive\r\n\r\n"
+    )?;
+
+    let mut version = None;
+    loop {
+        let content = {
+            let mut state = shared.state.lock().expect("Can lock the dashboard state.");
+            if version == Some(state.1) && !shared.stopped.load(Ordering::Relaxed) {
+                state = shared
+                    .changed
+                    .wait_timeout(state, KEEP_ALIVE_INTERVAL)
+                    .expect("Can lock the dashboard state.")
+                    .0;
+            }
+            if shared.stopped.load(Ordering::Relaxed) {
+                return Ok(());
+            }
+
+            match version == Some(state.1) {
+                true => None,
+                false => {
+                    version = Some(state.1);
+                    Some(serialize(&state.0))
+                }
+            }
+        };
+
+        match content {
+            Some(content) => write!(stream, "event: state\ndata: {content}\n\n")?,
+            None => write!(stream, ": keep-alive\n\n")?,
+        }
+        stream.flush()?;
+        std::thread::sleep(EVENT_INTERVAL);
+    }
+}
+
+fn serialize(state: &DashboardState) -> String {
+    serde_json::to_string(state).expect("Can serialize the dashboard state.")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logger::{FileMetricLogger, MetricLogger};
+    use crate::metric::MetricEntry;
+    use std::io::Read;
+
+    #[test]
+    fn should_replay_file_logs() {
+        let directory = tempfile::tempdir().unwrap();
+        let directory = directory.path();
+        let mut logger = FileMetricLogger::new(directory.join("train").to_str().unwrap());
+        for epoch in 1..3 {
+            for value in [3.0, 1.0] {
+                let value = value / epoch as f64;
+                logger.log(&MetricEntry::new(
+                    "Train Loss".to_string(),
+                    format!("Loss {value}"),
+                    value.to_string(),
+                ));
+            }
+            logger.end_epoch(epoch);
+        }
+        // Wait until the values are written.
+        logger.read_numeric("Train Loss", 2).unwrap();
+
+        let server = DashboardServer::replay(directory, "127.0.0.1:0").unwrap();
+        let mut stream = TcpStream::connect(server.address()).unwrap();
+        write!(stream, "GET /api/state HTTP/1.1\r\n\r\n").unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        server.stop();
+
+        let body = response.split("\r\n\r\n").nth(1).unwrap();
+        let state: serde_json::Value = serde_json::from_str(body).unwrap();
+        assert_eq!(state["status"], "Replay");
+        assert_eq!(
+            state["train"]["metrics"]["Train Loss"]["points"],
+            serde_json::json!([[0.5, 3.0], [1.0, 1.0], [1.5, 1.5], [2.0, 0.5]])
+        );
+        assert!(DashboardServer::replay(directory.join("missing"), "127.0.0.1:0").is_err());
+    }
+}
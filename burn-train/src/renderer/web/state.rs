@@ -0,0 +1,225 @@
+use crate::metric::store::Split;
+use crate::renderer::TrainingProgress;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// The maximum number of points of a series, which is decimated when exceeded.
+const MAX_POINTS: usize = 2000;
+
+/// The status of the run displayed by the dashboard.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+pub(crate) enum RunStatus {
+    /// The training is running.
+    #[default]
+    Running,
+    /// The training finished.
+    Finished,
+    /// The run is replayed from the files of its metric loggers.
+    Replay,
+}
+
+/// The values of a metric, with the position of each value in epochs.
+#[derive(Clone, Debug, Default, Serialize)]
+pub(crate) struct MetricSeries {
+    /// The points `[x, value]` of a numeric metric.
+    pub(crate) points: Vec<[f64; 2]>,
+    /// The last formatted value.
+    pub(crate) text: String,
+    #[serde(skip)]
+    stride: usize,
+    /// The number of values since the last point.
+    #[serde(skip)]
+    skipped: usize,
+}
+
+impl MetricSeries {
+    pub(crate) fn push(&mut self, x: f64, value: f64) {
+        // Keep one point every `stride` values, doubling the stride when the series is full.
+        let stride = self.stride.max(1);
+        if self.skipped == 0 {
+            self.points.push([x, value]);
+        }
+        self.skipped = (self.skipped + 1) % stride;
+
+        if self.points.len() > MAX_POINTS {
+            // The last point is kept, since the number of points is odd.
+            self.points = self.points.iter().copied().step_by(2).collect();
+            self.stride = stride * 2;
+            self.skipped = 1;
+        }
+    }
+}
+
+/// The metrics of a split, by name.
+#[derive(Clone, Debug, Default, Serialize)]
+pub(crate) struct SplitState {
+    pub(crate) metrics: BTreeMap<String, MetricSeries>,
+}
+
+impl SplitState {
+    pub(crate) fn update_text(&mut self, name: &str, text: String) {
+        self.series(name).text = text;
+    }
+
+    pub(crate) fn update_numeric(&mut self, name: &str, text: String, x: f64, value: f64) {
+        let series = self.series(name);
+        series.text = text;
+        series.push(x, value);
+    }
+
+    fn series(&mut self, name: &str) -> &mut MetricSeries {
+        self.metrics.entry(name.to_string()).or_default()
+    }
+}
+
+/// The progress of the current split.
+#[derive(Clone, Debug, Serialize)]
+pub(crate) struct ProgressState {
+    pub(crate) split: Split,
+    pub(crate) epoch: usize,
+    pub(crate) epoch_total: usize,
+    pub(crate) iteration: usize,
+    pub(crate) items_processed: usize,
+    pub(crate) items_total: usize,
+    /// The estimated remaining time of the training in seconds.
+    pub(crate) eta_secs: Option<u64>,
+    /// The time since the start of the training in seconds.
+    pub(crate) elapsed_secs: u64,
+}
+
+/// The state served by the dashboard.
+#[derive(Clone, Debug, Default, Serialize)]
+pub(crate) struct DashboardState {
+    pub(crate) status: RunStatus,
+    pub(crate) progress: Option<ProgressState>,
+    pub(crate) train: SplitState,
+    pub(crate) valid: SplitState,
+    pub(crate) test: SplitState,
+    /// The system metrics, with the time since the start of the training in seconds as position.
+    pub(crate) system: SplitState,
+}
+
+impl DashboardState {
+    pub(crate) fn split(&mut self, split: Split) -> &mut SplitState {
+        match split {
+            Split::Train => &mut self.train,
+            Split::Valid => &mut self.valid,
+            Split::Test => &mut self.test,
+        }
+    }
+
+    /// Load the state of a finished run from the files written by the
+    /// [file metric loggers](crate::logger::FileMetricLogger) in its directory.
+    ///
+    /// The epochs of each split are numbered in the order of their directories.
+    pub(crate) fn load(directory: &Path) -> std::io::Result<Self> {
+        if !directory.is_dir() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("The directory {} doesn't exist.", directory.display()),
+            ));
+        }
+
+        let mut state = Self {
+            status: RunStatus::Replay,
+            ..Default::default()
+        };
+
+        for (split, name) in [
+            (Split::Train, "train"),
+            (Split::Valid, "valid"),
+            (Split::Test, "test"),
+        ] {
+            let split = state.split(split);
+            for (index, epoch) in epoch_directories(&directory.join(name))?.iter().enumerate() {
+                load_epoch(split, epoch, index)?;
+            }
+        }
+
+        Ok(state)
+    }
+}
+
+/// The directories `epoch-{n}` of a split, ordered by epoch.
+fn epoch_directories(directory: &Path) -> std::io::Result<Vec<std::path::PathBuf>> {
+    if !directory.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut epochs = std::fs::read_dir(directory)?
+        .flatten()
+        .filter_map(|entry| {
+            let epoch = entry
+                .file_name()
+                .to_str()?
+                .strip_prefix("epoch-")?
+                .parse::<usize>()
+                .ok()?;
+            Some((epoch, entry.path()))
+        })
+        .collect::<Vec<_>>();
+    epochs.sort_by_key(|(epoch, _)| *epoch);
+
+    Ok(epochs.into_iter().map(|(_, path)| path).collect())
+}
+
+fn load_epoch(split: &mut SplitState, directory: &Path, index: usize) -> std::io::Result<()> {
+    for entry in std::fs::read_dir(directory)?.flatten() {
+        let path = entry.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("log") {
+            continue;
+        }
+        let name = match path.file_stem().and_then(|stem| stem.to_str()) {
+            // The spaces of the names are replaced by underscores in the file names.
+            Some(stem) => stem.replace('_', " "),
+            None => continue,
+        };
+
+        let content = std::fs::read_to_string(&path)?;
+        let lines = content
+            .lines()
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>();
+        for (i, line) in lines.iter().enumerate() {
+            match line.parse::<f64>() {
+                Ok(value) => {
+                    let x = index as f64 + (i + 1) as f64 / lines.len() as f64;
+                    split.update_numeric(&name, line.to_string(), x, value);
+                }
+                Err(_) => split.update_text(&name, line.to_string()),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// The position of the progress in epochs.
+pub(crate) fn epoch_position(progress: &TrainingProgress) -> f64 {
+    let fraction = match progress.progress.items_total {
+        0 => 0.0,
+        total => progress.progress.items_processed as f64 / total as f64,
+    };
+
+    progress.epoch.saturating_sub(1) as f64 + fraction
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_decimate_long_series() {
+        let mut series = MetricSeries::default();
+
+        for i in 0..MAX_POINTS * 4 {
+            series.push(i as f64, i as f64);
+        }
+
+        assert!(series.points.len() <= MAX_POINTS);
+        assert!(series.points.len() > MAX_POINTS / 2);
+        assert_eq!(series.points[0], [0.0, 0.0]);
+        assert_eq!(series.points[1][0] - series.points[0][0], 4.0);
+    }
+}
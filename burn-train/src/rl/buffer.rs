@@ -0,0 +1,427 @@
+use super::Transition;
+use rand::{rngs::StdRng, seq::index, Rng, SeedableRng};
+
+/// A buffer of the transitions collected by the current policy, for on-policy algorithms such as
+/// REINFORCE, A2C or PPO, which is cleared after each update.
+pub struct RolloutBuffer<S, A> {
+    transitions: Vec<Transition<S, A>>,
+}
+
+impl<S, A> Default for RolloutBuffer<S, A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S, A> RolloutBuffer<S, A> {
+    /// Creates an empty buffer.
+    pub fn new() -> Self {
+        Self {
+            transitions: Vec::new(),
+        }
+    }
+
+    /// Add a transition.
+    pub fn push(&mut self, transition: Transition<S, A>) {
+        self.transitions.push(transition);
+    }
+
+    /// The number of transitions.
+    pub fn len(&self) -> usize {
+        self.transitions.len()
+    }
+
+    /// If the buffer doesn't have any transition.
+    pub fn is_empty(&self) -> bool {
+        self.transitions.is_empty()
+    }
+
+    /// The transitions, in the order they were added.
+    pub fn transitions(&self) -> &[Transition<S, A>] {
+        &self.transitions
+    }
+
+    /// Remove all the transitions, returning them.
+    pub fn drain(&mut self) -> Vec<Transition<S, A>> {
+        std::mem::take(&mut self.transitions)
+    }
+
+    /// The discounted return of each transition, until the end of its episode.
+    pub fn returns(&self, gamma: f64) -> Vec<f64> {
+        let mut returns = vec![0.0; self.transitions.len()];
+        let mut future = 0.0;
+
+        for (i, transition) in self.transitions.iter().enumerate().rev() {
+            if transition.done {
+                future = 0.0;
+            }
+            future = transition.reward + gamma * future;
+            returns[i] = future;
+        }
+
+        returns
+    }
+
+    /// The advantages and the returns of the transitions with generalized advantage estimation.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The value of the state of each transition.
+    /// * `next_values` - The value of the next state of each transition, which is only used
+    ///   when the next state isn't terminal.
+    /// * `gamma` - The discount factor.
+    /// * `lambda` - The factor trading off the bias and the variance of the estimate.
+    pub fn advantages(
+        &self,
+        values: &[f64],
+        next_values: &[f64],
+        gamma: f64,
+        lambda: f64,
+    ) -> (Vec<f64>, Vec<f64>) {
+        assert_eq!(values.len(), self.len(), "One value per transition.");
+        assert_eq!(
+            next_values.len(),
+            self.len(),
+            "One next value per transition."
+        );
+
+        let mut advantages = vec![0.0; self.transitions.len()];
+        let mut future = 0.0;
+
+        for (i, transition) in self.transitions.iter().enumerate().rev() {
+            if transition.done {
+                future = 0.0;
+            }
+            let next_value = match transition.terminated {
+                true => 0.0,
+                false => next_values[i],
+            };
+            let delta = transition.reward + gamma * next_value - values[i];
+            future = delta + gamma * lambda * future;
+            advantages[i] = future;
+        }
+
+        let returns = advantages
+            .iter()
+            .zip(values)
+            .map(|(advantage, value)| advantage + value)
+            .collect();
+
+        (advantages, returns)
+    }
+}
+
+/// A buffer of the most recent transitions, sampled uniformly for off-policy algorithms such as
+/// DQN.
+pub struct ReplayBuffer<S, A> {
+    transitions: Vec<Transition<S, A>>,
+    capacity: usize,
+    position: usize,
+    rng: StdRng,
+}
+
+impl<S, A> ReplayBuffer<S, A> {
+    /// Creates an empty buffer, keeping at most `capacity` transitions.
+    pub fn new(capacity: usize, seed: u64) -> Self {
+        assert!(capacity > 0, "The capacity should be positive.");
+
+        Self {
+            transitions: Vec::with_capacity(capacity),
+            capacity,
+            position: 0,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    /// Add a transition, replacing the oldest one when the buffer is full.
+    pub fn push(&mut self, transition: Transition<S, A>) {
+        push_ring(
+            &mut self.transitions,
+            &mut self.position,
+            self.capacity,
+            transition,
+        );
+    }
+
+    /// The number of transitions.
+    pub fn len(&self) -> usize {
+        self.transitions.len()
+    }
+
+    /// If the buffer doesn't have any transition.
+    pub fn is_empty(&self) -> bool {
+        self.transitions.is_empty()
+    }
+
+    /// Sample distinct transitions, at most the number of transitions in the buffer.
+    pub fn sample(&mut self, batch_size: usize) -> Vec<&Transition<S, A>> {
+        let amount = batch_size.min(self.transitions.len());
+
+        index::sample(&mut self.rng, self.transitions.len(), amount)
+            .into_iter()
+            .map(|i| &self.transitions[i])
+            .collect()
+    }
+}
+
+/// Add an item to a ring buffer, returning its index.
+fn push_ring<T>(items: &mut Vec<T>, position: &mut usize, capacity: usize, item: T) -> usize {
+    let index = *position;
+    match items.len() < capacity {
+        true => items.push(item),
+        false => items[index] = item,
+    }
+    *position = (index + 1) % capacity;
+
+    index
+}
+
+/// A batch sampled from a [prioritized replay buffer](PrioritizedReplayBuffer).
+pub struct PrioritizedBatch<'a, S, A> {
+    /// The transitions.
+    pub transitions: Vec<&'a Transition<S, A>>,
+    /// The indices of the transitions, to [update](PrioritizedReplayBuffer::update_priorities)
+    /// their priorities.
+    pub indices: Vec<usize>,
+    /// The importance sampling weights correcting the bias of the sampling, normalized so that
+    /// the largest weight is `1`.
+    pub weights: Vec<f64>,
+}
+
+/// A [replay buffer](ReplayBuffer) sampling the transitions proportionally to their priority,
+/// usually their last TD error, as in prioritized experience replay.
+///
+/// New transitions have the largest priority seen so far, so that they are sampled at least
+/// once.
+pub struct PrioritizedReplayBuffer<S, A> {
+    transitions: Vec<Transition<S, A>>,
+    capacity: usize,
+    position: usize,
+    tree: SumTree,
+    alpha: f64,
+    beta: f64,
+    epsilon: f64,
+    max_priority: f64,
+    rng: StdRng,
+}
+
+impl<S, A> PrioritizedReplayBuffer<S, A> {
+    /// Creates an empty buffer keeping at most `capacity` transitions, with a priority exponent
+    /// `alpha` of `0.6` and an importance sampling exponent `beta` of `0.4`.
+    pub fn new(capacity: usize, seed: u64) -> Self {
+        assert!(capacity > 0, "The capacity should be positive.");
+
+        Self {
+            transitions: Vec::with_capacity(capacity),
+            capacity,
+            position: 0,
+            tree: SumTree::new(capacity),
+            alpha: 0.6,
+            beta: 0.4,
+            epsilon: 1e-6,
+            max_priority: 1.0,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    /// How much the priorities are used, from `0` for uniform sampling to `1` for sampling
+    /// proportionally to the priorities.
+    pub fn with_alpha(mut self, alpha: f64) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
+    /// How much the importance sampling weights correct the bias, usually annealed to `1` with
+    /// [set_beta](Self::set_beta) during the training.
+    pub fn with_beta(mut self, beta: f64) -> Self {
+        self.beta = beta;
+        self
+    }
+
+    /// Update the importance sampling exponent.
+    pub fn set_beta(&mut self, beta: f64) {
+        self.beta = beta;
+    }
+
+    /// Add a transition, replacing the oldest one when the buffer is full.
+    pub fn push(&mut self, transition: Transition<S, A>) {
+        let index = push_ring(
+            &mut self.transitions,
+            &mut self.position,
+            self.capacity,
+            transition,
+        );
+        self.tree.set(index, self.max_priority.powf(self.alpha));
+    }
+
+    /// The number of transitions.
+    pub fn len(&self) -> usize {
+        self.transitions.len()
+    }
+
+    /// If the buffer doesn't have any transition.
+    pub fn is_empty(&self) -> bool {
+        self.transitions.is_empty()
+    }
+
+    /// Sample transitions proportionally to their priority, one in each of `batch_size` segments
+    /// of the total priority, so the same transition can be sampled multiple times.
+    pub fn sample(&mut self, batch_size: usize) -> PrioritizedBatch<'_, S, A> {
+        let total = self.tree.total();
+        let len = self.transitions.len();
+        let mut batch = PrioritizedBatch {
+            transitions: Vec::with_capacity(batch_size),
+            indices: Vec::with_capacity(batch_size),
+            weights: Vec::with_capacity(batch_size),
+        };
+
+        if len == 0 {
+            return batch;
+        }
+
+        let segment = total / batch_size as f64;
+        for i in 0..batch_size {
+            let value = segment * (i as f64 + self.rng.gen::<f64>());
+            let index = self.tree.find(value).min(len - 1);
+            let probability = self.tree.get(index) / total;
+
+            batch.transitions.push(&self.transitions[index]);
+            batch.indices.push(index);
+            batch
+                .weights
+                .push((len as f64 * probability).powf(-self.beta));
+        }
+
+        let max_weight = batch.weights.iter().cloned().fold(f64::MIN, f64::max);
+        batch
+            .weights
+            .iter_mut()
+            .for_each(|weight| *weight /= max_weight);
+
+        batch
+    }
+
+    /// Update the priorities of sampled transitions, such as with their absolute TD errors.
+    pub fn update_priorities(&mut self, indices: &[usize], priorities: &[f64]) {
+        assert_eq!(indices.len(), priorities.len(), "One priority per index.");
+
+        for (index, priority) in indices.iter().zip(priorities) {
+            let priority = priority.abs() + self.epsilon;
+            self.max_priority = self.max_priority.max(priority);
+            self.tree.set(*index, priority.powf(self.alpha));
+        }
+    }
+}
+
+/// A binary tree where each node is the sum of its children, to sample the leaves
+/// proportionally to their value in logarithmic time.
+struct SumTree {
+    nodes: Vec<f64>,
+    leaves: usize,
+}
+
+impl SumTree {
+    fn new(capacity: usize) -> Self {
+        let leaves = capacity.next_power_of_two();
+
+        Self {
+            nodes: vec![0.0; 2 * leaves],
+            leaves,
+        }
+    }
+
+    fn total(&self) -> f64 {
+        self.nodes[1]
+    }
+
+    fn get(&self, index: usize) -> f64 {
+        self.nodes[self.leaves + index]
+    }
+
+    fn set(&mut self, index: usize, value: f64) {
+        let mut node = self.leaves + index;
+        self.nodes[node] = value;
+
+        while node > 1 {
+            node /= 2;
+            self.nodes[node] = self.nodes[2 * node] + self.nodes[2 * node + 1];
+        }
+    }
+
+    /// The leaf where the cumulative sum of the values reaches the given value.
+    fn find(&self, mut value: f64) -> usize {
+        let mut node = 1;
+
+        while node < self.leaves {
+            let left = 2 * node;
+            match value < self.nodes[left] || self.nodes[left + 1] <= 0.0 {
+                true => node = left,
+                false => {
+                    value -= self.nodes[left];
+                    node = left + 1;
+                }
+            }
+        }
+
+        node - self.leaves
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transition(reward: f64, terminated: bool) -> Transition<usize, usize> {
+        Transition::new(0, 0, reward, 0, terminated, terminated)
+    }
+
+    #[test]
+    fn should_compute_returns_and_advantages_per_episode() {
+        let mut buffer = RolloutBuffer::new();
+        buffer.push(transition(1.0, false));
+        buffer.push(transition(1.0, true));
+        buffer.push(transition(2.0, false));
+
+        assert_eq!(buffer.returns(0.5), vec![1.5, 1.0, 2.0]);
+
+        // With a lambda of 1, the advantages are the returns bootstrapped with the last value
+        // minus the values.
+        let (advantages, returns) = buffer.advantages(&[1.0, 1.0, 1.0], &[1.0, 1.0, 4.0], 0.5, 1.0);
+        assert_eq!(advantages, vec![0.5, 0.0, 3.0]);
+        assert_eq!(returns, vec![1.5, 1.0, 4.0]);
+    }
+
+    #[test]
+    fn should_replace_the_oldest_transitions() {
+        let mut buffer = ReplayBuffer::new(2, 0);
+        for reward in [1.0, 2.0, 3.0] {
+            buffer.push(transition(reward, false));
+        }
+
+        let mut rewards = buffer
+            .sample(4)
+            .iter()
+            .map(|transition| transition.reward)
+            .collect::<Vec<_>>();
+        rewards.sort_by(f64::total_cmp);
+
+        assert_eq!(rewards, vec![2.0, 3.0]);
+    }
+
+    #[test]
+    fn should_sample_proportionally_to_the_priorities() {
+        let mut buffer = PrioritizedReplayBuffer::new(3, 0).with_alpha(1.0);
+        for reward in [0.0, 1.0, 2.0] {
+            buffer.push(transition(reward, false));
+        }
+        buffer.update_priorities(&[0, 1, 2], &[1.0, 1.0, 8.0]);
+
+        let batch = buffer.sample(100);
+        let sampled = batch.indices.iter().filter(|index| **index == 2).count();
+
+        assert_eq!(sampled, 80);
+        // The most sampled transition has the smallest weight.
+        let weight = batch.weights[batch.indices.iter().position(|i| *i == 2).unwrap()];
+        assert!(batch.weights.iter().all(|w| *w >= weight && *w <= 1.0));
+    }
+}
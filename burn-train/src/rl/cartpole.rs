@@ -0,0 +1,121 @@
+use super::{Environment, EnvironmentStep};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+const GRAVITY: f32 = 9.8;
+const MASS_CART: f32 = 1.0;
+const MASS_POLE: f32 = 0.1;
+const HALF_POLE_LENGTH: f32 = 0.5;
+const FORCE: f32 = 10.0;
+const TIME_STEP: f32 = 0.02;
+const ANGLE_THRESHOLD: f32 = 12.0 * 2.0 * std::f32::consts::PI / 360.0;
+const POSITION_THRESHOLD: f32 = 2.4;
+
+/// The classic control problem of balancing a pole on a cart, with the same dynamics as the
+/// `CartPole-v1` environment of Gym.
+///
+/// - The state is the position and the velocity of the cart, and the angle and the angular
+///   velocity of the pole.
+/// - The action is `0` to push the cart to the left and `1` to push it to the right.
+/// - The reward is `1` for every step, until the pole falls more than 12 degrees or the cart
+///   leaves the track, with episodes truncated after 500 steps by default.
+pub struct CartPole {
+    state: [f32; 4],
+    steps: usize,
+    max_steps: usize,
+    rng: StdRng,
+}
+
+impl CartPole {
+    /// Creates the environment, with a seed for the initial states.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: [0.0; 4],
+            steps: 0,
+            max_steps: 500,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    /// The number of steps after which an episode is truncated.
+    pub fn with_max_steps(mut self, max_steps: usize) -> Self {
+        self.max_steps = max_steps;
+        self
+    }
+}
+
+impl Environment for CartPole {
+    type State = [f32; 4];
+    type Action = usize;
+
+    fn reset(&mut self) -> [f32; 4] {
+        self.state = [(); 4].map(|_| self.rng.gen_range(-0.05..0.05));
+        self.steps = 0;
+        self.state
+    }
+
+    fn step(&mut self, action: &usize) -> EnvironmentStep<[f32; 4]> {
+        let [x, x_dot, theta, theta_dot] = self.state;
+        let force = match action {
+            0 => -FORCE,
+            _ => FORCE,
+        };
+
+        let (sin, cos) = theta.sin_cos();
+        let total_mass = MASS_CART + MASS_POLE;
+        let pole_mass_length = MASS_POLE * HALF_POLE_LENGTH;
+        let temp = (force + pole_mass_length * theta_dot * theta_dot * sin) / total_mass;
+        let theta_acc = (GRAVITY * sin - cos * temp)
+            / (HALF_POLE_LENGTH * (4.0 / 3.0 - MASS_POLE * cos * cos / total_mass));
+        let x_acc = temp - pole_mass_length * theta_acc * cos / total_mass;
+
+        // Euler integration.
+        self.state = [
+            x + TIME_STEP * x_dot,
+            x_dot + TIME_STEP * x_acc,
+            theta + TIME_STEP * theta_dot,
+            theta_dot + TIME_STEP * theta_acc,
+        ];
+        self.steps += 1;
+
+        let [x, _, theta, _] = self.state;
+        let terminated = x.abs() > POSITION_THRESHOLD || theta.abs() > ANGLE_THRESHOLD;
+
+        EnvironmentStep::new(
+            self.state,
+            1.0,
+            terminated,
+            !terminated && self.steps >= self.max_steps,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn episode_length(env: &mut CartPole, policy: impl Fn(&[f32; 4]) -> usize) -> usize {
+        let mut state = env.reset();
+        let mut length = 0;
+
+        loop {
+            let step = env.step(&policy(&state));
+            length += 1;
+            state = step.state;
+
+            if step.done() {
+                return length;
+            }
+        }
+    }
+
+    #[test]
+    fn should_balance_longer_when_pushing_towards_the_pole() {
+        let mut env = CartPole::new(42).with_max_steps(200);
+
+        let constant = episode_length(&mut env, |_| 1);
+        let balancing = episode_length(&mut env, |state| (state[2] + state[3] > 0.0) as usize);
+
+        assert!(constant < 20, "{constant}");
+        assert_eq!(balancing, 200);
+    }
+}
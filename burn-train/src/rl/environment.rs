@@ -0,0 +1,53 @@
+/// An environment an [agent](crate::rl::Agent) interacts with, replacing the dataloader of
+/// supervised training as the source of data.
+pub trait Environment {
+    /// The state observed by the agent.
+    type State: Clone;
+    /// The action taken by the agent.
+    type Action: Clone;
+
+    /// Start a new episode, returning its initial state.
+    fn reset(&mut self) -> Self::State;
+
+    /// Apply an action, returning the next state and the reward.
+    fn step(&mut self, action: &Self::Action) -> EnvironmentStep<Self::State>;
+}
+
+/// The result of an action in an [environment](Environment).
+#[derive(new, Clone, Debug)]
+pub struct EnvironmentStep<S> {
+    /// The next state.
+    pub state: S,
+    /// The reward of the action.
+    pub reward: f64,
+    /// If the episode ended in a terminal state, such as the pole falling in
+    /// [CartPole](crate::rl::CartPole).
+    pub terminated: bool,
+    /// If the episode was cut short without reaching a terminal state, such as a time limit.
+    pub truncated: bool,
+}
+
+impl<S> EnvironmentStep<S> {
+    /// If the episode is over.
+    pub fn done(&self) -> bool {
+        self.terminated || self.truncated
+    }
+}
+
+/// A transition of an [environment](Environment), stored in the
+/// [rollout](crate::rl::RolloutBuffer) and [replay](crate::rl::ReplayBuffer) buffers.
+#[derive(new, Clone, Debug)]
+pub struct Transition<S, A> {
+    /// The state the action was taken in.
+    pub state: S,
+    /// The action.
+    pub action: A,
+    /// The reward of the action.
+    pub reward: f64,
+    /// The next state.
+    pub next_state: S,
+    /// If the next state is terminal, in which case its value isn't bootstrapped.
+    pub terminated: bool,
+    /// If the episode ended after the action, whether in a terminal state or not.
+    pub done: bool,
+}
@@ -0,0 +1,162 @@
+use crate::metric::state::{FormatOptions, NumericMetricState};
+use crate::metric::{Adaptor, Metric, MetricEntry, MetricMetadata, Numeric};
+
+/// The summary of an episode, processed by the metrics of the [trainer](crate::rl::RlTrainer).
+#[derive(new, Clone, Debug, PartialEq)]
+pub struct EpisodeOutput {
+    /// The sum of the rewards of the episode.
+    pub episode_return: f64,
+    /// The number of steps of the episode.
+    pub length: usize,
+    /// The mean loss of the updates of the agent during the episode, if it was updated.
+    pub loss: Option<f64>,
+}
+
+/// The [episode return metric](EpisodeReturnMetric) input type.
+#[derive(new)]
+pub struct EpisodeReturnInput {
+    value: f64,
+}
+
+/// The [episode length metric](EpisodeLengthMetric) input type.
+#[derive(new)]
+pub struct EpisodeLengthInput {
+    length: usize,
+}
+
+/// The [agent loss metric](AgentLossMetric) input type.
+#[derive(new)]
+pub struct AgentLossInput {
+    loss: Option<f64>,
+}
+
+impl Adaptor<EpisodeReturnInput> for EpisodeOutput {
+    fn adapt(&self) -> EpisodeReturnInput {
+        EpisodeReturnInput::new(self.episode_return)
+    }
+}
+
+impl Adaptor<EpisodeLengthInput> for EpisodeOutput {
+    fn adapt(&self) -> EpisodeLengthInput {
+        EpisodeLengthInput::new(self.length)
+    }
+}
+
+impl Adaptor<AgentLossInput> for EpisodeOutput {
+    fn adapt(&self) -> AgentLossInput {
+        AgentLossInput::new(self.loss)
+    }
+}
+
+/// Track the sum of the rewards of each episode.
+#[derive(Default)]
+pub struct EpisodeReturnMetric {
+    state: NumericMetricState,
+}
+
+impl EpisodeReturnMetric {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Metric for EpisodeReturnMetric {
+    const NAME: &'static str = "Episode Return";
+
+    type Input = EpisodeReturnInput;
+
+    fn update(&mut self, item: &EpisodeReturnInput, _metadata: &MetricMetadata) -> MetricEntry {
+        self.state
+            .update(item.value, 1, FormatOptions::new(Self::NAME).precision(2))
+    }
+
+    fn clear(&mut self) {
+        self.state.reset()
+    }
+}
+
+impl Numeric for EpisodeReturnMetric {
+    fn value(&self) -> f64 {
+        self.state.value()
+    }
+}
+
+/// Track the number of steps of each episode.
+#[derive(Default)]
+pub struct EpisodeLengthMetric {
+    state: NumericMetricState,
+}
+
+impl EpisodeLengthMetric {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Metric for EpisodeLengthMetric {
+    const NAME: &'static str = "Episode Length";
+
+    type Input = EpisodeLengthInput;
+
+    fn update(&mut self, item: &EpisodeLengthInput, _metadata: &MetricMetadata) -> MetricEntry {
+        self.state.update(
+            item.length as f64,
+            1,
+            FormatOptions::new(Self::NAME).unit("steps").precision(1),
+        )
+    }
+
+    fn clear(&mut self) {
+        self.state.reset()
+    }
+}
+
+impl Numeric for EpisodeLengthMetric {
+    fn value(&self) -> f64 {
+        self.state.value()
+    }
+}
+
+/// Track the loss of the updates of the agent, with the same name as the
+/// [loss metric](crate::metric::LossMetric).
+///
+/// Episodes without updates don't change the value of the epoch.
+#[derive(Default)]
+pub struct AgentLossMetric {
+    state: NumericMetricState,
+}
+
+impl AgentLossMetric {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Metric for AgentLossMetric {
+    const NAME: &'static str = "Loss";
+
+    type Input = AgentLossInput;
+
+    fn update(&mut self, item: &AgentLossInput, _metadata: &MetricMetadata) -> MetricEntry {
+        let (loss, count) = match item.loss {
+            Some(loss) => (loss, 1),
+            None => (self.state.value(), 0),
+        };
+
+        self.state
+            .update(loss, count, FormatOptions::new(Self::NAME).precision(4))
+    }
+
+    fn clear(&mut self) {
+        self.state.reset()
+    }
+}
+
+impl Numeric for AgentLossMetric {
+    fn value(&self) -> f64 {
+        self.state.value()
+    }
+}
@@ -0,0 +1,13 @@
+mod buffer;
+mod cartpole;
+mod environment;
+mod metric;
+mod target;
+mod trainer;
+
+pub use buffer::*;
+pub use cartpole::*;
+pub use environment::*;
+pub use metric::*;
+pub use target::*;
+pub use trainer::*;
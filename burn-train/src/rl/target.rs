@@ -0,0 +1,125 @@
+use burn_core::module::{Module, ModuleMapper, ModuleVisitor, ParamId};
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{Bool, Int, Tensor};
+use std::any::Any;
+use std::collections::VecDeque;
+
+/// Update the parameters of a target network towards the ones of the online network, with
+/// `target = tau * online + (1 - tau) * target`, as in DQN, DDPG or SAC.
+///
+/// The networks should have the same architecture, with the parameters matched in the order
+/// they are visited. The float parameters are interpolated without gradients, while the int and
+/// bool parameters are copied from the online network.
+///
+/// A `tau` of `1` copies the online network, which is the periodic hard update of DQN.
+pub fn soft_update<B: Backend, M: Module<B>>(target: M, online: &M, tau: f64) -> M {
+    let mut collector = ParamCollector::default();
+    online.visit(&mut collector);
+
+    let mut updater = SoftUpdater {
+        params: collector.params,
+        tau,
+    };
+    target.map(&mut updater)
+}
+
+/// Collect the parameters of the online network, in the order they are visited.
+#[derive(Default)]
+struct ParamCollector {
+    params: VecDeque<Box<dyn Any>>,
+}
+
+impl<B: Backend> ModuleVisitor<B> for ParamCollector {
+    fn visit_float<const D: usize>(&mut self, _id: &ParamId, tensor: &Tensor<B, D>) {
+        self.params.push_back(Box::new(tensor.clone()));
+    }
+
+    fn visit_int<const D: usize>(&mut self, _id: &ParamId, tensor: &Tensor<B, D, Int>) {
+        self.params.push_back(Box::new(tensor.clone()));
+    }
+
+    fn visit_bool<const D: usize>(&mut self, _id: &ParamId, tensor: &Tensor<B, D, Bool>) {
+        self.params.push_back(Box::new(tensor.clone()));
+    }
+}
+
+struct SoftUpdater {
+    params: VecDeque<Box<dyn Any>>,
+    tau: f64,
+}
+
+impl SoftUpdater {
+    fn next<T: 'static>(&mut self) -> T {
+        let param = self
+            .params
+            .pop_front()
+            .expect("The target network should have the same parameters as the online network.");
+
+        *param
+            .downcast()
+            .expect("The target network should have the same parameters as the online network.")
+    }
+}
+
+impl<B: Backend> ModuleMapper<B> for SoftUpdater {
+    fn map_float<const D: usize>(&mut self, _id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let online: Tensor<B, D> = self.next();
+        let require_grad = tensor.is_require_grad();
+
+        online
+            .detach()
+            .mul_scalar(self.tau)
+            .add(tensor.detach().mul_scalar(1.0 - self.tau))
+            .set_require_grad(require_grad)
+    }
+
+    fn map_int<const D: usize>(
+        &mut self,
+        _id: &ParamId,
+        _tensor: Tensor<B, D, Int>,
+    ) -> Tensor<B, D, Int> {
+        self.next()
+    }
+
+    fn map_bool<const D: usize>(
+        &mut self,
+        _id: &ParamId,
+        _tensor: Tensor<B, D, Bool>,
+    ) -> Tensor<B, D, Bool> {
+        self.next()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_core::nn::{Linear, LinearConfig};
+
+    #[test]
+    fn should_interpolate_the_parameters() {
+        let device = Default::default();
+        let online: Linear<TestBackend> = LinearConfig::new(2, 2).init(&device);
+        let target: Linear<TestBackend> = LinearConfig::new(2, 2).init(&device);
+        let expected = online
+            .weight
+            .val()
+            .mul_scalar(0.25)
+            .add(target.weight.val().mul_scalar(0.75));
+
+        let target = soft_update(target, &online, 0.25);
+
+        target
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 5);
+        let target = soft_update(target, &online, 1.0);
+        target
+            .bias
+            .unwrap()
+            .val()
+            .into_data()
+            .assert_approx_eq(&online.bias.unwrap().val().into_data(), 5);
+    }
+}
@@ -0,0 +1,359 @@
+use super::{
+    AgentLossMetric, Environment, EpisodeLengthMetric, EpisodeOutput, EpisodeReturnMetric,
+    Transition,
+};
+use crate::logger::{InMemoryMetricLogger, MetricLogger};
+use crate::metric::processor::{Event, EventProcessor, FullEventProcessor, LearnerItem, Metrics};
+use crate::metric::store::{EventStoreClient, LogEventStore};
+use crate::metric::{Adaptor, Metric, Numeric};
+use crate::renderer::{default_renderer, MetricsRenderer};
+use crate::TrainingInterrupter;
+use burn_core::data::dataloader::Progress;
+use std::sync::Arc;
+
+/// An agent acting in an [environment](Environment) and learning from its transitions, usually
+/// with a model and an [optimizer](burn_core::optim::Optimizer) of its own.
+pub trait Agent<E: Environment> {
+    /// Select the action to take in a state.
+    fn act(&mut self, state: &E::State) -> E::Action;
+
+    /// Observe the transition of the last action, such as to add it to a
+    /// [replay buffer](crate::rl::ReplayBuffer) and update the model, returning the loss of the
+    /// update if any.
+    fn observe(&mut self, transition: Transition<E::State, E::Action>) -> Option<f64>;
+
+    /// Called at the end of each episode, such as to update the model from a
+    /// [rollout buffer](crate::rl::RolloutBuffer), returning the loss of the update if any.
+    fn end_episode(&mut self) -> Option<f64> {
+        None
+    }
+}
+
+/// The result of the [training](RlTrainer::run) of an agent.
+#[derive(Clone, Debug, Default)]
+pub struct RlTrainingResult {
+    /// The summary of each episode, until the training stopped.
+    pub episodes: Vec<EpisodeOutput>,
+}
+
+impl RlTrainingResult {
+    /// The mean return of the last `count` episodes.
+    pub fn mean_return(&self, count: usize) -> Option<f64> {
+        let episodes = &self.episodes[self.episodes.len().saturating_sub(count)..];
+        if episodes.is_empty() {
+            return None;
+        }
+
+        Some(episodes.iter().map(|e| e.episode_return).sum::<f64>() / episodes.len() as f64)
+    }
+}
+
+/// The training loop of reinforcement learning, where the data comes from the interactions of
+/// an [agent](Agent) with an [environment](Environment) instead of a dataloader.
+///
+/// The episodes are grouped in epochs, each episode being rendered and logged as an iteration,
+/// with the [episode return](EpisodeReturnMetric), the [episode length](EpisodeLengthMetric)
+/// and the [loss](AgentLossMetric) of the agent as metrics.
+pub struct RlTrainer {
+    num_epochs: usize,
+    episodes_per_epoch: usize,
+    max_episode_steps: Option<usize>,
+    metrics: Metrics<EpisodeOutput, EpisodeOutput>,
+    event_store: LogEventStore,
+    num_loggers: usize,
+    renderer: Option<Box<dyn MetricsRenderer>>,
+    interrupter: TrainingInterrupter,
+}
+
+impl Default for RlTrainer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RlTrainer {
+    /// Creates the trainer, with one epoch of 100 episodes.
+    pub fn new() -> Self {
+        let mut metrics = Metrics::default();
+        metrics.register_train_metric_numeric(EpisodeReturnMetric::new());
+        metrics.register_train_metric_numeric(EpisodeLengthMetric::new());
+        metrics.register_train_metric_numeric(AgentLossMetric::new());
+
+        Self {
+            num_epochs: 1,
+            episodes_per_epoch: 100,
+            max_episode_steps: None,
+            metrics,
+            event_store: LogEventStore::default(),
+            num_loggers: 0,
+            renderer: None,
+            interrupter: TrainingInterrupter::new(),
+        }
+    }
+
+    /// The number of epochs.
+    pub fn num_epochs(mut self, num_epochs: usize) -> Self {
+        self.num_epochs = num_epochs;
+        self
+    }
+
+    /// The number of episodes of each epoch.
+    pub fn episodes_per_epoch(mut self, episodes_per_epoch: usize) -> Self {
+        self.episodes_per_epoch = episodes_per_epoch;
+        self
+    }
+
+    /// Truncate the episodes after the given number of steps, for environments without a time
+    /// limit.
+    pub fn max_episode_steps(mut self, max_episode_steps: usize) -> Self {
+        self.max_episode_steps = Some(max_episode_steps);
+        self
+    }
+
+    /// Register a numeric metric of the episodes.
+    pub fn metric<M>(mut self, metric: M) -> Self
+    where
+        M: Metric + Numeric + 'static,
+        EpisodeOutput: Adaptor<M::Input>,
+    {
+        self.metrics.register_train_metric_numeric(metric);
+        self
+    }
+
+    /// Register a metric logger, such as a [file logger](crate::logger::FileMetricLogger),
+    /// instead of keeping the metrics in memory.
+    ///
+    /// # Notes
+    ///
+    /// This method can be called multiple times to register multiple loggers.
+    pub fn metric_logger<ML: MetricLogger + 'static>(mut self, logger: ML) -> Self {
+        self.event_store.register_logger_train(logger);
+        self.num_loggers += 1;
+        self
+    }
+
+    /// Replace the default CLI renderer with a custom one.
+    pub fn renderer<MR>(mut self, renderer: MR) -> Self
+    where
+        MR: MetricsRenderer + 'static,
+    {
+        self.renderer = Some(Box::new(renderer));
+        self
+    }
+
+    /// Provides a handle that can be used to interrupt the training.
+    pub fn interrupter(&self) -> TrainingInterrupter {
+        self.interrupter.clone()
+    }
+
+    /// Train the agent in the environment.
+    pub fn run<E, A>(mut self, env: &mut E, agent: &mut A) -> RlTrainingResult
+    where
+        E: Environment,
+        A: Agent<E>,
+    {
+        if self.num_loggers == 0 {
+            self.event_store
+                .register_logger_train(InMemoryMetricLogger::new());
+        }
+        let store = Arc::new(EventStoreClient::new(self.event_store));
+        let renderer = self
+            .renderer
+            .unwrap_or_else(|| Box::new(default_renderer(self.interrupter.clone(), None)));
+        let mut processor = FullEventProcessor::new(self.metrics, renderer, store);
+        let mut result = RlTrainingResult::default();
+        let mut iteration = 0;
+
+        'training: for epoch in 1..self.num_epochs + 1 {
+            for episode in 1..self.episodes_per_epoch + 1 {
+                let output = run_episode(env, agent, self.max_episode_steps);
+                iteration += 1;
+
+                processor.process_train(Event::ProcessedItem(LearnerItem::new(
+                    output.clone(),
+                    Progress::new(episode, self.episodes_per_epoch),
+                    epoch,
+                    self.num_epochs,
+                    iteration,
+                    None,
+                )));
+                result.episodes.push(output);
+
+                if self.interrupter.should_stop() {
+                    processor.process_train(Event::EndEpoch(epoch));
+                    break 'training;
+                }
+            }
+
+            processor.process_train(Event::EndEpoch(epoch));
+        }
+
+        result
+    }
+}
+
+/// Run an episode until it's done or truncated after the maximum number of steps.
+fn run_episode<E, A>(env: &mut E, agent: &mut A, max_steps: Option<usize>) -> EpisodeOutput
+where
+    E: Environment,
+    A: Agent<E>,
+{
+    let mut state = env.reset();
+    let mut episode_return = 0.0;
+    let mut length = 0;
+    let mut losses = Vec::new();
+
+    loop {
+        let action = agent.act(&state);
+        let step = env.step(&action);
+        length += 1;
+        episode_return += step.reward;
+
+        let truncated = max_steps.is_some_and(|max_steps| length >= max_steps);
+        let done = step.done() || truncated;
+        let transition = Transition::new(
+            state,
+            action,
+            step.reward,
+            step.state.clone(),
+            step.terminated,
+            done,
+        );
+        losses.extend(agent.observe(transition));
+        state = step.state;
+
+        if done {
+            break;
+        }
+    }
+
+    losses.extend(agent.end_episode());
+    let loss = match losses.is_empty() {
+        true => None,
+        false => Some(losses.iter().sum::<f64>() / losses.len() as f64),
+    };
+
+    EpisodeOutput::new(episode_return, length, loss)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::{MetricState, TrainingProgress};
+    use crate::rl::{CartPole, RolloutBuffer};
+    use crate::TestAutodiffBackend;
+    use burn_core::module::Module;
+    use burn_core::nn::{Linear, LinearConfig};
+    use burn_core::optim::{AdamConfig, GradientsParams, Optimizer};
+    use burn_core::tensor::activation::{log_softmax, softmax};
+    use burn_core::tensor::{Data, ElementConversion, Int, Tensor};
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use std::sync::Mutex;
+
+    type TB = TestAutodiffBackend;
+
+    /// A REINFORCE agent with a linear policy.
+    struct Reinforce<O> {
+        policy: Linear<TB>,
+        optim: O,
+        rollout: RolloutBuffer<[f32; 4], usize>,
+        rng: StdRng,
+    }
+
+    impl<O: Optimizer<Linear<TB>, TB>> Agent<CartPole> for Reinforce<O> {
+        fn act(&mut self, state: &[f32; 4]) -> usize {
+            let state = Tensor::<TB, 2>::from_floats([*state], &Default::default());
+            let probs = softmax(self.policy.forward(state), 1).into_data().value;
+            let right = probs[1].elem::<f64>();
+
+            self.rng.gen_bool(right.clamp(0.0, 1.0)) as usize
+        }
+
+        fn observe(&mut self, transition: Transition<[f32; 4], usize>) -> Option<f64> {
+            self.rollout.push(transition);
+            None
+        }
+
+        fn end_episode(&mut self) -> Option<f64> {
+            let device = Default::default();
+            let returns = self.rollout.returns(0.99);
+            let transitions = self.rollout.drain();
+            let num_steps = transitions.len();
+
+            let states = transitions
+                .iter()
+                .flat_map(|transition| transition.state)
+                .collect::<Vec<_>>();
+            let states = Tensor::<TB, 2>::from_data(
+                Data::new(states, [num_steps, 4].into()).convert(),
+                &device,
+            );
+            let actions = transitions
+                .iter()
+                .map(|transition| transition.action as i64)
+                .collect::<Vec<_>>();
+            let actions = Tensor::<TB, 1, Int>::from_data(
+                Data::new(actions, [num_steps].into()).convert(),
+                &device,
+            );
+            let returns = Tensor::<TB, 1>::from_data(
+                Data::new(returns, [num_steps].into()).convert(),
+                &device,
+            );
+
+            let log_probs = log_softmax(self.policy.forward(states), 1)
+                .gather(1, actions.reshape([num_steps, 1]))
+                .reshape([num_steps]);
+            let loss = log_probs.mul(returns).mean().neg();
+            let value = loss.clone().into_scalar().elem::<f64>();
+
+            let grads = GradientsParams::from_grads(loss.backward(), &self.policy);
+            self.policy = self.optim.step(1e-2, self.policy.clone(), grads);
+
+            Some(value)
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct RecordingRenderer {
+        epochs: Arc<Mutex<Vec<usize>>>,
+    }
+
+    impl MetricsRenderer for RecordingRenderer {
+        fn update_train(&mut self, _state: MetricState) {}
+        fn update_valid(&mut self, _state: MetricState) {}
+        fn render_train(&mut self, item: TrainingProgress) {
+            self.epochs.lock().unwrap().push(item.epoch);
+        }
+        fn render_valid(&mut self, _item: TrainingProgress) {}
+    }
+
+    #[test]
+    fn should_train_an_agent_on_cartpole() {
+        let mut env = CartPole::new(0);
+        let mut agent = Reinforce {
+            policy: LinearConfig::new(4, 2).init(&Default::default()),
+            optim: AdamConfig::new().init(),
+            rollout: RolloutBuffer::new(),
+            rng: StdRng::seed_from_u64(0),
+        };
+        let renderer = RecordingRenderer::default();
+
+        let result = RlTrainer::new()
+            .num_epochs(2)
+            .episodes_per_epoch(3)
+            .max_episode_steps(50)
+            .renderer(renderer.clone())
+            .run(&mut env, &mut agent);
+
+        assert_eq!(result.episodes.len(), 6);
+        assert_eq!(*renderer.epochs.lock().unwrap(), vec![1, 1, 1, 2, 2, 2]);
+        for episode in result.episodes.iter() {
+            assert!(episode.length > 0 && episode.length <= 50);
+            assert_eq!(episode.episode_return, episode.length as f64);
+            assert!(episode.loss.is_some_and(f64::is_finite));
+        }
+        assert!(agent.policy.num_params() > 0);
+        assert!(result.mean_return(3).unwrap() > 0.0);
+    }
+}
@@ -0,0 +1,405 @@
+use super::trial::{SweepShared, SweepState};
+use super::{
+    Observation, Pruner, RandomSearch, SearchSpace, SearchStrategy, Trial, TrialRecord, TrialStatus,
+};
+use crate::metric::store::{Aggregate, Direction, Split};
+use crate::metric::Metric;
+use burn_core::config::Config;
+use std::panic::AssertUnwindSafe;
+use std::sync::{Arc, Mutex};
+
+/// The metric optimized by a [sweep](Sweep).
+pub struct Objective {
+    pub(crate) name: String,
+    pub(crate) aggregate: Aggregate,
+    pub(crate) direction: Direction,
+    pub(crate) split: Split,
+}
+
+impl Objective {
+    /// Creates the objective from a [metric](Metric).
+    pub fn new<M: Metric>(aggregate: Aggregate, direction: Direction, split: Split) -> Self {
+        Self {
+            name: M::NAME.to_string(),
+            aggregate: aggregate.chosen_for::<M>(),
+            direction,
+            split,
+        }
+    }
+
+    /// The score of a value of the objective, where lower is better.
+    pub(crate) fn score(&self, value: f64) -> f64 {
+        match self.direction {
+            Direction::Lowest => value,
+            Direction::Highest => -value,
+        }
+    }
+
+    /// The best of two values of the objective.
+    pub(crate) fn best(&self, a: f64, b: f64) -> f64 {
+        match self.score(a) <= self.score(b) {
+            true => a,
+            false => b,
+        }
+    }
+}
+
+/// Search the parameters of a [config](Config) by running trials, each with its own directory
+/// of artifacts in the directory of the sweep:
+///
+/// - `trial-{id}/config.json` - The config of the trial.
+/// - `trial-{id}/trial.json` - The parameters, status and metrics of the trial.
+/// - `leaderboard.csv` - The trials ranked by their objective, written at the end of the sweep.
+pub struct Sweep<C, D> {
+    base: C,
+    space: SearchSpace,
+    directory: String,
+    objective: Objective,
+    strategy: Box<dyn SearchStrategy>,
+    pruner: Option<Box<dyn Pruner>>,
+    metrics: Vec<(String, Aggregate, Split)>,
+    num_trials: usize,
+    devices: Vec<D>,
+}
+
+impl<C, D> Sweep<C, D>
+where
+    C: Config + Send + Sync,
+    D: Clone + Send + Sync,
+{
+    /// Creates a sweep of 10 random trials run sequentially on the given device.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The config with the default value of every parameter.
+    /// * `space` - The parameters to search.
+    /// * `objective` - The metric to optimize.
+    /// * `directory` - The directory of the sweep.
+    /// * `device` - The device of the trials.
+    pub fn new(
+        base: C,
+        space: SearchSpace,
+        objective: Objective,
+        directory: &str,
+        device: D,
+    ) -> Self {
+        Self {
+            base,
+            space,
+            directory: directory.to_string(),
+            objective,
+            strategy: Box::new(RandomSearch::new(0)),
+            pruner: None,
+            metrics: Vec::new(),
+            num_trials: 10,
+            devices: vec![device],
+        }
+    }
+
+    /// The [strategy](SearchStrategy) suggesting the parameters of the trials.
+    pub fn strategy<S: SearchStrategy + 'static>(mut self, strategy: S) -> Self {
+        self.strategy = Box::new(strategy);
+        self
+    }
+
+    /// The [pruner](Pruner) stopping the unpromising trials early.
+    pub fn pruner<P: Pruner + 'static>(mut self, pruner: P) -> Self {
+        self.pruner = Some(Box::new(pruner));
+        self
+    }
+
+    /// The maximum number of trials, which can be fewer when the strategy is exhausted.
+    pub fn num_trials(mut self, num_trials: usize) -> Self {
+        self.num_trials = num_trials;
+        self
+    }
+
+    /// Run the trials in parallel, one on each device at a time.
+    pub fn devices(mut self, devices: Vec<D>) -> Self {
+        self.devices = devices;
+        self
+    }
+
+    /// Record another [metric](Metric) of each epoch of the trials.
+    pub fn metric<M: Metric>(mut self, aggregate: Aggregate, split: Split) -> Self {
+        self.metrics
+            .push((M::NAME.to_string(), aggregate.chosen_for::<M>(), split));
+        self
+    }
+
+    /// Run the sweep, calling the function with each trial.
+    ///
+    /// The function trains the model of the trial, with the [callback](Trial::callback) of the
+    /// trial registered in its learner to report the metrics and prune the trial.
+    pub fn run<F>(self, train: F) -> Leaderboard
+    where
+        F: Fn(Trial<C, D>) + Sync,
+    {
+        std::fs::create_dir_all(&self.directory).ok();
+
+        let shared = Arc::new(SweepShared {
+            state: Mutex::new(SweepState {
+                strategy: self.strategy,
+                num_started: 0,
+                records: Vec::new(),
+            }),
+            objective: self.objective,
+            metrics: self.metrics,
+            pruner: self.pruner,
+        });
+        let worker = SweepWorker {
+            base: &self.base,
+            space: &self.space,
+            directory: &self.directory,
+            num_trials: self.num_trials,
+            shared: &shared,
+            train: &train,
+        };
+
+        std::thread::scope(|scope| {
+            for device in self.devices.iter() {
+                scope.spawn(|| worker.run(device.clone()));
+            }
+        });
+
+        let state = shared.state.lock().expect("Can lock the sweep state.");
+        let leaderboard = Leaderboard::new(state.records.clone(), &shared.objective);
+        leaderboard.save(&format!("{}/leaderboard.csv", self.directory));
+
+        leaderboard
+    }
+}
+
+struct SweepWorker<'a, C, F> {
+    base: &'a C,
+    space: &'a SearchSpace,
+    directory: &'a str,
+    num_trials: usize,
+    shared: &'a Arc<SweepShared>,
+    train: &'a F,
+}
+
+impl<C: Config, F> SweepWorker<'_, C, F> {
+    fn run<D>(&self, device: D)
+    where
+        F: Fn(Trial<C, D>),
+        D: Clone,
+    {
+        while let Some((id, params)) = self.next_trial() {
+            let directory = format!("{}/trial-{id}", self.directory);
+            std::fs::create_dir_all(&directory).ok();
+
+            let status = match params.apply(self.base) {
+                Ok(config) => {
+                    config
+                        .save(format!("{directory}/config.json"))
+                        .expect("Can save the config of the trial.");
+                    log::info!("Running trial {id} with {:?}", params);
+
+                    let trial = Trial {
+                        id,
+                        config,
+                        params,
+                        device: device.clone(),
+                        directory: directory.clone(),
+                        shared: self.shared.clone(),
+                    };
+                    match std::panic::catch_unwind(AssertUnwindSafe(|| (self.train)(trial))) {
+                        Ok(()) => TrialStatus::Completed,
+                        Err(_) => TrialStatus::Failed,
+                    }
+                }
+                Err(err) => {
+                    log::warn!("Invalid config for trial {id}: {err}");
+                    TrialStatus::Failed
+                }
+            };
+
+            self.finish_trial(id, status, &directory);
+        }
+    }
+
+    /// Suggest the parameters of the next trial, if any.
+    fn next_trial(&self) -> Option<(usize, super::Assignment)> {
+        let mut state = self.shared.state.lock().expect("Can lock the sweep state.");
+        if state.num_started >= self.num_trials {
+            return None;
+        }
+
+        let observations = state
+            .records
+            .iter()
+            .filter(|record| record.status != TrialStatus::Running)
+            .filter_map(|record| {
+                record.objective.map(|objective| {
+                    Observation::new(
+                        record.params.clone(),
+                        self.shared.objective.score(objective),
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
+        let params = state.strategy.suggest(self.space, &observations)?;
+
+        let id = state.num_started;
+        state.num_started += 1;
+        state.records.push(TrialRecord::new(id, params.clone()));
+
+        Some((id, params))
+    }
+
+    fn finish_trial(&self, id: usize, status: TrialStatus, directory: &str) {
+        let mut state = self.shared.state.lock().expect("Can lock the sweep state.");
+        let record = &mut state.records[id];
+
+        // A pruned trial completes normally once interrupted.
+        if record.status == TrialStatus::Running || status == TrialStatus::Failed {
+            record.status = status;
+        }
+
+        let content = serde_json::to_string_pretty(record).expect("Can serialize the trial.");
+        std::fs::write(format!("{directory}/trial.json"), content)
+            .expect("Can save the record of the trial.");
+    }
+}
+
+/// The trials of a [sweep](Sweep) ranked by their objective, with the trials without any value
+/// of the objective last.
+pub struct Leaderboard {
+    /// The ranked trials.
+    pub trials: Vec<TrialRecord>,
+}
+
+impl Leaderboard {
+    fn new(mut trials: Vec<TrialRecord>, objective: &Objective) -> Self {
+        let score = |record: &TrialRecord| {
+            record
+                .objective
+                .map(|value| objective.score(value))
+                .unwrap_or(f64::INFINITY)
+        };
+        trials.sort_by(|a, b| score(a).total_cmp(&score(b)));
+
+        Self { trials }
+    }
+
+    /// The best trial, if any reported the objective.
+    pub fn best(&self) -> Option<&TrialRecord> {
+        self.trials
+            .first()
+            .filter(|trial| trial.objective.is_some())
+    }
+
+    fn save(&self, file_path: &str) {
+        let keys: Vec<String> = self
+            .trials
+            .first()
+            .map(|trial| {
+                trial
+                    .params
+                    .iter()
+                    .map(|(key, _)| key.to_string())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let mut content = ["rank", "trial", "status", "objective", "epochs"]
+            .into_iter()
+            .map(str::to_string)
+            .chain(keys.iter().map(|key| escape_csv(key)))
+            .collect::<Vec<_>>()
+            .join(",");
+        content.push('\n');
+
+        for (rank, trial) in self.trials.iter().enumerate() {
+            let objective = trial
+                .objective
+                .map(|value| value.to_string())
+                .unwrap_or_default();
+            let row = [
+                (rank + 1).to_string(),
+                trial.id.to_string(),
+                format!("{:?}", trial.status),
+                objective,
+                trial.epochs.len().to_string(),
+            ]
+            .into_iter()
+            .chain(keys.iter().map(|key| match trial.params.get(key) {
+                Some(serde_json::Value::String(value)) => escape_csv(value),
+                Some(value) => escape_csv(&value.to_string()),
+                None => String::new(),
+            }))
+            .collect::<Vec<_>>()
+            .join(",");
+
+            content.push_str(&row);
+            content.push('\n');
+        }
+
+        std::fs::write(file_path, content).expect("Can save the leaderboard.");
+    }
+}
+
+fn escape_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metric::LossMetric;
+    use crate::sweep::{GridSearch, MedianPruner};
+    use crate::TestBackend;
+    use burn_core as burn;
+
+    #[derive(Config)]
+    struct TestConfig {
+        #[config(default = 0.0)]
+        x: f64,
+    }
+
+    #[test]
+    fn should_rank_trials_and_save_artifacts() {
+        let directory = tempfile::tempdir().unwrap();
+        let directory = directory.path().to_str().unwrap();
+        let space = SearchSpace::new().choice("x", vec![3.0, 1.0, 2.0, 4.0]);
+        let objective = Objective::new::<LossMetric<TestBackend>>(
+            Aggregate::Mean,
+            Direction::Lowest,
+            Split::Valid,
+        );
+
+        let leaderboard = Sweep::new(TestConfig::new(), space, objective, directory, ())
+            .strategy(GridSearch::new(1))
+            .pruner(MedianPruner::new(1))
+            .num_trials(10)
+            .devices(vec![(), ()])
+            .run(|trial| {
+                for epoch in 1..4 {
+                    // The loss of the first epoch is the same for every trial.
+                    let loss = match epoch {
+                        1 => 10.0,
+                        _ => trial.config.x * epoch as f64,
+                    };
+                    if trial.report(epoch, loss) {
+                        break;
+                    }
+                }
+            });
+
+        let ids = leaderboard.trials.iter().map(|t| t.id).collect::<Vec<_>>();
+        assert_eq!(ids[0], 1);
+        assert_eq!(leaderboard.best().unwrap().objective, Some(2.0));
+        assert_eq!(leaderboard.trials.len(), 4);
+
+        let saved = std::fs::read_to_string(format!("{directory}/leaderboard.csv")).unwrap();
+        assert!(saved.starts_with("rank,trial,status,objective,epochs,x\n1,1,Completed,2,3,1.0\n"));
+        let config = TestConfig::load(format!("{directory}/trial-3/config.json")).unwrap();
+        assert_eq!(config.x, 4.0);
+        assert!(std::path::Path::new(&format!("{directory}/trial-3/trial.json")).exists());
+    }
+}
@@ -0,0 +1,11 @@
+mod base;
+mod pruner;
+mod space;
+mod strategy;
+mod trial;
+
+pub use base::*;
+pub use pruner::*;
+pub use space::*;
+pub use strategy::*;
+pub use trial::*;
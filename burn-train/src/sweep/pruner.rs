@@ -0,0 +1,157 @@
+/// A strategy that stops the unpromising trials of a [sweep](super::Sweep) early.
+///
+/// The scores are the values of the objective at the end of each epoch, negated when higher is
+/// better, so that lower scores are better.
+pub trait Pruner: Send + Sync {
+    /// Whether the trial should be stopped after its last epoch.
+    ///
+    /// # Arguments
+    ///
+    /// * `scores` - The score of each epoch of the trial.
+    /// * `others` - The score of each epoch of the other trials, finished or running.
+    fn should_prune(&self, scores: &[f64], others: &[&[f64]]) -> bool;
+}
+
+/// Stop a trial when its best score is worse than the median of the best scores of the other
+/// trials at the same epoch.
+pub struct MedianPruner {
+    warmup_epochs: usize,
+    min_trials: usize,
+}
+
+impl MedianPruner {
+    /// Creates the pruner, which doesn't stop the trials during the given number of epochs.
+    pub fn new(warmup_epochs: usize) -> Self {
+        Self {
+            warmup_epochs,
+            min_trials: 1,
+        }
+    }
+
+    /// The minimum number of other trials that reached the epoch to compare against.
+    pub fn with_min_trials(mut self, min_trials: usize) -> Self {
+        self.min_trials = min_trials;
+        self
+    }
+}
+
+impl Pruner for MedianPruner {
+    fn should_prune(&self, scores: &[f64], others: &[&[f64]]) -> bool {
+        let epoch = scores.len();
+        if epoch <= self.warmup_epochs {
+            return false;
+        }
+
+        let mut others = others
+            .iter()
+            .filter(|scores| scores.len() >= epoch)
+            .map(|scores| best(&scores[..epoch]))
+            .collect::<Vec<_>>();
+        if others.is_empty() || others.len() < self.min_trials {
+            return false;
+        }
+
+        others.sort_by(f64::total_cmp);
+        let middle = others.len() / 2;
+        let median = match others.len() % 2 {
+            0 => (others[middle - 1] + others[middle]) / 2.0,
+            _ => others[middle],
+        };
+
+        best(scores) > median
+    }
+}
+
+/// Asynchronous successive halving (ASHA): a trial only continues after the epochs
+/// `min_epochs * reduction_factor^k` when its best score is among the best
+/// `1 / reduction_factor` of the trials that reached the same epoch.
+///
+/// A trial always continues until `reduction_factor` trials reached the epoch.
+pub struct AshaPruner {
+    min_epochs: usize,
+    reduction_factor: usize,
+}
+
+impl AshaPruner {
+    /// Creates the pruner with the epoch of the first rung and the reduction factor.
+    pub fn new(min_epochs: usize, reduction_factor: usize) -> Self {
+        assert!(
+            min_epochs > 0,
+            "The first rung must be after at least one epoch."
+        );
+        assert!(
+            reduction_factor > 1,
+            "The reduction factor must be at least 2."
+        );
+
+        Self {
+            min_epochs,
+            reduction_factor,
+        }
+    }
+
+    fn is_rung(&self, epoch: usize) -> bool {
+        let mut rung = self.min_epochs;
+        while rung < epoch {
+            rung *= self.reduction_factor;
+        }
+
+        rung == epoch
+    }
+}
+
+impl Pruner for AshaPruner {
+    fn should_prune(&self, scores: &[f64], others: &[&[f64]]) -> bool {
+        let epoch = scores.len();
+        if epoch == 0 || !self.is_rung(epoch) {
+            return false;
+        }
+
+        let score = best(scores);
+        let rung = others
+            .iter()
+            .filter(|scores| scores.len() >= epoch)
+            .map(|scores| best(&scores[..epoch]))
+            .collect::<Vec<_>>();
+        let num_trials = rung.len() + 1;
+        if num_trials < self.reduction_factor {
+            return false;
+        }
+
+        let num_promoted = num_trials / self.reduction_factor;
+        let rank = rung.iter().filter(|other| **other < score).count();
+
+        rank >= num_promoted
+    }
+}
+
+fn best(scores: &[f64]) -> f64 {
+    scores.iter().copied().fold(f64::INFINITY, f64::min)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_prune_worse_than_median() {
+        let pruner = MedianPruner::new(1);
+        let others: [&[f64]; 3] = [&[1.0, 0.5], &[2.0, 1.0], &[3.0, 2.0, 1.0]];
+
+        assert!(!pruner.should_prune(&[9.0], &others));
+        assert!(pruner.should_prune(&[9.0, 1.5], &others));
+        assert!(!pruner.should_prune(&[9.0, 0.8], &others));
+    }
+
+    #[test]
+    fn should_prune_at_rungs() {
+        let pruner = AshaPruner::new(1, 2);
+        let others: [&[f64]; 3] = [&[1.0, 0.5], &[2.0, 1.0], &[3.0]];
+
+        // Among the 4 trials at the first epoch, only the 2 best are promoted.
+        assert!(pruner.should_prune(&[2.5], &others));
+        assert!(!pruner.should_prune(&[1.5], &others));
+        // The third epoch isn't a rung.
+        assert!(!pruner.should_prune(&[1.5, 1.4, 9.0], &others));
+    }
+}
@@ -0,0 +1,281 @@
+use burn_core::config::Config;
+use rand::Rng;
+use serde::Serialize;
+use serde_json::Value;
+
+/// The values a parameter of the [search space](SearchSpace) can take.
+#[derive(Clone, Debug)]
+pub enum ParamDistribution {
+    /// One of the given values.
+    Choice(Vec<Value>),
+    /// A float uniformly distributed between the bounds.
+    Uniform {
+        /// The lower bound.
+        low: f64,
+        /// The upper bound.
+        high: f64,
+    },
+    /// A float whose logarithm is uniformly distributed between the logarithms of the bounds,
+    /// such as a learning rate.
+    LogUniform {
+        /// The lower bound, which must be positive.
+        low: f64,
+        /// The upper bound.
+        high: f64,
+    },
+    /// An integer between the bounds, included.
+    IntRange {
+        /// The lower bound.
+        low: i64,
+        /// The upper bound.
+        high: i64,
+    },
+}
+
+impl ParamDistribution {
+    /// Sample a value of the parameter.
+    pub(crate) fn sample<R: Rng>(&self, rng: &mut R) -> Value {
+        match self {
+            Self::Choice(values) => values[rng.gen_range(0..values.len())].clone(),
+            _ => self.value_at(rng.gen::<f64>()),
+        }
+    }
+
+    /// The value at the given position in `[0, 1]` between the bounds of a numeric parameter.
+    pub(crate) fn value_at(&self, position: f64) -> Value {
+        let position = position.clamp(0.0, 1.0);
+
+        match self {
+            Self::Choice(values) => {
+                let index = (position * values.len() as f64) as usize;
+                values[index.min(values.len() - 1)].clone()
+            }
+            Self::Uniform { low, high } => Value::from(low + position * (high - low)),
+            Self::LogUniform { low, high } => {
+                Value::from((low.ln() + position * (high.ln() - low.ln())).exp())
+            }
+            Self::IntRange { low, high } => {
+                let value = *low as f64 + position * (*high - *low + 1) as f64;
+                Value::from((value.floor() as i64).min(*high))
+            }
+        }
+    }
+
+    /// The position in `[0, 1]` of a value of a numeric parameter between its bounds.
+    pub(crate) fn position_of(&self, value: &Value) -> f64 {
+        let value = value.as_f64().unwrap_or(0.0);
+
+        let position = match self {
+            Self::Choice(_) => 0.0,
+            Self::Uniform { low, high } => (value - low) / (high - low),
+            Self::LogUniform { low, high } => (value.ln() - low.ln()) / (high.ln() - low.ln()),
+            Self::IntRange { low, high } => (value - *low as f64 + 0.5) / (*high - *low + 1) as f64,
+        };
+
+        match position.is_finite() {
+            true => position.clamp(0.0, 1.0),
+            false => 0.5,
+        }
+    }
+
+    /// The values of the parameter on a grid with the given number of points for the ranges.
+    pub(crate) fn grid(&self, num_points: usize) -> Vec<Value> {
+        match self {
+            Self::Choice(values) => values.clone(),
+            Self::IntRange { low, high } if ((*high - *low + 1) as usize) <= num_points => {
+                (*low..=*high).map(Value::from).collect()
+            }
+            _ => {
+                let mut values: Vec<Value> = Vec::with_capacity(num_points);
+                for index in 0..num_points {
+                    let position = match num_points {
+                        1 => 0.5,
+                        _ => index as f64 / (num_points - 1) as f64,
+                    };
+                    let value = match self {
+                        Self::IntRange { low, high } => Value::from(
+                            (*low as f64 + position * (*high - *low) as f64).round() as i64,
+                        ),
+                        _ => self.value_at(position),
+                    };
+                    if !values.contains(&value) {
+                        values.push(value);
+                    }
+                }
+                values
+            }
+        }
+    }
+}
+
+/// The parameters of a [config](Config) to search, identified by the path of their field, with
+/// the fields of nested configs separated by dots (e.g. `optimizer.weight_decay.penalty`).
+#[derive(Clone, Debug, Default)]
+pub struct SearchSpace {
+    params: Vec<(String, ParamDistribution)>,
+}
+
+impl SearchSpace {
+    /// Creates an empty search space.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a parameter taking one of the given values.
+    pub fn choice<V: Serialize>(self, key: &str, values: Vec<V>) -> Self {
+        let values = values
+            .into_iter()
+            .map(|value| serde_json::to_value(value).expect("Can serialize the value."))
+            .collect::<Vec<_>>();
+        assert!(
+            !values.is_empty(),
+            "The parameter {key} needs at least one value."
+        );
+
+        self.param(key, ParamDistribution::Choice(values))
+    }
+
+    /// Add a float parameter uniformly distributed between the bounds.
+    pub fn uniform(self, key: &str, low: f64, high: f64) -> Self {
+        self.param(key, ParamDistribution::Uniform { low, high })
+    }
+
+    /// Add a float parameter log-uniformly distributed between the bounds.
+    pub fn log_uniform(self, key: &str, low: f64, high: f64) -> Self {
+        assert!(
+            low > 0.0,
+            "The bounds of the parameter {key} must be positive."
+        );
+        self.param(key, ParamDistribution::LogUniform { low, high })
+    }
+
+    /// Add an integer parameter between the bounds, included.
+    pub fn int_range(self, key: &str, low: i64, high: i64) -> Self {
+        self.param(key, ParamDistribution::IntRange { low, high })
+    }
+
+    /// Add a parameter with the given distribution.
+    pub fn param(mut self, key: &str, distribution: ParamDistribution) -> Self {
+        self.params.push((key.to_string(), distribution));
+        self
+    }
+
+    /// The parameters of the search space.
+    pub fn params(&self) -> &[(String, ParamDistribution)] {
+        &self.params
+    }
+}
+
+/// The values of the parameters of a trial.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Assignment {
+    values: Vec<(String, Value)>,
+}
+
+impl Assignment {
+    /// Creates an assignment from the value of each parameter.
+    pub fn new(values: Vec<(String, Value)>) -> Self {
+        Self { values }
+    }
+
+    /// The value of the given parameter.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.values
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value)
+    }
+
+    /// Iterate over the parameters and their values.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Value)> {
+        self.values.iter().map(|(key, value)| (key.as_str(), value))
+    }
+
+    /// Create the config of a trial by replacing the values of the parameters in the base
+    /// config.
+    pub fn apply<C: Config>(&self, base: &C) -> Result<C, String> {
+        let mut config = serde_json::to_value(base).map_err(|err| err.to_string())?;
+
+        for (key, value) in self.values.iter() {
+            let mut field = &mut config;
+            for name in key.split('.') {
+                field = field
+                    .as_object_mut()
+                    .and_then(|fields| fields.get_mut(name))
+                    .ok_or_else(|| format!("The config has no field {key}."))?;
+            }
+            *field = value.clone();
+        }
+
+        serde_json::from_value(config).map_err(|err| format!("Invalid value: {err}"))
+    }
+}
+
+impl Serialize for Assignment {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(Some(self.values.len()))?;
+        for (key, value) in self.values.iter() {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_core as burn;
+
+    #[derive(Config)]
+    struct OptimizerConfig {
+        #[config(default = 1e-3)]
+        lr: f64,
+    }
+
+    #[derive(Config)]
+    struct TrainingConfig {
+        optimizer: OptimizerConfig,
+        #[config(default = 32)]
+        batch_size: usize,
+    }
+
+    #[test]
+    fn should_apply_nested_params() {
+        let base = TrainingConfig::new(OptimizerConfig::new());
+        let assignment = Assignment::new(vec![
+            ("optimizer.lr".to_string(), Value::from(0.1)),
+            ("batch_size".to_string(), Value::from(64)),
+        ]);
+
+        let config = assignment.apply(&base).unwrap();
+
+        assert_eq!(config.optimizer.lr, 0.1);
+        assert_eq!(config.batch_size, 64);
+        assert!(Assignment::new(vec![("lr".to_string(), Value::from(0.1))])
+            .apply(&base)
+            .is_err());
+    }
+
+    #[test]
+    fn should_create_grid_in_bounds() {
+        let log = ParamDistribution::LogUniform {
+            low: 1e-4,
+            high: 1e-2,
+        };
+        let int = ParamDistribution::IntRange { low: 1, high: 3 };
+
+        let grid = log.grid(3);
+        assert_eq!(grid.len(), 3);
+        assert!((grid[1].as_f64().unwrap() - 1e-3).abs() < 1e-12);
+        assert_eq!(
+            int.grid(5),
+            vec![Value::from(1), Value::from(2), Value::from(3)]
+        );
+        assert_eq!(
+            int.value_at(int.position_of(&Value::from(2))),
+            Value::from(2)
+        );
+    }
+}
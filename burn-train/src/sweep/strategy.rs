@@ -0,0 +1,347 @@
+use super::{Assignment, ParamDistribution, SearchSpace};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use serde_json::Value;
+
+/// The parameters of a finished trial with its score, where lower scores are better.
+#[derive(new, Clone, Debug)]
+pub struct Observation {
+    /// The parameters of the trial.
+    pub params: Assignment,
+    /// The score of the trial, which is the objective negated when higher is better.
+    pub score: f64,
+}
+
+/// A strategy that suggests the parameters of the trials of a [sweep](super::Sweep).
+pub trait SearchStrategy: Send {
+    /// Suggest the parameters of the next trial from the trials finished so far, or `None` when
+    /// the search is exhausted.
+    fn suggest(&mut self, space: &SearchSpace, observations: &[Observation]) -> Option<Assignment>;
+}
+
+/// Try every combination of the values of the parameters, with ranges split into the given
+/// number of points.
+pub struct GridSearch {
+    num_points: usize,
+    index: usize,
+}
+
+impl GridSearch {
+    /// Creates the strategy, with the given number of points for each range.
+    pub fn new(num_points: usize) -> Self {
+        Self {
+            num_points,
+            index: 0,
+        }
+    }
+}
+
+impl SearchStrategy for GridSearch {
+    fn suggest(
+        &mut self,
+        space: &SearchSpace,
+        _observations: &[Observation],
+    ) -> Option<Assignment> {
+        let grids = space
+            .params()
+            .iter()
+            .map(|(key, distribution)| (key, distribution.grid(self.num_points)))
+            .collect::<Vec<_>>();
+        let num_combinations = grids
+            .iter()
+            .map(|(_, values)| values.len())
+            .product::<usize>();
+
+        if self.index >= num_combinations {
+            return None;
+        }
+
+        // The index of the combination is decomposed with the last parameter varying fastest.
+        let mut remaining = self.index;
+        let mut values = Vec::with_capacity(grids.len());
+        for (key, grid) in grids.iter().rev() {
+            values.push((key.to_string(), grid[remaining % grid.len()].clone()));
+            remaining /= grid.len();
+        }
+        values.reverse();
+        self.index += 1;
+
+        Some(Assignment::new(values))
+    }
+}
+
+/// Sample the parameters of each trial independently at random.
+pub struct RandomSearch {
+    rng: StdRng,
+}
+
+impl RandomSearch {
+    /// Creates the strategy with the seed of the random number generator.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl SearchStrategy for RandomSearch {
+    fn suggest(
+        &mut self,
+        space: &SearchSpace,
+        _observations: &[Observation],
+    ) -> Option<Assignment> {
+        Some(sample(space, &mut self.rng))
+    }
+}
+
+/// Bayesian optimization with a tree-structured Parzen estimator (TPE).
+///
+/// After a number of random trials, the finished trials are split into the best ones and the
+/// others, and the suggested parameters are the candidates sampled from the density of the best
+/// trials that maximize the ratio between the density of the best trials and the density of the
+/// others. Each parameter is modeled independently.
+pub struct BayesianSearch {
+    rng: StdRng,
+    num_startup_trials: usize,
+    num_candidates: usize,
+    gamma: f64,
+}
+
+impl BayesianSearch {
+    /// Creates the strategy with the seed of the random number generator.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: StdRng::seed_from_u64(seed),
+            num_startup_trials: 10,
+            num_candidates: 24,
+            gamma: 0.25,
+        }
+    }
+
+    /// The number of random trials before the parameters are suggested from the finished ones.
+    pub fn with_startup_trials(mut self, num_trials: usize) -> Self {
+        self.num_startup_trials = num_trials;
+        self
+    }
+
+    /// The number of candidates sampled for each parameter.
+    pub fn with_candidates(mut self, num_candidates: usize) -> Self {
+        self.num_candidates = num_candidates;
+        self
+    }
+
+    /// The fraction of the finished trials considered the best ones.
+    pub fn with_gamma(mut self, gamma: f64) -> Self {
+        self.gamma = gamma;
+        self
+    }
+
+    fn suggest_numeric(
+        &mut self,
+        key: &str,
+        distribution: &ParamDistribution,
+        good: &[&Observation],
+        bad: &[&Observation],
+    ) -> Value {
+        let positions = |observations: &[&Observation]| {
+            observations
+                .iter()
+                .filter_map(|observation| observation.params.get(key))
+                .map(|value| distribution.position_of(value))
+                .collect::<Vec<_>>()
+        };
+        let good = positions(good);
+        let bad = positions(bad);
+        let bandwidth = |positions: &[f64]| 1.0 / (positions.len() as f64 + 1.0).powf(0.2) / 4.0;
+        let (bandwidth_good, bandwidth_bad) = (bandwidth(&good), bandwidth(&bad));
+
+        let mut best = (f64::NEG_INFINITY, 0.5);
+        for _ in 0..self.num_candidates {
+            // Sample from the mixture of the uniform prior and the kernels of the best trials.
+            let index = self.rng.gen_range(0..good.len() + 1);
+            let candidate = match good.get(index) {
+                Some(center) => reflect(center + bandwidth_good * standard_normal(&mut self.rng)),
+                None => self.rng.gen::<f64>(),
+            };
+            let ratio = parzen_density(candidate, &good, bandwidth_good).ln()
+                - parzen_density(candidate, &bad, bandwidth_bad).ln();
+
+            if ratio > best.0 {
+                best = (ratio, candidate);
+            }
+        }
+
+        distribution.value_at(best.1)
+    }
+
+    fn suggest_choice(
+        &mut self,
+        key: &str,
+        values: &[Value],
+        good: &[&Observation],
+        bad: &[&Observation],
+    ) -> Value {
+        // The frequency of each value, with one prior observation of every value.
+        let frequencies = |observations: &[&Observation]| {
+            let mut counts = vec![1.0; values.len()];
+            for value in observations
+                .iter()
+                .filter_map(|observation| observation.params.get(key))
+            {
+                if let Some(index) = values.iter().position(|v| v == value) {
+                    counts[index] += 1.0;
+                }
+            }
+            let total = counts.iter().sum::<f64>();
+            counts
+                .into_iter()
+                .map(|count| count / total)
+                .collect::<Vec<_>>()
+        };
+        let good = frequencies(good);
+        let bad = frequencies(bad);
+
+        let mut best = (f64::NEG_INFINITY, 0);
+        for _ in 0..self.num_candidates {
+            let mut target = self.rng.gen::<f64>();
+            let mut index = values.len() - 1;
+            for (i, probability) in good.iter().enumerate() {
+                if target < *probability {
+                    index = i;
+                    break;
+                }
+                target -= probability;
+            }
+
+            let ratio = good[index].ln() - bad[index].ln();
+            if ratio > best.0 {
+                best = (ratio, index);
+            }
+        }
+
+        values[best.1].clone()
+    }
+}
+
+impl SearchStrategy for BayesianSearch {
+    fn suggest(&mut self, space: &SearchSpace, observations: &[Observation]) -> Option<Assignment> {
+        if observations.len() < self.num_startup_trials.max(2) {
+            return Some(sample(space, &mut self.rng));
+        }
+
+        let mut sorted = observations.iter().collect::<Vec<_>>();
+        sorted.sort_by(|a, b| a.score.total_cmp(&b.score));
+        let num_good =
+            ((self.gamma * sorted.len() as f64).ceil() as usize).clamp(1, sorted.len() - 1);
+        let (good, bad) = sorted.split_at(num_good);
+
+        let values = space
+            .params()
+            .iter()
+            .map(|(key, distribution)| {
+                let value = match distribution {
+                    ParamDistribution::Choice(values) => {
+                        self.suggest_choice(key, values, good, bad)
+                    }
+                    _ => self.suggest_numeric(key, distribution, good, bad),
+                };
+                (key.clone(), value)
+            })
+            .collect();
+
+        Some(Assignment::new(values))
+    }
+}
+
+fn sample(space: &SearchSpace, rng: &mut StdRng) -> Assignment {
+    let values = space
+        .params()
+        .iter()
+        .map(|(key, distribution)| (key.clone(), distribution.sample(rng)))
+        .collect();
+
+    Assignment::new(values)
+}
+
+/// The density at the position of the mixture of the uniform prior on `[0, 1]` and Gaussian
+/// kernels centered on the observed positions.
+fn parzen_density(position: f64, centers: &[f64], bandwidth: f64) -> f64 {
+    let normalization = bandwidth * (2.0 * std::f64::consts::PI).sqrt();
+    let mut density = 1.0;
+    for center in centers {
+        let distance = (position - center) / bandwidth;
+        density += (-0.5 * distance * distance).exp() / normalization;
+    }
+
+    density / (centers.len() + 1) as f64
+}
+
+/// Reflect the position into `[0, 1]`.
+fn reflect(position: f64) -> f64 {
+    let position = position.rem_euclid(2.0);
+    match position > 1.0 {
+        true => 2.0 - position,
+        false => position,
+    }
+}
+
+/// Sample from the standard normal distribution with the Box-Muller transform.
+fn standard_normal(rng: &mut StdRng) -> f64 {
+    let u1 = 1.0 - rng.gen::<f64>();
+    let u2 = rng.gen::<f64>();
+
+    (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_enumerate_grid() {
+        let space = SearchSpace::new()
+            .choice("a", vec![1, 2])
+            .uniform("b", 0.0, 1.0);
+        let mut strategy = GridSearch::new(3);
+
+        let mut assignments = Vec::new();
+        while let Some(assignment) = strategy.suggest(&space, &[]) {
+            assignments.push(assignment);
+        }
+
+        assert_eq!(assignments.len(), 6);
+        assert_eq!(assignments[1].get("a"), Some(&Value::from(1)));
+        assert_eq!(assignments[1].get("b"), Some(&Value::from(0.5)));
+        assert_eq!(assignments[5].get("a"), Some(&Value::from(2)));
+    }
+
+    #[test]
+    fn should_suggest_near_the_best_trials() {
+        let space = SearchSpace::new()
+            .uniform("x", 0.0, 1.0)
+            .choice("c", vec!["a", "b"]);
+        let mut random = RandomSearch::new(0);
+        let mut strategy = BayesianSearch::new(0).with_startup_trials(20);
+        let objective = |params: &Assignment| {
+            let x = params.get("x").unwrap().as_f64().unwrap();
+            let penalty = match params.get("c").unwrap().as_str() {
+                Some("a") => 0.0,
+                _ => 1.0,
+            };
+            (x - 0.8).abs() + penalty
+        };
+
+        let mut observations = Vec::new();
+        for _ in 0..20 {
+            let params = random.suggest(&space, &observations).unwrap();
+            observations.push(Observation::new(params.clone(), objective(&params)));
+        }
+        let suggestions = (0..10)
+            .map(|_| objective(&strategy.suggest(&space, &observations).unwrap()))
+            .collect::<Vec<_>>();
+
+        let mean = suggestions.iter().sum::<f64>() / suggestions.len() as f64;
+        let mean_random =
+            observations.iter().map(|o| o.score).sum::<f64>() / observations.len() as f64;
+        assert!(mean < mean_random, "{mean} >= {mean_random}");
+    }
+}
@@ -0,0 +1,206 @@
+use super::{Assignment, Objective, Pruner, SearchStrategy};
+use crate::metric::store::{Aggregate, Split};
+use crate::{Callback, CallbackContext, TrainingInterrupter};
+use burn_core::module::AutodiffModule;
+use burn_core::optim::Optimizer;
+use burn_core::tensor::backend::AutodiffBackend;
+use serde::Serialize;
+use std::sync::{Arc, Mutex};
+
+/// The status of a trial.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum TrialStatus {
+    /// The trial is running.
+    Running,
+    /// The trial finished.
+    Completed,
+    /// The trial was stopped early by the [pruner](Pruner).
+    Pruned,
+    /// The config of the trial is invalid or the trial panicked.
+    Failed,
+}
+
+/// The metrics of a trial at the end of an epoch.
+#[derive(Clone, Debug, Serialize)]
+pub struct EpochRecord {
+    /// The epoch.
+    pub epoch: usize,
+    /// The value of the objective.
+    pub objective: f64,
+    /// The values of the other metrics tracked by the sweep, when available.
+    pub metrics: Vec<(String, f64)>,
+}
+
+/// The parameters, status and metrics of a trial, saved in `trial.json` in its directory.
+#[derive(Clone, Debug, Serialize)]
+pub struct TrialRecord {
+    /// The id of the trial.
+    pub id: usize,
+    /// The parameters of the trial.
+    pub params: Assignment,
+    /// The status of the trial.
+    pub status: TrialStatus,
+    /// The best value of the objective over the epochs.
+    pub objective: Option<f64>,
+    /// The metrics of each epoch reported by the trial.
+    pub epochs: Vec<EpochRecord>,
+}
+
+impl TrialRecord {
+    pub(crate) fn new(id: usize, params: Assignment) -> Self {
+        Self {
+            id,
+            params,
+            status: TrialStatus::Running,
+            objective: None,
+            epochs: Vec::new(),
+        }
+    }
+}
+
+/// The state of a sweep shared by the trials running in parallel.
+pub(crate) struct SweepState {
+    pub(crate) strategy: Box<dyn SearchStrategy>,
+    pub(crate) num_started: usize,
+    pub(crate) records: Vec<TrialRecord>,
+}
+
+pub(crate) struct SweepShared {
+    pub(crate) state: Mutex<SweepState>,
+    pub(crate) objective: Objective,
+    pub(crate) metrics: Vec<(String, Aggregate, Split)>,
+    pub(crate) pruner: Option<Box<dyn Pruner>>,
+}
+
+impl SweepShared {
+    /// Record the metrics of an epoch of a trial, returning whether it should stop.
+    fn report(&self, id: usize, epoch: usize, objective: f64, metrics: Vec<(String, f64)>) -> bool {
+        let mut state = self.state.lock().expect("Can lock the sweep state.");
+
+        let record = &mut state.records[id];
+        record.epochs.push(EpochRecord {
+            epoch,
+            objective,
+            metrics,
+        });
+        record.objective = Some(match record.objective {
+            Some(best) => self.objective.best(best, objective),
+            None => objective,
+        });
+
+        let pruner = match &self.pruner {
+            Some(pruner) => pruner,
+            None => return false,
+        };
+
+        let scores = state
+            .records
+            .iter()
+            .map(|record| {
+                record
+                    .epochs
+                    .iter()
+                    .map(|epoch| self.objective.score(epoch.objective))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let others = scores
+            .iter()
+            .enumerate()
+            .filter(|(other, _)| *other != id)
+            .map(|(_, scores)| scores.as_slice())
+            .collect::<Vec<_>>();
+
+        let pruned = pruner.should_prune(&scores[id], &others);
+        if pruned {
+            log::info!("Pruning trial {id} after epoch {epoch}");
+            state.records[id].status = TrialStatus::Pruned;
+        }
+
+        pruned
+    }
+}
+
+/// A trial of a [sweep](super::Sweep), with its config and the device it runs on.
+pub struct Trial<C, D> {
+    /// The id of the trial.
+    pub id: usize,
+    /// The config of the trial, with the values of the parameters applied.
+    pub config: C,
+    /// The values of the parameters of the trial.
+    pub params: Assignment,
+    /// The device of the trial.
+    pub device: D,
+    pub(crate) directory: String,
+    pub(crate) shared: Arc<SweepShared>,
+}
+
+impl<C, D> Trial<C, D> {
+    /// The directory of the artifacts of the trial, to be used as the directory of its
+    /// [learner](crate::Learner).
+    pub fn directory(&self) -> &str {
+        &self.directory
+    }
+
+    /// Report the value of the objective at the end of an epoch when the trial isn't trained
+    /// with a [learner](crate::Learner), returning whether the trial should stop.
+    pub fn report(&self, epoch: usize, objective: f64) -> bool {
+        self.shared.report(self.id, epoch, objective, Vec::new())
+    }
+
+    /// The [callback](Callback) to register in the [learner](crate::LearnerBuilder::callback)
+    /// of the trial, which reports the metrics of each epoch from the event store and stops the
+    /// training with the interrupter of the learner when the trial is pruned.
+    pub fn callback(&self, interrupter: TrainingInterrupter) -> TrialCallback {
+        TrialCallback {
+            id: self.id,
+            shared: self.shared.clone(),
+            interrupter,
+        }
+    }
+}
+
+/// The [callback](Callback) reporting the metrics of a [trial](Trial).
+pub struct TrialCallback {
+    id: usize,
+    shared: Arc<SweepShared>,
+    interrupter: TrainingInterrupter,
+}
+
+impl<B, M, O> Callback<B, M, O> for TrialCallback
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B>,
+    O: Optimizer<M, B>,
+{
+    fn on_epoch_end(&mut self, context: &CallbackContext<'_, M, O>) {
+        let objective = &self.shared.objective;
+        let value = match context.store.find_metric(
+            &objective.name,
+            context.epoch,
+            objective.aggregate,
+            objective.split,
+        ) {
+            Some(value) => value,
+            None => {
+                log::warn!("Can't find the objective of trial {}.", self.id);
+                return;
+            }
+        };
+        let metrics = self
+            .shared
+            .metrics
+            .iter()
+            .filter_map(|(name, aggregate, split)| {
+                context
+                    .store
+                    .find_metric(name, context.epoch, *aggregate, *split)
+                    .map(|value| (name.clone(), value))
+            })
+            .collect();
+
+        if self.shared.report(self.id, context.epoch, value, metrics) {
+            self.interrupter.stop();
+        }
+    }
+}
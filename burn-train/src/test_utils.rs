@@ -0,0 +1,75 @@
+use crate::renderer::{MetricState, MetricsRenderer, TrainingProgress};
+use crate::{TrainOutput, TrainStep, TrainStepContext, ValidStep};
+use burn_core as burn;
+use burn_core::data::dataloader::batcher::Batcher;
+use burn_core::module::Module;
+use burn_core::nn::{Linear, LinearConfig};
+use burn_core::tensor::backend::{AutodiffBackend, Backend};
+use burn_core::tensor::{ElementConversion, Tensor};
+
+/// A linear model with the mean of its squared outputs as loss.
+#[derive(Module, Debug)]
+pub(crate) struct TestModel<B: Backend> {
+    pub(crate) linear: Linear<B>,
+}
+
+impl<B: Backend> TestModel<B> {
+    /// A model with two inputs, matching the items of the [test batcher](TestBatcher).
+    pub(crate) fn new(device: &B::Device) -> Self {
+        Self {
+            linear: LinearConfig::new(2, 1).init(device),
+        }
+    }
+
+    pub(crate) fn loss(&self, item: Tensor<B, 2>) -> Tensor<B, 1> {
+        self.linear.forward(item).powf_scalar(2.0).mean()
+    }
+}
+
+impl<B: AutodiffBackend> TrainStep<Tensor<B, 2>, f64> for TestModel<B> {
+    fn step(&self, item: Tensor<B, 2>) -> TrainOutput<f64> {
+        self.step_with_context(item, &TrainStepContext::default())
+    }
+
+    fn step_with_context(
+        &self,
+        item: Tensor<B, 2>,
+        context: &TrainStepContext,
+    ) -> TrainOutput<f64> {
+        let loss = self.loss(item);
+        let value = loss.clone().into_scalar().elem::<f64>();
+
+        TrainOutput::from_loss(self, loss, value, context)
+    }
+}
+
+impl<B: Backend> ValidStep<Tensor<B, 2>, f64> for TestModel<B> {
+    fn step(&self, item: Tensor<B, 2>) -> f64 {
+        self.loss(item).into_scalar().elem::<f64>()
+    }
+}
+
+/// Batch each value `x` as the row `[x, 1]`.
+#[derive(Clone)]
+pub(crate) struct TestBatcher;
+
+impl<B: Backend> Batcher<f32, Tensor<B, 2>> for TestBatcher {
+    fn batch(&self, items: Vec<f32>) -> Tensor<B, 2> {
+        let items = items
+            .into_iter()
+            .map(|item| Tensor::from_floats([[item, 1.0]], &Default::default()))
+            .collect();
+
+        Tensor::cat(items, 0)
+    }
+}
+
+/// A renderer ignoring everything.
+pub(crate) struct NoRenderer;
+
+impl MetricsRenderer for NoRenderer {
+    fn update_train(&mut self, _state: MetricState) {}
+    fn update_valid(&mut self, _state: MetricState) {}
+    fn render_train(&mut self, _item: TrainingProgress) {}
+    fn render_valid(&mut self, _item: TrainingProgress) {}
+}
@@ -4,7 +4,7 @@ use crate::{
     tensor::WgpuTensor,
     AutoGraphicsApi, GraphicsApi, WgpuDevice,
 };
-use burn_tensor::backend::Backend;
+use burn_tensor::backend::{Backend, MemoryUsage};
 use rand::{rngs::StdRng, SeedableRng};
 use std::{marker::PhantomData, sync::Mutex};
 
@@ -60,4 +60,8 @@ impl<G: GraphicsApi + 'static, F: FloatElement, I: IntElement> Backend for Wgpu<
         let client = compute_client::<G>(device);
         client.sync();
     }
+
+    fn memory_usage(device: &Self::Device) -> Option<MemoryUsage> {
+        Some(compute_client::<G>(device).memory_usage())
+    }
 }
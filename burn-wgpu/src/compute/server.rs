@@ -2,7 +2,7 @@ use super::{WgpuAutotuneKey, WgpuStorage, WorkGroup};
 use crate::kernel::SourceTemplate;
 use alloc::{borrow::Cow, sync::Arc};
 use burn_compute::{
-    memory_management::MemoryManagement,
+    memory_management::{MemoryManagement, MemoryUsage},
     server::{self, ComputeServer},
 };
 use burn_tensor::Reader;
@@ -344,4 +344,8 @@ where
 
         self.device.poll(wgpu::Maintain::Wait);
     }
+
+    fn memory_usage(&self) -> MemoryUsage {
+        self.memory_management.memory_usage()
+    }
 }
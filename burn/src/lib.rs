@@ -46,6 +46,7 @@
 //! - NdArray: Backend using the NdArray primitive as data structure
 //! - Autodiff: Backend decorator that brings backpropagation to any backend
 //! - Fusion: Backend decorator that brings kernel fusion to backends that support it
+//! - Profiler: Backend decorator that records the timings of the tensor operations
 //!
 //! ## Feature Flags
 //!
@@ -77,6 +78,7 @@
 //! - Backend decorators
 //!   - `autodiff`: Makes available the Autodiff backend
 //!   - `fusion`: Makes available the Fusion backend
+//!   - `profiler`: Makes available the Profiler backend, and the profiler callback when training
 //! - Others:
 //!   - `std`: Activates the standard library (deactivate for no_std)
 //!   - `experimental-named-tensor`: Enables named tensors (experimental)